@@ -0,0 +1,216 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides derive macros for the `normalize_interval` crate.
+//!
+//! These are re-exported by `normalize_interval` when its `derive` feature is
+//! enabled, and should be used from there.
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![warn(missing_docs)]
+#![warn(rust_2018_idioms)]
+#![warn(unused)]
+
+// Standard library imports.
+use proc_macro::Delimiter;
+use proc_macro::Spacing;
+use proc_macro::TokenStream;
+use proc_macro::TokenTree;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Finite
+////////////////////////////////////////////////////////////////////////////////
+/// Derives `Finite` for a struct with a single field whose type is `Finite`,
+/// forwarding each of its methods to the field.
+///
+/// The struct must not be generic. It must also implement `Ord` and `Clone` to
+/// be normalized.
+#[proc_macro_derive(Finite)]
+pub fn derive_finite(input: TokenStream) -> TokenStream {
+    match Newtype::parse(input) {
+        Ok(newtype) => newtype.finite_impl(),
+        Err(msg)    => compile_error(msg),
+    }
+    .parse()
+    .expect("derived tokens")
+}
+
+/// Returns the source of a `compile_error!` with the given message.
+fn compile_error(msg: &str) -> String {
+    format!("compile_error!({:?});", msg)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Newtype
+////////////////////////////////////////////////////////////////////////////////
+/// A struct with a single field.
+#[derive(Debug)]
+struct Newtype {
+    /// The name of the struct.
+    name: String,
+    /// The name of the field, or `None` for a tuple struct.
+    field: Option<String>,
+    /// The source of the field's type.
+    ty: String,
+}
+
+impl Newtype {
+    /// Parses a `Newtype` from a struct definition.
+    fn parse(input: TokenStream) -> Result<Self, &'static str> {
+        const NOT_NEWTYPE: &str
+            = "Finite can only be derived for structs with a single field";
+
+        let mut tokens = input.into_iter().peekable();
+        skip_attributes_and_visibility(&mut tokens);
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "struct" => (),
+            _ => return Err(NOT_NEWTYPE),
+        }
+        let name = match tokens.next() {
+            Some(TokenTree::Ident(ident)) => ident.to_string(),
+            _                             => return Err(NOT_NEWTYPE),
+        };
+        let body = match tokens.next() {
+            Some(TokenTree::Group(group)) => group,
+            Some(TokenTree::Punct(p)) if p.as_char() == '<' => return Err(
+                "Finite cannot be derived for generic structs"),
+            _ => return Err(NOT_NEWTYPE),
+        };
+
+        let mut fields = body.stream().into_iter().peekable();
+        skip_attributes_and_visibility(&mut fields);
+        let field = match body.delimiter() {
+            Delimiter::Parenthesis => None,
+            Delimiter::Brace       => {
+                let field = match fields.next() {
+                    Some(TokenTree::Ident(ident)) => ident.to_string(),
+                    _                             => return Err(NOT_NEWTYPE),
+                };
+                match fields.next() {
+                    Some(TokenTree::Punct(p)) if p.as_char() == ':' => (),
+                    _ => return Err(NOT_NEWTYPE),
+                }
+                Some(field)
+            },
+            _ => return Err(NOT_NEWTYPE),
+        };
+
+        // The field's type runs until a comma outside of any angle brackets,
+        // which may only be followed by the end of the fields.
+        let mut ty = TokenStream::new();
+        let mut depth = 0usize;
+        for token in &mut fields {
+            if let TokenTree::Punct(ref p) = token {
+                match p.as_char() {
+                    '<' => depth += 1,
+                    // The `>` of a `->` does not close an angle bracket.
+                    '>' if !ty.is_empty() && depth > 0 => depth -= 1,
+                    ',' if depth == 0 => break,
+                    _ => (),
+                }
+            }
+            ty.extend(Some(token));
+        }
+        if ty.is_empty() || fields.next().is_some() {
+            return Err(NOT_NEWTYPE);
+        }
+
+        Ok(Newtype { name, field, ty: ty.to_string() })
+    }
+
+    /// Returns the source of the `Finite` impl for the `Newtype`.
+    fn finite_impl(&self) -> String {
+        let Newtype { name, ty, .. } = self;
+        let (access, wrap) = match self.field {
+            Some(ref field) => (
+                format!("self.{}", field),
+                format!("|v| {} {{ {}: v }}", name, field)),
+            None => ("self.0".to_owned(), name.clone()),
+        };
+        let finite = "::normalize_interval::normalize::Finite";
+        let other = access.replacen("self", "other", 1);
+        let (minimum, maximum) = match self.field {
+            Some(ref field) => (
+                format!("{} {{ {}: <{} as {}>::MINIMUM }}",
+                    name, field, ty, finite),
+                format!("{} {{ {}: <{} as {}>::MAXIMUM }}",
+                    name, field, ty, finite)),
+            None => (
+                format!("{}(<{} as {}>::MINIMUM)", name, ty, finite),
+                format!("{}(<{} as {}>::MAXIMUM)", name, ty, finite)),
+        };
+
+        format!("
+            #[automatically_derived]
+            impl {finite} for {name} {{
+                const MINIMUM: Self = {minimum};
+                const MAXIMUM: Self = {maximum};
+
+                fn pred(&self) -> ::std::option::Option<Self> {{
+                    <{ty} as {finite}>::pred(&{access}).map({wrap})
+                }}
+
+                fn succ(&self) -> ::std::option::Option<Self> {{
+                    <{ty} as {finite}>::succ(&{access}).map({wrap})
+                }}
+
+                fn pred_n(&self, n: usize) -> ::std::option::Option<Self> {{
+                    <{ty} as {finite}>::pred_n(&{access}, n).map({wrap})
+                }}
+
+                fn succ_n(&self, n: usize) -> ::std::option::Option<Self> {{
+                    <{ty} as {finite}>::succ_n(&{access}, n).map({wrap})
+                }}
+
+                fn steps_between(&self, other: &Self)
+                    -> ::std::option::Option<usize>
+                {{
+                    <{ty} as {finite}>::steps_between(&{access}, &{other})
+                }}
+            }}",
+            finite = finite,
+            name = name,
+            ty = ty,
+            access = access,
+            other = other,
+            wrap = wrap,
+            minimum = minimum,
+            maximum = maximum)
+    }
+}
+
+/// Advances the given tokens past any outer attributes and visibility.
+fn skip_attributes_and_visibility<I>(tokens: &mut std::iter::Peekable<I>)
+    where I: Iterator<Item=TokenTree>
+{
+    loop {
+        match tokens.peek() {
+            // An attribute is a `#` followed by a bracketed group.
+            Some(TokenTree::Punct(p))
+                if p.as_char() == '#' && p.spacing() == Spacing::Alone =>
+            {
+                let _ = tokens.next();
+                let _ = tokens.next();
+            },
+            // A visibility is `pub`, optionally followed by a parenthesized
+            // group.
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {
+                let _ = tokens.next();
+                if let Some(TokenTree::Group(g)) = tokens.peek() {
+                    if g.delimiter() == Delimiter::Parenthesis {
+                        let _ = tokens.next();
+                    }
+                }
+            },
+            _ => return,
+        }
+    }
+}
@@ -0,0 +1,306 @@
+#!/usr/bin/env python3
+# Copyright 2018 Skylor R. Schermer.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+################################################################################
+#
+# Generates `src/unicode_tables.rs` from the Unicode Character Database.
+#
+# Usage:
+#
+#     python3 scripts/unicode_tables.py <ucd-directory> > src/unicode_tables.rs
+#
+# The UCD directory is an unpacked copy of `UCD.zip` from
+# https://www.unicode.org/Public/<version>/ucd/, and must contain the files
+# `PropertyValueAliases.txt`, `Scripts.txt`, `PropList.txt`,
+# `DerivedCoreProperties.txt`, and `extracted/DerivedGeneralCategory.txt`.
+#
+################################################################################
+
+import os
+import re
+import sys
+
+
+SURROGATES = range(0xD800, 0xE000)
+
+
+def ucd_lines(path):
+    """Yields the semicolon-separated fields of each data line in a UCD file."""
+    with open(path, encoding="utf-8") as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if line:
+                yield [field.strip() for field in line.split(";")]
+
+
+def ucd_version(path):
+    """Reads the UCD version from the header line of a UCD file."""
+    with open(path, encoding="utf-8") as f:
+        match = re.search(r"-(\d+\.\d+\.\d+)\.txt", f.readline())
+    if match is None:
+        sys.exit("unable to read UCD version from {}".format(path))
+    return match.group(1)
+
+
+def code_point_range(field):
+    """Parses a `XXXX` or `XXXX..YYYY` code point range."""
+    lo, _, hi = field.partition("..")
+    return (int(lo, 16), int(hi or lo, 16))
+
+
+def property_ranges(path, values=None):
+    """Returns the sorted, merged ranges for each value of a property file.
+
+    Surrogate code points are removed, as they are not valid `char`s.
+    """
+    ranges = {}
+    for fields in ucd_lines(path):
+        value = fields[1]
+        if values is not None and value not in values:
+            continue
+        lo, hi = code_point_range(fields[0])
+        for (lo, hi) in without_surrogates(lo, hi):
+            ranges.setdefault(value, []).append((lo, hi))
+    return {value: merged(rs) for (value, rs) in ranges.items()}
+
+
+def without_surrogates(lo, hi):
+    """Splits a code point range around the surrogate code points."""
+    if hi < SURROGATES.start or lo >= SURROGATES.stop:
+        return [(lo, hi)]
+    parts = []
+    if lo < SURROGATES.start:
+        parts.append((lo, SURROGATES.start - 1))
+    if hi >= SURROGATES.stop:
+        parts.append((SURROGATES.stop, hi))
+    return parts
+
+
+def merged(ranges):
+    """Sorts and merges adjacent or overlapping code point ranges."""
+    out = []
+    for (lo, hi) in sorted(ranges):
+        if out and lo <= out[-1][1] + 1:
+            out[-1] = (out[-1][0], max(out[-1][1], hi))
+        else:
+            out.append((lo, hi))
+    return out
+
+
+def value_aliases(path, prop):
+    """Returns the list of aliases for each value of the given property.
+
+    The first alias is the abbreviation and the second is the long name.
+    """
+    aliases = {}
+    for fields in ucd_lines(path):
+        if fields[0] == prop:
+            names = [name for name in fields[1:] if name]
+            for name in names:
+                aliases[name] = names
+    return aliases
+
+
+def loose(name):
+    """Normalizes a property value name for loose matching (UAX44-LM3)."""
+    name = re.sub(r"[ _-]", "", name).lower()
+    return name[2:] if name.startswith("is") else name
+
+
+def variant(long_name):
+    """Converts a UCD long name into a Rust enum variant name."""
+    return long_name.replace("_", "")
+
+
+def rust_char(cp):
+    return "'\\u{{{:X}}}'".format(cp)
+
+
+def wrapped(items, indent="    ", width=80):
+    """Formats items as comma-separated lines no wider than `width`."""
+    lines = []
+    line = indent
+    for item in items:
+        piece = item + ","
+        if len(line) + len(piece) + 1 > width and line.strip():
+            lines.append(line.rstrip())
+            line = indent
+        line += piece + " "
+    if line.strip():
+        lines.append(line.rstrip())
+    return "\n".join(lines)
+
+
+def banner(title):
+    return "/" * 80 + "\n// " + title + "\n" + "/" * 80
+
+
+def emit_enum(name, doc, values, aliases):
+    """Emits a property value enum with its names and loose-match table."""
+    out = []
+    out.append("/// " + doc)
+    out.append(
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]")
+    out.append("pub enum {} {{".format(name))
+    for value in values:
+        out.append("    /// `{}`: {}.".format(
+            aliases[value][0], aliases[value][1].replace("_", " ")))
+        out.append("    {},".format(variant(aliases[value][1])))
+    out.append("}")
+    out.append("")
+    out.append("impl {} {{".format(name))
+    out.append("    /// All `{}` values, in declaration order.".format(name))
+    out.append("    pub const ALL: [{}; {}] = [".format(name, len(values)))
+    out.append(wrapped(
+        ["{}::{}".format(name, variant(aliases[v][1])) for v in values],
+        indent="        "))
+    out.append("    ];")
+    out.append("")
+    out.append("    /// Returns the short UCD alias of the value.")
+    out.append("    pub fn abbreviation(&self) -> &'static str {")
+    out.append("        match *self {")
+    for value in values:
+        out.append("            {}::{} => \"{}\",".format(
+            name, variant(aliases[value][1]), aliases[value][0]))
+    out.append("        }")
+    out.append("    }")
+    out.append("")
+    out.append("    /// Returns the long UCD name of the value.")
+    out.append("    pub fn name(&self) -> &'static str {")
+    out.append("        match *self {")
+    for value in values:
+        out.append("            {}::{} => \"{}\",".format(
+            name, variant(aliases[value][1]), aliases[value][1]))
+    out.append("        }")
+    out.append("    }")
+    out.append("}")
+    out.append("")
+
+    keys = {}
+    for value in values:
+        for alias in aliases[value]:
+            key = loose(alias)
+            if keys.get(key, value) != value:
+                sys.exit("ambiguous {} alias {}".format(name, alias))
+            keys[key] = value
+    table = "{}_NAMES".format(re.sub(r"(?<!^)([A-Z])", r"_\1", name).upper())
+    out.append("// Loosely matched value names, sorted for binary search.")
+    out.append("pub(in crate) const {}: &[(&str, {})] = &[".format(table, name))
+    out.append(wrapped(
+        ["(\"{}\", {}::{})".format(key, name, variant(aliases[keys[key]][1]))
+            for key in sorted(keys)]))
+    out.append("];")
+    return "\n".join(out)
+
+
+def emit_value_table(const, name, ranges, aliases):
+    """Emits a code point range table tagged with property values."""
+    rows = sorted(
+        (lo, hi, value) for (value, rs) in ranges.items() for (lo, hi) in rs)
+    out = ["pub(in crate) const {}: &[(char, char, {})] = {{".format(
+        const, name)]
+    out.append("    use self::{}::*;".format(name))
+    out.append("    &[")
+    out.append(wrapped(
+        ["({}, {}, {})".format(
+            rust_char(lo), rust_char(hi), variant(aliases[value][1]))
+            for (lo, hi, value) in rows], indent="        "))
+    out.append("    ]")
+    out.append("};")
+    return "\n".join(out)
+
+
+def emit_range_table(const, ranges):
+    """Emits a plain code point range table."""
+    out = ["pub(in crate) const {}: &[(char, char)] = &[".format(const)]
+    out.append(wrapped(
+        ["({}, {})".format(rust_char(lo), rust_char(hi))
+            for (lo, hi) in ranges]))
+    out.append("];")
+    return "\n".join(out)
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: unicode_tables.py <ucd-directory>")
+    ucd = sys.argv[1]
+    gc_path = os.path.join(ucd, "extracted", "DerivedGeneralCategory.txt")
+    if not os.path.exists(gc_path):
+        gc_path = os.path.join(ucd, "DerivedGeneralCategory.txt")
+    aliases_path = os.path.join(ucd, "PropertyValueAliases.txt")
+    version = ucd_version(gc_path)
+
+    gc_aliases = value_aliases(aliases_path, "gc")
+    sc_aliases = value_aliases(aliases_path, "sc")
+    gc_ranges = property_ranges(gc_path)
+    sc_ranges = property_ranges(os.path.join(ucd, "Scripts.txt"))
+    white_space = property_ranges(
+        os.path.join(ucd, "PropList.txt"), {"White_Space"})["White_Space"]
+    alphabetic = property_ranges(
+        os.path.join(ucd, "DerivedCoreProperties.txt"),
+        {"Alphabetic"})["Alphabetic"]
+
+    # Categories are declared in alias file order, which groups them by their
+    # major class. Scripts are declared alphabetically by long name.
+    gc_values = []
+    for fields in ucd_lines(aliases_path):
+        if fields[0] == "gc" and re.match(r"^[A-Z][a-z]$", fields[1]) \
+                and fields[1] not in gc_values:
+            gc_values.append(fields[1])
+    sc_values = sorted(
+        set(sc_aliases[value][0] for value in sc_ranges),
+        key=lambda value: sc_aliases[value][1])
+    gc_ranges = {gc_aliases[v][0]: rs for (v, rs) in gc_ranges.items()}
+    sc_ranges = {sc_aliases[v][0]: rs for (v, rs) in sc_ranges.items()}
+
+    print("""\
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Unicode character property tables.
+//!
+//! Generated by `scripts/unicode_tables.py` from the Unicode Character
+//! Database, version {version}. Do not edit by hand.
+//!
+////////////////////////////////////////////////////////////////////////////////
+#![allow(clippy::unreadable_literal)]
+
+/// The version of the Unicode Character Database the tables were generated
+/// from.
+pub const UNICODE_VERSION: (u8, u8, u8) = ({major}, {minor}, {micro});
+
+""".format(version=version, major=version.split(".")[0],
+        minor=version.split(".")[1], micro=version.split(".")[2]))
+    print(banner("GeneralCategory"))
+    print(emit_enum("GeneralCategory", "A Unicode general category.",
+        gc_values, gc_aliases))
+    print()
+    print(emit_value_table("GENERAL_CATEGORY", "GeneralCategory",
+        gc_ranges, gc_aliases))
+    print()
+    print()
+    print(banner("Script"))
+    print(emit_enum("Script", "A Unicode script.", sc_values, sc_aliases))
+    print()
+    print(emit_value_table("SCRIPT", "Script", sc_ranges, sc_aliases))
+    print()
+    print()
+    print(banner("Binary properties"))
+    print(emit_range_table("WHITE_SPACE", white_space))
+    print()
+    print(emit_range_table("ALPHABETIC", alphabetic))
+
+
+if __name__ == "__main__":
+    main()
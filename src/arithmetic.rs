@@ -0,0 +1,423 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides interval arithmetic operators for numeric `Interval`s.
+//!
+//! Each operator returns the `Interval` of every result of the operation on a
+//! point from each operand. A bound of the result is included only if it is
+//! the result of included points, and is infinite if it is unbounded. Any
+//! operation on an empty `Interval` returns an empty `Interval`.
+//!
+//! The bounds are computed with [`CheckedArithmetic`], and results outside of
+//! the range of `T` are dropped. A bound which overflows away from the
+//! `Interval` becomes unbounded, which for `Finite` types saturates to the
+//! type's extreme. If a bound overflows toward the `Interval`, none of its
+//! results are representable and the result is empty.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::cmp::Ordering;
+use std::ops::Add;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CheckedArithmetic
+////////////////////////////////////////////////////////////////////////////////
+/// Provides checked arithmetic on the points of numeric `Interval`s.
+///
+/// Each method returns `None` if the result is not representable by the type.
+/// The zero of the type is taken to be its `Default` value.
+pub trait CheckedArithmetic: Sized {
+    /// Returns the sum of the points, or `None` on overflow.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Returns the difference of the points, or `None` on overflow.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// Returns the product of the points, or `None` on overflow.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Returns the negation of the point, or `None` on overflow.
+    fn checked_neg(&self) -> Option<Self>;
+}
+
+// Implements checked arithmetic for builtin integer types.
+macro_rules! std_integer_checked_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl CheckedArithmetic for $t {
+            fn checked_add(&self, other: &Self) -> Option<Self> {
+                $t::checked_add(*self, *other)
+            }
+
+            fn checked_sub(&self, other: &Self) -> Option<Self> {
+                $t::checked_sub(*self, *other)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                $t::checked_mul(*self, *other)
+            }
+
+            fn checked_neg(&self) -> Option<Self> {
+                $t::checked_neg(*self)
+            }
+        })*
+    };
+}
+
+// Provide implementations of CheckedArithmetic for builtin integer types.
+std_integer_checked_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Operator implementations
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Add for Interval<T>
+    where
+        T: Ord + Clone + Default + CheckedArithmetic,
+        RawInterval<T>: Normalize,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        match (bounds(&self), bounds(&other)) {
+            (Some((al, au)), Some((bl, bu))) => from_bounds(
+                combine(al, bl, Ordering::Less, try_add),
+                combine(au, bu, Ordering::Greater, try_add)),
+            _ => Interval::empty(),
+        }
+    }
+}
+
+impl<T> Sub for Interval<T>
+    where
+        T: Ord + Clone + Default + CheckedArithmetic,
+        RawInterval<T>: Normalize,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        match (bounds(&self), bounds(&other)) {
+            (Some((al, au)), Some((bl, bu))) => from_bounds(
+                combine(al, bu, Ordering::Less, try_sub),
+                combine(au, bl, Ordering::Greater, try_sub)),
+            _ => Interval::empty(),
+        }
+    }
+}
+
+impl<T> Neg for Interval<T>
+    where
+        T: Ord + Clone + Default + CheckedArithmetic,
+        RawInterval<T>: Normalize,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        match bounds(&self) {
+            Some((l, u)) => from_bounds(
+                checked_bound(u, Ordering::Less, try_neg),
+                checked_bound(l, Ordering::Greater, try_neg)),
+            None => Interval::empty(),
+        }
+    }
+}
+
+impl<T> Mul for Interval<T>
+    where
+        T: Ord + Clone + Default + CheckedArithmetic,
+        RawInterval<T>: Normalize,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let ((al, au), (bl, bu)) = match (bounds(&self), bounds(&other)) {
+            (Some(a), Some(b)) => (a, b),
+            _                  => return Interval::empty(),
+        };
+        let (al, au) = (End::lower(al), End::upper(au));
+        let (bl, bu) = (End::lower(bl), End::upper(bu));
+
+        // The extremes of the product are among the products of the bounds.
+        let products = [
+            al.product(&bl),
+            al.product(&bu),
+            au.product(&bl),
+            au.product(&bu),
+        ];
+        match (End::extreme(&products, Ordering::Less),
+            End::extreme(&products, Ordering::Greater))
+        {
+            // Every product overflowed past the same extreme.
+            (End::PosInf, _) | (_, End::NegInf) => Interval::empty(),
+            (lower, upper) => Interval::from(
+                RawInterval::new(lower.into(), upper.into())),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the bounds of the given `Interval`, or `None` if it is empty.
+fn bounds<T>(interval: &Interval<T>) -> Option<(Bound<T>, Bound<T>)>
+    where T: Ord + Clone
+{
+    Some((interval.0.lower_bound()?, interval.0.upper_bound()?))
+}
+
+/// Returns the `Interval` with the given bounds, or an empty `Interval` if
+/// either bound has no representable points.
+fn from_bounds<T>(lower: Option<Bound<T>>, upper: Option<Bound<T>>)
+    -> Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    match (lower, upper) {
+        (Some(l), Some(u)) => Interval::from(RawInterval::new(l, u)),
+        _                  => Interval::empty(),
+    }
+}
+
+/// Returns the sign of the given point.
+fn sign<T>(p: &T) -> Ordering where T: Ord + Default {
+    p.cmp(&T::default())
+}
+
+/// Returns the sum of the points, or the direction of the overflow.
+fn try_add<T>(a: T, b: &T) -> Result<T, Ordering>
+    where T: Ord + Default + CheckedArithmetic
+{
+    a.checked_add(b).ok_or_else(|| sign(b))
+}
+
+/// Returns the difference of the points, or the direction of the overflow.
+fn try_sub<T>(a: T, b: &T) -> Result<T, Ordering>
+    where T: Ord + Default + CheckedArithmetic
+{
+    a.checked_sub(b).ok_or_else(|| sign(b).reverse())
+}
+
+/// Returns the product of the points, or the direction of the overflow.
+fn try_mul<T>(a: T, b: &T) -> Result<T, Ordering>
+    where T: Ord + Default + CheckedArithmetic
+{
+    a.checked_mul(b).ok_or_else(|| match sign(&a) == sign(b) {
+        true  => Ordering::Greater,
+        false => Ordering::Less,
+    })
+}
+
+/// Returns the negation of the point, or the direction of the overflow.
+fn try_neg<T>(a: T) -> Result<T, Ordering>
+    where T: Ord + Default + CheckedArithmetic
+{
+    a.checked_neg().ok_or_else(|| sign(&a).reverse())
+}
+
+/// Applies the given operation to the point of a bound, where the bound lies
+/// on the given side of an `Interval`: `Ordering::Less` for a lower bound and
+/// `Ordering::Greater` for an upper bound. If the operation overflows away
+/// from the `Interval`, the bound becomes unbounded. If it overflows toward
+/// the `Interval`, `None` is returned, as the `Interval` has no representable
+/// points.
+fn checked_bound<T, F>(bound: Bound<T>, side: Ordering, f: F)
+    -> Option<Bound<T>>
+    where F: FnOnce(T) -> Result<T, Ordering>
+{
+    use Bound::*;
+    let (p, included) = match bound {
+        Include(p) => (p, true),
+        Exclude(p) => (p, false),
+        Infinite   => return Some(Infinite),
+    };
+    match f(p) {
+        Ok(p) if included       => Some(Include(p)),
+        Ok(p)                   => Some(Exclude(p)),
+        Err(dir) if dir == side => Some(Infinite),
+        Err(_)                  => None,
+    }
+}
+
+/// Combines two bounds on the given side with the given operation.
+fn combine<T, F>(a: Bound<T>, b: Bound<T>, side: Ordering, f: F)
+    -> Option<Bound<T>>
+    where F: FnOnce(T, &T) -> Result<T, Ordering>
+{
+    use Bound::*;
+    let (a, b) = match (a, b) {
+        (Include(a), Include(b)) => (Include(a), b),
+        (Include(a), Exclude(b))
+            | (Exclude(a), Include(b))
+            | (Exclude(a), Exclude(b)) => (Exclude(a), b),
+        _                        => return Some(Infinite),
+    };
+    checked_bound(a, side, |a| f(a, &b))
+}
+
+/// Returns the `Interval` of points `x` for which every point of `x + w` is
+/// in the `Interval` `c`.
+pub(in crate) fn erode<T>(c: &Interval<T>, w: &Interval<T>) -> Interval<T>
+    where
+        T: Ord + Clone + Default + CheckedArithmetic,
+        RawInterval<T>: Normalize,
+{
+    match (bounds(c), bounds(w)) {
+        (_, None)                        => Interval::full(),
+        (None, _)                        => Interval::empty(),
+        (Some((cl, cu)), Some((wl, wu))) => from_bounds(
+            uncombine(cl, wl, Ordering::Less),
+            uncombine(cu, wu, Ordering::Greater)),
+    }
+}
+
+/// Returns the bound `x` on the given side for which `x` combined with the
+/// bound `w` lies just within the bound `c`, or `None` if there is no such
+/// bound.
+fn uncombine<T>(c: Bound<T>, w: Bound<T>, side: Ordering) -> Option<Bound<T>>
+    where T: Ord + Default + CheckedArithmetic
+{
+    use Bound::*;
+    let (c, w) = match (c, w) {
+        // An excluded bound of `w` is never reached, so it may reach an
+        // excluded bound of `c`.
+        (Exclude(c), Include(w)) => (Exclude(c), w),
+        (Include(c), Include(w))
+            | (Include(c), Exclude(w))
+            | (Exclude(c), Exclude(w)) => (Include(c), w),
+        (Infinite, _)            => return Some(Infinite),
+        (_, Infinite)            => return None,
+    };
+    checked_bound(c, side, |c| try_sub(c, &w))
+}
+
+/// A bound of an `Interval` on the extended number line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum End<T> {
+    /// Negative infinity.
+    NegInf,
+    /// A finite point, and whether it is included.
+    Point(T, bool),
+    /// Positive infinity.
+    PosInf,
+}
+
+impl<T> End<T> where T: Ord + Clone + Default + CheckedArithmetic {
+    /// Returns the `End` for the given lower bound.
+    fn lower(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Include(p) => End::Point(p, true),
+            Bound::Exclude(p) => End::Point(p, false),
+            Bound::Infinite   => End::NegInf,
+        }
+    }
+
+    /// Returns the `End` for the given upper bound.
+    fn upper(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Include(p) => End::Point(p, true),
+            Bound::Exclude(p) => End::Point(p, false),
+            Bound::Infinite   => End::PosInf,
+        }
+    }
+
+    /// Returns the sign of the `End`.
+    fn signum(&self) -> i8 {
+        match self {
+            End::NegInf      => -1,
+            End::Point(p, _) => match p.cmp(&T::default()) {
+                Ordering::Less    => -1,
+                Ordering::Equal   => 0,
+                Ordering::Greater => 1,
+            },
+            End::PosInf      => 1,
+        }
+    }
+
+    /// Returns the product of two `End`s.
+    fn product(&self, other: &Self) -> Self {
+        use End::*;
+        match (self, other) {
+            // The product is included if both points are, or if either is an
+            // included zero, as zero times any point of the other operand is
+            // zero.
+            (Point(a, ai), Point(b, bi)) => {
+                let zero = T::default();
+                let included = (*ai && (*bi || *a == zero))
+                    || (*bi && *b == zero);
+                // A product past the range of `T` is taken to be infinite.
+                match try_mul(a.clone(), b) {
+                    Ok(p)                  => Point(p, included),
+                    Err(Ordering::Greater) => PosInf,
+                    Err(_)                 => NegInf,
+                }
+            },
+            // Zero times an unbounded operand is taken to be zero.
+            (Point(p, i), _) | (_, Point(p, i)) if *p == T::default()
+                => Point(T::default(), *i),
+            _ => match self.signum() * other.signum() {
+                s if s < 0 => NegInf,
+                _          => PosInf,
+            },
+        }
+    }
+
+    /// Compares the `End`s by their positions on the extended number line.
+    fn cmp_position(&self, other: &Self) -> Ordering {
+        use End::*;
+        match (self, other) {
+            (NegInf, NegInf) | (PosInf, PosInf) => Ordering::Equal,
+            (NegInf, _) | (_, PosInf)           => Ordering::Less,
+            (_, NegInf) | (PosInf, _)           => Ordering::Greater,
+            (Point(a, _), Point(b, _))          => a.cmp(b),
+        }
+    }
+
+    /// Returns the least `End` if given `Ordering::Less`, or the greatest if
+    /// given `Ordering::Greater`. The result includes its point if any of the
+    /// `End`s at the same position do.
+    fn extreme(ends: &[Self], side: Ordering) -> Self {
+        let mut res = ends[0].clone();
+        for end in &ends[1..] {
+            match (end.cmp_position(&res), &mut res, end) {
+                (Ordering::Equal, End::Point(_, ri), End::Point(_, ei))
+                    => *ri |= *ei,
+                (ord, _, _) if ord == side => res = end.clone(),
+                _ => (),
+            }
+        }
+        res
+    }
+}
+
+impl<T> From<End<T>> for Bound<T> {
+    fn from(end: End<T>) -> Self {
+        match end {
+            End::Point(p, true)  => Bound::Include(p),
+            End::Point(p, false) => Bound::Exclude(p),
+            _                    => Bound::Infinite,
+        }
+    }
+}
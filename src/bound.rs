@@ -13,6 +13,7 @@
 
 // Standard library imports.
 use std::borrow::Borrow;
+use std::cmp::Ordering;
 use std::default::Default;
 
 // Local enum shortcut.
@@ -185,6 +186,61 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
         }
     }
 
+    /// Converts from `&Bound<T>` to `Bound<&T>`, keeping the bound type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x: Bound<String> = Bound::Exclude("abc".to_string());
+    /// let len: Bound<usize> = x.as_bound_ref().map(|s| s.len());
+    ///
+    /// assert_eq!(len, Bound::Exclude(3));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn as_bound_ref(&self) -> Bound<&T> {
+        match *self {
+            Include(ref bound) => Include(bound),
+            Exclude(ref bound) => Exclude(bound),
+            Infinite           => Infinite,
+        }
+    }
+
+    /// Converts from `&mut Bound<T>` to `Bound<&mut T>`, keeping the bound
+    /// type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut x: Bound<i32> = Bound::Include(34);
+    /// if let Bound::Include(p) = x.as_bound_mut() {
+    ///     *p += 1;
+    /// }
+    ///
+    /// assert_eq!(x, Bound::Include(35));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn as_bound_mut(&mut self) -> Bound<&mut T> {
+        match *self {
+            Include(ref mut bound) => Include(bound),
+            Exclude(ref mut bound) => Exclude(bound),
+            Infinite               => Infinite,
+        }
+    }
+
     // Getting to contained values
     ////////////////////////////////////////////////////////////////////////////
 
@@ -517,6 +573,103 @@ impl<T> Bound<T> where T: PartialOrd + PartialEq + Clone {
 }
 
 
+impl<T> Bound<&T> where T: Clone {
+    /// Maps a `Bound<&T>` to a `Bound<T>` by cloning the contained point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x: Bound<i32> = Bound::Include(12);
+    /// let y: Bound<&i32> = x.as_bound_ref();
+    ///
+    /// assert_eq!(y.cloned(), x);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn cloned(self) -> Bound<T> {
+        match self {
+            Include(bound) => Include(bound.clone()),
+            Exclude(bound) => Exclude(bound.clone()),
+            Infinite       => Infinite,
+        }
+    }
+}
+
+impl<T> Bound<Option<T>> {
+    /// Transposes a `Bound` of an `Option` into an `Option` of a `Bound`.
+    ///
+    /// [`Infinite`] is mapped to `Some(Infinite)`, a point of `None` is
+    /// mapped to `None`, and a point of `Some(p)` is mapped to `Some` of a
+    /// `Bound` of `p` with the same bound type.
+    ///
+    /// [`Infinite`]: #variant.Infinite
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x: Bound<Option<i32>> = Bound::Exclude(Some(5));
+    /// assert_eq!(x.transpose(), Some(Bound::Exclude(5)));
+    ///
+    /// let x: Bound<Option<i32>> = Bound::Include(None);
+    /// assert_eq!(x.transpose(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Option<Bound<T>> {
+        match self {
+            Include(bound) => bound.map(Include),
+            Exclude(bound) => bound.map(Exclude),
+            Infinite       => Some(Infinite),
+        }
+    }
+}
+
+impl<T, E> Bound<Result<T, E>> {
+    /// Transposes a `Bound` of a `Result` into a `Result` of a `Bound`.
+    ///
+    /// [`Infinite`] is mapped to `Ok(Infinite)`, a point of `Err(e)` is
+    /// mapped to `Err(e)`, and a point of `Ok(p)` is mapped to `Ok` of a
+    /// `Bound` of `p` with the same bound type.
+    ///
+    /// [`Infinite`]: #variant.Infinite
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let x: Bound<&str> = Bound::Include("17");
+    /// let parsed = x.map(str::parse::<i32>).transpose()?;
+    ///
+    /// assert_eq!(parsed, Bound::Include(17));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn transpose(self) -> Result<Bound<T>, E> {
+        match self {
+            Include(bound) => bound.map(Include),
+            Exclude(bound) => bound.map(Exclude),
+            Infinite       => Ok(Infinite),
+        }
+    }
+}
+
 // Default `Bound` is closed.
 impl<T> Default for Bound<T> where T: Default {
     #[inline]
@@ -525,6 +678,18 @@ impl<T> Default for Bound<T> where T: Default {
     }
 }
 
+// Display using interval notation. Included points are bracketed, excluded
+// points are parenthesized.
+impl<T> std::fmt::Display for Bound<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Include(ref p) => write!(f, "[{}]", p),
+            Exclude(ref p) => write!(f, "({})", p),
+            Infinite       => write!(f, "∞"),
+        }
+    }
+}
+
 // `Bound`-from-point conversion.
 impl<T> From<T> for Bound<T> {
     #[inline]
@@ -532,3 +697,86 @@ impl<T> From<T> for Bound<T> {
         Include(t)
     }
 }
+
+// Conversion from the standard library's bound type.
+impl<T> From<std::ops::Bound<T>> for Bound<T> {
+    #[inline]
+    fn from(bound: std::ops::Bound<T>) -> Self {
+        match bound {
+            std::ops::Bound::Included(p) => Include(p),
+            std::ops::Bound::Excluded(p) => Exclude(p),
+            std::ops::Bound::Unbounded   => Infinite,
+        }
+    }
+}
+
+// Conversion into the standard library's bound type.
+impl<T> From<Bound<T>> for std::ops::Bound<T> {
+    #[inline]
+    fn from(bound: Bound<T>) -> Self {
+        match bound {
+            Include(p) => std::ops::Bound::Included(p),
+            Exclude(p) => std::ops::Bound::Excluded(p),
+            Infinite   => std::ops::Bound::Unbounded,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// LowerBound
+////////////////////////////////////////////////////////////////////////////////
+/// A lower `Bound` of an `Interval`, ordered by the points it includes. An
+/// `Include` bound is ordered before an `Exclude` bound at the same point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(in crate) struct LowerBound<T>(pub(in crate) Bound<T>);
+
+impl<T> PartialOrd for LowerBound<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Lower bound ordering is total.
+impl<T> Ord for LowerBound<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (&Infinite,       &Infinite)       => Ordering::Equal,
+            (&Infinite,       _)               => Ordering::Less,
+            (_,               &Infinite)       => Ordering::Greater,
+            (&Include(ref a), &Exclude(ref b)) => a.cmp(b).then(Ordering::Less),
+            (&Exclude(ref a), &Include(ref b))
+                => a.cmp(b).then(Ordering::Greater),
+            (&Include(ref a), &Include(ref b)) => a.cmp(b),
+            (&Exclude(ref a), &Exclude(ref b)) => a.cmp(b),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// UpperBound
+////////////////////////////////////////////////////////////////////////////////
+/// An upper `Bound` of an `Interval`, ordered by the points it includes. An
+/// `Exclude` bound is ordered before an `Include` bound at the same point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(in crate) struct UpperBound<T>(pub(in crate) Bound<T>);
+
+impl<T> PartialOrd for UpperBound<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Upper bound ordering is total.
+impl<T> Ord for UpperBound<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (&self.0, &other.0) {
+            (Infinite,   Infinite)   => Ordering::Equal,
+            (Infinite,   _)          => Ordering::Greater,
+            (_,          Infinite)   => Ordering::Less,
+            (Include(a), Exclude(b)) => a.cmp(b).then(Ordering::Greater),
+            (Exclude(a), Include(b)) => a.cmp(b).then(Ordering::Less),
+            (Include(a), Include(b)) => a.cmp(b),
+            (Exclude(a), Exclude(b)) => a.cmp(b),
+        }
+    }
+}
@@ -0,0 +1,529 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a bitset-backed selection of bytes.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::ops::BitAnd;
+use std::ops::BitAndAssign;
+use std::ops::BitOr;
+use std::ops::BitOrAssign;
+use std::ops::BitXor;
+use std::ops::BitXorAssign;
+use std::ops::Not;
+use std::ops::Sub;
+use std::ops::SubAssign;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ByteSelection
+////////////////////////////////////////////////////////////////////////////////
+/// A set of `u8` points, stored as a 256-bit bitset.
+///
+/// A `ByteSelection` holds the same sets as a `Selection<u8>`, and converts to
+/// and from one, but is stored in a fixed 32 bytes and performs each operation
+/// in constant time, however fragmented it is. This suits uses such as
+/// computing byte classes for a lexer, which perform many small unions.
+///
+/// The set operations are also available as the bitwise operators `|`, `&`,
+/// `^`, `-`, and `!`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::ByteSelection;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut ident = ByteSelection::new();
+/// ident.union_in_place(b'a'..=b'z');
+/// ident.union_in_place(b'A'..=b'Z');
+/// ident.union_in_place(b'_');
+/// let digit = ByteSelection::from(Interval::closed(b'0', b'9'));
+///
+/// let ident_continue = ident | digit;
+/// assert!(ident_continue.contains(&b'7'));
+/// assert_eq!(ident_continue.interval_count(), 4);
+///
+/// let sel: Selection<u8> = Selection::from(ident_continue);
+/// assert_eq!(ByteSelection::from(&sel), ident_continue);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ByteSelection([u64; 4]);
+
+impl ByteSelection {
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `ByteSelection`.
+    pub const fn new() -> Self {
+        ByteSelection([0; 4])
+    }
+
+    /// Constructs a new `ByteSelection` containing every byte.
+    pub const fn full() -> Self {
+        ByteSelection([u64::MAX; 4])
+    }
+
+    /// Constructs a new `ByteSelection` containing the given byte.
+    pub fn point(point: u8) -> Self {
+        let mut sel = ByteSelection::new();
+        let _ = sel.insert(point);
+        sel
+    }
+
+    /// Constructs a `ByteSelection` from its bitset, in which byte `n` is
+    /// given by bit `n % 64` of word `n / 64`.
+    pub const fn from_bits(bits: [u64; 4]) -> Self {
+        ByteSelection(bits)
+    }
+
+    /// Returns the bitset of the `ByteSelection`, in which byte `n` is given
+    /// by bit `n % 64` of word `n / 64`.
+    pub const fn to_bits(&self) -> [u64; 4] {
+        self.0
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `ByteSelection` contains no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    /// Returns `true` if the `ByteSelection` contains every byte.
+    pub fn is_full(&self) -> bool {
+        self.0 == [u64::MAX; 4]
+    }
+
+    /// Returns `true` if the `ByteSelection` contains the given byte.
+    pub fn contains(&self, point: &u8) -> bool {
+        let (word, bit) = position(*point);
+        self.0[word] & bit != 0
+    }
+
+    /// Returns the number of bytes in the `ByteSelection`.
+    pub fn point_count(&self) -> usize {
+        self.0.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns the number of disjoint `Interval`s in the `ByteSelection`.
+    pub fn interval_count(&self) -> usize {
+        // Count the bytes which are selected but whose predecessors are not.
+        let mut carry = 0;
+        let mut count = 0;
+        for &word in &self.0 {
+            let starts = word & !(word << 1 | carry);
+            count += starts.count_ones() as usize;
+            carry = word >> 63;
+        }
+        count
+    }
+
+    /// Returns the least byte in the `ByteSelection`, or `None` if it is
+    /// empty.
+    pub fn infimum(&self) -> Option<u8> {
+        self.iter().next()
+    }
+
+    /// Returns the greatest byte in the `ByteSelection`, or `None` if it is
+    /// empty.
+    pub fn supremum(&self) -> Option<u8> {
+        self.iter().next_back()
+    }
+
+    /// Returns `true` if every byte of the `ByteSelection` is in the other.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.minus(other).is_empty()
+    }
+
+    /// Returns `true` if every byte of the other `ByteSelection` is in this
+    /// one.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if the `ByteSelection`s have no bytes in common.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersect(other).is_empty()
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `ByteSelection` containing the bytes not in this one.
+    pub fn complement(&self) -> Self {
+        let [a, b, c, d] = self.0;
+        ByteSelection([!a, !b, !c, !d])
+    }
+
+    /// Returns a `ByteSelection` containing the bytes in either
+    /// `ByteSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Returns a `ByteSelection` containing the bytes in both
+    /// `ByteSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Returns a `ByteSelection` containing the bytes in this `ByteSelection`
+    /// which are not in the other.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & !b)
+    }
+
+    /// Returns a `ByteSelection` containing the bytes in exactly one of the
+    /// `ByteSelection`s.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Applies the given function to each pair of corresponding words.
+    fn zip_with<F>(&self, other: &Self, f: F) -> Self
+        where F: Fn(u64, u64) -> u64
+    {
+        let (a, b) = (self.0, other.0);
+        ByteSelection([f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]),
+            f(a[3], b[3])])
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Adds the given byte to the `ByteSelection`, returning `true` if it was
+    /// not already present.
+    pub fn insert(&mut self, point: u8) -> bool {
+        let (word, bit) = position(point);
+        let inserted = self.0[word] & bit == 0;
+        self.0[word] |= bit;
+        inserted
+    }
+
+    /// Removes the given byte from the `ByteSelection`, returning `true` if it
+    /// was present.
+    pub fn remove(&mut self, point: u8) -> bool {
+        let (word, bit) = position(point);
+        let removed = self.0[word] & bit != 0;
+        self.0[word] &= !bit;
+        removed
+    }
+
+    /// Adds the bytes of the given `Interval` to the `ByteSelection`.
+    pub fn union_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<u8>>
+    {
+        *self |= ByteSelection::from(interval.into());
+    }
+
+    /// Removes the bytes outside of the given `Interval` from the
+    /// `ByteSelection`.
+    pub fn intersect_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<u8>>
+    {
+        *self &= ByteSelection::from(interval.into());
+    }
+
+    /// Removes the bytes of the given `Interval` from the `ByteSelection`.
+    pub fn minus_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<u8>>
+    {
+        *self -= ByteSelection::from(interval.into());
+    }
+
+    /// Toggles the bytes of the given `Interval` in the `ByteSelection`.
+    pub fn symmetric_difference_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<u8>>
+    {
+        *self ^= ByteSelection::from(interval.into());
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the bytes in the `ByteSelection`, in ascending
+    /// order.
+    pub fn iter(&self) -> Iter {
+        Iter(self.0)
+    }
+
+    /// Returns an iterator over the disjoint `Interval`s of the
+    /// `ByteSelection`, in ascending order.
+    pub fn interval_iter(&self) -> IntervalIter {
+        IntervalIter { bits: *self, front: 0, back: 256 }
+    }
+}
+
+/// Returns the index of the word holding the given byte, and the mask of its
+/// bit within the word.
+fn position(point: u8) -> (usize, u64) {
+    (usize::from(point >> 6), 1 << (point & 63))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversions
+////////////////////////////////////////////////////////////////////////////////
+impl From<Interval<u8>> for ByteSelection {
+    fn from(interval: Interval<u8>) -> Self {
+        let (lower, upper) = match (interval.infimum(), interval.supremum()) {
+            (Some(l), Some(u)) => (usize::from(l), usize::from(u)),
+            _                  => return ByteSelection::new(),
+        };
+
+        let mut bits = [0; 4];
+        for (i, word) in bits.iter_mut().enumerate() {
+            // Clamp the interval to the bytes of the word.
+            let (start, end) = (i * 64, i * 64 + 63);
+            if lower > end || upper < start { continue; }
+            let low = lower.max(start) - start;
+            let high = upper.min(end) - start;
+            *word = (u64::MAX << low) & (u64::MAX >> (63 - high));
+        }
+        ByteSelection(bits)
+    }
+}
+
+impl From<&Selection<u8>> for ByteSelection {
+    fn from(selection: &Selection<u8>) -> Self {
+        selection.interval_iter().collect()
+    }
+}
+
+impl From<Selection<u8>> for ByteSelection {
+    fn from(selection: Selection<u8>) -> Self {
+        ByteSelection::from(&selection)
+    }
+}
+
+impl From<ByteSelection> for Selection<u8> {
+    fn from(selection: ByteSelection) -> Self {
+        Selection::from_sorted_disjoint(selection.interval_iter())
+    }
+}
+
+impl FromIterator<u8> for ByteSelection {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=u8> {
+        let mut sel = ByteSelection::new();
+        sel.extend(iter);
+        sel
+    }
+}
+
+impl FromIterator<Interval<u8>> for ByteSelection {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<u8>> {
+        let mut sel = ByteSelection::new();
+        sel.extend(iter);
+        sel
+    }
+}
+
+impl Extend<u8> for ByteSelection {
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=u8> {
+        for point in iter {
+            let _ = self.insert(point);
+        }
+    }
+}
+
+impl Extend<Interval<u8>> for ByteSelection {
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<u8>> {
+        for interval in iter {
+            self.union_in_place(interval);
+        }
+    }
+}
+
+impl IntoIterator for ByteSelection {
+    type Item = u8;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ByteSelection {
+    type Item = u8;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Display using the union notation of `Selection`.
+impl std::fmt::Display for ByteSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&Selection::from(*self), f)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Operators
+////////////////////////////////////////////////////////////////////////////////
+macro_rules! byte_selection_op_impl {
+    // For each operator trait, its assigning form, and the set operation...
+    ($($op:ident :: $f:ident,
+        $op_assign:ident :: $f_assign:ident => $set:ident);*) =>
+    {
+        $(impl $op for ByteSelection {
+            type Output = ByteSelection;
+
+            fn $f(self, other: Self) -> Self::Output {
+                self.$set(&other)
+            }
+        }
+
+        impl $op_assign for ByteSelection {
+            fn $f_assign(&mut self, other: Self) {
+                *self = self.$set(&other);
+            }
+        })*
+    };
+}
+
+byte_selection_op_impl![
+    BitOr::bitor, BitOrAssign::bitor_assign => union;
+    BitAnd::bitand, BitAndAssign::bitand_assign => intersect;
+    BitXor::bitxor, BitXorAssign::bitxor_assign => symmetric_difference;
+    Sub::sub, SubAssign::sub_assign => minus
+];
+
+impl Not for ByteSelection {
+    type Output = ByteSelection;
+
+    fn not(self) -> Self::Output {
+        self.complement()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// An iterator over the bytes of a `ByteSelection`. Returned by
+/// [`ByteSelection::iter`].
+///
+/// [`ByteSelection::iter`]: struct.ByteSelection.html#method.iter
+#[derive(Debug, Clone)]
+pub struct Iter([u64; 4]);
+
+impl Iterator for Iter {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (i, word) in self.0.iter_mut().enumerate() {
+            if *word != 0 {
+                let bit = word.trailing_zeros() as usize;
+                *word &= *word - 1;
+                return Some((i * 64 + bit) as u8);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = ByteSelection(self.0).point_count();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Iter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        for (i, word) in self.0.iter_mut().enumerate().rev() {
+            if *word != 0 {
+                let bit = 63 - word.leading_zeros() as usize;
+                *word &= !(1 << bit);
+                return Some((i * 64 + bit) as u8);
+            }
+        }
+        None
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl FusedIterator for Iter {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An iterator over the disjoint `Interval`s of a `ByteSelection`. Returned by
+/// [`ByteSelection::interval_iter`].
+///
+/// [`ByteSelection::interval_iter`]: struct.ByteSelection.html#method.interval_iter
+#[derive(Debug, Clone)]
+pub struct IntervalIter {
+    /// The bytes being iterated over.
+    bits: ByteSelection,
+    /// The least byte not yet iterated over from the front.
+    front: usize,
+    /// One past the greatest byte not yet iterated over from the back.
+    back: usize,
+}
+
+impl IntervalIter {
+    /// Returns `true` if the byte at the given position is selected.
+    fn selected(&self, position: usize) -> bool {
+        self.bits.contains(&(position as u8))
+    }
+}
+
+impl Iterator for IntervalIter {
+    type Item = Interval<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.front < self.back && !self.selected(self.front) {
+            self.front += 1;
+        }
+        if self.front == self.back { return None; }
+
+        let start = self.front;
+        while self.front < self.back && self.selected(self.front) {
+            self.front += 1;
+        }
+        Some(Interval::closed(start as u8, (self.front - 1) as u8))
+    }
+}
+
+impl DoubleEndedIterator for IntervalIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.back > self.front && !self.selected(self.back - 1) {
+            self.back -= 1;
+        }
+        if self.back == self.front { return None; }
+
+        let end = self.back - 1;
+        while self.back > self.front && self.selected(self.back - 1) {
+            self.back -= 1;
+        }
+        Some(Interval::closed(self.back as u8, end as u8))
+    }
+}
+
+impl FusedIterator for IntervalIter {}
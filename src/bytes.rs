@@ -0,0 +1,579 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a compact binary encoding for `Selection`s.
+//!
+//! An encoded [`Selection`] begins with a single format version byte, followed
+//! by the bounds of its intervals in ascending order. Each bound is written as
+//! a tag byte giving its kind, followed by the encoding of its point unless it
+//! is infinite.
+//!
+//! `Selection`s of integer types can also be written in a delta encoding,
+//! which is much smaller for large, fragmented selections. It begins with a
+//! single format byte, followed by two [LEB128] varints for each interval in
+//! ascending order: the number of points skipped since the end of the previous
+//! interval, and the number of points after the interval's lower bound. The
+//! delta encoding can be decoded incrementally using a [`DeltaDecoder`].
+//!
+//! [`Selection`]: ../selection/struct.Selection.html
+//! [`DeltaDecoder`]: struct.DeltaDecoder.html
+//! [LEB128]: https://en.wikipedia.org/wiki/LEB128
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::tine::Tine;
+
+// Standard library imports.
+use std::convert::TryFrom;
+use std::convert::TryInto;
+use std::io::Write;
+
+
+/// The version of the encoding written by `Selection::to_bytes`.
+pub(in crate) const FORMAT_VERSION: u8 = 1;
+
+/// The format byte of the delta encoding written by
+/// `Selection::to_delta_bytes`, which is distinct from every version of the
+/// standard encoding.
+pub(in crate) const DELTA_FORMAT: u8 = 0x81;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// PointBytes
+////////////////////////////////////////////////////////////////////////////////
+/// Provides a binary encoding for the points of a [`Selection`].
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+pub trait PointBytes: Sized {
+    /// Appends the encoding of the point to the given buffer.
+    fn write_bytes(&self, buf: &mut Vec<u8>);
+
+    /// Decodes a point from the start of the given bytes, returning it along
+    /// with the number of bytes read, or `None` if the bytes do not begin with
+    /// a valid encoding.
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)>;
+}
+
+macro_rules! std_integer_point_bytes_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl PointBytes for $t {
+            fn write_bytes(&self, buf: &mut Vec<u8>) {
+                buf.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+                const SIZE: usize = std::mem::size_of::<$t>();
+                let point = bytes.get(..SIZE)?.try_into().ok()?;
+                Some(($t::from_le_bytes(point), SIZE))
+            }
+        })*
+    };
+}
+
+std_integer_point_bytes_impl![
+    u8, u16, u32, u64, u128,
+    i8, i16, i32, i64, i128
+];
+
+// Pointer sized integers are written as 64 bits, so that the encoding does not
+// depend on the platform.
+impl PointBytes for usize {
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        (*self as u64).write_bytes(buf);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (point, read) = u64::read_bytes(bytes)?;
+        Some((usize::try_from(point).ok()?, read))
+    }
+}
+
+impl PointBytes for isize {
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        (*self as i64).write_bytes(buf);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (point, read) = i64::read_bytes(bytes)?;
+        Some((isize::try_from(point).ok()?, read))
+    }
+}
+
+impl PointBytes for char {
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        u32::from(*self).write_bytes(buf);
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (point, read) = u32::read_bytes(bytes)?;
+        Some((char::from_u32(point)?, read))
+    }
+}
+
+// Strings are written as their length in bytes followed by their UTF-8
+// encoding.
+impl PointBytes for String {
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        self.len().write_bytes(buf);
+        buf.extend_from_slice(self.as_bytes());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let (len, read) = usize::read_bytes(bytes)?;
+        let end = read.checked_add(len)?;
+        let text = std::str::from_utf8(bytes.get(read..end)?).ok()?;
+        Some((text.to_owned(), end))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// DeltaPoint
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the delta encoding for the points of a [`Selection`] over an
+/// integer type.
+///
+/// Points are written by their offset from the least point of the type, so
+/// that every type's offsets are ordered the same as its points.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+pub trait DeltaPoint: Finite {
+    /// Returns the point's offset from the least point of the type.
+    fn to_offset(&self) -> u128;
+
+    /// Returns the point at the given offset from the least point of the
+    /// type, or `None` if the offset is past the greatest point.
+    fn from_offset(offset: u128) -> Option<Self>;
+}
+
+macro_rules! std_unsigned_delta_point_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        impl DeltaPoint for $t {
+            fn to_offset(&self) -> u128 {
+                *self as u128
+            }
+
+            fn from_offset(offset: u128) -> Option<Self> {
+                $t::try_from(offset).ok()
+            }
+        })*
+    };
+}
+
+std_unsigned_delta_point_impl![u8, u16, u32, u64, u128, usize];
+
+// Signed integers are offset by flipping their sign bit, which maps their
+// least point to zero.
+macro_rules! std_signed_delta_point_impl {
+    // For each given type and its unsigned counterpart...
+    ($($t:ident => $u:ident),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        impl DeltaPoint for $t {
+            fn to_offset(&self) -> u128 {
+                ((*self as $u) ^ ($t::MIN as $u)) as u128
+            }
+
+            fn from_offset(offset: u128) -> Option<Self> {
+                let offset = $u::try_from(offset).ok()?;
+                Some((offset ^ ($t::MIN as $u)) as $t)
+            }
+        })*
+    };
+}
+
+std_signed_delta_point_impl![
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128,
+    isize => usize
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// DeltaDecoder
+////////////////////////////////////////////////////////////////////////////////
+/// Decodes a [`Selection`] from the delta encoding written by
+/// [`Selection::to_delta_bytes`] as its bytes arrive.
+///
+/// Bytes may be given to the decoder in pieces of any size, either through
+/// [`DeltaDecoder::feed`] or its `io::Write` impl. Each interval is added to
+/// the `Selection` as soon as it has been read, so that no more than one
+/// interval is held outside of it at a time. A decoder which has returned an
+/// error should be discarded.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+/// [`Selection::to_delta_bytes`]: ../selection/struct.Selection.html#method.to_delta_bytes
+/// [`DeltaDecoder::feed`]: #method.feed
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::bytes::DeltaDecoder;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: Selection<u64> = Selection::from(Interval::closed(0, 999));
+/// sel.union_in_place(Interval::closed(4096, 8191));
+/// let bytes = sel.to_delta_bytes();
+///
+/// let mut decoder = DeltaDecoder::new();
+/// for chunk in bytes.chunks(3) {
+///     decoder.feed(chunk)?;
+/// }
+/// assert_eq!(decoder.finish()?, sel);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeltaDecoder<T> {
+    /// The decoded intervals.
+    selection: Selection<T>,
+    /// The number of bytes read.
+    read: usize,
+    /// The bits of the varint being read.
+    varint: u128,
+    /// The number of bits of the varint read.
+    shift: u32,
+    /// The offset of the start of the varint being read.
+    start: usize,
+    /// The offset and point of the lower bound of the interval being read.
+    lower: Option<(u128, T)>,
+    /// The least offset of the next interval's lower bound, or `None` if the
+    /// previous interval ended at the greatest point.
+    next: Option<u128>,
+}
+
+impl<T> DeltaDecoder<T>
+    where
+        T: Ord + Clone + DeltaPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `DeltaDecoder` decoding into an empty `Selection`.
+    pub fn new() -> Self {
+        DeltaDecoder::with_selection(Selection::new())
+    }
+
+    /// Constructs a new `DeltaDecoder` which adds the decoded intervals to
+    /// the given `Selection`.
+    pub fn with_selection(selection: Selection<T>) -> Self {
+        DeltaDecoder {
+            selection,
+            read: 0,
+            varint: 0,
+            shift: 0,
+            start: 0,
+            lower: None,
+            next: Some(0),
+        }
+    }
+
+    /// Returns the `Selection` of the intervals decoded so far.
+    pub fn selection(&self) -> &Selection<T> {
+        &self.selection
+    }
+
+    /// Decodes the given bytes, which continue from the bytes given
+    /// previously.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if the bytes are not a valid encoding.
+    ///
+    /// [`FromBytesError`]: enum.FromBytesError.html
+    pub fn feed(&mut self, bytes: &[u8]) -> Result<(), FromBytesError> {
+        use FromBytesError::*;
+        for &byte in bytes {
+            let at = self.read;
+            self.read += 1;
+            if at == 0 {
+                if byte != DELTA_FORMAT {
+                    return Err(UnsupportedVersion(byte));
+                }
+                continue;
+            }
+
+            if self.shift == 0 { self.start = at; }
+            // Only the lowest two bits of the last byte of a `u128` varint
+            // are free.
+            if self.shift == 126 && byte > 0b11 {
+                return Err(InvalidPoint(self.start));
+            }
+            self.varint |= u128::from(byte & 0x7F) << self.shift;
+            if byte & 0x80 != 0 {
+                self.shift += 7;
+                continue;
+            }
+
+            let varint = std::mem::take(&mut self.varint);
+            self.shift = 0;
+            self.read_varint(varint)?;
+        }
+        Ok(())
+    }
+
+    /// Completes the decoding, returning the decoded `Selection`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FromBytesError::UnexpectedEnd`] if the bytes given ended
+    /// partway through the encoding.
+    ///
+    /// [`FromBytesError::UnexpectedEnd`]: enum.FromBytesError.html#variant.UnexpectedEnd
+    pub fn finish(self) -> Result<Selection<T>, FromBytesError> {
+        if self.read == 0 || self.shift > 0 || self.lower.is_some() {
+            return Err(FromBytesError::UnexpectedEnd);
+        }
+        Ok(self.selection)
+    }
+
+    /// Handles a complete varint, which is either the gap before an interval
+    /// or its length.
+    fn read_varint(&mut self, varint: u128) -> Result<(), FromBytesError> {
+        use FromBytesError::*;
+        match self.lower.take() {
+            None => {
+                let lower = self.next
+                    .and_then(|next| next.checked_add(varint))
+                    .ok_or(InvalidBound(self.start))?;
+                let point = T::from_offset(lower)
+                    .ok_or(InvalidPoint(self.start))?;
+                self.lower = Some((lower, point));
+            },
+            Some((lower, l)) => {
+                let upper = lower.checked_add(varint)
+                    .ok_or(InvalidBound(self.start))?;
+                let u = T::from_offset(upper)
+                    .ok_or(InvalidPoint(self.start))?;
+                self.selection.union_in_place(Interval::closed(l, u));
+                // Adjacent intervals are merged, so at least one point is
+                // skipped before the next.
+                self.next = upper.checked_add(2);
+            },
+        }
+        Ok(())
+    }
+}
+
+impl<T> Default for DeltaDecoder<T>
+    where
+        T: Ord + Clone + DeltaPoint,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        DeltaDecoder::new()
+    }
+}
+
+// Write bytes into the decoder, as from `std::io::copy`. Invalid encodings are
+// reported as `InvalidData` errors.
+impl<T> Write for DeltaDecoder<T>
+    where
+        T: Ord + Clone + DeltaPoint,
+        RawInterval<T>: Normalize,
+{
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        use std::io::Error;
+        use std::io::ErrorKind;
+        self.feed(buf)
+            .map(|_| buf.len())
+            .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FromBytesError
+////////////////////////////////////////////////////////////////////////////////
+/// An error which can be returned when decoding a [`Selection`] from bytes.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FromBytesError {
+    /// The bytes were written by an unsupported version of the encoding.
+    UnsupportedVersion(u8),
+    /// The bytes ended partway through a bound.
+    UnexpectedEnd,
+    /// A bound had an invalid tag byte at the given offset.
+    InvalidTag(usize),
+    /// A bound had an invalid point at the given offset.
+    InvalidPoint(usize),
+    /// The bound at the given offset was out of order or did not form an
+    /// interval with the bounds around it.
+    InvalidBound(usize),
+}
+
+impl std::fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use FromBytesError::*;
+        match *self {
+            UnsupportedVersion(v) => write!(f,
+                "unsupported selection encoding version {}", v),
+            UnexpectedEnd         => write!(f,
+                "unexpected end of selection encoding"),
+            InvalidTag(at)        => write!(f,
+                "invalid bound tag at offset {}", at),
+            InvalidPoint(at)      => write!(f,
+                "invalid bound point at offset {}", at),
+            InvalidBound(at)      => write!(f,
+                "invalid bound at offset {}", at),
+        }
+    }
+}
+
+impl std::error::Error for FromBytesError {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Tine encoding
+////////////////////////////////////////////////////////////////////////////////
+
+/// Appends the encoding of the given `Tine` to the buffer.
+pub(in crate) fn write_tine<T>(tine: &Tine<T>, buf: &mut Vec<u8>)
+    where T: PointBytes + Ord + Clone
+{
+    let kind = match *tine {
+        Tine::Lower(_) => 0,
+        Tine::Point(_) => 1,
+        Tine::Upper(_) => 2,
+    };
+    match tine.bound() {
+        Bound::Include(p) => { buf.push(kind << 2); p.write_bytes(buf); },
+        Bound::Exclude(p) => { buf.push(kind << 2 | 1); p.write_bytes(buf); },
+        Bound::Infinite   => buf.push(kind << 2 | 2),
+    }
+}
+
+/// Decodes the `RawInterval`s from the given encoded `Tine`s, validating that
+/// they are in order and form complete intervals.
+pub(in crate) fn read_intervals<T>(bytes: &[u8])
+    -> Result<Vec<RawInterval<T>>, FromBytesError>
+    where T: PointBytes + Ord + Clone
+{
+    use FromBytesError::*;
+    match bytes.first() {
+        Some(&FORMAT_VERSION) => (),
+        Some(&version)        => return Err(UnsupportedVersion(version)),
+        None                  => return Err(UnexpectedEnd),
+    }
+
+    let mut intervals = Vec::new();
+    let mut prev: Option<Tine<T>> = None;
+    let mut lower: Option<Bound<T>> = None;
+    let mut at = 1;
+    while at < bytes.len() {
+        let (tine, read) = read_tine(&bytes[at..], at)?;
+
+        // Each tine must follow the previous one, and an infinite bound may
+        // only start or end the selection.
+        let ordered = match (&prev, &tine) {
+            (Some(Tine::Upper(Bound::Infinite)), _) => false,
+            (_, Tine::Lower(Bound::Infinite))       => prev.is_none(),
+            (Some(prev), tine)                      => prev < tine,
+            (None, _)                               => true,
+        };
+        if !ordered { return Err(InvalidBound(at)); }
+
+        // Lower bounds must alternate with upper bounds, with point excludes
+        // acting as both.
+        lower = match (lower, &tine) {
+            (None, Tine::Point(Bound::Include(p))) => {
+                intervals.push(RawInterval::Point(p.clone()));
+                None
+            },
+            (None, Tine::Lower(l)) => Some(l.clone()),
+            (Some(l), Tine::Point(Bound::Exclude(p))) => {
+                intervals.push(RawInterval::new(l, Bound::Exclude(p.clone())));
+                Some(Bound::Exclude(p.clone()))
+            },
+            (Some(l), Tine::Upper(u)) => {
+                intervals.push(RawInterval::new(l, u.clone()));
+                None
+            },
+            _ => return Err(InvalidBound(at)),
+        };
+
+        prev = Some(tine);
+        at += read;
+    }
+
+    if lower.is_some() { return Err(UnexpectedEnd); }
+    Ok(intervals)
+}
+
+/// Decodes a `Tine` from the start of the given bytes, which begin at the
+/// given offset, returning it along with the number of bytes read.
+fn read_tine<T>(bytes: &[u8], at: usize)
+    -> Result<(Tine<T>, usize), FromBytesError>
+    where T: PointBytes
+{
+    use FromBytesError::*;
+    let tag = bytes[0];
+    let make_tine = match tag >> 2 {
+        0 => Tine::Lower,
+        1 => Tine::Point,
+        2 => Tine::Upper,
+        _ => return Err(InvalidTag(at)),
+    };
+    let make_bound = match tag & 0b11 {
+        0 => Bound::Include,
+        1 => Bound::Exclude,
+        2 if tag >> 2 != 1 => return Ok((make_tine(Bound::Infinite), 1)),
+        _ => return Err(InvalidTag(at)),
+    };
+
+    if bytes.len() == 1 { return Err(UnexpectedEnd); }
+    let (point, read) = T::read_bytes(&bytes[1..])
+        .ok_or(InvalidPoint(at + 1))?;
+    Ok((make_tine(make_bound(point)), 1 + read))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Delta encoding
+////////////////////////////////////////////////////////////////////////////////
+
+/// Appends the delta encoding of the interval with the given bounds to the
+/// buffer, where `next` is the least offset its lower bound could have. Updates
+/// `next` for the following interval.
+pub(in crate) fn write_delta_interval<T>(
+    lower: &T,
+    upper: &T,
+    next: &mut u128,
+    buf: &mut Vec<u8>)
+    where T: DeltaPoint
+{
+    let (lower, upper) = (lower.to_offset(), upper.to_offset());
+    write_varint(lower - *next, buf);
+    write_varint(upper - lower, buf);
+    *next = upper.saturating_add(2);
+}
+
+/// Appends the LEB128 encoding of the given value to the buffer.
+fn write_varint(mut value: u128, buf: &mut Vec<u8>) {
+    while value >= 0x80 {
+        buf.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
@@ -0,0 +1,270 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides normalization support for `chrono` date and time types.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// External library imports.
+use chrono::Days;
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NaiveDate
+////////////////////////////////////////////////////////////////////////////////
+
+// `NaiveDate`s are normalized at day granularity.
+impl Finite for NaiveDate {
+    const MINIMUM: NaiveDate = NaiveDate::MIN;
+    const MAXIMUM: NaiveDate = NaiveDate::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.pred_opt()
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.succ_opt()
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        self.checked_sub_days(Days::new(u64::try_from(n).ok()?))
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        self.checked_add_days(Days::new(u64::try_from(n).ok()?))
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        usize::try_from(other.signed_duration_since(*self).num_days()).ok()
+    }
+}
+
+impl Interval<NaiveDate> {
+    /// Constructs a new `Interval` of dates starting at the given date and
+    /// spanning the whole number of days in the given `Duration`. If the
+    /// `Duration` is shorter than one day, an empty `Interval` will be
+    /// returned. If the end of the `Interval` is past the maximum date, the
+    /// `Interval` will extend to the maximum date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use chrono::Duration;
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2020, 2, 27).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+    /// let interval = Interval::naive_date_span(start, Duration::days(4));
+    ///
+    /// assert_eq!(interval, Interval::closed(start, end));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn naive_date_span(start: NaiveDate, duration: Duration) -> Self {
+        if duration.num_days() <= 0 { return Interval::empty(); }
+        match start.checked_add_signed(duration) {
+            Some(end) => Interval::right_open(start, end),
+            None      => Interval::unbounded_from(start),
+        }
+    }
+
+    /// Returns the total `Duration` of the days in the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use chrono::Duration;
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2020, 2, 27).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+    /// let interval = Interval::closed(start, end);
+    ///
+    /// assert_eq!(interval.duration(), Duration::days(4));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn duration(&self) -> Duration {
+        match self.0 {
+            RawInterval::Empty         => Duration::zero(),
+            RawInterval::Point(_)      => Duration::days(1),
+            RawInterval::Closed(l, u)
+                => u.signed_duration_since(l) + Duration::days(1),
+            _ => unreachable!("duration for Finite interval with open bound"),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NaiveDateTime
+////////////////////////////////////////////////////////////////////////////////
+
+// `NaiveDateTime`s are normalized at nanosecond granularity. Leap seconds are
+// never reached by stepping.
+impl Finite for NaiveDateTime {
+    const MINIMUM: NaiveDateTime = NaiveDateTime::MIN;
+    const MAXIMUM: NaiveDateTime = NaiveDateTime::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.checked_sub_signed(Duration::nanoseconds(1))
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.checked_add_signed(Duration::nanoseconds(1))
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        let n = i64::try_from(n).ok()?;
+        self.checked_sub_signed(Duration::nanoseconds(n))
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        let n = i64::try_from(n).ok()?;
+        self.checked_add_signed(Duration::nanoseconds(n))
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        let steps = other.signed_duration_since(*self).num_nanoseconds()?;
+        usize::try_from(steps).ok()
+    }
+}
+
+impl Interval<NaiveDateTime> {
+    /// Constructs a new right-open `Interval` starting at the given time and
+    /// spanning the given `Duration`. If the `Duration` is not positive, an
+    /// empty `Interval` will be returned. If the end of the `Interval` is past
+    /// the maximum time, the `Interval` will extend to the maximum time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use chrono::Duration;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2020, 7, 18).unwrap();
+    /// let start = date.and_hms_opt(9, 0, 0).unwrap();
+    /// let end = date.and_hms_opt(10, 30, 0).unwrap();
+    /// let span = Duration::minutes(90);
+    /// let interval = Interval::naive_date_time_span(start, span);
+    ///
+    /// assert_eq!(interval, Interval::right_open(start, end));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn naive_date_time_span(start: NaiveDateTime, duration: Duration)
+        -> Self
+    {
+        if duration <= Duration::zero() { return Interval::empty(); }
+        match start.checked_add_signed(duration) {
+            Some(end) => Interval::right_open(start, end),
+            None      => Interval::unbounded_from(start),
+        }
+    }
+
+    /// Returns the total `Duration` spanned by the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use chrono::Duration;
+    /// use chrono::NaiveDate;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2020, 7, 18).unwrap();
+    /// let start = date.and_hms_opt(9, 0, 0).unwrap();
+    /// let end = date.and_hms_opt(10, 30, 0).unwrap();
+    /// let interval = Interval::right_open(start, end);
+    ///
+    /// assert_eq!(interval.duration(), Duration::minutes(90));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn duration(&self) -> Duration {
+        match self.0 {
+            RawInterval::Empty         => Duration::zero(),
+            RawInterval::Point(_)      => Duration::nanoseconds(1),
+            RawInterval::Closed(l, u)
+                => u.signed_duration_since(l) + Duration::nanoseconds(1),
+            _ => unreachable!("duration for Finite interval with open bound"),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Duration
+////////////////////////////////////////////////////////////////////////////////
+
+// `Duration`s are treated as dense, so their intervals are already in normal
+// form.
+impl Normalize for RawInterval<Duration> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+// `Duration` intervals are measured by the difference between their bounds.
+impl Measurable for Duration {
+    type Output = Duration;
+
+    fn empty_measure() -> Duration {
+        Duration::zero()
+    }
+
+    fn measure_between(lower: &Bound<Self>, upper: &Bound<Self>)
+        -> Option<Duration>
+    {
+        match (lower.as_ref(), upper.as_ref()) {
+            (Some(l), Some(u)) => u.checked_sub(l),
+            _                  => None,
+        }
+    }
+
+    fn checked_add_measures(a: Duration, b: Duration) -> Option<Duration> {
+        a.checked_add(&b)
+    }
+
+    fn measure_to_f64(measure: &Duration) -> f64 {
+        // Measures are never negative, so they convert to `std` durations.
+        measure.to_std().map_or(f64::NAN, |d| d.as_secs_f64())
+    }
+}
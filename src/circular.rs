@@ -0,0 +1,487 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides intervals over a circular domain which may wrap around the origin.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CircularInterval<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A contiguous arc of a circular domain of the type `T`.
+///
+/// The domain is given as an `Interval` whose upper end is considered to wrap
+/// around to its lower end. For continuous types, the domain should be right
+/// open (e.g. `[0, 360)` for angles in degrees,) and for [`Finite`] types it
+/// should be closed (e.g. `[0, 1439]` for minutes in a day.)
+///
+/// An arc whose start is greater than its end wraps around the origin of the
+/// domain, and is stored as the union of the two pieces on either side of
+/// it.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CircularInterval<T> {
+    /// The domain of the arc.
+    domain: Interval<T>,
+    /// The points of the domain covered by the arc.
+    arc: Selection<T>,
+}
+
+impl<T> CircularInterval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `CircularInterval` over the given domain, running from
+    /// the `start` bound upwards to the `end` bound. If the `start` point is
+    /// greater than the `end` point, the arc wraps around the origin of the
+    /// domain.
+    ///
+    /// Any part of the arc lying outside of the domain is discarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::circular::CircularInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let degrees = Interval::right_open(0, 360);
+    /// let arc = CircularInterval::new(degrees, Include(350), Exclude(10));
+    ///
+    /// assert!(arc.is_wrapping());
+    /// assert!(arc.contains(&355));
+    /// assert!(arc.contains(&5));
+    /// assert!(!arc.contains(&10));
+    /// assert!(!arc.contains(&180));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(domain: Interval<T>, start: Bound<T>, end: Bound<T>) -> Self {
+        let wraps = match (start.as_ref(), end.as_ref()) {
+            (Some(s), Some(e)) => s > e,
+            _                  => false,
+        };
+
+        let mut arc = Selection::new();
+        if wraps {
+            arc.union_in_place(Interval::new(start, Bound::Infinite));
+            arc.union_in_place(Interval::new(Bound::Infinite, end));
+        } else {
+            arc.union_in_place(Interval::new(start, end));
+        }
+        arc.intersect_in_place(domain.clone());
+
+        CircularInterval { domain, arc }
+    }
+
+    /// Constructs a new `CircularInterval` over the given domain, including
+    /// both the `start` and `end` points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::circular::CircularInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let hours = Interval::closed(0, 23);
+    /// let night = CircularInterval::closed(hours, 22, 5);
+    ///
+    /// assert!(night.contains(&23));
+    /// assert!(night.contains(&0));
+    /// assert!(!night.contains(&12));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn closed(domain: Interval<T>, start: T, end: T) -> Self {
+        CircularInterval::new(
+            domain,
+            Bound::Include(start),
+            Bound::Include(end))
+    }
+
+    /// Constructs a new `CircularInterval` over the given domain, including
+    /// the `start` point and excluding the `end` point.
+    pub fn right_open(domain: Interval<T>, start: T, end: T) -> Self {
+        CircularInterval::new(
+            domain,
+            Bound::Include(start),
+            Bound::Exclude(end))
+    }
+
+    /// Constructs a new empty `CircularInterval` over the given domain.
+    pub fn empty(domain: Interval<T>) -> Self {
+        CircularInterval { domain, arc: Selection::new() }
+    }
+
+    /// Constructs a new `CircularInterval` covering the whole of the given
+    /// domain.
+    pub fn full(domain: Interval<T>) -> Self {
+        let arc = domain.clone().into();
+        CircularInterval { domain, arc }
+    }
+
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the domain of the `CircularInterval`.
+    #[inline]
+    pub fn domain(&self) -> &Interval<T> {
+        &self.domain
+    }
+
+    /// Returns the [`Bound`] at which the arc begins, or `None` if the
+    /// `CircularInterval` is empty.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    pub fn start(&self) -> Option<Bound<T>> {
+        if self.is_wrapping() {
+            self.arc.interval_iter().last().and_then(|i| i.lower_bound())
+        } else {
+            self.arc.lower_bound()
+        }
+    }
+
+    /// Returns the [`Bound`] at which the arc ends, or `None` if the
+    /// `CircularInterval` is empty.
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    pub fn end(&self) -> Option<Bound<T>> {
+        if self.is_wrapping() {
+            self.arc.interval_iter().next().and_then(|i| i.upper_bound())
+        } else {
+            self.arc.upper_bound()
+        }
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `CircularInterval` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.arc.is_empty()
+    }
+
+    /// Returns `true` if the `CircularInterval` covers its entire domain.
+    pub fn is_full(&self) -> bool {
+        self.arc == self.domain
+    }
+
+    /// Returns `true` if the `CircularInterval` wraps around the origin of
+    /// its domain.
+    pub fn is_wrapping(&self) -> bool {
+        self.arc.interval_iter().nth(1).is_some()
+    }
+
+    /// Returns `true` if the `CircularInterval` contains the given point.
+    #[inline]
+    pub fn contains(&self, point: &T) -> bool {
+        self.arc.contains(point)
+    }
+
+    /// Returns `true` if the `CircularInterval` shares any point with the
+    /// given `CircularInterval`.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.arc.intersects_selection(&other.arc)
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `CircularInterval` containing all points of the domain not
+    /// contained in the `CircularInterval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::circular::CircularInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let hours = Interval::closed(0, 23);
+    /// let day = CircularInterval::closed(hours, 6, 21);
+    ///
+    /// assert_eq!(day.complement(), CircularInterval::closed(hours, 22, 5));
+    /// assert_eq!(day.complement().start(), Some(Include(22)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> Self {
+        CircularInterval {
+            domain: self.domain.clone(),
+            arc: Selection::from(self.domain.clone()).minus(&self.arc),
+        }
+    }
+
+    /// Returns the `CircularSelection` containing all points in both the
+    /// `CircularInterval` and the given `CircularInterval`.
+    ///
+    /// The intersection of two arcs may consist of two separate arcs, so the
+    /// result is a `CircularSelection`. Points of the given
+    /// `CircularInterval` outside of this one's domain are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::circular::CircularInterval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let hours = Interval::closed(0, 23);
+    /// let a = CircularInterval::closed(hours, 20, 10);
+    /// let b = CircularInterval::closed(hours, 8, 22);
+    ///
+    /// assert_eq!(a.intersect(&b).arcs().collect::<Vec<_>>(), [
+    ///     CircularInterval::closed(hours, 8, 10),
+    ///     CircularInterval::closed(hours, 20, 22),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> CircularSelection<T> {
+        CircularSelection::from(self.clone()).intersect(&other.clone().into())
+    }
+
+    /// Returns the `CircularSelection` containing all points in either the
+    /// `CircularInterval` or the given `CircularInterval`.
+    ///
+    /// Points of the given `CircularInterval` outside of this one's domain
+    /// are ignored.
+    pub fn union(&self, other: &Self) -> CircularSelection<T> {
+        CircularSelection::from(self.clone()).union(&other.clone().into())
+    }
+
+    /// Returns the `CircularSelection` containing all points in the
+    /// `CircularInterval` which are not in the given `CircularInterval`.
+    pub fn minus(&self, other: &Self) -> CircularSelection<T> {
+        CircularSelection::from(self.clone()).minus(&other.clone().into())
+    }
+
+    // Conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `Selection` containing the points of the `CircularInterval`.
+    pub fn to_selection(&self) -> Selection<T> {
+        self.arc.clone()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CircularSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of arcs of a circular domain of the
+/// type `T`.
+///
+/// Arcs which meet at the origin of the domain are treated as a single
+/// wrapping arc.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CircularSelection<T> {
+    /// The domain of the selection.
+    domain: Interval<T>,
+    /// The points of the domain covered by the selection.
+    selection: Selection<T>,
+}
+
+impl<T> CircularSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `CircularSelection` over the given domain.
+    pub fn new(domain: Interval<T>) -> Self {
+        CircularSelection { domain, selection: Selection::new() }
+    }
+
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the domain of the `CircularSelection`.
+    #[inline]
+    pub fn domain(&self) -> &Interval<T> {
+        &self.domain
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `CircularSelection` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.selection.is_empty()
+    }
+
+    /// Returns `true` if the `CircularSelection` covers its entire domain.
+    pub fn is_full(&self) -> bool {
+        self.selection == self.domain
+    }
+
+    /// Returns `true` if the `CircularSelection` contains the given point.
+    #[inline]
+    pub fn contains(&self, point: &T) -> bool {
+        self.selection.contains(point)
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `CircularSelection` containing all points of the domain
+    /// not contained in the `CircularSelection`.
+    pub fn complement(&self) -> Self {
+        CircularSelection {
+            domain: self.domain.clone(),
+            selection: Selection::from(self.domain.clone())
+                .minus(&self.selection),
+        }
+    }
+
+    /// Returns the `CircularSelection` containing all points in both the
+    /// `CircularSelection` and the given `CircularSelection`.
+    pub fn intersect(&self, other: &Self) -> Self {
+        self.with_selection(self.selection.intersect(&other.selection))
+    }
+
+    /// Returns the `CircularSelection` containing all points in either the
+    /// `CircularSelection` or the given `CircularSelection`. Points of the
+    /// given `CircularSelection` outside of this one's domain are ignored.
+    pub fn union(&self, other: &Self) -> Self {
+        self.with_selection(self.selection.union(&other.selection))
+    }
+
+    /// Returns the `CircularSelection` containing all points in the
+    /// `CircularSelection` which are not in the given `CircularSelection`.
+    pub fn minus(&self, other: &Self) -> Self {
+        self.with_selection(self.selection.minus(&other.selection))
+    }
+
+    /// Returns a `CircularSelection` over the same domain with the given
+    /// points, restricted to the domain.
+    fn with_selection(&self, mut selection: Selection<T>) -> Self {
+        selection.intersect_in_place(self.domain.clone());
+        CircularSelection { domain: self.domain.clone(), selection }
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Adds all points of the given `CircularInterval` to the
+    /// `CircularSelection`. Points outside of the domain are ignored.
+    pub fn union_in_place(&mut self, arc: &CircularInterval<T>) {
+        for interval in arc.arc.interval_iter() {
+            self.selection.union_in_place(interval);
+        }
+        self.selection.intersect_in_place(self.domain.clone());
+    }
+
+    /// Removes all points of the given `CircularInterval` from the
+    /// `CircularSelection`.
+    pub fn minus_in_place(&mut self, arc: &CircularInterval<T>) {
+        for interval in arc.arc.interval_iter() {
+            self.selection.minus_in_place(interval);
+        }
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the maximal arcs of the `CircularSelection`,
+    /// in order of their lowest point. An arc which wraps around the origin
+    /// is returned last.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::circular::CircularInterval;
+    /// # use normalize_interval::circular::CircularSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let hours = Interval::closed(0, 23);
+    /// let mut sel = CircularSelection::new(hours);
+    /// sel.union_in_place(&CircularInterval::closed(hours, 0, 2));
+    /// sel.union_in_place(&CircularInterval::closed(hours, 10, 12));
+    /// sel.union_in_place(&CircularInterval::closed(hours, 22, 23));
+    ///
+    /// assert_eq!(sel.arcs().collect::<Vec<_>>(), [
+    ///     CircularInterval::closed(hours, 10, 12),
+    ///     CircularInterval::closed(hours, 22, 2),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn arcs(&self) -> impl Iterator<Item=CircularInterval<T>> + '_ {
+        let mut intervals: Vec<_> = self.selection.interval_iter().collect();
+        let mut wrapped = None;
+        if intervals.len() > 1
+            && intervals[0].lower_bound() == self.domain.lower_bound()
+            && intervals[intervals.len() - 1].upper_bound()
+                == self.domain.upper_bound()
+        {
+            let first = intervals.remove(0);
+            let last = intervals.pop().expect("nonempty intervals");
+            let mut arc = Selection::from(first);
+            arc.union_in_place(last);
+            wrapped = Some(arc);
+        }
+
+        intervals
+            .into_iter()
+            .map(Selection::from)
+            .chain(wrapped)
+            .map(move |arc| CircularInterval {
+                domain: self.domain.clone(),
+                arc,
+            })
+    }
+
+    /// Returns a `Selection` containing the points of the
+    /// `CircularSelection`.
+    pub fn to_selection(&self) -> Selection<T> {
+        self.selection.clone()
+    }
+}
+
+impl<T> From<CircularInterval<T>> for CircularSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(arc: CircularInterval<T>) -> Self {
+        CircularSelection { domain: arc.domain, selection: arc.arc }
+    }
+}
@@ -0,0 +1,253 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides normalization support and numeric helpers for `rust_decimal`
+//! intervals.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::arithmetic::CheckedArithmetic;
+use crate::bound::Bound;
+use crate::bytes::PointBytes;
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// External library imports.
+use rust_decimal::Decimal;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::prelude::ToPrimitive;
+
+// Standard library imports.
+use std::convert::TryInto;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Normalize implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// `Decimal`s are dense, so their intervals are already in normal form.
+impl Normalize for RawInterval<Decimal> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CheckedArithmetic implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// `Decimal` negation only flips the sign, so it never overflows.
+impl CheckedArithmetic for Decimal {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        Decimal::checked_add(*self, *other)
+    }
+
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Decimal::checked_sub(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Decimal::checked_mul(*self, *other)
+    }
+
+    fn checked_neg(&self) -> Option<Self> {
+        Some(-*self)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// PointBytes implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// `Decimal`s are written in their fixed 16 byte serialized form.
+impl PointBytes for Decimal {
+    fn write_bytes(&self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.serialize());
+    }
+
+    fn read_bytes(bytes: &[u8]) -> Option<(Self, usize)> {
+        let point = bytes.get(..16)?.try_into().ok()?;
+        Some((Decimal::deserialize(point), 16))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Measurable implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// `Decimal` intervals are measured by the difference between their bounds.
+impl Measurable for Decimal {
+    type Output = Decimal;
+
+    fn empty_measure() -> Decimal {
+        Decimal::ZERO
+    }
+
+    fn measure_between(lower: &Bound<Self>, upper: &Bound<Self>)
+        -> Option<Decimal>
+    {
+        match (lower.as_ref(), upper.as_ref()) {
+            (Some(l), Some(u)) => Decimal::checked_sub(*u, *l),
+            _                  => None,
+        }
+    }
+
+    fn checked_add_measures(a: Decimal, b: Decimal) -> Option<Decimal> {
+        a.checked_add(b)
+    }
+
+    fn measure_to_f64(measure: &Decimal) -> f64 {
+        // Every `Decimal` is within the range of `f64`.
+        measure.to_f64().unwrap_or(f64::NAN)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Numeric helpers
+////////////////////////////////////////////////////////////////////////////////
+
+// Applies a checked operation to the point of a `Bound`, returning `None` if
+// the operation fails.
+fn checked_bound_map<F>(bound: Bound<Decimal>, f: F) -> Option<Bound<Decimal>>
+    where F: FnOnce(Decimal) -> Option<Decimal>
+{
+    match bound {
+        Bound::Include(p) => f(p).map(Bound::Include),
+        Bound::Exclude(p) => f(p).map(Bound::Exclude),
+        Bound::Infinite   => Some(Bound::Infinite),
+    }
+}
+
+impl Interval<Decimal> {
+    /// Returns the `Interval` shifted by the given offset, or `None` if either
+    /// bound overflows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use rust_decimal::Decimal;
+    ///
+    /// let interval = Interval::left_open(Decimal::new(1, 1), Decimal::ONE);
+    ///
+    /// assert_eq!(interval.translate(Decimal::new(-5, 1)),
+    ///     Some(Interval::left_open(Decimal::new(-4, 1), Decimal::new(5, 1))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn translate(&self, offset: Decimal) -> Option<Self> {
+        let (l, u) = match (self.0.lower_bound(), self.0.upper_bound()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Some(Interval::empty()),
+        };
+        Some(Interval::from(RawInterval::new(
+            checked_bound_map(l, |p| p.checked_add(offset))?,
+            checked_bound_map(u, |p| p.checked_add(offset))?)))
+    }
+
+    /// Returns the `Interval` with each point multiplied by the given factor,
+    /// or `None` if either bound overflows. Negative factors reverse the
+    /// bounds, and a zero factor collapses any non-empty `Interval` to zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use rust_decimal::Decimal;
+    ///
+    /// let interval = Interval::right_open(Decimal::ONE, Decimal::TWO);
+    ///
+    /// assert_eq!(interval.scale(Decimal::new(-15, 1)), Some(
+    ///     Interval::left_open(Decimal::new(-30, 1), Decimal::new(-15, 1))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn scale(&self, factor: Decimal) -> Option<Self> {
+        let (l, u) = match (self.0.lower_bound(), self.0.upper_bound()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Some(Interval::empty()),
+        };
+        if factor.is_zero() { return Some(Interval::point(Decimal::ZERO)); }
+        let (l, u) = if factor.is_sign_negative() { (u, l) } else { (l, u) };
+        Some(Interval::from(RawInterval::new(
+            checked_bound_map(l, |p| p.checked_mul(factor))?,
+            checked_bound_map(u, |p| p.checked_mul(factor))?)))
+    }
+
+    /// Returns the point at the fraction `t` of the way from the `Interval`'s
+    /// infimum to its supremum. `t` is clamped to `[0, 1]`.
+    ///
+    /// Returns `None` if the `Interval` is empty or unbounded, or `t` is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use rust_decimal::Decimal;
+    ///
+    /// let interval = Interval::closed(Decimal::ONE, Decimal::new(3, 0));
+    ///
+    /// assert_eq!(interval.lerp(0.25), Some(Decimal::new(15, 1)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn lerp(&self, t: f64) -> Option<Decimal> {
+        if t.is_nan() { return None; }
+        let (l, u) = (self.infimum()?, self.supremum()?);
+        let t = Decimal::from_f64(t.clamp(0.0, 1.0))?;
+        l.checked_add(u.checked_sub(l)?.checked_mul(t)?)
+    }
+
+    /// Returns the fraction of the way the given point lies from the
+    /// `Interval`'s infimum to its supremum, or `None` if the point is not in
+    /// the `Interval` or the `Interval` is unbounded. The only point of a
+    /// single-point `Interval` lies at zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use rust_decimal::Decimal;
+    ///
+    /// let interval = Interval::closed(Decimal::ONE, Decimal::new(3, 0));
+    ///
+    /// assert_eq!(interval.fraction_of(&Decimal::new(15, 1)), Some(0.25));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn fraction_of(&self, point: &Decimal) -> Option<f64> {
+        if !self.contains(point) { return None; }
+        let (l, u) = (self.infimum()?, self.supremum()?);
+        let width = u.checked_sub(l)?;
+        if width.is_zero() { return Some(0.0); }
+        Decimal::checked_sub(*point, l)?.checked_div(width)?.to_f64()
+    }
+}
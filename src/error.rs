@@ -0,0 +1,75 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides the error type returned by the non-panicking `try_` operations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalError
+////////////////////////////////////////////////////////////////////////////////
+/// An error which can be returned by an `Interval` or `Selection` operation
+/// which would otherwise panic.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Bound;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::IntervalError;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let res = Interval::try_new(Bound::Include(7), Bound::Include(-7));
+/// assert_eq!(res, Err(IntervalError::InvertedBounds));
+///
+/// let interval: Interval<i32> = Interval::closed(0, 10);
+/// assert_eq!(interval.try_iter_step(0).err(), Some(IntervalError::ZeroStep));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntervalError {
+    /// The lower bound's point was greater than the upper bound's point.
+    InvertedBounds,
+    /// A bound was infinite where a finite bound was required.
+    InfiniteBound,
+    /// An iteration step of zero was given.
+    ZeroStep,
+    /// The number of points was too large to represent.
+    TooManyPoints,
+    /// The intervals were not sorted by their lower bounds.
+    UnsortedIntervals,
+    /// An upper bound was the maximum point, which has no successor to use as
+    /// an exclusive bound.
+    BoundOverflow,
+}
+
+impl std::fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use IntervalError::*;
+        match *self {
+            InvertedBounds    => write!(f,
+                "interval lower bound is greater than its upper bound"),
+            InfiniteBound     => write!(f,
+                "infinite bound where a finite bound is required"),
+            ZeroStep          => write!(f, "step of zero"),
+            TooManyPoints     => write!(f,
+                "number of points is too large to represent"),
+            UnsortedIntervals => write!(f,
+                "intervals are not sorted by their lower bounds"),
+            BoundOverflow     => write!(f,
+                "upper bound has no successor to use as an exclusive bound"),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
@@ -0,0 +1,316 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides configurable formatting of `Interval`s and `Selection`s.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// BoundStyle
+////////////////////////////////////////////////////////////////////////////////
+/// Determines which bounds of a normalized `Interval` are written excluded.
+///
+/// Intervals of [`Finite`] types are normalized to closed bounds, which can be
+/// written in an equivalent excluded form by stepping outward from them. A
+/// bound at the extreme of the type has no point beyond it, and so is written
+/// as infinite instead. Intervals of other types are always written as they
+/// are.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BoundStyle {
+    /// Both bounds are written as they are normalized.
+    Normalized,
+    /// The lower bound is written excluded.
+    LeftOpen,
+    /// The upper bound is written excluded.
+    RightOpen,
+    /// Both bounds are written excluded.
+    Open,
+}
+
+impl BoundStyle {
+    /// Returns `true` if the lower bound is written excluded.
+    fn lower_open(self) -> bool {
+        self == BoundStyle::LeftOpen || self == BoundStyle::Open
+    }
+
+    /// Returns `true` if the upper bound is written excluded.
+    fn upper_open(self) -> bool {
+        self == BoundStyle::RightOpen || self == BoundStyle::Open
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalFormat
+////////////////////////////////////////////////////////////////////////////////
+/// Options for writing `Interval`s and `Selection`s in interval notation.
+///
+/// The default options match the `Display` impls of `Interval` and
+/// `Selection`.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::format::BoundStyle;
+/// # use normalize_interval::format::IntervalFormat;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: Selection<i32> = Selection::from(Interval::closed(1, 4));
+/// sel.union_in_place(Interval::unbounded_from(9));
+///
+/// let format = IntervalFormat::ascii()
+///     .bound_style(BoundStyle::RightOpen);
+/// assert_eq!(format.selection(&sel).to_string(), "[1, 5) U [9, inf)");
+///
+/// let format = IntervalFormat::new()
+///     .exclude_delimiters("]", "[")
+///     .separator("; ")
+///     .bound_style(BoundStyle::Open);
+/// assert_eq!(format.interval(&Interval::closed(1, 4)).to_string(),
+///     "]0; 5[");
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalFormat {
+    infinity: String,
+    empty: String,
+    union: String,
+    separator: String,
+    include: (String, String),
+    exclude: (String, String),
+    point: (String, String),
+    bound_style: BoundStyle,
+}
+
+impl IntervalFormat {
+    /// Constructs a new `IntervalFormat` using Unicode symbols.
+    pub fn new() -> Self {
+        IntervalFormat {
+            infinity: "∞".into(),
+            empty: "∅".into(),
+            union: " ∪ ".into(),
+            separator: ", ".into(),
+            include: ("[".into(), "]".into()),
+            exclude: ("(".into(), ")".into()),
+            point: ("{".into(), "}".into()),
+            bound_style: BoundStyle::Normalized,
+        }
+    }
+
+    /// Constructs a new `IntervalFormat` using only ASCII symbols.
+    pub fn ascii() -> Self {
+        IntervalFormat::new()
+            .infinity("inf")
+            .empty("{}")
+            .union(" U ")
+    }
+
+    /// Sets the symbol written for an infinite bound. A lower infinite bound
+    /// is prefixed with `-`.
+    pub fn infinity<S>(mut self, infinity: S) -> Self where S: Into<String> {
+        self.infinity = infinity.into();
+        self
+    }
+
+    /// Sets the symbol written for an empty `Interval` or `Selection`.
+    pub fn empty<S>(mut self, empty: S) -> Self where S: Into<String> {
+        self.empty = empty.into();
+        self
+    }
+
+    /// Sets the symbol written between the `Interval`s of a `Selection`.
+    pub fn union<S>(mut self, union: S) -> Self where S: Into<String> {
+        self.union = union.into();
+        self
+    }
+
+    /// Sets the symbol written between the bounds of an `Interval`.
+    pub fn separator<S>(mut self, separator: S) -> Self
+        where S: Into<String>
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the delimiters written for included lower and upper bounds.
+    pub fn include_delimiters<L, R>(mut self, lower: L, upper: R) -> Self
+        where L: Into<String>, R: Into<String>
+    {
+        self.include = (lower.into(), upper.into());
+        self
+    }
+
+    /// Sets the delimiters written for excluded and infinite lower and upper
+    /// bounds.
+    pub fn exclude_delimiters<L, R>(mut self, lower: L, upper: R) -> Self
+        where L: Into<String>, R: Into<String>
+    {
+        self.exclude = (lower.into(), upper.into());
+        self
+    }
+
+    /// Sets the delimiters written around an `Interval` containing a single
+    /// point, when written in the [`Normalized`] style.
+    ///
+    /// [`Normalized`]: enum.BoundStyle.html#variant.Normalized
+    pub fn point_delimiters<L, R>(mut self, lower: L, upper: R) -> Self
+        where L: Into<String>, R: Into<String>
+    {
+        self.point = (lower.into(), upper.into());
+        self
+    }
+
+    /// Sets which bounds are written excluded.
+    pub fn bound_style(mut self, bound_style: BoundStyle) -> Self {
+        self.bound_style = bound_style;
+        self
+    }
+
+    /// Returns a wrapper which writes the given `Interval` using the
+    /// `IntervalFormat`.
+    pub fn interval<'f, T>(&'f self, interval: &'f Interval<T>)
+        -> FormattedInterval<'f, T>
+    {
+        FormattedInterval { format: self, interval }
+    }
+
+    /// Returns a wrapper which writes the given `Selection` using the
+    /// `IntervalFormat`.
+    pub fn selection<'f, T>(&'f self, selection: &'f Selection<T>)
+        -> FormattedSelection<'f, T>
+    {
+        FormattedSelection { format: self, selection }
+    }
+
+    /// Writes the given `Interval`.
+    fn write_interval<T>(&self, f: &mut Formatter<'_>, interval: &Interval<T>)
+        -> std::fmt::Result
+        where
+            T: Ord + Clone + Display,
+            RawInterval<T>: Normalize,
+    {
+        let bounds = (interval.lower_bound(), interval.upper_bound());
+        let (lower, upper) = match bounds {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return f.write_str(&self.empty),
+        };
+
+        if self.bound_style == BoundStyle::Normalized {
+            if let RawInterval::Point(ref p) = interval.0 {
+                return write!(f, "{}{}{}", self.point.0, p, self.point.1);
+            }
+        }
+
+        let denormalized = interval.0.clone().denormalized();
+        let lower = match denormalized.lower_bound() {
+            Some(l) if self.bound_style.lower_open() => l,
+            _                                        => lower,
+        };
+        let upper = match denormalized.upper_bound() {
+            Some(u) if self.bound_style.upper_open() => u,
+            _                                        => upper,
+        };
+
+        match lower {
+            Bound::Include(p) => write!(f, "{}{}", self.include.0, p)?,
+            Bound::Exclude(p) => write!(f, "{}{}", self.exclude.0, p)?,
+            Bound::Infinite   => write!(f, "{}-{}",
+                self.exclude.0, self.infinity)?,
+        }
+        f.write_str(&self.separator)?;
+        match upper {
+            Bound::Include(p) => write!(f, "{}{}", p, self.include.1),
+            Bound::Exclude(p) => write!(f, "{}{}", p, self.exclude.1),
+            Bound::Infinite   => write!(f, "{}{}",
+                self.infinity, self.exclude.1),
+        }
+    }
+}
+
+impl Default for IntervalFormat {
+    fn default() -> Self {
+        IntervalFormat::new()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FormattedInterval
+////////////////////////////////////////////////////////////////////////////////
+/// Writes an `Interval` using an `IntervalFormat`. Returned by
+/// [`IntervalFormat::interval`].
+///
+/// [`IntervalFormat::interval`]: struct.IntervalFormat.html#method.interval
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedInterval<'f, T> {
+    format: &'f IntervalFormat,
+    interval: &'f Interval<T>,
+}
+
+impl<'f, T> Display for FormattedInterval<'f, T>
+    where
+        T: Ord + Clone + Display,
+        RawInterval<T>: Normalize,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.format.write_interval(f, self.interval)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// FormattedSelection
+////////////////////////////////////////////////////////////////////////////////
+/// Writes a `Selection` using an `IntervalFormat`. Returned by
+/// [`IntervalFormat::selection`].
+///
+/// [`IntervalFormat::selection`]: struct.IntervalFormat.html#method.selection
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedSelection<'f, T> {
+    format: &'f IntervalFormat,
+    selection: &'f Selection<T>,
+}
+
+impl<'f, T> Display for FormattedSelection<'f, T>
+    where
+        T: Ord + Clone + Display,
+        RawInterval<T>: Normalize,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut intervals = self.selection.interval_iter();
+        match intervals.next() {
+            Some(first) => self.format.write_interval(f, &first)?,
+            None        => return f.write_str(&self.format.empty),
+        }
+        for interval in intervals {
+            f.write_str(&self.format.union)?;
+            self.format.write_interval(f, &interval)?;
+        }
+        Ok(())
+    }
+}
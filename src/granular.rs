@@ -0,0 +1,290 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides normalization onto a grid of evenly spaced integer points.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Granular<T, STEP>
+////////////////////////////////////////////////////////////////////////////////
+/// An integer which is a multiple of `STEP`.
+///
+/// `Granular` values are [`Finite`], stepping from one multiple of `STEP` to
+/// the next, so `Interval`s of them normalize onto the grid of multiples
+/// rather than onto every integer. The extremes of the grid are the multiples
+/// of `STEP` nearest to the extremes of `T`.
+///
+/// Using a `STEP` of zero or one which does not fit in `T` is a compile-time
+/// error.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::granular::Granular;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// type Page = Granular<u64, 4096>;
+///
+/// // The pages overlapping the byte range [5000, 13000).
+/// let pages: Interval<Page> = Interval::right_open(
+///     Page::floor(5000).unwrap(),
+///     Page::ceil(13000).unwrap());
+///
+/// assert_eq!(pages.iter().map(Page::get).collect::<Vec<_>>(),
+///     [4096, 8192, 12288]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Granular<T, const STEP: u64>(T);
+
+impl<T, const STEP: u64> Granular<T, STEP> {
+    /// Returns the value of the `Granular`.
+    #[inline]
+    pub fn get(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const STEP: u64> std::fmt::Display for Granular<T, STEP>
+    where T: std::fmt::Display
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer Granular implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Implements `Granular` construction and stepping for a single builtin integer
+// type. Steps are taken over the underlying integer type, `STEP` points at a
+// time.
+macro_rules! std_integer_granular_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl<const STEP: u64> Granular<$t, STEP> {
+            /// The distance between adjacent points, checked to be valid for
+            /// the type.
+            #[allow(trivial_numeric_casts)]
+            const STEP_SIZE: $t = {
+                assert!(STEP != 0 && STEP as u128 <= $t::MAX as u128,
+                    "granular step out of range");
+                STEP as $t
+            };
+
+            /// Constructs a new `Granular` from the given value, or returns
+            /// `None` if it is not a multiple of `STEP`.
+            pub fn new(value: $t) -> Option<Self> {
+                if value % Self::STEP_SIZE == 0 {
+                    Some(Granular(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the greatest multiple of `STEP` less than or equal to
+            /// the given value, or `None` if it is out of range.
+            pub fn floor(value: $t) -> Option<Self> {
+                let offset = value.rem_euclid(Self::STEP_SIZE);
+                value.checked_sub(offset).map(Granular)
+            }
+
+            /// Returns the least multiple of `STEP` greater than or equal to
+            /// the given value, or `None` if it is out of range.
+            pub fn ceil(value: $t) -> Option<Self> {
+                match value.rem_euclid(Self::STEP_SIZE) {
+                    0      => Some(Granular(value)),
+                    offset => value
+                        .checked_add(Self::STEP_SIZE - offset)
+                        .map(Granular),
+                }
+            }
+        }
+
+        impl<const STEP: u64> Finite for Granular<$t, STEP> {
+            const MINIMUM: Self = Granular(
+                $t::MIN / Self::STEP_SIZE * Self::STEP_SIZE);
+            const MAXIMUM: Self = Granular(
+                $t::MAX / Self::STEP_SIZE * Self::STEP_SIZE);
+
+            fn pred(&self) -> Option<Self> {
+                self.0.checked_sub(Self::STEP_SIZE).map(Granular)
+            }
+
+            fn succ(&self) -> Option<Self> {
+                self.0.checked_add(Self::STEP_SIZE).map(Granular)
+            }
+
+            fn pred_n(&self, n: usize) -> Option<Self> {
+                let n = n.checked_mul(usize::try_from(STEP).ok()?)?;
+                self.0.pred_n(n).map(Granular)
+            }
+
+            fn succ_n(&self, n: usize) -> Option<Self> {
+                let n = n.checked_mul(usize::try_from(STEP).ok()?)?;
+                self.0.succ_n(n).map(Granular)
+            }
+
+            fn steps_between(&self, other: &Self) -> Option<usize> {
+                let steps = self.0.steps_between(&other.0)?;
+                Some(steps / usize::try_from(STEP).ok()?)
+            }
+        })*
+    };
+}
+
+// Provide implementations of Granular for builtin integer types.
+std_integer_granular_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SnapDirection
+////////////////////////////////////////////////////////////////////////////////
+/// The direction in which [`Interval::snap`] rounds an `Interval`'s bounds.
+///
+/// [`Interval::snap`]: ../interval/struct.Interval.html#method.snap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SnapDirection {
+    /// The bounds are rounded away from each other, covering every grid cell
+    /// the `Interval` overlaps.
+    Outward,
+    /// The bounds are rounded toward each other, covering only the grid cells
+    /// lying entirely within the `Interval`.
+    Inward,
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Snap
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the grid cells used to snap `Interval`s onto multiples of a step.
+///
+/// A grid of step `n` divides the points into cells `[k * n, (k + 1) * n)`.
+/// The cells at the extremes of the type are truncated to fit within it.
+pub trait Snap: Finite {
+    /// Returns the first point of the grid cell containing the point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    fn cell_start(&self, step: &Self) -> Self;
+
+    /// Returns the last point of the grid cell containing the point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    fn cell_end(&self, step: &Self) -> Self;
+}
+
+// Implements `Snap` for a single builtin integer type.
+macro_rules! std_integer_snap_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(impl Snap for $t {
+            fn cell_start(&self, step: &Self) -> Self {
+                assert!(*step > 0, "snap with a non-positive step");
+                self.checked_sub(self.rem_euclid(*step)).unwrap_or($t::MIN)
+            }
+
+            fn cell_end(&self, step: &Self) -> Self {
+                assert!(*step > 0, "snap with a non-positive step");
+                self.checked_add(*step - 1 - self.rem_euclid(*step))
+                    .unwrap_or($t::MAX)
+            }
+        })*
+    };
+}
+
+// Provide implementations of Snap for builtin integer types.
+std_integer_snap_impl![
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+];
+
+impl<T> Interval<T> where T: Ord + Clone + Snap {
+    /// Returns the `Interval` with its bounds rounded to the cells of a grid
+    /// of multiples of `step`, in the given direction.
+    ///
+    /// Rounding [`Outward`] gives the smallest union of grid cells containing
+    /// the `Interval`, and rounding [`Inward`] gives the largest union of grid
+    /// cells within it, which may be empty.
+    ///
+    /// [`Outward`]: ../granular/enum.SnapDirection.html#variant.Outward
+    /// [`Inward`]: ../granular/enum.SnapDirection.html#variant.Inward
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::granular::SnapDirection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let bytes: Interval<u64> = Interval::right_open(5000, 13000);
+    ///
+    /// assert_eq!(bytes.snap(4096, SnapDirection::Outward),
+    ///     Interval::right_open(4096, 16384));
+    /// assert_eq!(bytes.snap(4096, SnapDirection::Inward),
+    ///     Interval::right_open(8192, 12288));
+    /// assert_eq!(bytes.snap(8192, SnapDirection::Inward), Interval::empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn snap(&self, step: T, direction: SnapDirection) -> Self {
+        let (l, u) = match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l, u),
+            _                  => return Interval::empty(),
+        };
+        match direction {
+            SnapDirection::Outward => Interval::closed(
+                l.cell_start(&step),
+                u.cell_end(&step)),
+            SnapDirection::Inward  => {
+                let start = l.cell_start(&step);
+                let lower = if start == l { Some(l) } else {
+                    l.cell_end(&step).succ()
+                };
+                let end = u.cell_end(&step);
+                let upper = if end == u { Some(u) } else {
+                    u.cell_start(&step).pred()
+                };
+                match (lower, upper) {
+                    (Some(l), Some(u)) => Interval::closed(l, u),
+                    _                  => Interval::empty(),
+                }
+            },
+        }
+    }
+}
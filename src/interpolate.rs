@@ -0,0 +1,94 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides linear interpolation within integer `Interval`s.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard integer interpolation implementations
+////////////////////////////////////////////////////////////////////////////////
+
+// Implements `lerp` and `fraction_of` for a single builtin integer type. The
+// distance between the bounds is taken as the unsigned type of the same width,
+// so it never overflows.
+macro_rules! std_integer_interpolate_impl {
+    // For each given type...
+    ($($t:ident : $u:ident),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        impl Interval<$t> {
+            /// Returns the point at the fraction `t` of the way from the
+            /// `Interval`'s least point to its greatest, rounded to the
+            /// nearest point. `t` is clamped to `[0, 1]`.
+            ///
+            /// Returns `None` if the `Interval` is empty or `t` is NaN.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use std::error::Error;
+            /// # use normalize_interval::Interval;
+            /// # fn main() -> Result<(), Box<dyn Error>> {
+            /// # //-----------------------------------------------------------
+            /// let interval: Interval<i32> = Interval::closed(-10, 10);
+            /// assert_eq!(interval.lerp(0.25), Some(-5));
+            /// assert_eq!(interval.lerp(1.5), Some(10));
+            /// # //-----------------------------------------------------------
+            /// #     Ok(())
+            /// # }
+            /// ```
+            pub fn lerp(&self, t: f64) -> Option<$t> {
+                if t.is_nan() { return None; }
+                let (l, u) = (self.infimum()?, self.supremum()?);
+                let width = u.abs_diff(l);
+                let offset = (t.clamp(0.0, 1.0) * width as f64).round() as $u;
+                // The float may round past the width for wide types.
+                Some(l.wrapping_add(offset.min(width) as $t))
+            }
+
+            /// Returns the fraction of the way the given point lies from the
+            /// `Interval`'s least point to its greatest, or `None` if the
+            /// point is not in the `Interval`. The only point of a
+            /// single-point `Interval` lies at zero.
+            ///
+            /// # Example
+            ///
+            /// ```rust
+            /// # use std::error::Error;
+            /// # use normalize_interval::Interval;
+            /// # fn main() -> Result<(), Box<dyn Error>> {
+            /// # //-----------------------------------------------------------
+            /// let interval: Interval<i32> = Interval::closed(-10, 10);
+            /// assert_eq!(interval.fraction_of(&-5), Some(0.25));
+            /// assert_eq!(interval.fraction_of(&11), None);
+            /// # //-----------------------------------------------------------
+            /// #     Ok(())
+            /// # }
+            /// ```
+            pub fn fraction_of(&self, point: &$t) -> Option<f64> {
+                if !self.contains(point) { return None; }
+                let (l, u) = (self.infimum()?, self.supremum()?);
+                match u.abs_diff(l) {
+                    0     => Some(0.0),
+                    width => Some(point.abs_diff(l) as f64 / width as f64),
+                }
+            }
+        })*
+    };
+}
+
+// Provide implementations of interpolation for builtin integer types.
+std_integer_interpolate_impl![
+    u8:u8, u16:u16, u32:u32, u64:u64, u128:u128, usize:usize,
+    i8:u8, i16:u16, i32:u32, i64:u64, i128:u128, isize:usize
+];
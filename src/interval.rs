@@ -13,19 +13,28 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::error::IntervalError;
 use crate::normalize::Finite;
 use crate::normalize::Normalize;
 use crate::raw_interval::RawInterval;
+use crate::raw_interval::RawIntervalRef;
 
 // Standard library imports.
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
 use std::iter::FusedIterator;
+use std::ops;
 use std::ops::Range;
 use std::ops::RangeFrom;
 use std::ops::RangeFull;
-// use std::ops::RangeInclusive; // TODO: Add when RangeInclusive accessors stabilize.
+use std::ops::RangeInclusive;
 use std::ops::RangeTo;
 use std::ops::RangeToInclusive;
 use std::ops::Sub;
+use std::str::FromStr;
 
 
 
@@ -35,7 +44,9 @@ use std::ops::Sub;
 /// A contiguous interval of the type T.
 ///
 /// `Interval`s are [`Normalize`]d when created. For [`Finite`] types, open
-/// bounds will be converted to the nearest contained closed bound.
+/// bounds will be converted to the nearest contained closed bound. Equality
+/// and hashing are therefore performed on the normalized form, so equivalent
+/// `Interval`s can be used interchangeably as `HashMap` keys.
 ///
 /// [`Normalize`]: ../normalize/trait.Normalize.html
 /// [`Finite`]: ../normalize/trait.Finite.html
@@ -108,6 +119,47 @@ impl<T> Interval<T>
     pub fn new(left: Bound<T>, right: Bound<T>) -> Self {
         Interval(RawInterval::new(left, right).normalized())
     }
+
+    /// Constructs a new `Interval` from the given [`Bound`]s, or returns an
+    /// error if the lower bound's point is greater than the upper bound's.
+    ///
+    /// Bounds at the same point which exclude it form an empty `Interval`,
+    /// as they do for [`new`].
+    ///
+    /// [`Bound`]: ../bound/enum.Bound.html
+    /// [`new`]: #method.new
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound::*;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalError;
+    /// # use std::convert::TryFrom;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::try_new(Exclude(-3), Infinite)?;
+    /// assert_eq!(interval, Interval::unbounded_up_from(-3));
+    ///
+    /// assert_eq!(Interval::try_new(Include(7), Exclude(-7)),
+    ///     Err(IntervalError::InvertedBounds));
+    ///
+    /// // Pairs of bounds are converted the same way.
+    /// let interval = Interval::try_from((Include(2), Exclude(5)))?;
+    /// assert_eq!(interval, Interval::right_open(2, 5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_new(left: Bound<T>, right: Bound<T>)
+        -> Result<Self, IntervalError>
+    {
+        match (left.as_ref(), right.as_ref()) {
+            (Some(l), Some(r)) if l > r => Err(IntervalError::InvertedBounds),
+            _                           => Ok(Interval::new(left, right)),
+        }
+    }
     
     
     /// Constructs an empty `Interval`.
@@ -125,7 +177,7 @@ impl<T> Interval<T>
     /// # }
     /// ```
     #[inline]
-    pub fn empty() -> Self {
+    pub const fn empty() -> Self {
         // Normalization not needed for empty intervals.
         Interval(RawInterval::Empty)
     }
@@ -144,8 +196,23 @@ impl<T> Interval<T>
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// Point `Interval`s can be constructed in constant expressions:
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// static ORIGIN: Interval<i32> = Interval::point(0);
+    ///
+    /// assert!(ORIGIN.contains(&0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
     #[inline]
-    pub fn point(point: T) -> Self {
+    pub const fn point(point: T) -> Self {
        // Normalization not needed for point intervals.
        Interval(RawInterval::Point(point))
     }
@@ -755,6 +822,123 @@ impl<T> Interval<T>
         }
     }
 
+    /// Returns the bounds of the `Interval` as a pair of standard library
+    /// bounds, or `None` if it is empty.
+    ///
+    /// The pair implements `RangeBounds`, and so can be passed to the `range`
+    /// methods of the standard collections. The empty `Interval` has no such
+    /// pair, as `BTreeMap::range` and `BTreeSet::range` panic when given
+    /// excluded bounds at the same point. Use [`btree_map_range`] or
+    /// [`btree_set_range`] to handle the empty case automatically.
+    ///
+    /// [`btree_map_range`]: #method.btree_map_range
+    /// [`btree_set_range`]: #method.btree_set_range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::collections::BTreeSet;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let set: BTreeSet<i32> = (0..10).collect();
+    /// let interval: Interval<i32> = Interval::open(2, 6);
+    ///
+    /// let bounds = interval.as_range_bounds().unwrap();
+    /// assert_eq!(set.range(bounds).collect::<Vec<_>>(), [&3, &4, &5]);
+    ///
+    /// assert_eq!(Interval::<i32>::empty().as_range_bounds(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_range_bounds(&self)
+        -> Option<(ops::Bound<&T>, ops::Bound<&T>)>
+    {
+        use RawInterval::*;
+        use ops::Bound::*;
+        match self.0 {
+            Empty                   => None,
+            Point(ref p)            => Some((Included(p), Included(p))),
+            Open(ref l, ref r)      => Some((Excluded(l), Excluded(r))),
+            LeftOpen(ref l, ref r)  => Some((Excluded(l), Included(r))),
+            RightOpen(ref l, ref r) => Some((Included(l), Excluded(r))),
+            Closed(ref l, ref r)    => Some((Included(l), Included(r))),
+            UpTo(ref r)             => Some((Unbounded, Excluded(r))),
+            UpFrom(ref l)           => Some((Excluded(l), Unbounded)),
+            To(ref r)               => Some((Unbounded, Included(r))),
+            From(ref l)             => Some((Included(l), Unbounded)),
+            Full                    => Some((Unbounded, Unbounded)),
+        }
+    }
+
+    /// Returns an iterator over the entries of the given `BTreeMap` whose
+    /// keys are in the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::collections::BTreeMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut map = BTreeMap::new();
+    /// let _ = map.insert(10, "apple");
+    /// let _ = map.insert(20, "banana");
+    /// let _ = map.insert(30, "cherry");
+    ///
+    /// let interval: Interval<i32> = Interval::left_open(10, 25);
+    /// assert_eq!(interval.btree_map_range(&map).collect::<Vec<_>>(),
+    ///     [(&20, &"banana")]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn btree_map_range<'m, K, V>(&self, map: &'m BTreeMap<K, V>)
+        -> impl DoubleEndedIterator<Item=(&'m K, &'m V)> + 'm
+        where K: Borrow<T> + Ord
+    {
+        self.as_range_bounds()
+            .map(|bounds| map.range::<T, _>(bounds))
+            .into_iter()
+            .flatten()
+    }
+
+    /// Returns an iterator over the elements of the given `BTreeSet` which
+    /// are in the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use std::collections::BTreeSet;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let set: BTreeSet<i32> = (0..10).collect();
+    ///
+    /// let interval: Interval<i32> = Interval::unbounded_up_from(6);
+    /// assert_eq!(interval.btree_set_range(&set).collect::<Vec<_>>(),
+    ///     [&7, &8, &9]);
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.btree_set_range(&set).next(), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn btree_set_range<'s, K>(&self, set: &'s BTreeSet<K>)
+        -> impl DoubleEndedIterator<Item=&'s K> + 's
+        where K: Borrow<T> + Ord
+    {
+        self.as_range_bounds()
+            .map(|bounds| set.range::<T, _>(bounds))
+            .into_iter()
+            .flatten()
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Bound accessors
     ////////////////////////////////////////////////////////////////////////////
@@ -1543,6 +1727,41 @@ impl<T> Interval<T>
         self.0.adjacent(&other.0)
     }
 
+    /// Compares the `Interval`s by inclusion, returning `Less` if this
+    /// `Interval` is a strict subset of the other, `Greater` if it is a strict
+    /// superset, `Equal` if they contain the same points, and `None` if
+    /// neither contains the other.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use std::cmp::Ordering;
+    ///
+    /// let a: Interval<i32> = Interval::closed(0, 10);
+    /// let b: Interval<i32> = Interval::open(0, 10);
+    /// let c: Interval<i32> = Interval::closed(5, 15);
+    ///
+    /// assert_eq!(b.partial_cmp_by_inclusion(&a), Some(Ordering::Less));
+    /// assert_eq!(a.partial_cmp_by_inclusion(&b), Some(Ordering::Greater));
+    /// assert_eq!(a.partial_cmp_by_inclusion(&c), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn partial_cmp_by_inclusion(&self, other: &Self) -> Option<Ordering> {
+        let common = self.intersect(other);
+        match (common == *self, common == *other) {
+            (true,  true)  => Some(Ordering::Equal),
+            (true,  false) => Some(Ordering::Less),
+            (false, true)  => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+
     ////////////////////////////////////////////////////////////////////////////
     // Set operations
     ////////////////////////////////////////////////////////////////////////////
@@ -1685,6 +1904,46 @@ impl<T> Interval<T>
     pub fn closure(&self) -> Self {
         self.0.closure().normalized().into()
     }
+
+    /// Returns the `Interval` formed by mapping its bounds through the given
+    /// strictly increasing function.
+    ///
+    /// In debug builds, this panics if the function is found not to be
+    /// strictly increasing at the bounds of the `Interval`. Otherwise, a
+    /// decreasing function results in an empty `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let bytes: Interval<u64> = Interval::right_open(1024, 4096);
+    /// let blocks: Interval<u64> = bytes.map_monotone(|b| b / 512);
+    ///
+    /// assert_eq!(blocks, Interval::closed(2, 7));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn map_monotone<U, F>(&self, mut f: F) -> Interval<U>
+        where
+            U: Ord + Clone,
+            RawInterval<U>: Normalize,
+            F: FnMut(&T) -> U,
+    {
+        let (lower, upper) = match (self.lower_bound(), self.upper_bound()) {
+            (Some(l), Some(u)) => (l.map(|p| f(&p)), u.map(|p| f(&p))),
+            _                  => return Interval::empty(),
+        };
+        debug_assert!(match (lower.as_ref(), upper.as_ref()) {
+                (Some(l), Some(u)) => self.is_degenerate() || l < u,
+                _                  => true,
+            },
+            "map_monotone with function that is not increasing");
+        Interval(RawInterval::new(lower, upper).normalized())
+    }
 }
 
 
@@ -1702,6 +1961,16 @@ impl<T> From<RawInterval<T>> for Interval<T>
     }
 }
 
+impl<'a, T> From<RawIntervalRef<'a, T>> for Interval<T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(interval: RawIntervalRef<'a, T>) -> Self {
+        Interval(interval.to_raw_interval().normalized())
+    }
+}
+
 // NOTE: Conflicts with From<RangeFull> convertion.
 impl<T> From<T> for Interval<T> 
     where
@@ -1727,18 +1996,16 @@ impl<T> From<Range<T>> for Interval<T>
     }
 }
 
-// // TODO: Fix RangeInclusive once the accessors become available.
-// impl<T> From<RangeInclusive<T>> for Interval<T>
-//     where
-//         T: Ord + Clone,
-//         RawInterval<T>: Normalize,
-// {
-//     fn from(r: RangeInclusive<T>) -> Self {
-//         Interval(RawInterval::closed(
-//             r.next().unwrap(),
-//             r.next_back().unwrap()).normalized())
-//     }
-// }
+impl<T> From<RangeInclusive<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(r: RangeInclusive<T>) -> Self {
+        let (start, end) = r.into_inner();
+        Interval(RawInterval::closed(start, end).normalized())
+    }
+}
 
 impl<T> From<RangeFrom<T>> for Interval<T>
     where
@@ -1780,6 +2047,113 @@ impl<T> From<RangeFull> for Interval<T>
     }
 }
 
+// Validates the bounds as `Interval::try_new` does.
+impl<T> TryFrom<(Bound<T>, Bound<T>)> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Error = IntervalError;
+
+    fn try_from(bounds: (Bound<T>, Bound<T>)) -> Result<Self, Self::Error> {
+        Interval::try_new(bounds.0, bounds.1)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Display
+////////////////////////////////////////////////////////////////////////////////
+// Display using interval notation.
+impl<T> std::fmt::Display for Interval<T> where T: std::fmt::Display {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// Parse using interval notation.
+impl<T> FromStr for Interval<T>
+    where
+        T: Ord + Clone + FromStr,
+        RawInterval<T>: Normalize,
+{
+    type Err = ParseIntervalError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RawInterval::from_str(s).map(Interval::from)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// ParseIntervalError
+////////////////////////////////////////////////////////////////////////////////
+/// An error which can be returned when parsing an [`Interval`] or
+/// [`Selection`].
+///
+/// `Interval`s are parsed from standard interval notation, such as `[1, 5)`,
+/// `(, 10]`, `{3}`, or `empty`. Missing points and the `∞` and `inf` symbols
+/// denote unbounded sides. `Selection`s are parsed from unions of
+/// `Interval`s, separated by `∪`, `u`, or commas.
+///
+/// [`Interval`]: struct.Interval.html
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let interval: Interval<i32> = "[1, 5)".parse()?;
+/// assert_eq!(interval, Interval::right_open(1, 5));
+///
+/// let interval: Interval<i32> = "(, 10]".parse()?;
+/// assert_eq!(interval, Interval::unbounded_to(10));
+///
+/// assert!("[1; 5)".parse::<Interval<i32>>().is_err());
+///
+/// let sel: Selection<i32> = "[1, 3) ∪ {5} ∪ [7,]".parse()?;
+/// assert_eq!(sel, "[1,2] u {5}, [7, inf)".parse::<Selection<i32>>()?);
+/// assert!("[1, 3) ∪".parse::<Selection<i32>>().is_err());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntervalError<E> {
+    /// The interval was not enclosed by valid brackets.
+    InvalidDelimiters,
+    /// The interval bounds were not separated by a single comma.
+    InvalidSeparator,
+    /// A bound point could not be parsed.
+    InvalidPoint(E),
+}
+
+impl<E> std::fmt::Display for ParseIntervalError<E>
+    where E: std::fmt::Display
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ParseIntervalError::*;
+        match *self {
+            InvalidDelimiters => write!(f, "invalid interval delimiters"),
+            InvalidSeparator  => write!(f, "invalid interval separator"),
+            InvalidPoint(ref e) => write!(f, "invalid interval point: {}", e),
+        }
+    }
+}
+
+impl<E> std::error::Error for ParseIntervalError<E>
+    where E: std::error::Error + 'static
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            ParseIntervalError::InvalidPoint(ref e) => Some(e),
+            _                                      => None,
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Default
 ////////////////////////////////////////////////////////////////////////////////
@@ -1794,6 +2168,129 @@ impl<T> Default for Interval<T>
 }
 
 
+////////////////////////////////////////////////////////////////////////////////
+// Finite form conversions
+////////////////////////////////////////////////////////////////////////////////
+// `Finite` intervals are always stored closed, so each equivalent form is
+// found by stepping outward from the closed bounds.
+impl<T> Interval<T> where T: Ord + Clone + Finite {
+    /// Returns the bounds of the `Interval` in closed form, or `None` if it is
+    /// empty. Only defined for `Finite` `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(0, 6);
+    /// assert_eq!(interval.as_closed(),
+    ///     Some((Bound::Include(1), Bound::Include(5))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_closed(&self) -> Option<(Bound<T>, Bound<T>)> {
+        let (l, u) = (self.infimum()?, self.supremum()?);
+        Some((Bound::Include(l), Bound::Include(u)))
+    }
+
+    /// Returns the bounds of the `Interval` in open form, or `None` if it is
+    /// empty. Only defined for `Finite` `Interval`s.
+    ///
+    /// A bound at the extreme of `T` has no point beyond it to exclude, so it
+    /// is given as `Infinite`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(1, 5);
+    /// assert_eq!(interval.as_open(),
+    ///     Some((Bound::Exclude(0), Bound::Exclude(6))));
+    ///
+    /// let interval: Interval<u8> = Interval::closed(0, 5);
+    /// assert_eq!(interval.as_open(),
+    ///     Some((Bound::Infinite, Bound::Exclude(6))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_open(&self) -> Option<(Bound<T>, Bound<T>)> {
+        let (l, u) = (self.infimum()?, self.supremum()?);
+        Some((exclude_pred(&l), exclude_succ(&u)))
+    }
+
+    /// Returns the bounds of the `Interval` in left-open form, or `None` if it
+    /// is empty. Only defined for `Finite` `Interval`s.
+    ///
+    /// A lower bound at the minimum of `T` is given as `Infinite`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(1, 5);
+    /// assert_eq!(interval.as_left_open(),
+    ///     Some((Bound::Exclude(0), Bound::Include(5))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_left_open(&self) -> Option<(Bound<T>, Bound<T>)> {
+        let (l, u) = (self.infimum()?, self.supremum()?);
+        Some((exclude_pred(&l), Bound::Include(u)))
+    }
+
+    /// Returns the bounds of the `Interval` in right-open form, or `None` if
+    /// it is empty. Only defined for `Finite` `Interval`s.
+    ///
+    /// An upper bound at the maximum of `T` is given as `Infinite`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(1, 5);
+    /// assert_eq!(interval.as_right_open(),
+    ///     Some((Bound::Include(1), Bound::Exclude(6))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn as_right_open(&self) -> Option<(Bound<T>, Bound<T>)> {
+        let (l, u) = (self.infimum()?, self.supremum()?);
+        Some((Bound::Include(l), exclude_succ(&u)))
+    }
+}
+
+/// Returns the bound excluding the point before the given one, or `Infinite`
+/// if there is none.
+fn exclude_pred<T>(point: &T) -> Bound<T> where T: Finite {
+    point.pred().map_or(Bound::Infinite, Bound::Exclude)
+}
+
+/// Returns the bound excluding the point after the given one, or `Infinite`
+/// if there is none.
+fn exclude_succ<T>(point: &T) -> Bound<T> where T: Finite {
+    point.succ().map_or(Bound::Infinite, Bound::Exclude)
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // Finite iteration support
 ////////////////////////////////////////////////////////////////////////////////
@@ -1833,6 +2330,192 @@ impl<T> Interval<T> where T: Ord + Clone + Finite {
             inner: self.clone(),
         }
     }
+
+    /// Returns an `Iterator` over every `step`-th point in the `Interval`,
+    /// starting from its lower bound. Only defined for `Finite` `Interval`s.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero, or if the number of points in the `Interval`
+    /// overflows a `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(3, 12);
+    /// assert_eq!(interval.iter_step(4).collect::<Vec<_>>(), [3, 7, 11]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// The points are always anchored at the lower bound, so iterating in
+    /// reverse yields the same points:
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(3, 12);
+    /// let iter = interval.iter_step(4);
+    ///
+    /// assert_eq!(iter.len(), 3);
+    /// assert_eq!(iter.rev().collect::<Vec<_>>(), [11, 7, 3]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_step(&self, step: usize) -> StepIter<T> {
+        match self.try_iter_step(step) {
+            Ok(iter) => iter,
+            Err(IntervalError::ZeroStep)
+                => panic!("iter_step with a step of zero"),
+            Err(_) => panic!("iter_step over interval with too many points"),
+        }
+    }
+
+    /// Returns an `Iterator` over every `step`-th point in the `Interval`,
+    /// starting from its lower bound, or an error if `step` is zero or the
+    /// number of points in the `Interval` overflows a `usize`. Only defined
+    /// for `Finite` `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalError;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::closed(3, 12);
+    /// assert_eq!(interval.try_iter_step(4)?.collect::<Vec<_>>(), [3, 7, 11]);
+    ///
+    /// let interval: Interval<u128> = Interval::full();
+    /// assert_eq!(interval.try_iter_step(1).err(),
+    ///     Some(IntervalError::TooManyPoints));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_iter_step(&self, step: usize)
+        -> Result<StepIter<T>, IntervalError>
+    {
+        if step == 0 { return Err(IntervalError::ZeroStep); }
+        match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => {
+                let remaining = l
+                    .steps_between(&u)
+                    .and_then(|steps| (steps / step).checked_add(1))
+                    .ok_or(IntervalError::TooManyPoints)?;
+                Ok(StepIter {
+                    front: Some(l),
+                    remaining,
+                    step,
+                })
+            },
+            _ => Ok(StepIter {
+                front: None,
+                remaining: 0,
+                step,
+            }),
+        }
+    }
+
+    /// Returns an `Iterator` over the points in the `Interval` in ascending
+    /// order, starting from its lower bound. Only defined for `Finite`
+    /// `Interval`s.
+    ///
+    /// Unlike [`iter_step`], this never needs to count the points in the
+    /// `Interval`, so it can be used to lazily draw points from an interval
+    /// which is unbounded above, continuing until the type's maximum.
+    ///
+    /// [`iter_step`]: #method.iter_step
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let watermark = 1_000u64;
+    /// let ids = Interval::unbounded_up_from(watermark);
+    ///
+    /// assert_eq!(ids.iter_from_lower().take(3).collect::<Vec<_>>(),
+    ///     [1_001, 1_002, 1_003]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_from_lower(&self) -> AscendingIter<T> {
+        AscendingIter {
+            next: self.infimum(),
+            last: self.supremum(),
+        }
+    }
+
+    /// Returns an `Iterator` over the points in the `Interval` in descending
+    /// order, starting from its upper bound. Only defined for `Finite`
+    /// `Interval`s.
+    ///
+    /// Like [`iter_from_lower`], this never needs to count the points in the
+    /// `Interval`, so it can be used to lazily draw points from an interval
+    /// which is unbounded below, continuing until the type's minimum.
+    ///
+    /// [`iter_from_lower`]: #method.iter_from_lower
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i64> = Interval::unbounded_to(0);
+    ///
+    /// assert_eq!(interval.iter_from_upper().take(3).collect::<Vec<_>>(),
+    ///     [0, -1, -2]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_from_upper(&self) -> DescendingIter<T> {
+        DescendingIter {
+            next: self.supremum(),
+            last: self.infimum(),
+        }
+    }
+
+    /// Returns the number of points in the `Interval`, or `None` if the count
+    /// is too large to represent.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// assert_eq!(interval.point_count(), Some(3));
+    ///
+    /// let interval: Interval<u8> = Interval::full();
+    /// assert_eq!(interval.point_count(), Some(256));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn point_count(&self) -> Option<u128> {
+        match (self.infimum(), self.supremum()) {
+            (Some(l), Some(u)) => (l.steps_between(&u)? as u128).checked_add(1),
+            _                  => Some(0),
+        }
+    }
 }
 
 
@@ -1900,3 +2583,125 @@ impl<T> FusedIterator for Iter<T>
     where
         T: Ord + Clone + Finite
 {}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// StepIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over every `step`-th point in an `Interval`.
+#[derive(Debug)]
+pub struct StepIter<T> {
+    /// The next point to be returned from the front.
+    front: Option<T>,
+    /// The number of points remaining.
+    remaining: usize,
+    /// The number of points between each returned point.
+    step: usize,
+}
+
+impl<T> Iterator for StepIter<T>
+    where T: Ord + Clone + Finite
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 { return None; }
+        self.remaining -= 1;
+
+        let next = self.front.take()?;
+        if self.remaining > 0 {
+            self.front = next.succ_n(self.step);
+        }
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for StepIter<T>
+    where T: Ord + Clone + Finite
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 { return None; }
+        self.remaining -= 1;
+
+        let front = self.front.as_ref()?;
+        if self.remaining == 0 {
+            self.front.take()
+        } else {
+            front.succ_n(self.step * self.remaining)
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for StepIter<T>
+    where T: Ord + Clone + Finite
+{}
+
+impl<T> FusedIterator for StepIter<T>
+    where T: Ord + Clone + Finite
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// AscendingIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points in an `Interval` in ascending order.
+#[derive(Debug)]
+pub struct AscendingIter<T> {
+    /// The next point to be returned.
+    next: Option<T>,
+    /// The last point to be returned.
+    last: Option<T>,
+}
+
+impl<T> Iterator for AscendingIter<T>
+    where T: Ord + Clone + Finite
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.take()?;
+        if self.last.as_ref() != Some(&next) {
+            self.next = next.succ();
+        }
+        Some(next)
+    }
+}
+
+impl<T> FusedIterator for AscendingIter<T>
+    where T: Ord + Clone + Finite
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// DescendingIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the points in an `Interval` in descending order.
+#[derive(Debug)]
+pub struct DescendingIter<T> {
+    /// The next point to be returned.
+    next: Option<T>,
+    /// The last point to be returned.
+    last: Option<T>,
+}
+
+impl<T> Iterator for DescendingIter<T>
+    where T: Ord + Clone + Finite
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.take()?;
+        if self.last.as_ref() != Some(&next) {
+            self.next = next.pred();
+        }
+        Some(next)
+    }
+}
+
+impl<T> FusedIterator for DescendingIter<T>
+    where T: Ord + Clone + Finite
+{}
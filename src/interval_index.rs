@@ -0,0 +1,406 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides an index of possibly overlapping intervals for stabbing queries.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::sync::OnceLock;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIndex<K, V>
+////////////////////////////////////////////////////////////////////////////////
+/// A collection of possibly overlapping `Interval`s of the type `K`, each with
+/// an associated value of the type `V`.
+///
+/// Unlike a `Selection`, inserted `Interval`s are never merged, so queries
+/// return each stored `Interval` which contains a point or overlaps a range.
+/// Queries take `O(log n + k)` time for `k` results. Insertion and removal
+/// take `O(n)` time, so bulk construction should use `FromIterator`.
+///
+/// Implemented as an augmented interval tree stored implicitly in a list of
+/// entries sorted by lower bound. Each entry is the root of the subtree of
+/// entries around it, and records the greatest upper bound in that subtree.
+/// Edits discard these bounds, and the first query after a sequence of edits
+/// recomputes them.
+#[derive(Debug, Clone)]
+pub struct IntervalIndex<K, V> {
+    entries: Vec<(Interval<K>, V)>,
+    max_upper: OnceLock<Vec<Bound<K>>>,
+}
+
+impl<K, V> IntervalIndex<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `IntervalIndex`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::IntervalIndex;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let index: IntervalIndex<i32, &str> = IntervalIndex::new();
+    ///
+    /// assert!(index.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        IntervalIndex {
+            entries: Vec::new(),
+            max_upper: OnceLock::new(),
+        }
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `IntervalIndex` contains no `Interval`s.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the number of `Interval`s in the `IntervalIndex`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns an iterator over the stored `Interval`s which contain the given
+    /// point, along with their values, in order of their lower bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalIndex;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut index = IntervalIndex::new();
+    /// index.insert(Interval::closed(0, 10), "a");
+    /// index.insert(Interval::closed(5, 15), "b");
+    /// index.insert(Interval::closed(5, 15), "c");
+    ///
+    /// assert_eq!(index.containing(&7).map(|(_, v)| *v).collect::<Vec<_>>(),
+    ///     ["a", "b", "c"]);
+    /// assert_eq!(index.containing(&12).map(|(_, v)| *v).collect::<Vec<_>>(),
+    ///     ["b", "c"]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn containing(&self, point: &K) -> Overlapping<'_, K, V> {
+        Overlapping::new(self, RawInterval::Point(point.clone()))
+    }
+
+    /// Returns an iterator over the stored `Interval`s which overlap the given
+    /// `Interval`, along with their values, in order of their lower bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalIndex;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut index = IntervalIndex::new();
+    /// index.insert(Interval::closed(0, 10), "a");
+    /// index.insert(Interval::closed(20, 30), "b");
+    /// index.insert(Interval::closed(40, 50), "c");
+    ///
+    /// let overlapping = index.overlapping(&Interval::closed(8, 22));
+    /// assert_eq!(overlapping.map(|(_, v)| *v).collect::<Vec<_>>(),
+    ///     ["a", "b"]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlapping(&self, interval: &Interval<K>) -> Overlapping<'_, K, V> {
+        Overlapping::new(self, interval.0.clone())
+    }
+
+    // Mutation operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Inserts the given `Interval` and value into the `IntervalIndex`. Empty
+    /// `Interval`s are ignored, as they can never be returned by a query.
+    pub fn insert(&mut self, interval: Interval<K>, value: V) {
+        let lower = match interval.lower_bound() {
+            Some(lower) => LowerBound(lower),
+            None        => return,
+        };
+        let index = self.entries.partition_point(|(i, _)|
+            LowerBound(i.lower_bound().expect("nonempty interval")) <= lower);
+        self.entries.insert(index, (interval, value));
+        let _ = self.max_upper.take();
+    }
+
+    /// Removes one stored `Interval` equal to the given `Interval`, returning
+    /// its value, or `None` if no such `Interval` is stored.
+    pub fn remove(&mut self, interval: &Interval<K>) -> Option<V> {
+        let lower = LowerBound(interval.lower_bound()?);
+        let start = self.entries.partition_point(|(i, _)|
+            LowerBound(i.lower_bound().expect("nonempty interval")) < lower);
+        let index = start + self.entries[start..].iter()
+            .take_while(|(i, _)| i.lower_bound()
+                .is_some_and(|l| LowerBound(l) == lower))
+            .position(|(i, _)| i == interval)?;
+        let (_, value) = self.entries.remove(index);
+        let _ = self.max_upper.take();
+        Some(value)
+    }
+
+    /// Removes all `Interval`s from the `IntervalIndex`.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        let _ = self.max_upper.take();
+    }
+
+    /// Returns the greatest upper bound of each subtree, recomputing them if
+    /// the `IntervalIndex` has been edited since they were last used.
+    fn max_upper(&self) -> &[Bound<K>] {
+        self.max_upper.get_or_init(|| {
+            let mut max_upper: Vec<_> = self.entries.iter()
+                .map(|(i, _)| i.upper_bound().expect("nonempty interval"))
+                .collect();
+            let _ = rebuild_subtree(&mut max_upper, 0, self.entries.len());
+            max_upper
+        })
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the `IntervalIndex`
+    /// along with their values, in order of their lower bounds.
+    pub fn iter(&self) -> impl Iterator<Item=(&Interval<K>, &V)> + '_ {
+        self.entries.iter().map(|(i, v)| (i, v))
+    }
+}
+
+/// Recomputes the greatest upper bound of the subtree for the entries in the
+/// range `[lo, hi)`, returning it, or `None` if the range is empty.
+fn rebuild_subtree<K>(max_upper: &mut [Bound<K>], lo: usize, hi: usize)
+    -> Option<Bound<K>>
+    where K: Ord + Clone
+{
+    if lo >= hi { return None; }
+    let mid = lo + (hi - lo) / 2;
+    let mut max = max_upper[mid].clone();
+    if let Some(left) = rebuild_subtree(max_upper, lo, mid) {
+        max = max.greatest_union(&left);
+    }
+    if let Some(right) = rebuild_subtree(max_upper, mid + 1, hi) {
+        max = max.greatest_union(&right);
+    }
+    max_upper[mid] = max.clone();
+    Some(max)
+}
+
+// The subtree bounds are derived from the entries, so they are excluded from
+// comparisons.
+impl<K, V> PartialEq for IntervalIndex<K, V>
+    where
+        K: PartialEq,
+        V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K, V> Eq for IntervalIndex<K, V> where K: Eq, V: Eq {}
+
+impl<K, V> Hash for IntervalIndex<K, V> where K: Hash, V: Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.entries.hash(state);
+    }
+}
+
+impl<K, V> Default for IntervalIndex<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    fn default() -> Self {
+        IntervalIndex::new()
+    }
+}
+
+impl<K, V> FromIterator<(Interval<K>, V)> for IntervalIndex<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=(Interval<K>, V)>
+    {
+        let mut entries: Vec<_> = iter.into_iter()
+            .filter(|(i, _)| !i.is_empty())
+            .collect();
+        entries.sort_by_cached_key(|(i, _)|
+            LowerBound(i.lower_bound().expect("nonempty interval")));
+        IntervalIndex { entries, max_upper: OnceLock::new() }
+    }
+}
+
+impl<K, V> Extend<(Interval<K>, V)> for IntervalIndex<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I)
+        where I: IntoIterator<Item=(Interval<K>, V)>
+    {
+        let entries = std::mem::take(&mut self.entries);
+        *self = entries.into_iter().chain(iter).collect();
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Overlapping
+////////////////////////////////////////////////////////////////////////////////
+/// Returns `true` if every point below the given upper `Bound` is below every
+/// point above the given lower `Bound`.
+fn upper_precedes_lower<K>(upper: &Bound<K>, lower: &Bound<K>) -> bool
+    where K: Ord
+{
+    use Bound::*;
+    match (upper, lower) {
+        (&Include(ref u), &Include(ref l)) => u < l,
+        (&Include(ref u), &Exclude(ref l)) |
+        (&Exclude(ref u), &Include(ref l)) |
+        (&Exclude(ref u), &Exclude(ref l)) => u <= l,
+        _                                  => false,
+    }
+}
+
+/// A pending step of an `Overlapping` tree traversal.
+#[derive(Debug)]
+enum Visit {
+    /// Visit the subtree for the entries in the range `[lo, hi)`.
+    Subtree(usize, usize),
+    /// Yield the entry at the given index if it overlaps the query.
+    Entry(usize),
+}
+
+/// An `Iterator` over the `Interval`s of an `IntervalIndex` which overlap a
+/// query `Interval`.
+#[derive(Debug)]
+pub struct Overlapping<'i, K, V> {
+    index: &'i IntervalIndex<K, V>,
+    max_upper: &'i [Bound<K>],
+    query: RawInterval<K>,
+    query_lower: Bound<K>,
+    query_upper: Bound<K>,
+    stack: Vec<Visit>,
+}
+
+impl<'i, K, V> Overlapping<'i, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    /// Constructs a new `Overlapping` iterator for the given query.
+    fn new(index: &'i IntervalIndex<K, V>, query: RawInterval<K>) -> Self {
+        match (query.lower_bound(), query.upper_bound()) {
+            (Some(query_lower), Some(query_upper)) => Overlapping {
+                index,
+                max_upper: index.max_upper(),
+                query,
+                query_lower,
+                query_upper,
+                stack: vec![Visit::Subtree(0, index.entries.len())],
+            },
+            _ => Overlapping {
+                index,
+                max_upper: &[],
+                query,
+                query_lower: Bound::Infinite,
+                query_upper: Bound::Infinite,
+                stack: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<'i, K, V> Iterator for Overlapping<'i, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    type Item = (&'i Interval<K>, &'i V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(visit) = self.stack.pop() {
+            match visit {
+                Visit::Subtree(lo, hi) => {
+                    if lo >= hi { continue; }
+                    let mid = lo + (hi - lo) / 2;
+                    // Skip subtrees which end before the query begins.
+                    if upper_precedes_lower(
+                        &self.max_upper[mid],
+                        &self.query_lower)
+                    {
+                        continue;
+                    }
+                    // Skip entries which begin after the query ends. Entries
+                    // are sorted, so the right subtree can be skipped too.
+                    let (interval, _) = &self.index.entries[mid];
+                    let mid_lower = interval.lower_bound()
+                        .expect("nonempty interval");
+                    if !upper_precedes_lower(&self.query_upper, &mid_lower) {
+                        self.stack.push(Visit::Subtree(mid + 1, hi));
+                        self.stack.push(Visit::Entry(mid));
+                    }
+                    self.stack.push(Visit::Subtree(lo, mid));
+                },
+                Visit::Entry(index) => {
+                    let (interval, value) = &self.index.entries[index];
+                    if interval.0.intersects(&self.query) {
+                        return Some((interval, value));
+                    }
+                },
+            }
+        }
+        None
+    }
+}
+
+impl<'i, K, V> FusedIterator for Overlapping<'i, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{}
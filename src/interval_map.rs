@@ -0,0 +1,437 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a map from disjoint intervals to values.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::BTreeMap;
+use std::collections::btree_map;
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalMap<K, V>
+////////////////////////////////////////////////////////////////////////////////
+/// A map from disjoint `Interval`s of the type `K` to values of the type `V`.
+///
+/// Inserting an `Interval` overwrites the values of any overlapping portions
+/// of previously inserted `Interval`s. Adjacent `Interval`s are not merged,
+/// even if their values are equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalMap<K, V>(BTreeMap<LowerBound<K>, (Bound<K>, V)>);
+
+// All intervals in the map must be normalized and disjoint. Each interval is
+// keyed by its lower bound, and stores its upper bound with its value.
+impl<K, V> IntervalMap<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `IntervalMap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::IntervalMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let map: IntervalMap<i32, &str> = IntervalMap::new();
+    ///
+    /// assert!(map.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        IntervalMap(BTreeMap::new())
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `IntervalMap` contains no `Interval`s.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of disjoint `Interval`s in the `IntervalMap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Interval::closed(0, 10), "a");
+    /// map.insert(Interval::closed(3, 5), "b");
+    ///
+    /// assert_eq!(map.len(), 3);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns a reference to the value assigned to the given point, or `None`
+    /// if the point is not in any `Interval` of the `IntervalMap`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Interval::right_open(0, 10), "a");
+    ///
+    /// assert_eq!(map.get(&3), Some(&"a"));
+    /// assert_eq!(map.get(&10), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn get(&self, point: &K) -> Option<&V> {
+        let probe = LowerBound(Bound::Include(point.clone()));
+        let (_, (upper, value)) = self.0.range(..=probe).next_back()?;
+        let contained = match *upper {
+            Bound::Include(ref u) => point <= u,
+            Bound::Exclude(ref u) => point < u,
+            Bound::Infinite       => true,
+        };
+        if contained { Some(value) } else { None }
+    }
+
+    // Mutation operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Assigns the given value to every point in the given `Interval`,
+    /// overwriting the values of any overlapping `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalMap;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut map = IntervalMap::new();
+    /// map.insert(Interval::closed(0, 10), "a");
+    /// map.insert(Interval::closed(3, 5), "b");
+    ///
+    /// assert_eq!(map.iter().collect::<Vec<_>>(), [
+    ///     (Interval::closed(0, 2), &"a"),
+    ///     (Interval::closed(3, 5), &"b"),
+    ///     (Interval::closed(6, 10), &"a"),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn insert(&mut self, interval: Interval<K>, value: V) where V: Clone {
+        let raw = interval.0;
+        if raw.is_empty() { return; }
+        let _ = self.take_overlapping(&raw);
+        self.insert_raw(raw, value);
+    }
+
+    /// Replaces the values of every point in the given `Interval` using the
+    /// given function, which receives the point's current value, or `None` if
+    /// the point has no value. Points for which the function returns `None`
+    /// are removed from the map. Adjacent `Interval`s in and around the updated
+    /// region are merged if their values are equal.
+    pub(in crate) fn update<F>(&mut self, interval: Interval<K>, mut f: F)
+        where
+            V: Clone + PartialEq,
+            F: FnMut(Option<V>) -> Option<V>,
+    {
+        let raw = interval.0.clone();
+        if raw.is_empty() { return; }
+
+        // Split the interval into the parts with values and the gaps between
+        // them.
+        let mut parts: Vec<_> = self.take_overlapping(&raw)
+            .into_iter()
+            .map(|(part, value)| (part, Some(value)))
+            .collect();
+        let mut gaps = Selection::from(interval);
+        for (part, _) in parts.iter() {
+            gaps.minus_in_place(Interval(part.clone()));
+        }
+        parts.extend(gaps.into_interval_iter().map(|gap| (gap.0, None)));
+
+        for (part, value) in parts {
+            if let Some(value) = f(value) {
+                self.insert_raw(part, value);
+            }
+        }
+
+        self.coalesce_around(&raw);
+    }
+
+    /// Removes all intervals overlapping the given non-empty `RawInterval`,
+    /// reinserting the parts outside of it. Returns the parts inside of it
+    /// along with their values, in ascending order.
+    fn take_overlapping(&mut self, raw: &RawInterval<K>)
+        -> Vec<(RawInterval<K>, V)>
+        where V: Clone
+    {
+        let lower = LowerBound(raw.lower_bound()
+            .expect("take overlapping for empty interval"));
+
+        // Find the keys of all overlapping intervals. The first interval that
+        // might overlap is the last one starting at or before the interval.
+        let overlapping: Vec<_> = {
+            let start = self.0.range(..=lower.clone()).next_back()
+                .map(|(k, _)| k.clone());
+            let candidates = match start {
+                Some(start) => self.0.range(start..),
+                None        => self.0.range(..),
+            };
+            let mut overlapping = Vec::new();
+            for (key, (u, _)) in candidates {
+                let existing = RawInterval::new(key.0.clone(), u.clone());
+                if existing.intersects(raw) {
+                    overlapping.push(key.clone());
+                } else if *key > lower {
+                    break;
+                }
+            }
+            overlapping
+        };
+
+        // Remove the overlapping intervals, reinserting any remaining parts.
+        let mut taken = Vec::with_capacity(overlapping.len());
+        for key in overlapping {
+            let (u, value) = self.0.remove(&key)
+                .expect("overlapping interval key is present");
+            let existing = RawInterval::new(key.0, u);
+            for part in existing.minus(raw).map(Normalize::normalized) {
+                if !part.is_empty() {
+                    self.insert_raw(part, value.clone());
+                }
+            }
+            let inside = existing.intersect(raw).normalized();
+            taken.push((inside, value));
+        }
+        taken
+    }
+
+    /// Merges adjacent intervals with equal values which overlap or border the
+    /// given non-empty `RawInterval`.
+    fn coalesce_around(&mut self, raw: &RawInterval<K>) where V: PartialEq {
+        let lower = LowerBound(raw.lower_bound()
+            .expect("coalesce around empty interval"));
+
+        // Collect the keys of the intervals in the region, along with the
+        // intervals on either side of it.
+        let keys: Vec<_> = {
+            let start = self.0.range(..lower.clone()).next_back()
+                .map(|(k, _)| k.clone());
+            let candidates = match start {
+                Some(start) => self.0.range(start..),
+                None        => self.0.range(..),
+            };
+            let mut keys = Vec::new();
+            for (key, (u, _)) in candidates {
+                keys.push(key.clone());
+                let existing = RawInterval::new(key.0.clone(), u.clone());
+                if *key > lower && !existing.intersects(raw) { break; }
+            }
+            keys
+        };
+
+        let mut keys = keys.into_iter();
+        let mut current = match keys.next() {
+            Some(key) => key,
+            None      => return,
+        };
+        for next in keys {
+            let mergeable = {
+                let (cu, cv) = &self.0[&current];
+                let (nu, nv) = &self.0[&next];
+                let a = RawInterval::new(current.0.clone(), cu.clone());
+                let b = RawInterval::new(next.0.clone(), nu.clone());
+                cv == nv && a.denormalized()
+                    .union(&b.denormalized())
+                    .count() == 1
+            };
+            if mergeable {
+                let (u, _) = self.0.remove(&next)
+                    .expect("coalesced interval key is present");
+                let entry = self.0.get_mut(&current)
+                    .expect("coalesced interval key is present");
+                entry.0 = u;
+            } else {
+                current = next;
+            }
+        }
+    }
+
+    /// Inserts a non-empty normalized `RawInterval` that is disjoint from all
+    /// other intervals in the map.
+    fn insert_raw(&mut self, raw: RawInterval<K>, value: V) {
+        match (raw.lower_bound(), raw.upper_bound()) {
+            (Some(l), Some(u)) => {
+                let _ = self.0.insert(LowerBound(l), (u, value));
+            },
+            _ => unreachable!("insert of empty interval"),
+        }
+    }
+
+    /// Removes all `Interval`s from the `IntervalMap`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the `IntervalMap`
+    /// along with their assigned values, in ascending order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter(self.0.iter())
+    }
+}
+
+impl<K, V> Default for IntervalMap<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    fn default() -> Self {
+        IntervalMap::new()
+    }
+}
+
+impl<K, V> Extend<(Interval<K>, V)> for IntervalMap<K, V>
+    where
+        K: Ord + Clone,
+        V: Clone,
+        RawInterval<K>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I)
+        where I: IntoIterator<Item=(Interval<K>, V)>
+    {
+        for (interval, value) in iter.into_iter() {
+            self.insert(interval, value);
+        }
+    }
+}
+
+impl<K, V> FromIterator<(Interval<K>, V)> for IntervalMap<K, V>
+    where
+        K: Ord + Clone,
+        V: Clone,
+        RawInterval<K>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=(Interval<K>, V)>
+    {
+        let mut map = IntervalMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<'m, K, V> IntoIterator for &'m IntervalMap<K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    type Item = (Interval<K>, &'m V);
+    type IntoIter = Iter<'m, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Iter
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the `Interval` for the given `IntervalMap` entry bounds.
+fn entry_interval<K>(lower: &LowerBound<K>, upper: &Bound<K>) -> Interval<K>
+    where K: Ord + Clone
+{
+    // Entries are stored normalized, so no normalization is needed here.
+    Interval(RawInterval::new(lower.0.clone(), upper.clone()))
+}
+
+/// An `Iterator` over the `Interval`s and values of an `IntervalMap`.
+#[derive(Debug)]
+pub struct Iter<'m, K, V>(btree_map::Iter<'m, LowerBound<K>, (Bound<K>, V)>);
+
+impl<'m, K, V> Iterator for Iter<'m, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    type Item = (Interval<K>, &'m V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(|(l, (u, v))| (entry_interval(l, u), v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'m, K, V> DoubleEndedIterator for Iter<'m, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0
+            .next_back()
+            .map(|(l, (u, v))| (entry_interval(l, u), v))
+    }
+}
+
+impl<'m, K, V> ExactSizeIterator for Iter<'m, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{}
+
+impl<'m, K, V> FusedIterator for Iter<'m, K, V>
+    where
+        K: Ord + Clone,
+        RawInterval<K>: Normalize,
+{}
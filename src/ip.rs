@@ -0,0 +1,270 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides normalization support and prefix conversions for IP addresses.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::net::Ipv4Addr;
+use std::net::Ipv6Addr;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Prefix utilities
+////////////////////////////////////////////////////////////////////////////////
+
+// Returns the inclusive range of addresses covered by the given prefix within
+// an address space of the given bit width, or `None` if the prefix length is
+// too long.
+fn prefix_range(addr: u128, len: u8, width: u32) -> Option<(u128, u128)> {
+    let len = u32::from(len);
+    if len > width { return None; }
+    let host_bits = width - len;
+    let host_mask = u128::MAX.checked_shr(128 - host_bits).unwrap_or(0);
+    let lower = addr & !host_mask;
+    Some((lower, lower | host_mask))
+}
+
+// Returns the smallest list of prefixes exactly covering the inclusive range
+// of addresses within an address space of the given bit width.
+fn range_prefixes(mut lower: u128, upper: u128, width: u32) -> Vec<(u128, u8)>
+{
+    let mut prefixes = Vec::new();
+    while lower <= upper {
+        let span = upper - lower;
+        let span_bits = if span == u128::MAX {
+            128
+        } else {
+            127 - (span + 1).leading_zeros()
+        };
+        let host_bits = lower.trailing_zeros().min(span_bits).min(width);
+        // Prefix lengths are at most 128, so this cast can't truncate.
+        prefixes.push((lower, (width - host_bits) as u8));
+        match 1u128.checked_shl(host_bits)
+            .and_then(|size| lower.checked_add(size))
+        {
+            Some(next) => lower = next,
+            None       => break,
+        }
+    }
+    prefixes
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Ipv4Addr
+////////////////////////////////////////////////////////////////////////////////
+
+// `Ipv4Addr`s are stepped over as their `u32` representations.
+impl Finite for Ipv4Addr {
+    const MINIMUM: Ipv4Addr = Ipv4Addr::UNSPECIFIED;
+    const MAXIMUM: Ipv4Addr = Ipv4Addr::BROADCAST;
+
+    fn pred(&self) -> Option<Self> {
+        u32::from(*self).pred().map(Ipv4Addr::from)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        u32::from(*self).succ().map(Ipv4Addr::from)
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        u32::from(*self).pred_n(n).map(Ipv4Addr::from)
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        u32::from(*self).succ_n(n).map(Ipv4Addr::from)
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        u32::from(*self).steps_between(&u32::from(*other))
+    }
+}
+
+impl Interval<Ipv4Addr> {
+    /// Constructs a new `Interval` containing all addresses with the given
+    /// prefix, or `None` if the prefix length is greater than 32. The host
+    /// bits of the given address are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let interval = Interval::from_ipv4_prefix(
+    ///     Ipv4Addr::new(10, 1, 2, 3), 16);
+    ///
+    /// assert_eq!(interval, Some(Interval::closed(
+    ///     Ipv4Addr::new(10, 1, 0, 0),
+    ///     Ipv4Addr::new(10, 1, 255, 255))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_ipv4_prefix(addr: Ipv4Addr, len: u8) -> Option<Self> {
+        let (l, u) = prefix_range(u128::from(u32::from(addr)), len, 32)?;
+        // Both bounds are within the 32-bit address space.
+        Some(Interval::closed(
+            Ipv4Addr::from(l as u32),
+            Ipv4Addr::from(u as u32)))
+    }
+
+    /// Returns an iterator over the smallest set of prefixes whose addresses
+    /// exactly cover the `Interval`, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use std::net::Ipv4Addr;
+    ///
+    /// let interval = Interval::closed(
+    ///     Ipv4Addr::new(10, 0, 0, 1),
+    ///     Ipv4Addr::new(10, 0, 0, 6));
+    ///
+    /// assert_eq!(interval.prefixes().collect::<Vec<_>>(), [
+    ///     (Ipv4Addr::new(10, 0, 0, 1), 32),
+    ///     (Ipv4Addr::new(10, 0, 0, 2), 31),
+    ///     (Ipv4Addr::new(10, 0, 0, 4), 31),
+    ///     (Ipv4Addr::new(10, 0, 0, 6), 32),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn prefixes(&self) -> impl Iterator<Item=(Ipv4Addr, u8)> {
+        let prefixes = match self.0 {
+            RawInterval::Empty        => Vec::new(),
+            RawInterval::Point(p)     => vec![(p, 32)],
+            RawInterval::Closed(l, u) => range_prefixes(
+                    u128::from(u32::from(l)),
+                    u128::from(u32::from(u)),
+                    32)
+                .into_iter()
+                // Prefix addresses are within the 32-bit address space.
+                .map(|(addr, len)| (Ipv4Addr::from(addr as u32), len))
+                .collect(),
+            _ => unreachable!("prefixes for Finite interval with open bound"),
+        };
+        prefixes.into_iter()
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Ipv6Addr
+////////////////////////////////////////////////////////////////////////////////
+
+// `Ipv6Addr`s are stepped over as their `u128` representations.
+impl Finite for Ipv6Addr {
+    const MINIMUM: Ipv6Addr = Ipv6Addr::UNSPECIFIED;
+    const MAXIMUM: Ipv6Addr = Ipv6Addr::new(
+        0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF);
+
+    fn pred(&self) -> Option<Self> {
+        u128::from(*self).pred().map(Ipv6Addr::from)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        u128::from(*self).succ().map(Ipv6Addr::from)
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        u128::from(*self).pred_n(n).map(Ipv6Addr::from)
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        u128::from(*self).succ_n(n).map(Ipv6Addr::from)
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        u128::from(*self).steps_between(&u128::from(*other))
+    }
+}
+
+impl Interval<Ipv6Addr> {
+    /// Constructs a new `Interval` containing all addresses with the given
+    /// prefix, or `None` if the prefix length is greater than 128. The host
+    /// bits of the given address are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use std::net::Ipv6Addr;
+    ///
+    /// let interval = Interval::from_ipv6_prefix(
+    ///     Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 1), 32);
+    ///
+    /// assert_eq!(interval, Some(Interval::closed(
+    ///     Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 0),
+    ///     Ipv6Addr::new(0x2001, 0xDB8,
+    ///         0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF, 0xFFFF))));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_ipv6_prefix(addr: Ipv6Addr, len: u8) -> Option<Self> {
+        let (l, u) = prefix_range(u128::from(addr), len, 128)?;
+        Some(Interval::closed(Ipv6Addr::from(l), Ipv6Addr::from(u)))
+    }
+
+    /// Returns an iterator over the smallest set of prefixes whose addresses
+    /// exactly cover the `Interval`, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use std::net::Ipv6Addr;
+    ///
+    /// let interval: Interval<Ipv6Addr> = Interval::full();
+    ///
+    /// assert_eq!(interval.prefixes().collect::<Vec<_>>(), [
+    ///     (Ipv6Addr::UNSPECIFIED, 0),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn prefixes(&self) -> impl Iterator<Item=(Ipv6Addr, u8)> {
+        let prefixes = match self.0 {
+            RawInterval::Empty        => Vec::new(),
+            RawInterval::Point(p)     => vec![(p, 128)],
+            RawInterval::Closed(l, u) => range_prefixes(
+                    u128::from(l),
+                    u128::from(u),
+                    128)
+                .into_iter()
+                .map(|(addr, len)| (Ipv6Addr::from(addr), len))
+                .collect(),
+            _ => unreachable!("prefixes for Finite interval with open bound"),
+        };
+        prefixes.into_iter()
+    }
+}
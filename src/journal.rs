@@ -0,0 +1,224 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a selection which records its changes for undo and redo.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::selection::SelectionDiff;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// JournaledSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A `Selection` of the type `T` which records a [`SelectionDiff`] for each
+/// change made to it, so that the changes can be undone and redone.
+///
+/// The recorded diffs are taken between normalized `Selection`s, so undoing a
+/// change always restores exactly the points selected before it.
+///
+/// [`SelectionDiff`]: ../selection/struct.SelectionDiff.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel = Selection::from(Interval::closed(-3, 5)).journaled();
+/// sel.union_in_place(Interval::closed(4, 9));
+/// sel.remove_point(&0);
+///
+/// assert!(sel.undo());
+/// assert_eq!(*sel.selection(), Interval::closed(-3, 9));
+/// assert!(sel.undo());
+/// assert_eq!(*sel.selection(), Interval::closed(-3, 5));
+/// assert!(!sel.undo());
+///
+/// assert!(sel.redo());
+/// assert_eq!(*sel.selection(), Interval::closed(-3, 9));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JournaledSelection<T>
+    where T: Ord + Clone
+{
+    /// The current `Selection`.
+    current: Selection<T>,
+    /// The diffs of the changes which can be undone, from oldest to newest.
+    undo: Vec<SelectionDiff<T>>,
+    /// The diffs of the undone changes which can be redone, from newest to
+    /// oldest.
+    redo: Vec<SelectionDiff<T>>,
+}
+
+impl<T> JournaledSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `JournaledSelection` with an empty journal.
+    pub fn new(selection: Selection<T>) -> Self {
+        JournaledSelection {
+            current: selection,
+            undo: Vec::new(),
+            redo: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the current `Selection`.
+    pub fn selection(&self) -> &Selection<T> {
+        &self.current
+    }
+
+    /// Consumes the `JournaledSelection`, returning the current `Selection`
+    /// and discarding the journal.
+    pub fn into_inner(self) -> Selection<T> {
+        self.current
+    }
+
+    // Journal operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Applies the given function to the `Selection`, recording its changes
+    /// and returning the function's result.
+    ///
+    /// Recording a change discards any undone changes. A change which leaves
+    /// the selected points unchanged is not recorded.
+    pub fn edit<F, R>(&mut self, f: F) -> R
+        where F: FnOnce(&mut Selection<T>) -> R
+    {
+        let before = self.current.clone();
+        let res = f(&mut self.current);
+        let diff = before.diff(&self.current);
+        if !diff.is_empty() {
+            self.undo.push(diff);
+            self.redo.clear();
+        }
+        res
+    }
+
+    /// Reverts the most recent change which has not been undone, returning
+    /// `false` if there is none.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(diff) => {
+                diff.revert(&mut self.current);
+                self.redo.push(diff);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Reapplies the most recently undone change, returning `false` if there
+    /// is none.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(diff) => {
+                diff.apply(&mut self.current);
+                self.undo.push(diff);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Returns `true` if there is a change which can be undone.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone change which can be redone.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns the diffs of the changes which can be undone, from oldest to
+    /// newest.
+    ///
+    /// Applying them in order to the `Selection` from before the first change
+    /// produces the current `Selection`.
+    pub fn patches(&self) -> &[SelectionDiff<T>] {
+        &self.undo
+    }
+
+    /// Removes and returns the diffs of the changes which can be undone, from
+    /// oldest to newest, and discards any undone changes.
+    pub fn take_patches(&mut self) -> Vec<SelectionDiff<T>> {
+        self.redo.clear();
+        std::mem::take(&mut self.undo)
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `Selection` to only those points contained in it and the
+    /// given `Interval`, recording the change.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        self.edit(|sel| sel.intersect_in_place(interval));
+    }
+
+    /// Adds all of the points in the given `Interval` to the `Selection`,
+    /// recording the change.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        self.edit(|sel| sel.union_in_place(interval));
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `Selection`, recording the change.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        self.edit(|sel| sel.minus_in_place(interval));
+    }
+
+    /// Toggles all of the points in the given `Interval`, recording the
+    /// change.
+    pub fn symmetric_difference_in_place(&mut self, interval: Interval<T>) {
+        self.edit(|sel| sel.symmetric_difference_in_place(interval));
+    }
+
+    /// Adds the given point to the `Selection`, recording the change.
+    pub fn insert_point(&mut self, point: T) {
+        self.edit(|sel| sel.insert_point(point));
+    }
+
+    /// Removes the given point from the `Selection`, recording the change.
+    pub fn remove_point(&mut self, point: &T) {
+        self.edit(|sel| sel.remove_point(point));
+    }
+}
+
+impl<T> Default for JournaledSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        JournaledSelection::new(Selection::new())
+    }
+}
+
+impl<T> From<Selection<T>> for JournaledSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(selection: Selection<T>) -> Self {
+        JournaledSelection::new(selection)
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides lattice operations for writing algorithms generic over intervals
+//! and selections.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Join
+////////////////////////////////////////////////////////////////////////////////
+/// A join-semilattice with a least element.
+///
+/// `join` must be associative, commutative, and idempotent, and `bottom` must
+/// be its identity. For `Interval`s, the join is [`Interval::enclose`] and the
+/// least element is the empty interval. For `Selection`s, the join is
+/// [`Selection::union`] and the least element is the empty selection.
+///
+/// [`Interval::enclose`]: ../interval/struct.Interval.html#method.enclose
+/// [`Selection::union`]: ../selection/struct.Selection.html#method.union
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::lattice::Join;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// fn join_all<L: Join>(items: &[L]) -> L {
+///     items.iter().fold(L::bottom(), |acc, item| acc.join(item))
+/// }
+///
+/// let intervals = [Interval::closed(3, 5), Interval::closed(-2, 0)];
+/// assert_eq!(join_all(&intervals), Interval::closed(-2, 5));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub trait Join: Sized {
+    /// Returns the least element of the lattice.
+    fn bottom() -> Self;
+
+    /// Returns the least upper bound of the two elements.
+    fn join(&self, other: &Self) -> Self;
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Meet
+////////////////////////////////////////////////////////////////////////////////
+/// A meet-semilattice with a greatest element.
+///
+/// `meet` must be associative, commutative, and idempotent, and `top` must be
+/// its identity. For `Interval`s, the meet is [`Interval::intersect`] and the
+/// greatest element is the full interval. For `Selection`s, the meet is
+/// [`Selection::intersect`] and the greatest element is the full selection.
+///
+/// [`Interval::intersect`]: ../interval/struct.Interval.html#method.intersect
+/// [`Selection::intersect`]: ../selection/struct.Selection.html#method.intersect
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::lattice::Meet;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// fn meet_all<L: Meet>(items: &[L]) -> L {
+///     items.iter().fold(L::top(), |acc, item| acc.meet(item))
+/// }
+///
+/// let sels = [
+///     Selection::from(Interval::closed(0, 9)),
+///     Selection::from(Interval::closed(4, 20)),
+/// ];
+/// assert_eq!(meet_all(&sels), Interval::closed(4, 9));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub trait Meet: Sized {
+    /// Returns the greatest element of the lattice.
+    fn top() -> Self;
+
+    /// Returns the greatest lower bound of the two elements.
+    fn meet(&self, other: &Self) -> Self;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval impls
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Join for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn bottom() -> Self {
+        Interval::empty()
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        self.enclose(other)
+    }
+}
+
+impl<T> Meet for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn top() -> Self {
+        Interval::full()
+    }
+
+    fn meet(&self, other: &Self) -> Self {
+        self.intersect(other)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection impls
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Join for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn bottom() -> Self {
+        Selection::new()
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl<T> Meet for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn top() -> Self {
+        Selection::full()
+    }
+
+    fn meet(&self, other: &Self) -> Self {
+        self.intersect(other)
+    }
+}
@@ -0,0 +1,397 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a selection which counts how many intervals cover each point.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::interval_map::IntervalMap;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::cmp::Ordering;
+use std::iter::FromIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// LayeredSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A multiset of `Interval`s of the type `T`, which tracks the number of
+/// inserted `Interval`s covering each point.
+///
+/// Equality and hashing depend only on the coverage of each point, not on the
+/// order or form of the `Interval`s used to construct the `LayeredSelection`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LayeredSelection<T>(IntervalMap<T, usize>);
+
+// Only points with nonzero coverage are stored in the `IntervalMap`, and
+// adjacent intervals with equal coverage are always merged.
+impl<T> LayeredSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `LayeredSelection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::LayeredSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let layers: LayeredSelection<i32> = LayeredSelection::new();
+    ///
+    /// assert!(layers.is_empty());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[inline]
+    pub const fn new() -> Self {
+        LayeredSelection(IntervalMap::new())
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if no point is covered by the `LayeredSelection`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of inserted `Interval`s covering the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::LayeredSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut layers = LayeredSelection::new();
+    /// layers.insert(Interval::closed(0, 10));
+    /// layers.insert(Interval::closed(5, 15));
+    ///
+    /// assert_eq!(layers.coverage_at(&3), 1);
+    /// assert_eq!(layers.coverage_at(&7), 2);
+    /// assert_eq!(layers.coverage_at(&20), 0);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn coverage_at(&self, point: &T) -> usize {
+        self.0.get(point).copied().unwrap_or(0)
+    }
+
+    /// Returns the maximum coverage of any point in the `LayeredSelection`.
+    pub fn max_coverage(&self) -> usize {
+        self.0.iter().map(|(_, c)| *c).max().unwrap_or(0)
+    }
+
+    /// Returns a `Selection` containing all points covered by at least `n`
+    /// inserted `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::LayeredSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut layers = LayeredSelection::new();
+    /// layers.insert(Interval::closed(0, 10));
+    /// layers.insert(Interval::closed(5, 15));
+    ///
+    /// assert_eq!(layers.at_least(2), Interval::closed(5, 10));
+    /// assert_eq!(layers.at_least(1), Interval::closed(0, 15));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn at_least(&self, n: usize) -> Selection<T> {
+        if n == 0 { return Selection::full(); }
+        self.0.iter()
+            .filter(|&(_, c)| *c >= n)
+            .map(|(interval, _)| interval)
+            .collect()
+    }
+
+    // Mutation operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Increments the coverage of every point in the given `Interval`.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        self.0.update(interval, |c| Some(c.map_or(1, |c| c + 1)));
+    }
+
+    /// Decrements the coverage of every point in the given `Interval`. Points
+    /// which are not covered are left uncovered.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::LayeredSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut layers = LayeredSelection::new();
+    /// layers.insert(Interval::closed(0, 10));
+    /// layers.insert(Interval::closed(5, 15));
+    /// layers.remove(Interval::closed(0, 10));
+    ///
+    /// assert_eq!(layers.coverage_at(&3), 0);
+    /// assert_eq!(layers.coverage_at(&7), 1);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remove(&mut self, interval: Interval<T>) {
+        self.0.update(interval, |c| c.and_then(|c| c.checked_sub(1))
+            .filter(|&c| c > 0));
+    }
+
+    /// Removes all `Interval`s from the `LayeredSelection`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over the maximal `Interval`s of constant nonzero
+    /// coverage in the `LayeredSelection`, along with their coverage.
+    pub fn iter(&self) -> impl Iterator<Item=(Interval<T>, usize)> + '_ {
+        self.0.iter().map(|(interval, c)| (interval, *c))
+    }
+}
+
+impl<T> Default for LayeredSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        LayeredSelection::new()
+    }
+}
+
+impl<T> Extend<Interval<T>> for LayeredSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        for interval in iter.into_iter() {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for LayeredSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        let mut layers = LayeredSelection::new();
+        layers.extend(iter);
+        layers
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// coverage
+////////////////////////////////////////////////////////////////////////////////
+/// Returns the coverage of the given possibly overlapping `Interval`s, as the
+/// maximal `Interval`s of constant nonzero coverage in order, along with
+/// their coverage.
+///
+/// This is the same sequence returned by [`LayeredSelection::iter`] after
+/// inserting each of the `Interval`s, but is computed with a single sweep
+/// over their sorted bounds, taking `O(n log n)` time for `n` `Interval`s.
+///
+/// [`LayeredSelection::iter`]: struct.LayeredSelection.html#method.iter
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::layered_selection::coverage;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let reads = vec![
+///     Interval::closed(0, 10),
+///     Interval::closed(5, 15),
+///     Interval::closed(8, 9),
+///     Interval::closed(20, 25),
+/// ];
+///
+/// assert_eq!(coverage(reads), [
+///     (Interval::closed(0, 4), 1),
+///     (Interval::closed(5, 7), 2),
+///     (Interval::closed(8, 9), 3),
+///     (Interval::point(10), 2),
+///     (Interval::closed(11, 15), 1),
+///     (Interval::closed(20, 25), 1),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn coverage<T, I>(intervals: I) -> Vec<(Interval<T>, usize)>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: IntoIterator<Item=Interval<T>>,
+{
+    // Each interval enters at its lower cut and exits at its upper cut. The
+    // intervals are denormalized so that adjacent intervals of `Finite` types
+    // overlap, leaving only empty steps between them.
+    let mut cuts = Vec::new();
+    for interval in intervals {
+        let raw = interval.0.denormalized();
+        if let (Some(lower), Some(upper)) = (raw.lower_bound(),
+            raw.upper_bound())
+        {
+            cuts.push((Cut::from_lower(lower), true));
+            cuts.push((Cut::from_upper(upper), false));
+        }
+    }
+    cuts.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut steps: Vec<(Interval<T>, usize)> = Vec::new();
+    let mut count = 0;
+    let mut start: Option<Cut<T>> = None;
+    // Whether the last step ends where the current one starts, with only
+    // empty steps between them.
+    let mut joined = false;
+    let mut idx = 0;
+    while idx < cuts.len() {
+        let cut = cuts[idx].0.clone();
+        let (mut enters, mut exits) = (0, 0);
+        while idx < cuts.len() && cuts[idx].0 == cut {
+            if cuts[idx].1 { enters += 1; } else { exits += 1; }
+            idx += 1;
+        }
+        // Cuts where as many intervals enter as exit do not change coverage.
+        if enters == exits { continue; }
+
+        if let Some(lower) = start.take() {
+            let step = RawInterval::new(
+                    lower.into_lower(),
+                    cut.clone().into_upper())
+                .normalized();
+            match steps.last_mut() {
+                _ if step.is_empty() => (),
+                Some((last, c)) if joined && *c == count => {
+                    *last = Interval(last.0.enclose(&step));
+                },
+                _ => {
+                    steps.push((Interval(step), count));
+                    joined = true;
+                },
+            }
+        }
+        count = count + enters - exits;
+        if count > 0 { start = Some(cut); } else { joined = false; }
+    }
+    steps
+}
+
+/// A position between points, at which the coverage may change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Cut<T> {
+    /// The position below all points.
+    Below,
+    /// The position just before the point.
+    Before(T),
+    /// The position just after the point.
+    After(T),
+    /// The position above all points.
+    Above,
+}
+
+impl<T> Cut<T> {
+    /// Returns the cut at the given lower bound.
+    fn from_lower(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Include(p) => Cut::Before(p),
+            Bound::Exclude(p) => Cut::After(p),
+            Bound::Infinite   => Cut::Below,
+        }
+    }
+
+    /// Returns the cut at the given upper bound.
+    fn from_upper(bound: Bound<T>) -> Self {
+        match bound {
+            Bound::Include(p) => Cut::After(p),
+            Bound::Exclude(p) => Cut::Before(p),
+            Bound::Infinite   => Cut::Above,
+        }
+    }
+
+    /// Returns the lower bound of the points above the cut.
+    fn into_lower(self) -> Bound<T> {
+        match self {
+            Cut::Before(p)          => Bound::Include(p),
+            Cut::After(p)           => Bound::Exclude(p),
+            Cut::Below | Cut::Above => Bound::Infinite,
+        }
+    }
+
+    /// Returns the upper bound of the points below the cut.
+    fn into_upper(self) -> Bound<T> {
+        match self {
+            Cut::Before(p)          => Bound::Exclude(p),
+            Cut::After(p)           => Bound::Include(p),
+            Cut::Below | Cut::Above => Bound::Infinite,
+        }
+    }
+}
+
+impl<T> PartialOrd for Cut<T> where T: Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Cut ordering is total.
+impl<T> Ord for Cut<T> where T: Ord {
+    fn cmp(&self, other: &Self) -> Ordering {
+        use Cut::*;
+        match (self, other) {
+            (Below,     Below)     => Ordering::Equal,
+            (Below,     _)         => Ordering::Less,
+            (_,         Below)     => Ordering::Greater,
+            (Above,     Above)     => Ordering::Equal,
+            (Above,     _)         => Ordering::Greater,
+            (_,         Above)     => Ordering::Less,
+            (Before(a), After(b))  => a.cmp(b).then(Ordering::Less),
+            (After(a),  Before(b)) => a.cmp(b).then(Ordering::Greater),
+            (Before(a), Before(b)) => a.cmp(b),
+            (After(a),  After(b))  => a.cmp(b),
+        }
+    }
+}
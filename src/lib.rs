@@ -10,6 +10,7 @@
 //! Provides a basic bounded interval type for doing complex set selections.
 //!
 ////////////////////////////////////////////////////////////////////////////////
+#![cfg_attr(feature = "step", feature(step_trait))]
 #![warn(anonymous_parameters)]
 #![warn(bad_style)]
 #![warn(bare_trait_objects)]
@@ -42,9 +43,27 @@
 #![warn(while_true)]
 
 // // Internal modules.
+pub(in crate) mod arithmetic;
+#[cfg(feature = "chrono")]
+pub(in crate) mod chrono_impl;
+#[cfg(feature = "rust_decimal")]
+pub(in crate) mod decimal_impl;
+pub(in crate) mod interpolate;
+pub(in crate) mod ip;
+pub(in crate) mod macros;
 pub(in crate) mod raw_interval;
+#[cfg(feature = "rayon")]
+pub(in crate) mod rayon_impl;
+#[cfg(feature = "rand")]
+pub(in crate) mod sample;
+#[cfg(feature = "step")]
+pub(in crate) mod step_impl;
+#[cfg(feature = "time")]
+pub(in crate) mod time_impl;
 pub(in crate) mod tine;
 pub(in crate) mod tine_tree;
+#[cfg(feature = "unicode")]
+pub(in crate) mod unicode_tables;
 pub(in crate) mod utility {
     pub(in crate) use few::Few;
 }
@@ -52,13 +71,52 @@ pub(in crate) mod utility {
 #[cfg(test)]
 mod test;
 
+// Allows the paths generated by the derive macros to resolve within the crate.
+#[cfg(all(test, feature = "derive"))]
+extern crate self as normalize_interval;
+
 // Public modules.
 pub mod bound;
+pub mod byte_selection;
+pub mod bytes;
+pub mod circular;
+pub mod error;
+pub mod format;
+pub mod granular;
 pub mod interval;
+pub mod interval_index;
+pub mod interval_map;
+pub mod journal;
+pub mod lattice;
+pub mod layered_selection;
+pub mod measure;
+pub mod merge;
+#[cfg(any(test, feature = "test-support"))]
+pub mod naive;
 pub mod normalize;
+pub mod persistent;
+pub mod region;
 pub mod selection;
+#[cfg(feature = "serde")]
+pub mod serde_repr;
+pub mod set_ops;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+pub mod sync_selection;
+#[cfg(feature = "unicode")]
+pub mod unicode;
 
 // Exports.
+pub use crate::arithmetic::CheckedArithmetic;
 pub use crate::bound::Bound;
+pub use crate::byte_selection::ByteSelection;
+pub use crate::error::IntervalError;
 pub use crate::interval::Interval;
+pub use crate::interval_index::IntervalIndex;
+pub use crate::interval_map::IntervalMap;
+pub use crate::journal::JournaledSelection;
+pub use crate::layered_selection::LayeredSelection;
+pub use crate::persistent::PersistentSelection;
+pub use crate::raw_interval::RawIntervalRef;
 pub use crate::selection::Selection;
+pub use crate::sync_selection::SyncSelection;
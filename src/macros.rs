@@ -0,0 +1,94 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides macros for constructing intervals and selections.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+
+////////////////////////////////////////////////////////////////////////////////
+// interval!
+////////////////////////////////////////////////////////////////////////////////
+/// Constructs an [`Interval`] from a range expression or a point.
+///
+/// Any of the standard range types may be given, as may a single point. With
+/// no arguments, an empty `Interval` is constructed.
+///
+/// [`Interval`]: interval/struct.Interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::interval;
+/// # use normalize_interval::Interval;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// assert_eq!(interval!(1..5), Interval::right_open(1, 5));
+/// assert_eq!(interval!(3..=9), Interval::closed(3, 9));
+/// assert_eq!(interval!(..=0), Interval::unbounded_to(0));
+/// assert_eq!(interval!(7), Interval::point(7));
+/// assert_eq!(interval!(), Interval::<i32>::empty());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! interval {
+    () => {
+        $crate::Interval::empty()
+    };
+    ($e:expr) => {
+        $crate::Interval::from($e)
+    };
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// selection!
+////////////////////////////////////////////////////////////////////////////////
+/// Constructs a [`Selection`] from a list of range expressions and points.
+///
+/// Each element is converted as by [`interval!`], and the resulting
+/// `Interval`s are merged in a single sweep. With no arguments, an empty
+/// `Selection` is constructed.
+///
+/// [`Selection`]: selection/struct.Selection.html
+/// [`interval!`]: macro.interval.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::selection;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel: Selection<u8> = selection![1..3, 7, 10..];
+///
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(1, 2),
+///     Interval::point(7),
+///     Interval::closed(10, 255),
+/// ]);
+/// assert_eq!(selection![], Selection::<u8>::new());
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! selection {
+    () => {
+        $crate::Selection::new()
+    };
+    ($($e:expr),+ $(,)?) => {
+        $crate::Selection::union_all(vec![$($crate::interval!($e)),+])
+    };
+}
@@ -0,0 +1,325 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides the length of intervals for types with a notion of size.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::bound::UpperBound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::time::Duration;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Measurable
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the length, or measure, of intervals over a type.
+///
+/// The measure of an interval of a [`Finite`] type is the number of points it
+/// contains, while the measure of an interval of a dense type is the
+/// difference between its bounds. Empty intervals have zero measure, and
+/// unbounded intervals of dense types have no measure.
+///
+/// Dense implementations are provided for `Duration` and, behind their
+/// features, for `Decimal` and the `chrono` and `time` duration types. Floats
+/// are not supported, as they are not `Ord` and so cannot be the points of an
+/// interval.
+///
+/// [`Finite`]: ../normalize/trait.Finite.html
+pub trait Measurable: Ord + Clone {
+    /// The type of the measure.
+    type Output;
+
+    /// Returns the measure of an empty interval.
+    fn empty_measure() -> Self::Output;
+
+    /// Returns the measure of the non-empty interval with the given bounds, or
+    /// `None` if it cannot be represented.
+    fn measure_between(lower: &Bound<Self>, upper: &Bound<Self>)
+        -> Option<Self::Output>;
+
+    /// Returns the sum of two measures, or `None` if it cannot be represented.
+    fn checked_add_measures(a: Self::Output, b: Self::Output)
+        -> Option<Self::Output>;
+
+    /// Returns the measure as an `f64`, for taking ratios of measures.
+    fn measure_to_f64(measure: &Self::Output) -> f64;
+}
+
+// `Finite` types are measured by counting their points.
+impl<T> Measurable for T where T: Ord + Clone + Finite {
+    type Output = u128;
+
+    fn empty_measure() -> u128 {
+        0
+    }
+
+    fn measure_between(lower: &Bound<Self>, upper: &Bound<Self>)
+        -> Option<u128>
+    {
+        // An excluded bound at the type's extreme leaves no points.
+        let first = match lower {
+            Bound::Include(p) => p.clone(),
+            Bound::Exclude(p) => match p.succ() {
+                Some(p) => p,
+                None    => return Some(0),
+            },
+            Bound::Infinite   => T::MINIMUM,
+        };
+        let last = match upper {
+            Bound::Include(p) => p.clone(),
+            Bound::Exclude(p) => match p.pred() {
+                Some(p) => p,
+                None    => return Some(0),
+            },
+            Bound::Infinite   => T::MAXIMUM,
+        };
+        if last < first { return Some(0); }
+        (first.steps_between(&last)? as u128).checked_add(1)
+    }
+
+    fn checked_add_measures(a: u128, b: u128) -> Option<u128> {
+        a.checked_add(b)
+    }
+
+    fn measure_to_f64(measure: &u128) -> f64 {
+        *measure as f64
+    }
+}
+
+
+// `Duration` intervals are measured by the difference between their bounds.
+impl Measurable for Duration {
+    type Output = Duration;
+
+    fn empty_measure() -> Duration {
+        Duration::ZERO
+    }
+
+    fn measure_between(lower: &Bound<Self>, upper: &Bound<Self>)
+        -> Option<Duration>
+    {
+        match (lower.as_ref(), upper.as_ref()) {
+            (Some(l), Some(u)) => u.checked_sub(*l),
+            _                  => None,
+        }
+    }
+
+    fn checked_add_measures(a: Duration, b: Duration) -> Option<Duration> {
+        a.checked_add(b)
+    }
+
+    fn measure_to_f64(measure: &Duration) -> f64 {
+        measure.as_secs_f64()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Measure helpers
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Interval<T>
+    where
+        T: Measurable,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the measure of the `Interval`, or `None` if it cannot be
+    /// represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let interval: Interval<i32> = Interval::open(3, 7);
+    /// assert_eq!(interval.measure(), Some(3));
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.measure(), Some(0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn measure(&self) -> Option<T::Output> {
+        match (self.0.lower_bound(), self.0.upper_bound()) {
+            (Some(l), Some(u)) => T::measure_between(&l, &u),
+            _                  => Some(T::empty_measure()),
+        }
+    }
+}
+
+impl<T> Selection<T>
+    where
+        T: Measurable,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the total measure of the `Selection`'s intervals, or `None` if
+    /// it cannot be represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::open(10, 15));
+    /// assert_eq!(sel.measure(), Some(13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn measure(&self) -> Option<T::Output> {
+        self.interval_iter().try_fold(T::empty_measure(), |sum, interval|
+            T::checked_add_measures(sum, interval.measure()?))
+    }
+
+    /// Returns the total measure of the points shared by the `Selection`s, or
+    /// `None` if it cannot be represented.
+    ///
+    /// This walks the intervals of both `Selection`s together, without
+    /// constructing their intersection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// a.union_in_place(Interval::closed(10, 15));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 12));
+    ///
+    /// assert_eq!(a.overlap_measure(&b), Some(5));
+    /// assert_eq!(a.overlap_measure(&b), a.intersect(&b).measure());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlap_measure(&self, other: &Self) -> Option<T::Output> {
+        let mut a_iter = self.interval_iter();
+        let mut b_iter = other.interval_iter();
+        let mut a = a_iter.next();
+        let mut b = b_iter.next();
+        let mut sum = T::empty_measure();
+        while let (Some(x), Some(y)) = (&a, &b) {
+            let overlap = x.intersect(y);
+            if !overlap.is_empty() {
+                sum = T::checked_add_measures(sum, overlap.measure()?)?;
+            }
+            // The interval which ends first can't overlap any later interval
+            // of the other selection.
+            if upper_of(x) <= upper_of(y) {
+                a = a_iter.next();
+            } else {
+                b = b_iter.next();
+            }
+        }
+        Some(sum)
+    }
+
+    /// Returns the Jaccard similarity of the `Selection`s: the measure of
+    /// their intersection divided by the measure of their union. Returns
+    /// `None` if any of the measures cannot be represented.
+    ///
+    /// If the union has zero measure, the similarity is `1.0` if the
+    /// `Selection`s are equal and `0.0` otherwise.
+    ///
+    /// This walks the intervals of both `Selection`s together, without
+    /// constructing their intersection or union.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::right_open(0, 30));
+    /// let b: Selection<i32> = Selection::from(Interval::right_open(20, 40));
+    ///
+    /// assert_eq!(a.jaccard(&b), Some(0.25));
+    /// assert_eq!(a.jaccard(&a), Some(1.0));
+    /// assert_eq!(a.jaccard(&Selection::new()), Some(0.0));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn jaccard(&self, other: &Self) -> Option<f64> {
+        let add = |sum: T::Output, interval: &Interval<T>|
+            T::checked_add_measures(sum, interval.measure()?);
+        let mut a_iter = self.interval_iter();
+        let mut b_iter = other.interval_iter();
+        let mut a = a_iter.next();
+        let mut b = b_iter.next();
+        let mut shared = T::empty_measure();
+        let mut a_total = T::empty_measure();
+        let mut b_total = T::empty_measure();
+        // Each interval is added to its selection's total as it is passed.
+        loop {
+            match (&a, &b) {
+                (Some(x), Some(y)) => {
+                    let overlap = x.intersect(y);
+                    if !overlap.is_empty() {
+                        shared = add(shared, &overlap)?;
+                    }
+                    if upper_of(x) <= upper_of(y) {
+                        a_total = add(a_total, x)?;
+                        a = a_iter.next();
+                    } else {
+                        b_total = add(b_total, y)?;
+                        b = b_iter.next();
+                    }
+                },
+                (Some(x), None) => {
+                    a_total = add(a_total, x)?;
+                    a = a_iter.next();
+                },
+                (None, Some(y)) => {
+                    b_total = add(b_total, y)?;
+                    b = b_iter.next();
+                },
+                (None, None) => break,
+            }
+        }
+
+        let shared = T::measure_to_f64(&shared);
+        let union = T::measure_to_f64(&a_total) + T::measure_to_f64(&b_total)
+            - shared;
+        if union > 0.0 {
+            Some(shared / union)
+        } else if self == other {
+            Some(1.0)
+        } else {
+            Some(0.0)
+        }
+    }
+}
+
+/// Returns the upper bound of a nonempty `Interval`, ordered by the points it
+/// includes.
+fn upper_of<T>(interval: &Interval<T>) -> UpperBound<T>
+    where T: Ord + Clone
+{
+    UpperBound(interval.0.upper_bound().expect("nonempty interval"))
+}
@@ -0,0 +1,334 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides lazy merging of sorted streams of `Interval`s.
+//!
+//! The adapters here produce the same `Interval`s as collecting their inputs
+//! into a `Selection` and iterating over it, but hold only one pending
+//! `Interval` per input, so they can be used on streams too large to collect.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::LowerBound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::iter::Fuse;
+use std::iter::FusedIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Constructors
+////////////////////////////////////////////////////////////////////////////////
+/// Returns an iterator which lazily merges two streams of `Interval`s sorted
+/// by their lower bounds, coalescing any which overlap or are adjacent.
+///
+/// Empty `Interval`s in the inputs are skipped. The inputs need not be
+/// disjoint, but if either is not sorted, the output `Interval`s may not be
+/// sorted or disjoint.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::merge::merge_sorted;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let a = vec![Interval::closed(0, 3), Interval::closed(10, 12)];
+/// let b = vec![Interval::closed(4, 6), Interval::closed(20, 25)];
+///
+/// let merged: Vec<_> = merge_sorted(a, b).collect();
+/// assert_eq!(merged, [
+///     Interval::closed(0, 6),
+///     Interval::closed(10, 12),
+///     Interval::closed(20, 25),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn merge_sorted<T, I, J>(a: I, b: J)
+    -> MergeSorted<T, I::IntoIter, J::IntoIter>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: IntoIterator<Item=Interval<T>>,
+        J: IntoIterator<Item=Interval<T>>,
+{
+    MergeSorted {
+        a: a.into_iter().fuse(),
+        b: b.into_iter().fuse(),
+        a_next: None,
+        b_next: None,
+        pending: None,
+    }
+}
+
+/// Returns an iterator which lazily merges any number of streams of
+/// `Interval`s sorted by their lower bounds, coalescing any which overlap or
+/// are adjacent.
+///
+/// Each step takes `O(log k)` time for `k` input streams. Empty `Interval`s
+/// in the inputs are skipped. The inputs need not be disjoint, but if any is
+/// not sorted, the output `Interval`s may not be sorted or disjoint.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::merge::kmerge_sorted;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let streams = vec![
+///     vec![Interval::closed(0, 2), Interval::closed(30, 40)],
+///     vec![Interval::closed(1, 5)],
+///     vec![Interval::closed(6, 9), Interval::closed(35, 50)],
+/// ];
+///
+/// let merged: Vec<_> = kmerge_sorted(streams).collect();
+/// assert_eq!(merged, [
+///     Interval::closed(0, 9),
+///     Interval::closed(30, 50),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub fn kmerge_sorted<T, I>(streams: I)
+    -> KMergeSorted<T, <I::Item as IntoIterator>::IntoIter>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: IntoIterator,
+        I::Item: IntoIterator<Item=Interval<T>>,
+{
+    let mut streams: Vec<_> = streams
+        .into_iter()
+        .map(|s| s.into_iter().fuse())
+        .collect();
+    let mut heads = Vec::with_capacity(streams.len());
+    let mut heap = BinaryHeap::with_capacity(streams.len());
+    for (idx, stream) in streams.iter_mut().enumerate() {
+        let head = next_raw(stream);
+        if let Some(lower) = head.as_ref().and_then(lower_of) {
+            heap.push(Reverse((lower, idx)));
+        }
+        heads.push(head);
+    }
+
+    KMergeSorted {
+        streams,
+        heads,
+        heap,
+        pending: None,
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// MergeSorted
+////////////////////////////////////////////////////////////////////////////////
+/// An iterator over the coalesced `Interval`s of two sorted streams.
+///
+/// This `struct` is created by the [`merge_sorted`] function. See its
+/// documentation for more.
+///
+/// [`merge_sorted`]: fn.merge_sorted.html
+#[derive(Debug, Clone)]
+pub struct MergeSorted<T, I, J> {
+    a: Fuse<I>,
+    b: Fuse<J>,
+    a_next: Option<RawInterval<T>>,
+    b_next: Option<RawInterval<T>>,
+    pending: Option<RawInterval<T>>,
+}
+
+impl<T, I, J> MergeSorted<T, I, J>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+        J: Iterator<Item=Interval<T>>,
+{
+    /// Returns the next input interval with the least lower bound.
+    fn next_input(&mut self) -> Option<RawInterval<T>> {
+        if self.a_next.is_none() { self.a_next = next_raw(&mut self.a); }
+        if self.b_next.is_none() { self.b_next = next_raw(&mut self.b); }
+
+        let take_a = match (&self.a_next, &self.b_next) {
+            (Some(a), Some(b)) => lower_of(a) <= lower_of(b),
+            (a, _)             => a.is_some(),
+        };
+        if take_a { self.a_next.take() } else { self.b_next.take() }
+    }
+}
+
+impl<T, I, J> Iterator for MergeSorted<T, I, J>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+        J: Iterator<Item=Interval<T>>,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.next_input() {
+            if let Some(done) = coalesce(&mut self.pending, next) {
+                return Some(Interval::from(done));
+            }
+        }
+        self.pending.take().map(Interval::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.a_next.iter().count()
+            + self.b_next.iter().count()
+            + self.pending.iter().count();
+        let upper = match (self.a.size_hint().1, self.b.size_hint().1) {
+            (Some(a), Some(b)) => a.checked_add(b)
+                .and_then(|n| n.checked_add(buffered)),
+            _ => None,
+        };
+        (buffered.min(1), upper)
+    }
+}
+
+impl<T, I, J> FusedIterator for MergeSorted<T, I, J>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+        J: Iterator<Item=Interval<T>>,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// KMergeSorted
+////////////////////////////////////////////////////////////////////////////////
+/// An iterator over the coalesced `Interval`s of any number of sorted streams.
+///
+/// This `struct` is created by the [`kmerge_sorted`] function. See its
+/// documentation for more.
+///
+/// [`kmerge_sorted`]: fn.kmerge_sorted.html
+#[derive(Debug, Clone)]
+pub struct KMergeSorted<T, I> {
+    streams: Vec<Fuse<I>>,
+    /// The next interval of each stream.
+    heads: Vec<Option<RawInterval<T>>>,
+    /// The lower bounds of the heads, with their stream indices. Ties are
+    /// broken by stream index, keeping the merge stable.
+    heap: BinaryHeap<Reverse<(LowerBound<T>, usize)>>,
+    pending: Option<RawInterval<T>>,
+}
+
+impl<T, I> KMergeSorted<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    /// Returns the next input interval with the least lower bound.
+    fn next_input(&mut self) -> Option<RawInterval<T>> {
+        let Reverse((_, idx)) = self.heap.pop()?;
+        let next = self.heads[idx].take();
+
+        self.heads[idx] = next_raw(&mut self.streams[idx]);
+        if let Some(lower) = self.heads[idx].as_ref().and_then(lower_of) {
+            self.heap.push(Reverse((lower, idx)));
+        }
+        next
+    }
+}
+
+impl<T, I> Iterator for KMergeSorted<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(next) = self.next_input() {
+            if let Some(done) = coalesce(&mut self.pending, next) {
+                return Some(Interval::from(done));
+            }
+        }
+        self.pending.take().map(Interval::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let buffered = self.heap.len() + self.pending.iter().count();
+        let upper = self.streams
+            .iter()
+            .try_fold(buffered, |acc, s| s.size_hint().1
+                .and_then(|n| acc.checked_add(n)));
+        (buffered.min(1), upper)
+    }
+}
+
+impl<T, I> FusedIterator for KMergeSorted<T, I>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Helpers
+////////////////////////////////////////////////////////////////////////////////
+// Returns the next non-empty interval of the stream, denormalized so that
+// adjacent intervals of `Finite` types can be detected.
+fn next_raw<T, I>(stream: &mut I) -> Option<RawInterval<T>>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        I: Iterator<Item=Interval<T>>,
+{
+    stream
+        .map(|interval| interval.0.denormalized())
+        .find(|raw| !raw.is_empty())
+}
+
+// Returns the lower bound of a non-empty interval.
+fn lower_of<T>(raw: &RawInterval<T>) -> Option<LowerBound<T>>
+    where T: Ord + Clone
+{
+    raw.lower_bound().map(LowerBound)
+}
+
+// Merges the next interval into the pending one, returning the pending
+// interval if it is complete.
+fn coalesce<T>(pending: &mut Option<RawInterval<T>>, next: RawInterval<T>)
+    -> Option<RawInterval<T>>
+    where T: Ord + Clone
+{
+    match pending.take() {
+        Some(current) if current.intersects(&next)
+            || current.adjacent(&next) =>
+        {
+            *pending = Some(current.enclose(&next));
+            None
+        },
+        done => {
+            *pending = Some(next);
+            done
+        },
+    }
+}
@@ -0,0 +1,275 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a brute-force reference implementation of `Selection` for
+//! differential and property testing.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::collections::BTreeSet;
+use std::iter::FromIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// NaiveSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A selection of the `Finite` type `T` which stores each of its points
+/// explicitly.
+///
+/// `NaiveSelection` mirrors the API of [`Selection`], but implements each
+/// operation directly on its set of points, making it an obviously correct
+/// oracle to compare `Selection`s against. Every operation takes time and
+/// space proportional to the number of points involved, so it is only
+/// suitable for small intervals.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::naive::NaiveSelection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel = Selection::from(Interval::closed(-3, 5));
+/// let mut naive = NaiveSelection::from(Interval::closed(-3, 5));
+///
+/// sel.minus_in_place(Interval::open(0, 3));
+/// naive.minus_in_place(Interval::open(0, 3));
+///
+/// assert_eq!(naive, sel);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NaiveSelection<T>(BTreeSet<T>);
+
+impl<T> NaiveSelection<T> where T: Ord + Clone + Finite {
+    /// Constructs a new empty `NaiveSelection`.
+    pub fn new() -> Self {
+        NaiveSelection(BTreeSet::new())
+    }
+
+    /// Returns the least point in the `NaiveSelection`.
+    pub fn infimum(&self) -> Option<T> {
+        self.0.iter().next().cloned()
+    }
+
+    /// Returns the greatest point in the `NaiveSelection`.
+    pub fn supremum(&self) -> Option<T> {
+        self.0.iter().next_back().cloned()
+    }
+
+    /// Returns `true` if the `NaiveSelection` contains no points.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the `NaiveSelection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        self.0.contains(point)
+    }
+
+    /// Returns the number of intervals in the `NaiveSelection`.
+    pub fn interval_count(&self) -> usize {
+        self.interval_iter().count()
+    }
+
+    /// Returns the number of points in the `NaiveSelection`.
+    pub fn point_count(&self) -> Option<u128> {
+        Some(self.0.len() as u128)
+    }
+
+    /// Returns `true` if the `NaiveSelection` shares any points with the
+    /// given `Interval`.
+    pub fn intersects(&self, interval: &Interval<T>) -> bool {
+        !self.is_disjoint(&NaiveSelection::from(interval.clone()))
+    }
+
+    /// Returns `true` if the `NaiveSelection`s share any points.
+    pub fn intersects_selection(&self, other: &Self) -> bool {
+        !self.0.is_disjoint(&other.0)
+    }
+
+    /// Returns `true` if every point of the `NaiveSelection` is in the other.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.0.is_subset(&other.0)
+    }
+
+    /// Returns `true` if every point of the other `NaiveSelection` is in
+    /// this one.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        self.0.is_superset(&other.0)
+    }
+
+    /// Returns `true` if the `NaiveSelection`s share no points.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.0.is_disjoint(&other.0)
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a `NaiveSelection` containing the points of the given
+    /// `Interval` which are not in this one.
+    pub fn complement_within(&self, bounds: &Interval<T>) -> Self {
+        NaiveSelection::from(bounds.clone()).minus(self)
+    }
+
+    /// Returns a `NaiveSelection` containing the points in both
+    /// `NaiveSelection`s.
+    pub fn intersect(&self, other: &Self) -> Self {
+        NaiveSelection(self.0.intersection(&other.0).cloned().collect())
+    }
+
+    /// Returns a `NaiveSelection` containing the points in either
+    /// `NaiveSelection`.
+    pub fn union(&self, other: &Self) -> Self {
+        NaiveSelection(self.0.union(&other.0).cloned().collect())
+    }
+
+    /// Returns a `NaiveSelection` containing the points in this
+    /// `NaiveSelection` but not the other.
+    pub fn minus(&self, other: &Self) -> Self {
+        NaiveSelection(self.0.difference(&other.0).cloned().collect())
+    }
+
+    /// Returns a `NaiveSelection` containing the points in exactly one of the
+    /// `NaiveSelection`s.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        NaiveSelection(self.0
+            .symmetric_difference(&other.0)
+            .cloned()
+            .collect())
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `NaiveSelection` to only those points contained in it and
+    /// the given `Interval`.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        self.0.retain(|p| interval.contains(p));
+    }
+
+    /// Adds all of the points in the given `Interval` to the
+    /// `NaiveSelection`.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        self.0.extend(interval.iter_from_lower());
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `NaiveSelection`.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        self.0.retain(|p| !interval.contains(p));
+    }
+
+    /// Toggles all of the points in the given `Interval`.
+    pub fn symmetric_difference_in_place(&mut self, interval: Interval<T>) {
+        for point in interval.iter_from_lower() {
+            if !self.0.remove(&point) {
+                let _ = self.0.insert(point);
+            }
+        }
+    }
+
+    /// Adds the given point to the `NaiveSelection`.
+    pub fn insert_point(&mut self, point: T) {
+        let _ = self.0.insert(point);
+    }
+
+    /// Removes the given point from the `NaiveSelection`.
+    pub fn remove_point(&mut self, point: &T) {
+        let _ = self.0.remove(point);
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the
+    /// `NaiveSelection`, formed from its runs of consecutive points.
+    pub fn interval_iter(&self) -> impl Iterator<Item=Interval<T>> + '_ {
+        let mut points = self.0.iter().peekable();
+        std::iter::from_fn(move || {
+            let first = points.next()?;
+            let mut last = first;
+            while let Some(next) = points.peek() {
+                if last.succ().as_ref() != Some(*next) { break; }
+                last = points.next().expect("peeked point");
+            }
+            Some(Interval::closed(first.clone(), last.clone()))
+        })
+    }
+
+    /// Returns an iterator over each of the points in the `NaiveSelection`.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item=T> + '_ {
+        self.0.iter().cloned()
+    }
+
+    /// Returns a `Selection` containing the points of the `NaiveSelection`.
+    pub fn to_selection(&self) -> Selection<T> {
+        Selection::from_sorted_disjoint(self.interval_iter())
+    }
+}
+
+impl<T> Default for NaiveSelection<T> where T: Ord + Clone + Finite {
+    fn default() -> Self {
+        NaiveSelection::new()
+    }
+}
+
+impl<T> From<Interval<T>> for NaiveSelection<T>
+    where T: Ord + Clone + Finite
+{
+    fn from(interval: Interval<T>) -> Self {
+        NaiveSelection(interval.iter_from_lower().collect())
+    }
+}
+
+impl<'t, T> From<&'t Selection<T>> for NaiveSelection<T>
+    where T: Ord + Clone + Finite
+{
+    fn from(selection: &'t Selection<T>) -> Self {
+        NaiveSelection(selection
+            .interval_iter()
+            .flat_map(|interval| interval.iter_from_lower())
+            .collect())
+    }
+}
+
+impl<T> FromIterator<T> for NaiveSelection<T> where T: Ord + Clone + Finite {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
+        NaiveSelection(iter.into_iter().collect())
+    }
+}
+
+impl<T> PartialEq<Selection<T>> for NaiveSelection<T>
+    where T: Ord + Clone + Finite
+{
+    fn eq(&self, other: &Selection<T>) -> bool {
+        self.interval_iter().eq(other.interval_iter())
+    }
+}
+
+impl<T> PartialEq<NaiveSelection<T>> for Selection<T>
+    where T: Ord + Clone + Finite
+{
+    fn eq(&self, other: &NaiveSelection<T>) -> bool {
+        other == self
+    }
+}
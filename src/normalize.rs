@@ -14,6 +14,31 @@
 // Local imports.
 use crate::raw_interval::RawInterval;
 
+// Standard library imports.
+use std::convert::TryFrom;
+use std::num::NonZeroI8;
+use std::num::NonZeroI16;
+use std::num::NonZeroI32;
+use std::num::NonZeroI64;
+use std::num::NonZeroI128;
+use std::num::NonZeroIsize;
+use std::num::NonZeroU8;
+use std::num::NonZeroU16;
+use std::num::NonZeroU32;
+use std::num::NonZeroU64;
+use std::num::NonZeroU128;
+use std::num::NonZeroUsize;
+use std::num::Wrapping;
+use std::time::Duration;
+
+// Nightly re-exports.
+#[cfg(feature = "step")]
+pub use crate::step_impl::StepBounds;
+
+// Derive macro re-exports.
+#[cfg(feature = "derive")]
+pub use normalize_interval_derive::Finite;
+
 
 ////////////////////////////////////////////////////////////////////////////////
 // Finite
@@ -21,8 +46,16 @@ use crate::raw_interval::RawInterval;
 /// Provides the methods needed to iterate over an type's points. Used
 /// to [`Normalize`] finite types used in [`Interval`] bounds.
 ///
+/// With the `derive` feature enabled, `Finite` can be derived for newtypes
+/// around `Finite` types, forwarding each method to the wrapped value. Such
+/// newtypes must also implement `Ord` and `Clone` to be normalized.
+///
+/// With the nightly-only `step` feature enabled, `Finite` is implemented for
+/// every type implementing `std::iter::Step` and [`StepBounds`].
+///
 /// [`Normalize`]: trait.Normalize.html
 /// [`Interval`]: ../interval/struct.Interval.html
+/// [`StepBounds`]: trait.StepBounds.html
 pub trait Finite: Sized {
     /// The minimum value of the type.
     const MINIMUM: Self;
@@ -35,6 +68,42 @@ pub trait Finite: Sized {
 
     /// Returns the next element after the given one.
     fn succ(&self) -> Option<Self>;
+
+    /// Returns the element `n` steps before the given one, or `None` if no
+    /// such element exists.
+    fn pred_n(&self, n: usize) -> Option<Self> where Self: Clone {
+        let mut res = self.clone();
+        for _ in 0..n {
+            res = res.pred()?;
+        }
+        Some(res)
+    }
+
+    /// Returns the element `n` steps after the given one, or `None` if no
+    /// such element exists.
+    fn succ_n(&self, n: usize) -> Option<Self> where Self: Clone {
+        let mut res = self.clone();
+        for _ in 0..n {
+            res = res.succ()?;
+        }
+        Some(res)
+    }
+
+    /// Returns the number of steps needed to reach the given element from this
+    /// one, or `None` if it is before this one or the number of steps would
+    /// overflow a `usize`.
+    fn steps_between(&self, other: &Self) -> Option<usize>
+        where Self: PartialOrd + Clone
+    {
+        if other < self { return None; }
+        let mut count: usize = 0;
+        let mut current = self.clone();
+        while current < *other {
+            current = current.succ()?;
+            count = count.checked_add(1)?;
+        }
+        Some(count)
+    }
 }
 
 
@@ -75,24 +144,35 @@ pub trait Normalize {
 // }
 
 /// Specialization for [`Finite`] intervals.
-impl<T> Normalize for RawInterval<T> where T: Finite {
+///
+/// Normalization never steps past the type's extremes. An excluded bound with
+/// no point beyond it leaves the interval empty, so `UpFrom(MAXIMUM)` and
+/// `UpTo(MINIMUM)` normalize to `Empty`, while infinite bounds saturate to
+/// [`MINIMUM`] and [`MAXIMUM`]. Denormalizing a bound at an extreme makes it
+/// infinite instead, so `Closed(MINIMUM, p)` denormalizes to
+/// `UpTo(p.succ())`.
+///
+/// [`MINIMUM`]: trait.Finite.html#associatedconstant.MINIMUM
+/// [`MAXIMUM`]: trait.Finite.html#associatedconstant.MAXIMUM
+impl<T> Normalize for RawInterval<T> where T: Finite + Ord + Clone {
     fn normalize(&mut self) {
         use RawInterval::*;
+        let closed = RawInterval::closed;
         *self = match std::mem::replace(self, Empty) {
             Empty           => Empty,
             Point(p)        => Point(p),
             Open(l, r)      => match (l.succ(), r.pred()) {
-                (Some(l), Some(r)) => Closed(l, r),
+                (Some(l), Some(r)) => closed(l, r),
                 _                  => Empty,
             },
-            LeftOpen(l, r)  => l.succ().map_or(Empty, |l| Closed(l, r)),
-            RightOpen(l, r) => r.pred().map_or(Empty, |r| Closed(l, r)),
-            Closed(l, r)    => Closed(l, r),
-            UpTo(r)         => r.pred().map_or(Empty, |r| Closed(T::MINIMUM, r)),
-            UpFrom(l)       => l.succ().map_or(Empty, |l| Closed(l, T::MAXIMUM)),
-            To(p)           => Closed(T::MINIMUM, p),
-            From(p)         => Closed(p, T::MAXIMUM),
-            Full            => Closed(T::MINIMUM, T::MAXIMUM),
+            LeftOpen(l, r)  => l.succ().map_or(Empty, |l| closed(l, r)),
+            RightOpen(l, r) => r.pred().map_or(Empty, |r| closed(l, r)),
+            Closed(l, r)    => closed(l, r),
+            UpTo(r)         => r.pred().map_or(Empty, |r| closed(T::MINIMUM, r)),
+            UpFrom(l)       => l.succ().map_or(Empty, |l| closed(l, T::MAXIMUM)),
+            To(p)           => closed(T::MINIMUM, p),
+            From(p)         => closed(p, T::MAXIMUM),
+            Full            => closed(T::MINIMUM, T::MAXIMUM),
         }
     }
 
@@ -123,32 +203,67 @@ impl<T> Normalize for RawInterval<T> where T: Finite {
             },
             UpTo(r)         => UpTo(r),
             UpFrom(l)       => UpFrom(l),
-            To(p)           => p.pred().map_or(Empty, |r| UpTo(r)),
-            From(p)         => p.succ().map_or(Empty, |l| UpFrom(l)),
+            To(p)           => p.succ().map_or(Full, UpTo),
+            From(p)         => p.pred().map_or(Full, UpFrom),
             Full            => Full,
         }
     }
 }
 
 
+// `Duration`s are treated as dense, so their intervals are already in normal
+// form.
+impl Normalize for RawInterval<Duration> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+
 ////////////////////////////////////////////////////////////////////////////////
 // Standard integer Finite implementations
 ////////////////////////////////////////////////////////////////////////////////
 
-// Implements basic normalization for a single builtin integer type.
+// Implements basic normalization for a single builtin integer type. Each type
+// is paired with the unsigned type of the same width, which is used to compute
+// step distances without overflow.
 macro_rules! std_integer_finite_impl {
     // For each given type...
-    ($($t:ident),*) => {
-        $(impl Finite for $t {
-            const MINIMUM: $t = {std::$t::MIN};
-            const MAXIMUM: $t = {std::$t::MAX};
+    ($($t:ident : $u:ident),*) => {
+        $(#[allow(trivial_numeric_casts)]
+        impl Finite for $t {
+            const MINIMUM: $t = {$t::MIN};
+            const MAXIMUM: $t = {$t::MAX};
 
             fn pred(&self) -> Option<Self> {
-                if *self != std::$t::MIN {Some(self - 1)} else {None}
+                if *self != $t::MIN {Some(self - 1)} else {None}
             }
 
             fn succ(&self) -> Option<Self> {
-                if *self != std::$t::MAX {Some(self + 1)} else {None}
+                if *self != $t::MAX {Some(self + 1)} else {None}
+            }
+
+            fn pred_n(&self, n: usize) -> Option<Self> {
+                let n = <$u as TryFrom<usize>>::try_from(n).ok()?;
+                if (self.wrapping_sub($t::MIN) as $u) < n {
+                    None
+                } else {
+                    Some(self.wrapping_sub(n as $t))
+                }
+            }
+
+            fn succ_n(&self, n: usize) -> Option<Self> {
+                let n = <$u as TryFrom<usize>>::try_from(n).ok()?;
+                if ($t::MAX.wrapping_sub(*self) as $u) < n {
+                    None
+                } else {
+                    Some(self.wrapping_add(n as $t))
+                }
+            }
+
+            fn steps_between(&self, other: &Self) -> Option<usize> {
+                if other < self { return None; }
+                let steps = other.wrapping_sub(*self) as $u;
+                <usize as TryFrom<$u>>::try_from(steps).ok()
             }
         })*
     };
@@ -156,9 +271,145 @@ macro_rules! std_integer_finite_impl {
 
 // Provide implementations of Finite for builtin integer types.
 std_integer_finite_impl![
-    u8, u16, u32, u64, u128, usize,
-    i8, i16, i32, i64, i128, isize
+    u8: u8, u16: u16, u32: u32, u64: u64, u128: u128, usize: usize,
+    i8: u8, i16: u16, i32: u32, i64: u64, i128: u128, isize: usize
 ];
 
 
+// Implements basic normalization for a single builtin nonzero integer type.
+// Steps are taken over the underlying integer type, skipping over zero.
+macro_rules! std_nonzero_finite_impl {
+    // For each given type...
+    ($($t:ident),*) => {
+        $(#[allow(unused_comparisons)]
+        impl Finite for $t {
+            const MINIMUM: $t = {$t::MIN};
+            const MAXIMUM: $t = {$t::MAX};
+
+            fn pred(&self) -> Option<Self> {
+                self.pred_n(1)
+            }
+
+            fn succ(&self) -> Option<Self> {
+                self.succ_n(1)
+            }
+
+            fn pred_n(&self, n: usize) -> Option<Self> {
+                let prev = self.get().pred_n(n)?;
+                if self.get() > 0 && prev <= 0 {
+                    prev.pred().and_then($t::new)
+                } else {
+                    $t::new(prev)
+                }
+            }
+
+            fn succ_n(&self, n: usize) -> Option<Self> {
+                let next = self.get().succ_n(n)?;
+                if self.get() < 0 && next >= 0 {
+                    next.succ().and_then($t::new)
+                } else {
+                    $t::new(next)
+                }
+            }
+
+            fn steps_between(&self, other: &Self) -> Option<usize> {
+                let steps = self.get().steps_between(&other.get())?;
+                if self.get() < 0 && other.get() > 0 {
+                    Some(steps - 1)
+                } else {
+                    Some(steps)
+                }
+            }
+        })*
+    };
+}
+
+// Provide implementations of Finite for builtin nonzero integer types.
+std_nonzero_finite_impl![
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+];
+
+// `Wrapping` values are ordered by their inner values, so they are normalized
+// as their inner values would be, without wrapping.
+impl<T> Finite for Wrapping<T> where T: Finite + Ord + Clone {
+    const MINIMUM: Self = Wrapping(T::MINIMUM);
+    const MAXIMUM: Self = Wrapping(T::MAXIMUM);
+
+    fn pred(&self) -> Option<Self> {
+        self.0.pred().map(Wrapping)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.0.succ().map(Wrapping)
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        self.0.pred_n(n).map(Wrapping)
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        self.0.succ_n(n).map(Wrapping)
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        self.0.steps_between(&other.0)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Character Finite implementation
+////////////////////////////////////////////////////////////////////////////////
+
+// The first code point of the surrogate range, which contains no `char`s.
+const SURROGATE_START: u32 = 0xD800;
+
+// The number of code points in the surrogate range.
+const SURROGATE_COUNT: u32 = 0x800;
+
+// Returns the index of the given `char` among all valid `char`s.
+fn char_to_index(c: char) -> u32 {
+    let c = u32::from(c);
+    if c < SURROGATE_START { c } else { c - SURROGATE_COUNT }
+}
+
+// Returns the `char` at the given index among all valid `char`s.
+fn index_to_char(index: u32) -> Option<char> {
+    if index < SURROGATE_START {
+        std::char::from_u32(index)
+    } else {
+        std::char::from_u32(index.checked_add(SURROGATE_COUNT)?)
+    }
+}
+
+// `char`s are stepped over by code point, skipping the surrogate range.
+impl Finite for char {
+    const MINIMUM: char = '\0';
+    const MAXIMUM: char = char::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.pred_n(1)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.succ_n(1)
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        let n = u32::try_from(n).ok()?;
+        index_to_char(char_to_index(*self).checked_sub(n)?)
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        let n = u32::try_from(n).ok()?;
+        index_to_char(char_to_index(*self).checked_add(n)?)
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        let steps = char_to_index(*other).checked_sub(char_to_index(*self))?;
+        usize::try_from(steps).ok()
+    }
+}
+
 // TODO: Use nextUp and nextDown IEEE 754 functions to normalize float values?
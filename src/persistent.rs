@@ -0,0 +1,467 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a persistent selection which shares structure between copies.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// PersistentSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of `Interval`s of the type `T`, which
+/// can be cloned in constant time.
+///
+/// Clones share their structure, and modifying one copies only the few parts
+/// of it which change, leaving the others untouched. This makes it cheap to
+/// keep earlier versions of a selection around, such as for an undo stack.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::PersistentSelection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let mut sel: PersistentSelection<i32> = PersistentSelection::new();
+/// sel.union_in_place(Interval::closed(-3, 5));
+///
+/// let before = sel.clone();
+/// sel.union_in_place(Interval::closed(6, 9));
+///
+/// assert_eq!(before.interval_iter().collect::<Vec<_>>(),
+///     [Interval::closed(-3, 5)]);
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+///     [Interval::closed(-3, 9)]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PersistentSelection<T>(Link<T>);
+
+// The intervals are stored denormalized in a treap ordered by their lower
+// bounds, with adjacent intervals always merged. Each modification splits out
+// the intervals it affects and joins the results back together, copying only
+// the nodes along the way.
+impl<T> PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new empty `PersistentSelection`.
+    #[inline]
+    pub const fn new() -> Self {
+        PersistentSelection(None)
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `PersistentSelection` is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the number of disjoint `Interval`s in the
+    /// `PersistentSelection`.
+    #[inline]
+    pub fn interval_count(&self) -> usize {
+        size(&self.0)
+    }
+
+    /// Returns `true` if the `PersistentSelection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        let probe = LowerBound(Bound::Include(point.clone()));
+        let mut link = &self.0;
+        while let Some(node) = link {
+            if node.interval.contains(point) { return true; }
+            link = if probe < lower_of(&node.interval) {
+                &node.left
+            } else {
+                &node.right
+            };
+        }
+        false
+    }
+
+    /// Returns `true` if the `PersistentSelection`s share all of their
+    /// structure, which implies that they are equal.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None,    None)    => true,
+            _                  => false,
+        }
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Adds all of the points in the given `Interval` to the
+    /// `PersistentSelection`.
+    pub fn union_in_place(&mut self, interval: Interval<T>) {
+        let x = interval.0.denormalized();
+        if x.is_empty() { return; }
+
+        let (before, touching, after) = self.split_around(&x, |a, x| {
+            a.intersects(x) || a.adjacent(x)
+        });
+        let merged = in_order(&touching)
+            .into_iter()
+            .fold(x, |merged, a| merged.enclose(&a));
+        self.0 = join(join(before, leaf(merged)), after);
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `PersistentSelection`.
+    pub fn minus_in_place(&mut self, interval: Interval<T>) {
+        let x = interval.0.denormalized();
+        if x.is_empty() { return; }
+
+        let (before, touching, after) = self.split_around(&x, |a, x| {
+            a.intersects(x)
+        });
+        let middle = in_order(&touching)
+            .into_iter()
+            .flat_map(|a| a.minus(&x).collect::<Vec<_>>())
+            .filter_map(renormalized)
+            .fold(None, |link, a| join(link, leaf(a)));
+        self.0 = join(join(before, middle), after);
+    }
+
+    /// Removes all of the points not in the given `Interval` from the
+    /// `PersistentSelection`.
+    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
+        let x = interval.0.denormalized();
+        if x.is_empty() { self.0 = None; return; }
+
+        let (_, touching, _) = self.split_around(&x, |a, x| {
+            a.intersects(x)
+        });
+        self.0 = in_order(&touching)
+            .into_iter()
+            .filter_map(|a| renormalized(a.intersect(&x)))
+            .fold(None, |link, a| join(link, leaf(a)));
+    }
+
+    /// Splits the stored intervals into those before the given interval,
+    /// those which touch it according to the given predicate, and those
+    /// after it.
+    fn split_around<F>(&self, x: &RawInterval<T>, touches: F)
+        -> (Link<T>, Link<T>, Link<T>)
+        where F: Fn(&RawInterval<T>, &RawInterval<T>) -> bool
+    {
+        // The touching intervals are contiguous, so every interval before them
+        // has a lesser lower bound.
+        let x_lower = lower_of(x);
+        let (before, rest) = split(&self.0, &|a: &RawInterval<T>| {
+            !touches(a, x) && lower_of(a) < x_lower
+        });
+        let (touching, after) = split(&rest, &|a: &RawInterval<T>| {
+            touches(a, x)
+        });
+        (before, touching, after)
+    }
+
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the
+    /// `PersistentSelection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        let mut iter = IntervalIter {
+            stack: Vec::new(),
+            remaining: self.interval_count(),
+        };
+        iter.push_left(&self.0);
+        iter
+    }
+
+    /// Returns a `Selection` containing the same points as the
+    /// `PersistentSelection`.
+    pub fn to_selection(&self) -> Selection<T> {
+        Selection::from_sorted_disjoint(self.interval_iter())
+    }
+}
+
+impl<T> Default for PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        PersistentSelection::new()
+    }
+}
+
+impl<T> PartialEq for PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr_eq(other) || (self.interval_count() == other.interval_count()
+            && self.interval_iter().eq(other.interval_iter()))
+    }
+}
+
+impl<T> Eq for PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+////////////////////////////////////////////////////////////////////////////////
+// Conversion traits
+////////////////////////////////////////////////////////////////////////////////
+impl<T> From<Interval<T>> for PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(interval: Interval<T>) -> Self {
+        let mut sel = PersistentSelection::new();
+        sel.union_in_place(interval);
+        sel
+    }
+}
+
+impl<T> From<&Selection<T>> for PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(selection: &Selection<T>) -> Self {
+        // The intervals are already sorted and merged, so they can be joined
+        // on directly.
+        PersistentSelection(selection
+            .interval_iter()
+            .map(|interval| interval.0.denormalized())
+            .fold(None, |link, a| join(link, leaf(a))))
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for PersistentSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self
+        where I: IntoIterator<Item=Interval<T>>
+    {
+        let mut sel = PersistentSelection::new();
+        for interval in iter {
+            sel.union_in_place(interval);
+        }
+        sel
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `PersistentSelection`.
+#[derive(Debug)]
+pub struct IntervalIter<'t, T> {
+    /// The nodes whose intervals and right subtrees are yet to be visited.
+    stack: Vec<&'t Node<T>>,
+    /// The number of intervals not yet returned.
+    remaining: usize,
+}
+
+impl<'t, T> IntervalIter<'t, T> {
+    /// Pushes the given subtree's leftmost path onto the stack.
+    fn push_left(&mut self, mut link: &'t Link<T>) {
+        while let Some(node) = link {
+            self.stack.push(node);
+            link = &node.left;
+        }
+    }
+}
+
+impl<'t, T> Iterator for IntervalIter<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        self.remaining -= 1;
+        Some(Interval(node.interval.clone().normalized()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'t, T> ExactSizeIterator for IntervalIter<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Node
+////////////////////////////////////////////////////////////////////////////////
+/// A shared link to a subtree of a treap.
+type Link<T> = Option<Arc<Node<T>>>;
+
+/// A node of a treap, ordered by its intervals' lower bounds and heap ordered
+/// by its priorities.
+#[derive(Debug)]
+struct Node<T> {
+    interval: RawInterval<T>,
+    priority: u64,
+    /// The number of intervals in the subtree.
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+/// Returns the number of intervals in the given subtree.
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+
+/// Returns a new subtree from the given parts.
+fn node<T>(
+    interval: RawInterval<T>,
+    priority: u64,
+    left: Link<T>,
+    right: Link<T>)
+    -> Link<T>
+{
+    let size = size(&left) + size(&right) + 1;
+    Some(Arc::new(Node { interval, priority, size, left, right }))
+}
+
+/// Returns a copy of the given node with the given children.
+fn with_children<T>(parent: &Node<T>, left: Link<T>, right: Link<T>) -> Link<T>
+    where T: Clone
+{
+    node(parent.interval.clone(), parent.priority, left, right)
+}
+
+/// Returns a new subtree containing only the given interval.
+fn leaf<T>(interval: RawInterval<T>) -> Link<T> {
+    node(interval, next_priority(), None, None)
+}
+
+/// Returns a new pseudorandom priority for a node.
+fn next_priority() -> u64 {
+    // A SplitMix64 sequence, which spreads out consecutive counter values.
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let mut z = COUNTER
+        .fetch_add(0x9E37_79B9_7F4A_7C15, Ordering::Relaxed)
+        .wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Splits the given subtree into the intervals satisfying the given predicate
+/// and those after them. The predicate must hold for a prefix of the
+/// intervals.
+fn split<T, F>(link: &Link<T>, pred: &F) -> (Link<T>, Link<T>)
+    where
+        T: Clone,
+        F: Fn(&RawInterval<T>) -> bool,
+{
+    match link {
+        None       => (None, None),
+        Some(root) => if pred(&root.interval) {
+            let (left, right) = split(&root.right, pred);
+            (with_children(root, root.left.clone(), left), right)
+        } else {
+            let (left, right) = split(&root.left, pred);
+            (left, with_children(root, right, root.right.clone()))
+        },
+    }
+}
+
+/// Joins two subtrees, all of whose intervals in the first precede those in
+/// the second.
+fn join<T>(a: Link<T>, b: Link<T>) -> Link<T> where T: Clone {
+    match (a, b) {
+        (None, b) => b,
+        (a, None) => a,
+        (Some(a), Some(b)) => if a.priority >= b.priority {
+            let right = join(a.right.clone(), Some(b));
+            with_children(&a, a.left.clone(), right)
+        } else {
+            let left = join(Some(a), b.left.clone());
+            with_children(&b, left, b.right.clone())
+        },
+    }
+}
+
+/// Returns the intervals of the given subtree in order.
+fn in_order<T>(link: &Link<T>) -> Vec<RawInterval<T>> where T: Clone {
+    fn visit<T>(link: &Link<T>, out: &mut Vec<RawInterval<T>>) where T: Clone {
+        if let Some(node) = link {
+            visit(&node.left, out);
+            out.push(node.interval.clone());
+            visit(&node.right, out);
+        }
+    }
+    let mut out = Vec::with_capacity(size(link));
+    visit(link, &mut out);
+    out
+}
+
+/// Returns the lower bound of a nonempty `RawInterval` for ordering.
+fn lower_of<T>(interval: &RawInterval<T>) -> LowerBound<T>
+    where T: Ord + Clone
+{
+    LowerBound(interval.lower_bound().expect("nonempty interval"))
+}
+
+/// Returns the given interval in denormalized form, or `None` if it contains
+/// no points.
+fn renormalized<T>(interval: RawInterval<T>) -> Option<RawInterval<T>>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    let interval = interval.normalized();
+    if interval.is_empty() { None } else { Some(interval.denormalized()) }
+}
@@ -11,10 +11,12 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::interval::ParseIntervalError;
 use crate::utility::Few;
 
 // Standard library imports.
 use std::cmp::Ordering;
+use std::str::FromStr;
 
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -430,17 +432,155 @@ impl<T> std::fmt::Display for RawInterval<T> where T: std::fmt::Display {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         use RawInterval::*;
         match *self {
-            Empty                   => write!(f, "Ø"),
-            Point(ref p)            => write!(f, "{}", p),
+            Empty                   => write!(f, "∅"),
+            Point(ref p)            => write!(f, "{{{}}}", p),
             Open(ref l, ref r)      => write!(f, "({}, {})", l, r),
             LeftOpen(ref l, ref r)  => write!(f, "({}, {}]", l, r),
             RightOpen(ref l, ref r) => write!(f, "[{}, {})", l, r),
             Closed(ref l, ref r)    => write!(f, "[{}, {}]", l, r),
             UpTo(ref p)             => write!(f, "(-∞, {})", p),
             UpFrom(ref p)           => write!(f, "({}, ∞)", p),
-            To(ref p)               => write!(f, "(-∞, {})", p),
-            From(ref p)             => write!(f, "({}, ∞)", p),
+            To(ref p)               => write!(f, "(-∞, {}]", p),
+            From(ref p)             => write!(f, "[{}, ∞)", p),
             Full                    => write!(f, "(-∞, ∞)"),
         }
     }
 }
+
+
+// Parse using interval notation.
+impl<T> FromStr for RawInterval<T> where T: Ord + Clone + FromStr {
+    type Err = ParseIntervalError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use ParseIntervalError::*;
+        let s = s.trim();
+
+        if s == "∅" || s == "Ø" || s == "{}" || s.eq_ignore_ascii_case("empty") {
+            return Ok(RawInterval::Empty);
+        }
+
+        if s.starts_with('{') && s.ends_with('}') {
+            let point = s[1..s.len() - 1].trim();
+            return T::from_str(point)
+                .map(RawInterval::Point)
+                .map_err(InvalidPoint);
+        }
+
+        let mut chars = s.chars();
+        let (open, close) = match (chars.next(), chars.next_back()) {
+            (Some(open), Some(close)) => (open, close),
+            _                         => return Err(InvalidDelimiters),
+        };
+        if !"[(".contains(open) || !"])".contains(close) {
+            return Err(InvalidDelimiters);
+        }
+        let inner = chars.as_str();
+
+        let mut parts = inner.split(',');
+        let (l, r) = match (parts.next(), parts.next(), parts.next()) {
+            (Some(l), Some(r), None) => (l.trim(), r.trim()),
+            _                        => return Err(InvalidSeparator),
+        };
+
+        let lower = match (open, parse_infinite(l)) {
+            (_,   true)  => Bound::Infinite,
+            ('[', false) => Bound::Include(T::from_str(l).map_err(InvalidPoint)?),
+            ('(', false) => Bound::Exclude(T::from_str(l).map_err(InvalidPoint)?),
+            _            => unreachable!("invalid interval delimiter"),
+        };
+        let upper = match (close, parse_infinite(r)) {
+            (_,   true)  => Bound::Infinite,
+            (']', false) => Bound::Include(T::from_str(r).map_err(InvalidPoint)?),
+            (')', false) => Bound::Exclude(T::from_str(r).map_err(InvalidPoint)?),
+            _            => unreachable!("invalid interval delimiter"),
+        };
+
+        Ok(RawInterval::new(lower, upper))
+    }
+}
+
+/// Returns `true` if the given bound text denotes an infinite bound.
+fn parse_infinite(s: &str) -> bool {
+    match s {
+        "" | "∞" | "-∞" | "+∞" | "inf" | "-inf" | "+inf" => true,
+        _                                               => false,
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RawIntervalRef<'a, T>
+////////////////////////////////////////////////////////////////////////////////
+/// A borrowed view of a nonempty interval stored in a [`Selection`], whose
+/// bounds refer to the selection's points rather than cloning them.
+///
+/// The bounds are those stored by the selection, which for [`Finite`] types
+/// may exclude the points just outside the interval rather than include the
+/// points at its ends. Both describe the same points, and converting the view
+/// into an [`Interval`] will normalize it.
+///
+/// [`Selection`]: selection/struct.Selection.html
+/// [`Finite`]: normalize/trait.Finite.html
+/// [`Interval`]: interval/struct.Interval.html
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct RawIntervalRef<'a, T> {
+    /// The lower bound of the interval.
+    lower: Bound<&'a T>,
+    /// The upper bound of the interval.
+    upper: Bound<&'a T>,
+}
+
+impl<'a, T> RawIntervalRef<'a, T> where T: Ord + Clone {
+    /// Constructs a new view from the given bounds, which must describe a
+    /// nonempty interval.
+    pub(in crate) fn new(lower: Bound<&'a T>, upper: Bound<&'a T>) -> Self {
+        RawIntervalRef { lower, upper }
+    }
+
+    /// Returns the lower [`Bound`] of the interval.
+    ///
+    /// [`Bound`]: bound/enum.Bound.html
+    pub fn lower_bound(&self) -> Bound<&'a T> {
+        self.lower
+    }
+
+    /// Returns the upper [`Bound`] of the interval.
+    ///
+    /// [`Bound`]: bound/enum.Bound.html
+    pub fn upper_bound(&self) -> Bound<&'a T> {
+        self.upper
+    }
+
+    /// Returns `true` if the interval contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        use Bound::*;
+        let above_lower = match self.lower {
+            Include(l) => point >= l,
+            Exclude(l) => point > l,
+            Infinite   => true,
+        };
+        let below_upper = match self.upper {
+            Include(u) => point <= u,
+            Exclude(u) => point < u,
+            Infinite   => true,
+        };
+        above_lower && below_upper
+    }
+
+    /// Returns the interval as a `RawInterval`, cloning its bounds.
+    pub(in crate) fn to_raw_interval(self) -> RawInterval<T> {
+        RawInterval::new(
+            self.lower.map(Clone::clone),
+            self.upper.map(Clone::clone))
+    }
+}
+
+// Implemented manually to avoid requiring `T: Clone`.
+impl<'a, T> Clone for RawIntervalRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for RawIntervalRef<'a, T> {}
@@ -0,0 +1,91 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides parallel reduction of many selections using `rayon`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use rayon::iter::IntoParallelIterator;
+use rayon::iter::ParallelIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Parallel reduction
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Selection<T>
+    where
+        T: Ord + Clone + Send,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the union of the given `Selection`s, combining them in parallel
+    /// as a tree of pairwise unions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let shards: Vec<Selection<i32>> = (0..8)
+    ///     .map(|i| Selection::from(Interval::closed(i * 10, i * 10 + 4)))
+    ///     .collect();
+    ///
+    /// let sel = Selection::par_union_all(shards);
+    /// assert_eq!(sel.interval_count(), 8);
+    /// assert!(sel.contains(&72));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn par_union_all<I>(selections: I) -> Self
+        where I: IntoParallelIterator<Item=Selection<T>>
+    {
+        selections
+            .into_par_iter()
+            .reduce(Selection::new, |a, b| a.union(&b))
+    }
+
+    /// Returns the intersection of the given `Selection`s, combining them in
+    /// parallel as a tree of pairwise intersections. Returns a full
+    /// `Selection` if there are none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let shards: Vec<Selection<i32>> = (0..8)
+    ///     .map(|i| Selection::from(Interval::closed(i, i + 10)))
+    ///     .collect();
+    ///
+    /// let sel = Selection::par_intersect_all(shards);
+    /// assert_eq!(sel, Selection::from(Interval::closed(7, 10)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn par_intersect_all<I>(selections: I) -> Self
+        where I: IntoParallelIterator<Item=Selection<T>>
+    {
+        selections
+            .into_par_iter()
+            .reduce(Selection::full, |a, b| a.intersect(&b))
+    }
+}
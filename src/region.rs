@@ -0,0 +1,724 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides multi-dimensional regions formed from products of intervals.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// Standard library imports.
+use std::iter::FromIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Region2<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A two-dimensional region formed by the cartesian product of two
+/// `Interval`s of the type `T`.
+///
+/// A `Region2` is empty if either of its axes is empty. Empty regions are
+/// stored with both axes empty, so all empty regions compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Region2<T> {
+    /// The interval along the first axis.
+    x: Interval<T>,
+    /// The interval along the second axis.
+    y: Interval<T>,
+}
+
+impl<T> Region2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `Region2` from the given `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Region2;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let region = Region2::new(
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(5, 9));
+    ///
+    /// assert_eq!(region.x(), &Interval::closed(0, 3));
+    /// assert_eq!(region.y(), &Interval::closed(5, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(x: Interval<T>, y: Interval<T>) -> Self {
+        if x.is_empty() || y.is_empty() {
+            Region2::empty()
+        } else {
+            Region2 { x, y }
+        }
+    }
+
+    /// Constructs a new empty `Region2`.
+    #[inline]
+    pub const fn empty() -> Self {
+        Region2 { x: Interval::empty(), y: Interval::empty() }
+    }
+
+    /// Constructs a new `Region2` containing every point.
+    #[inline]
+    pub fn full() -> Self {
+        Region2 { x: Interval::full(), y: Interval::full() }
+    }
+
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Interval` along the first axis.
+    #[inline]
+    pub fn x(&self) -> &Interval<T> {
+        &self.x
+    }
+
+    /// Returns the `Interval` along the second axis.
+    #[inline]
+    pub fn y(&self) -> &Interval<T> {
+        &self.y
+    }
+
+    /// Returns the pair of `Interval`s making up the `Region2`.
+    #[inline]
+    pub fn into_intervals(self) -> (Interval<T>, Interval<T>) {
+        (self.x, self.y)
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `Region2` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Returns `true` if the `Region2` contains the given point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Region2;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let region = Region2::new(
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(5, 9));
+    ///
+    /// assert!(region.contains(&(2, 6)));
+    /// assert!(!region.contains(&(6, 2)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn contains(&self, point: &(T, T)) -> bool {
+        self.x.contains(&point.0) && self.y.contains(&point.1)
+    }
+
+    /// Returns `true` if every point of the given `Region2` is contained in
+    /// the `Region2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Region2;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = Region2::new(Interval::closed(0, 10), Interval::closed(0, 10));
+    /// let b = Region2::new(Interval::closed(2, 5), Interval::closed(3, 8));
+    ///
+    /// assert!(a.contains_region(&b));
+    /// assert!(!b.contains_region(&a));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn contains_region(&self, other: &Self) -> bool {
+        other.is_empty() ||
+            (self.x.0.enclose(&other.x.0) == self.x.0
+            && self.y.0.enclose(&other.y.0) == self.y.0)
+    }
+
+    /// Returns `true` if the `Region2` shares any point with the given
+    /// `Region2`.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.x.intersects(&other.x) && self.y.intersects(&other.y)
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the largest `Region2` whose points are all contained in both
+    /// the `Region2` and the given `Region2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Region2;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = Region2::new(Interval::closed(0, 5), Interval::closed(0, 5));
+    /// let b = Region2::new(Interval::closed(3, 8), Interval::closed(4, 9));
+    ///
+    /// assert_eq!(a.intersect(&b),
+    ///     Region2::new(Interval::closed(3, 5), Interval::closed(4, 5)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Self {
+        Region2::new(self.x.intersect(&other.x), self.y.intersect(&other.y))
+    }
+
+    /// Returns disjoint `Region2`s containing all points in the `Region2`
+    /// which are not in the given `Region2`.
+    ///
+    /// The result is split into at most four pieces: the parts of the
+    /// `Region2` to either side of the given one along the first axis, and
+    /// then the parts above and below it along the second axis.
+    pub fn minus(&self, other: &Self) -> impl Iterator<Item=Self> {
+        let mut pieces = Vec::with_capacity(4);
+        if !self.intersects(other) {
+            if !self.is_empty() { pieces.push(self.clone()); }
+            return pieces.into_iter();
+        }
+
+        for x in self.x.minus(&other.x) {
+            pieces.push(Region2::new(x, self.y.clone()));
+        }
+        let middle = self.x.intersect(&other.x);
+        for y in self.y.minus(&other.y) {
+            pieces.push(Region2::new(middle.clone(), y));
+        }
+        pieces.retain(|r| !r.is_empty());
+        pieces.into_iter()
+    }
+
+    /// Returns a `RegionSelection2` containing all points in the `Region2`
+    /// and the given `Region2`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Region2;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = Region2::new(Interval::closed(0, 4), Interval::closed(0, 4));
+    /// let b = Region2::new(Interval::closed(2, 6), Interval::closed(2, 6));
+    /// let union = a.union(&b);
+    ///
+    /// assert!(union.contains(&(1, 1)));
+    /// assert!(union.contains(&(5, 5)));
+    /// assert!(!union.contains(&(1, 5)));
+    /// assert_eq!(union.area(), Some(25 + 25 - 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> RegionSelection2<T> {
+        let mut selection = RegionSelection2::new();
+        selection.union_in_place(self.clone());
+        selection.union_in_place(other.clone());
+        selection
+    }
+
+    /// Returns the smallest `Region2` containing all of the points in the
+    /// `Region2` and the given `Region2`.
+    pub fn enclose(&self, other: &Self) -> Self {
+        if self.is_empty() { return other.clone(); }
+        if other.is_empty() { return self.clone(); }
+        Region2::new(self.x.enclose(&other.x), self.y.enclose(&other.y))
+    }
+}
+
+impl<T> Region2<T>
+    where
+        T: Ord + Clone + Finite,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the number of points in the `Region2`, or `None` if the count
+    /// would overflow a `u128`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Region2;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let region = Region2::new(
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(5, 9));
+    ///
+    /// assert_eq!(region.area(), Some(20));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn area(&self) -> Option<u128> {
+        self.x.point_count()?.checked_mul(self.y.point_count()?)
+    }
+}
+
+impl<T> Default for Region2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        Region2::empty()
+    }
+}
+
+impl<T> From<(Interval<T>, Interval<T>)> for Region2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(axes: (Interval<T>, Interval<T>)) -> Self {
+        Region2::new(axes.0, axes.1)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RegionSelection2<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A possibly noncontiguous collection of `Region2`s of the type `T`.
+///
+/// The stored `Region2`s are kept disjoint, but their arrangement depends on
+/// the order of insertion, so two `RegionSelection2`s covering the same
+/// points may iterate over different `Region2`s.
+#[derive(Debug, Clone)]
+pub struct RegionSelection2<T>(Vec<Region2<T>>);
+
+impl<T> RegionSelection2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new empty `RegionSelection2`.
+    #[inline]
+    pub const fn new() -> Self {
+        RegionSelection2(Vec::new())
+    }
+
+    /// Returns `true` if the `RegionSelection2` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if the `RegionSelection2` contains the given point.
+    pub fn contains(&self, point: &(T, T)) -> bool {
+        self.0.iter().any(|r| r.contains(point))
+    }
+
+    /// Adds all points in the given `Region2` to the `RegionSelection2`.
+    pub fn union_in_place(&mut self, region: Region2<T>) {
+        let mut pieces = vec![region];
+        for existing in &self.0 {
+            pieces = pieces
+                .iter()
+                .flat_map(|p| p.minus(existing))
+                .collect();
+            if pieces.is_empty() { return; }
+        }
+        self.0.extend(pieces);
+    }
+
+    /// Removes all points in the given `Region2` from the `RegionSelection2`.
+    pub fn minus_in_place(&mut self, region: &Region2<T>) {
+        self.0 = self.0
+            .iter()
+            .flat_map(|r| r.minus(region))
+            .collect();
+    }
+
+    /// Returns the `RegionSelection2` containing all points in both the
+    /// `RegionSelection2` and the given `Region2`.
+    pub fn intersect(&self, region: &Region2<T>) -> Self {
+        RegionSelection2(self.0
+            .iter()
+            .map(|r| r.intersect(region))
+            .filter(|r| !r.is_empty())
+            .collect())
+    }
+
+    /// Removes all points from the `RegionSelection2`.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns an iterator over the disjoint `Region2`s in the
+    /// `RegionSelection2`.
+    pub fn iter(&self) -> impl Iterator<Item=&Region2<T>> + '_ {
+        self.0.iter()
+    }
+}
+
+impl<T> RegionSelection2<T>
+    where
+        T: Ord + Clone + Finite,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the number of points in the `RegionSelection2`, or `None` if
+    /// the count would overflow a `u128`.
+    pub fn area(&self) -> Option<u128> {
+        self.0
+            .iter()
+            .try_fold(0u128, |sum, r| sum.checked_add(r.area()?))
+    }
+}
+
+impl<T> Default for RegionSelection2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        RegionSelection2::new()
+    }
+}
+
+impl<T> From<Region2<T>> for RegionSelection2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(region: Region2<T>) -> Self {
+        let mut selection = RegionSelection2::new();
+        selection.union_in_place(region);
+        selection
+    }
+}
+
+impl<T> Extend<Region2<T>> for RegionSelection2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Region2<T>> {
+        for region in iter.into_iter() {
+            self.union_in_place(region);
+        }
+    }
+}
+
+impl<T> FromIterator<Region2<T>> for RegionSelection2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Region2<T>> {
+        let mut selection = RegionSelection2::new();
+        selection.extend(iter);
+        selection
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Orthotope<T, N>
+////////////////////////////////////////////////////////////////////////////////
+/// An `N`-dimensional box formed by the cartesian product of `N` `Interval`s
+/// of the type `T`.
+///
+/// An `Orthotope` is empty if any of its axes is empty. Empty orthotopes are
+/// stored with every axis empty, so all empty orthotopes compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Orthotope<T, const N: usize>([Interval<T>; N]);
+
+impl<T, const N: usize> Orthotope<T, N>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // Constructors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Constructs a new `Orthotope` from the given `Interval`s, one per axis.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Orthotope;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let cube = Orthotope::new([
+    ///     Interval::closed(0, 3),
+    ///     Interval::closed(0, 4),
+    ///     Interval::closed(0, 5),
+    /// ]);
+    ///
+    /// assert_eq!(cube.axis(1), &Interval::closed(0, 4));
+    /// assert_eq!(cube.volume(), Some(4 * 5 * 6));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        if axes.iter().any(Interval::is_empty) {
+            Orthotope::empty()
+        } else {
+            Orthotope(axes)
+        }
+    }
+
+    /// Constructs a new empty `Orthotope`.
+    pub fn empty() -> Self {
+        Orthotope(std::array::from_fn(|_| Interval::empty()))
+    }
+
+    /// Constructs a new `Orthotope` containing every point.
+    pub fn full() -> Self {
+        Orthotope(std::array::from_fn(|_| Interval::full()))
+    }
+
+    // Bound accessors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Interval` along the given axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not less than `N`.
+    #[inline]
+    pub fn axis(&self, axis: usize) -> &Interval<T> {
+        &self.0[axis]
+    }
+
+    /// Returns the `Interval`s along each axis of the `Orthotope`.
+    #[inline]
+    pub fn axes(&self) -> &[Interval<T>; N] {
+        &self.0
+    }
+
+    /// Returns the `Interval`s making up the `Orthotope`.
+    #[inline]
+    pub fn into_axes(self) -> [Interval<T>; N] {
+        self.0
+    }
+
+    // Query operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns `true` if the `Orthotope` contains no points.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.iter().any(Interval::is_empty)
+    }
+
+    /// Returns `true` if the `Orthotope` contains the given point.
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.0.iter().zip(point.iter()).all(|(axis, p)| axis.contains(p))
+    }
+
+    /// Returns `true` if every point of the given `Orthotope` is contained in
+    /// the `Orthotope`.
+    pub fn contains_orthotope(&self, other: &Self) -> bool {
+        other.is_empty() || self.0.iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.0.enclose(&b.0) == a.0)
+    }
+
+    /// Returns `true` if the `Orthotope` shares any point with the given
+    /// `Orthotope`.
+    pub fn intersects(&self, other: &Self) -> bool {
+        !self.is_empty() && !other.is_empty() && self.0.iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.intersects(b))
+    }
+
+    // Set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the largest `Orthotope` whose points are all contained in both
+    /// the `Orthotope` and the given `Orthotope`.
+    pub fn intersect(&self, other: &Self) -> Self {
+        Orthotope::new(std::array::from_fn(|i| {
+            self.0[i].intersect(&other.0[i])
+        }))
+    }
+
+    /// Returns disjoint `Orthotope`s containing all points in the `Orthotope`
+    /// which are not in the given `Orthotope`.
+    ///
+    /// The result is split into at most `2 * N` pieces. Each axis in turn
+    /// contributes the parts of the `Orthotope` to either side of the given
+    /// one, restricted to the overlap along all preceding axes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Orthotope;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a = Orthotope::new([Interval::closed(0, 9); 3]);
+    /// let b = Orthotope::new([Interval::closed(3, 5); 3]);
+    /// let pieces: Vec<_> = a.minus(&b).collect();
+    ///
+    /// assert_eq!(pieces.len(), 6);
+    /// assert_eq!(pieces.iter().map(|p| p.volume().unwrap()).sum::<u128>(),
+    ///     1000 - 27);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus(&self, other: &Self) -> impl Iterator<Item=Self> {
+        let mut pieces = Vec::with_capacity(2 * N);
+        if !self.intersects(other) {
+            if !self.is_empty() { pieces.push(self.clone()); }
+            return pieces.into_iter();
+        }
+
+        let mut remaining = self.0.clone();
+        for i in 0..N {
+            for part in self.0[i].minus(&other.0[i]) {
+                let mut axes = remaining.clone();
+                axes[i] = part;
+                pieces.push(Orthotope::new(axes));
+            }
+            remaining[i] = self.0[i].intersect(&other.0[i]);
+        }
+        pieces.retain(|o| !o.is_empty());
+        pieces.into_iter()
+    }
+
+    /// Returns the smallest `Orthotope` containing all of the points in the
+    /// `Orthotope` and the given `Orthotope`.
+    pub fn enclose(&self, other: &Self) -> Self {
+        if self.is_empty() { return other.clone(); }
+        if other.is_empty() { return self.clone(); }
+        Orthotope::new(std::array::from_fn(|i| {
+            self.0[i].enclose(&other.0[i])
+        }))
+    }
+
+    /// Splits the `Orthotope` along the given axis, returning the parts below
+    /// and at-or-above the given point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `axis` is not less than `N`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::region::Orthotope;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let square = Orthotope::new([Interval::closed(0, 9); 2]);
+    /// let (left, right) = square.split_at(0, &4);
+    ///
+    /// assert_eq!(left.axis(0), &Interval::closed(0, 3));
+    /// assert_eq!(right.axis(0), &Interval::closed(4, 9));
+    /// assert_eq!(right.axis(1), &Interval::closed(0, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_at(&self, axis: usize, point: &T) -> (Self, Self) {
+        let mut lower = self.0.clone();
+        let mut upper = self.0.clone();
+        lower[axis] = self.0[axis]
+            .intersect(&Interval::unbounded_up_to(point.clone()));
+        upper[axis] = self.0[axis]
+            .intersect(&Interval::unbounded_from(point.clone()));
+        (Orthotope::new(lower), Orthotope::new(upper))
+    }
+}
+
+impl<T, const N: usize> Orthotope<T, N>
+    where
+        T: Ord + Clone + Finite,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the number of points in the `Orthotope`, or `None` if the
+    /// count would overflow a `u128`.
+    pub fn volume(&self) -> Option<u128> {
+        self.0
+            .iter()
+            .try_fold(1u128, |prod, axis| prod.checked_mul(axis.point_count()?))
+    }
+}
+
+impl<T, const N: usize> Default for Orthotope<T, N>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        Orthotope::empty()
+    }
+}
+
+impl<T, const N: usize> From<[Interval<T>; N]> for Orthotope<T, N>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(axes: [Interval<T>; N]) -> Self {
+        Orthotope::new(axes)
+    }
+}
+
+impl<T> From<Region2<T>> for Orthotope<T, 2>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(region: Region2<T>) -> Self {
+        let (x, y) = region.into_intervals();
+        Orthotope::new([x, y])
+    }
+}
+
+impl<T> From<Orthotope<T, 2>> for Region2<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(orthotope: Orthotope<T, 2>) -> Self {
+        let [x, y] = orthotope.into_axes();
+        Region2::new(x, y)
+    }
+}
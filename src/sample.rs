@@ -0,0 +1,262 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides random sampling of points from intervals and selections.
+//!
+//! Sampling is only provided for [`Finite`] point types, whose intervals have
+//! a countable number of points to choose between. Intervals of continuous
+//! types such as `f64` cannot be sampled, and will fail to compile.
+//!
+//! [`Finite`]: ../normalize/trait.Finite.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use rand::distributions::uniform::SampleRange;
+use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
+use rand::RngCore;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval sampling
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Interval<T> where T: Ord + Clone + Finite + SampleUniform {
+    /// Returns a uniformly distributed random point from the `Interval`, or
+    /// `None` if the `Interval` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let interval: Interval<i32> = Interval::open(-3, 7);
+    /// let point = interval.sample(&mut rng).unwrap();
+    /// assert!(interval.contains(&point));
+    ///
+    /// let interval: Interval<i32> = Interval::empty();
+    /// assert_eq!(interval.sample(&mut rng), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sample<R>(&self, rng: &mut R) -> Option<T> where R: Rng + ?Sized {
+        match self.0 {
+            RawInterval::Empty                => None,
+            RawInterval::Point(ref p)         => Some(p.clone()),
+            RawInterval::Closed(ref l, ref u)
+                => Some(rng.gen_range(l.clone()..=u.clone())),
+            _ => unreachable!("sample for Finite interval with open bound"),
+        }
+    }
+}
+
+// Allows sampling with `Rng::gen_range`. Panics when sampling an empty
+// `Interval`, as with empty std ranges.
+impl<T> SampleRange<T> for Interval<T>
+    where T: Ord + Clone + Finite + SampleUniform
+{
+    fn sample_single<R>(self, rng: &mut R) -> T where R: RngCore + ?Sized {
+        self.sample(rng).expect("cannot sample empty interval")
+    }
+
+    fn is_empty(&self) -> bool {
+        Interval::is_empty(self)
+    }
+}
+
+impl<T> Interval<T> where T: Ord + Clone + Finite {
+    /// Returns a uniformly distributed random sub-interval of the `Interval`
+    /// containing `len` points, or `None` if no such sub-interval fits. A
+    /// `len` of zero gives an empty `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let interval: Interval<i32> = Interval::closed(0, 99);
+    /// let sub = interval.sample_subinterval(&mut rng, 10).unwrap();
+    /// assert_eq!(sub.point_count(), Some(10));
+    /// assert_eq!(sub.intersect(&interval), sub);
+    ///
+    /// assert_eq!(interval.sample_subinterval(&mut rng, 101), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sample_subinterval<R>(&self, rng: &mut R, len: usize)
+        -> Option<Interval<T>>
+        where R: Rng + ?Sized
+    {
+        if len == 0 { return Some(Interval::empty()); }
+        let count = placement_count(self, len);
+        if count == 0 { return None; }
+        subinterval_at(self, rng.gen_range(0..count), len)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection sampling
+////////////////////////////////////////////////////////////////////////////////
+impl<T> Selection<T> where T: Ord + Clone + Finite {
+    /// Returns a uniformly distributed random point from the `Selection`, or
+    /// `None` if the `Selection` is empty. Each `Interval` of the `Selection`
+    /// is weighted by the number of points it contains.
+    ///
+    /// `Interval`s containing more than `usize::MAX` points are weighted as if
+    /// they contained `usize::MAX` points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let mut sel: Selection<i32> = Selection::new();
+    /// sel.union_in_place(Interval::closed(-3, 7));
+    /// sel.union_in_place(Interval::closed(100, 200));
+    ///
+    /// let point = sel.sample(&mut rng).unwrap();
+    /// assert!(sel.contains(&point));
+    ///
+    /// let sel: Selection<i32> = Selection::new();
+    /// assert_eq!(sel.sample(&mut rng), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sample<R>(&self, rng: &mut R) -> Option<T> where R: Rng + ?Sized {
+        let total = self
+            .interval_iter()
+            .map(|interval| point_weight(&interval))
+            .fold(0u128, u128::saturating_add);
+        if total == 0 { return None; }
+
+        let mut index = rng.gen_range(0..total);
+        for interval in self.interval_iter() {
+            let weight = point_weight(&interval);
+            if index < weight {
+                let lower = interval.infimum().expect("nonempty interval");
+                // The index is less than the weight, so it must fit in a usize.
+                return lower.succ_n(index as usize);
+            }
+            index -= weight;
+        }
+        unreachable!("sample index exceeds selection weight")
+    }
+
+    /// Returns a uniformly distributed random sub-interval of the `Selection`
+    /// containing `len` points, or `None` if no such sub-interval fits within
+    /// any of its `Interval`s. Each `Interval` of the `Selection` is weighted
+    /// by the number of places such a sub-interval fits within it. A `len` of
+    /// zero gives an empty `Interval`.
+    ///
+    /// `Interval`s containing more than `usize::MAX` points are weighted as if
+    /// they contained `usize::MAX` points.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut rng = rand::thread_rng();
+    ///
+    /// let mut sel: Selection<i32> = Selection::new();
+    /// sel.union_in_place(Interval::closed(-3, 7));
+    /// sel.union_in_place(Interval::closed(100, 200));
+    ///
+    /// let sub = sel.sample_subinterval(&mut rng, 20).unwrap();
+    /// assert_eq!(sub.point_count(), Some(20));
+    /// assert_eq!(sub.intersect(&Interval::closed(100, 200)), sub);
+    ///
+    /// assert_eq!(sel.sample_subinterval(&mut rng, 102), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn sample_subinterval<R>(&self, rng: &mut R, len: usize)
+        -> Option<Interval<T>>
+        where R: Rng + ?Sized
+    {
+        if len == 0 { return Some(Interval::empty()); }
+        let total = self
+            .interval_iter()
+            .map(|interval| placement_count(&interval, len))
+            .fold(0u128, u128::saturating_add);
+        if total == 0 { return None; }
+
+        let mut index = rng.gen_range(0..total);
+        for interval in self.interval_iter() {
+            let weight = placement_count(&interval, len);
+            if index < weight {
+                return subinterval_at(&interval, index, len);
+            }
+            index -= weight;
+        }
+        unreachable!("sample index exceeds selection weight")
+    }
+}
+
+/// Returns the number of points in the given `Interval`, saturating at
+/// `usize::MAX`.
+fn point_weight<T>(interval: &Interval<T>) -> u128
+    where T: Ord + Clone + Finite
+{
+    match (interval.infimum(), interval.supremum()) {
+        (Some(l), Some(u)) => l
+            .steps_between(&u)
+            .map(|steps| steps as u128 + 1)
+            .unwrap_or(usize::MAX as u128),
+        _ => 0,
+    }
+}
+
+/// Returns the number of places a sub-interval of `len` points fits within
+/// the given `Interval`, as if it contained at most `usize::MAX` points. The
+/// `len` must be nonzero.
+fn placement_count<T>(interval: &Interval<T>, len: usize) -> u128
+    where T: Ord + Clone + Finite
+{
+    point_weight(interval).saturating_sub(len as u128 - 1)
+}
+
+/// Returns the sub-interval of `len` points starting `index` points after the
+/// lower bound of the given `Interval`. The `len` must be nonzero.
+fn subinterval_at<T>(interval: &Interval<T>, index: u128, len: usize)
+    -> Option<Interval<T>>
+    where T: Ord + Clone + Finite
+{
+    // The index is less than the placement count, so it must fit in a usize.
+    let lower = interval.infimum()?.succ_n(index as usize)?;
+    let upper = lower.succ_n(len - 1)?;
+    Some(Interval::closed(lower, upper))
+}
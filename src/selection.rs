@@ -12,16 +12,33 @@
 ////////////////////////////////////////////////////////////////////////////////
 
 // Local imports.
+use crate::arithmetic::CheckedArithmetic;
+use crate::arithmetic::erode;
 use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::bytes::DeltaDecoder;
+use crate::bytes::DeltaPoint;
+use crate::bytes::FromBytesError;
+use crate::bytes::PointBytes;
+use crate::error::IntervalError;
 use crate::interval::Interval;
+use crate::interval::ParseIntervalError;
+use crate::journal::JournaledSelection;
 use crate::normalize::Normalize;
 use crate::normalize::Finite;
 use crate::raw_interval::RawInterval;
+use crate::raw_interval::RawIntervalRef;
+use crate::tine::Tine;
 use crate::tine_tree::TineTree;
 
 // Standard library imports.
+use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::iter::FromIterator;
 use std::iter::FusedIterator;
+use std::ops::Range;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
 
 
 
@@ -29,6 +46,9 @@ use std::iter::FusedIterator;
 // Selection<T>
 ////////////////////////////////////////////////////////////////////////////////
 /// A possibly noncontiguous collection of `Interval`s of the type `T`.
+///
+/// Equality and hashing depend only on the points selected, not on the order
+/// or form of the `Interval`s used to construct the `Selection`.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Selection<T>(TineTree<T>);
 
@@ -58,16 +78,131 @@ impl<T> Selection<T>
     /// # }
     /// ```
     #[inline]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Selection(TineTree::new())
     }
 
     /// Constructs a new empty `Selection`.
     #[inline]
-    pub fn empty() -> Self {
+    pub const fn empty() -> Self {
         Selection::new()
     }
 
+    /// Returns a [`SelectionBuilder`] for constructing a `Selection` from many
+    /// additions and removals at once.
+    ///
+    /// [`SelectionBuilder`]: struct.SelectionBuilder.html
+    pub const fn builder() -> SelectionBuilder<T> {
+        SelectionBuilder::new()
+    }
+
+    /// Constructs a `Selection` containing all of the points in the given
+    /// `Interval`s.
+    ///
+    /// This sorts the `Interval`s and merges them in a single pass, which is
+    /// much faster than inserting each of them into the `Selection` in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::union_all(vec![
+    ///     Interval::closed(8, 12),
+    ///     Interval::closed(-3, 5),
+    ///     Interval::closed(4, 6),
+    /// ]);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 6), Interval::closed(8, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_all<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=Interval<T>>
+    {
+        Selection(TineTree::union_all(intervals
+            .into_iter()
+            .map(|interval| interval.0.denormalized())))
+    }
+
+    /// Constructs a new `Selection` from `Interval`s sorted by their lower
+    /// bounds, building it directly rather than inserting each `Interval` in
+    /// turn. Any `Interval`s which overlap or are adjacent are merged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Interval`s are not sorted by their lower bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::from_sorted_disjoint(vec![
+    ///     Interval::closed(-3, 5),
+    ///     Interval::closed(6, 7),
+    ///     Interval::closed(10, 12),
+    /// ]);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 7), Interval::closed(10, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_sorted_disjoint<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=Interval<T>>
+    {
+        Selection(TineTree::from_sorted_disjoint(intervals
+            .into_iter()
+            .map(|interval| interval.0.denormalized())))
+    }
+
+    /// Constructs a new `Selection` from `Interval`s sorted by their lower
+    /// bounds, or returns an error if they are not sorted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalError;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<i32> = Selection::try_from_sorted_disjoint(vec![
+    ///     Interval::closed(-3, 5),
+    ///     Interval::closed(10, 12),
+    /// ])?;
+    /// assert_eq!(sel.interval_count(), 2);
+    ///
+    /// let res = Selection::<i32>::try_from_sorted_disjoint(vec![
+    ///     Interval::closed(10, 12),
+    ///     Interval::closed(-3, 5),
+    /// ]);
+    /// assert_eq!(res, Err(IntervalError::UnsortedIntervals));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_sorted_disjoint<I>(intervals: I)
+        -> Result<Self, IntervalError>
+        where I: IntoIterator<Item=Interval<T>>
+    {
+        TineTree::try_from_sorted_disjoint(intervals
+            .into_iter()
+            .map(|interval| interval.0.denormalized()))
+            .map(Selection)
+    }
+
     /// Constructs a new full `Selection`.
     ///
     /// # Examples
@@ -195,7 +330,7 @@ impl<T> Selection<T>
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// assert_eq!(sel.infimum(), Some(-3));
+    /// assert_eq!(sel.infimum(), Some(-2));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -213,14 +348,14 @@ impl<T> Selection<T>
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
     /// 
-    /// assert_eq!(sel.infimum(), Some(-3));
+    /// assert_eq!(sel.infimum(), Some(-2));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[inline]
     pub fn infimum(&self) -> Option<T> {
-        self.0.lower_bound().and_then(|b| b.as_ref().cloned())
+        self.lower_bound().and_then(|b| b.as_ref().cloned())
     }
     
     
@@ -238,7 +373,7 @@ impl<T> Selection<T>
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// assert_eq!(sel.supremum(), Some(5));
+    /// assert_eq!(sel.supremum(), Some(4));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -256,14 +391,14 @@ impl<T> Selection<T>
     /// # //-------------------------------------------------------------------
     /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
     /// 
-    /// assert_eq!(sel.supremum(), Some(5));
+    /// assert_eq!(sel.supremum(), Some(4));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
     #[inline]
     pub fn supremum(&self) -> Option<T> {
-        self.0.upper_bound().and_then(|b| b.as_ref().cloned())
+        self.upper_bound().and_then(|b| b.as_ref().cloned())
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -446,329 +581,419 @@ impl<T> Selection<T>
         self.0.contains(point)
     }
 
-    // Set comparisons
-    ////////////////////////////////////////////////////////////////////////////
-    
-    /// Returns `true` if the `Selection` overlaps the given `Selection`.
+    /// Returns the number of disjoint `Interval`s in the `Selection`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 15));
-    /// assert_eq!(a.intersects(&b), true);
-    ///
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(8, 12));
-    /// assert_eq!(a.intersects(&b), false);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// sel.union_in_place(Interval::closed(6, 7));
+    /// sel.union_in_place(Interval::point(20));
+    /// assert_eq!(sel.interval_count(), 2);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersects(&self, other: &Self) -> bool {
-        // TODO: Make generic?
-        !self.0.intersect(&other.0).is_empty()
+    #[inline]
+    pub fn interval_count(&self) -> usize {
+        self.0.interval_count()
     }
 
-    // Symmetric set operations
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns the `Selection` containing all points in not contained in the
-    /// `Selection`.
+    /// Returns the number of bounds stored for the `Selection`'s `Interval`s.
+    ///
+    /// Each `Interval` is stored as a lower and upper bound, except that the
+    /// bounds of a single point may be stored together, as may those of two
+    /// `Interval`s separated by a single excluded point.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    // /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    // /// 
-    // /// assert_eq!(sel.complement().collect::<Vec<_>>(), 
-    // ///     vec![Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::point(20));
+    /// assert_eq!(sel.tine_count(), 4);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    #[inline]
+    pub fn tine_count(&self) -> usize {
+        self.0.tine_count()
+    }
+
+    /// Returns an estimate of the number of bytes of heap memory used by the
+    /// `Selection`.
     ///
-    /// [`Finite`] types will have their bounds closed:
+    /// The estimate covers the storage of the `Selection`'s bounds, but not
+    /// any heap memory owned by the points themselves.
     ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use std::i32;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
-    /// 
-    /// assert_eq!(sel.complement().iter().collect::<Vec<_>>(), vec![
-    ///     Interval::closed(i32::MIN, -4),
-    ///     Interval::closed(6, i32::MAX),
-    /// ]);
+    /// let mut sel: Selection<i32> = Selection::new();
+    /// assert_eq!(sel.heap_size_estimate(), 0);
+    ///
+    /// sel.union_in_place(Interval::closed(-3, 5));
+    /// assert!(sel.heap_size_estimate() > 0);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn complement(&self) -> Self {
-        Selection(self.0.complement())
+    pub fn heap_size_estimate(&self) -> usize {
+        self.0.heap_size_estimate()
     }
 
-    /// Returns the `Selection` containing all points in both the given
-    /// `Selection`s.
+    /// Returns the `Interval` at the given index in the `Selection`, ordered
+    /// from lowest to highest, or `None` if the index is out of bounds.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.intersect(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(4, 7)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// sel.union_in_place(Interval::closed(15, 17));
+    /// assert_eq!(sel.get(1), Some(Interval::closed(8, 12)));
+    /// assert_eq!(sel.get(3), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn get(&self, index: usize) -> Option<Interval<T>> {
+        // Walk from whichever end of the tree is nearer.
+        let count = self.interval_count();
+        if index >= count {
+            None
+        } else if index < count / 2 {
+            self.interval_iter().nth(index)
+        } else {
+            self.interval_iter().rev().nth(count - 1 - index)
+        }
+    }
+
+    /// Returns the lowest `Interval` in the `Selection`, or `None` if the
+    /// `Selection` is empty.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
-    /// assert_eq!(a.intersect(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(5, 6)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// assert_eq!(sel.first(), Some(Interval::closed(-3, 5)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersect(&self, other: &Self) -> Self {
-        Selection(self.0.intersect(&other.0))
+    #[inline]
+    pub fn first(&self) -> Option<Interval<T>> {
+        self.interval_iter().next()
     }
 
-    /// Returns the `Selection` containing all points in either of the given
-    /// `Selection`s.
+    /// Returns the highest `Interval` in the `Selection`, or `None` if the
+    /// `Selection` is empty.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(-3, 13)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// assert_eq!(sel.last(), Some(Interval::closed(8, 12)));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    #[inline]
+    pub fn last(&self) -> Option<Interval<T>> {
+        self.interval_iter().next_back()
+    }
+
+    /// Removes and returns the lowest `Interval` in the `Selection`, or
+    /// returns `None` if the `Selection` is empty.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
-    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::closed(-2, 12)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    ///
+    /// assert_eq!(sel.pop_first(), Some(Interval::closed(-3, 5)));
+    /// assert_eq!(sel.pop_first(), Some(Interval::closed(8, 12)));
+    /// assert_eq!(sel.pop_first(), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn union(&self, other: &Self) -> Self {
-        Selection(self.0.union(&other.0))
+    pub fn pop_first(&mut self) -> Option<Interval<T>> {
+        let first = self.first()?;
+        self.minus_in_place(first.clone());
+        Some(first)
     }
 
-    /// Returns the `Selection` containing all points in the `Selection` which
-    /// are not in the given `Selection`s.
+    /// Removes and returns the highest `Interval` in the `Selection`, or
+    /// returns `None` if the `Selection` is empty.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
-    /// assert_eq!(a.minus(&b).iter().collect::<Vec<_>>(),
-    ///     vec![Interval::right_open(-3, 4)]);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    ///
+    /// assert_eq!(sel.pop_last(), Some(Interval::closed(8, 12)));
+    /// assert_eq!(sel, Interval::closed(-3, 5));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn minus(&self, other: &Self) -> Self {
-        Selection(self.0.minus(&other.0))
+    pub fn pop_last(&mut self) -> Option<Interval<T>> {
+        let last = self.last()?;
+        self.minus_in_place(last.clone());
+        Some(last)
     }
 
-    /// Returns the smallest `Interval` containing all of the points in the 
-    /// `Selection`.
+    // Set comparisons
+    ////////////////////////////////////////////////////////////////////////////
+    
+    /// Returns `true` if the `Selection` overlaps the given `Interval`.
+    ///
+    /// This seeks directly to the `Interval`s of the `Selection` near the
+    /// given one, without constructing their intersection.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
-    /// let sel = a.union(&b);
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(10, 12));
     ///
-    /// assert_eq!(sel.enclose(), Interval::left_open(-3, 13));
+    /// assert!(sel.intersects(&Interval::closed(4, 8)));
+    /// assert!(!sel.intersects(&Interval::open(5, 10)));
+    /// assert!(!sel.intersects(&Interval::empty()));
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn enclose(&self) -> Interval<T> {
-        Interval(self.0.enclose().normalized())
+    pub fn intersects(&self, interval: &Interval<T>) -> bool {
+        self.overlapping(interval).next().is_some()
     }
 
-    /// Returns the smallest closed `Interval` containing all of the points
-    /// in the `Selection`.
+    /// Returns `true` if the `Selection` overlaps the given `Selection`.
+    ///
+    /// Each `Interval` of the `Selection` with fewer `Interval`s is sought in
+    /// the other, without constructing their intersection.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
-    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
-    /// let sel = a.union(&b);
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 15));
+    /// assert_eq!(a.intersects_selection(&b), true);
     ///
-    /// assert_eq!(sel.enclose(), Interval::closed(-2, 13));
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(8, 12));
+    /// assert_eq!(a.intersects_selection(&b), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn closure(&self) -> Interval<T> {
-        Interval(self.0.closure().normalized())
+    pub fn intersects_selection(&self, other: &Self) -> bool {
+        let (fewer, more) = if self.tine_count() <= other.tine_count() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        fewer.interval_iter().any(|interval| more.intersects(&interval))
     }
 
-    // In-place operations
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Reduces the `Selection` to only those points contained in the given
-    /// `Interval`.
+    /// Returns `true` if every point in the `Selection` is also in the given
+    /// `Selection`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.intersect_in_place(Interval::open(2, 5));
-    ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::open(2, 5)]);
+    /// let a: Selection<i32> = Selection::from(Interval::closed(2, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// assert_eq!(a.is_subset(&b), true);
+    /// assert_eq!(b.is_subset(&a), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        // Each interval must lie within a single interval of the other
+        // selection, as adjacent intervals are always merged.
+        let mut others = other.interval_iter();
+        let mut current = others.next();
+        for interval in self.interval_iter() {
+            loop {
+                match current {
+                    Some(ref y) if y.intersects(&interval) => {
+                        if y.intersect(&interval) != interval { return false; }
+                        break;
+                    },
+                    Some(ref y) if lower_of(y) < lower_of(&interval) => {
+                        current = others.next();
+                    },
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if every point in the given `Selection` is also in the
+    /// `Selection`.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.intersect_in_place(Interval::open(2, 5));
-    ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(3, 4)]);
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(2, 5));
+    /// assert_eq!(a.is_superset(&b), true);
+    /// assert_eq!(b.is_superset(&a), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn intersect_in_place(&mut self, interval: Interval<T>) {
-        self.0.intersect_in_place(&interval.0.denormalized());
+    #[inline]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
     }
 
-    /// Adds all of the points in the given `Interval` to the `Selection`.
+    /// Returns `true` if the `Selection` has no points in common with the
+    /// given `Selection`.
     ///
     /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.union_in_place(Interval::open(12, 15));
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(6, 12));
+    /// assert_eq!(a.is_disjoint(&b), true);
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 7), Interval::open(12, 15)]);
+    /// let b: Selection<i32> = Selection::from(Interval::closed(5, 12));
+    /// assert_eq!(a.is_disjoint(&b), false);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        let mut a_iter = self.interval_iter();
+        let mut b_iter = other.interval_iter();
+        let mut a = a_iter.next();
+        let mut b = b_iter.next();
+        while let (Some(x), Some(y)) = (&a, &b) {
+            if x.intersects(y) { return false; }
+            // Neither interval overlaps the other, so the lower one can be
+            // skipped.
+            if lower_of(x) < lower_of(y) {
+                a = a_iter.next();
+            } else {
+                b = b_iter.next();
+            }
+        }
+        true
+    }
+
+    /// Compares the `Selection`s by inclusion, returning `Less` if this
+    /// `Selection` is a strict subset of the other, `Greater` if it is a
+    /// strict superset, `Equal` if they contain the same points, and `None` if
+    /// neither contains the other.
     ///
-    /// [`Finite`] types will have their bounds closed:
-    ///
-    /// [`Finite`]: ../normalize/trait.Finite.html
+    /// # Example
     ///
     /// ```rust
     /// # use std::error::Error;
-    /// # use interval::Interval;
-    /// # use interval::Selection;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 8));
-    /// sel.union_in_place(Interval::open(7, 10));
+    /// use std::cmp::Ordering;
     ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-2, 9)]);
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    /// let b = a.clone();
+    /// a.union_in_place(Interval::closed(20, 30));
+    ///
+    /// assert_eq!(b.partial_cmp_by_inclusion(&a), Some(Ordering::Less));
+    /// assert_eq!(a.partial_cmp_by_inclusion(&a), Some(Ordering::Equal));
+    /// assert_eq!(a.partial_cmp_by_inclusion(&a.complement()), None);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
     /// ```
-    pub fn union_in_place(&mut self, interval: Interval<T>) {
-        self.0.union_in_place(&interval.0.denormalized());
+    pub fn partial_cmp_by_inclusion(&self, other: &Self) -> Option<Ordering> {
+        if self == other { return Some(Ordering::Equal); }
+        if self.is_subset(other) { return Some(Ordering::Less); }
+        if other.is_subset(self) { return Some(Ordering::Greater); }
+        None
     }
 
-    /// Removes all of the points in the given `Interval` from the `Selection`.
+    // Symmetric set operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns the `Selection` containing all points in not contained in the
+    /// `Selection`.
     ///
     /// # Example
     ///
@@ -778,11 +1003,10 @@ impl<T> Selection<T>
     /// # use interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.minus_in_place(Interval::open(2, 5));
-    ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 2), Interval::closed(5, 7)]);
+    // /// let sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    // /// 
+    // /// assert_eq!(sel.complement().collect::<Vec<_>>(), 
+    // ///     vec![Interval::unbounded_to(-3), Interval::unbounded_from(5)]);
     /// # //-------------------------------------------------------------------
     /// #     Ok(())
     /// # }
@@ -794,142 +1018,3014 @@ impl<T> Selection<T>
     ///
     /// ```rust
     /// # use std::error::Error;
+    /// # use std::i32;
     /// # use interval::Interval;
     /// # use interval::Selection;
     /// # fn main() -> Result<(), Box<dyn Error>> {
     /// # //-------------------------------------------------------------------
-    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
-    /// sel.minus_in_place(Interval::closed(2, 5));
-    ///
-    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
-    ///     [Interval::closed(-3, 1), Interval::closed(6, 7)]);
-    /// # //-------------------------------------------------------------------
-    /// #     Ok(())
-    /// # }
-    /// ```
-    pub fn minus_in_place(&mut self, interval: Interval<T>) {
-        self.0.minus_in_place(&interval.0.denormalized());
-    }
+    /// let sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// 
+    /// assert_eq!(sel.complement().iter().collect::<Vec<_>>(), vec![
+    ///     Interval::closed(i32::MIN, -4),
+    ///     Interval::closed(6, i32::MAX),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement(&self) -> Self {
+        Selection::renormalized(self.0.complement())
+    }
+
+    /// Returns the `Selection` containing all points in the given `Interval`
+    /// not contained in the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// let bounds = Interval::closed(0, 20);
+    ///
+    /// assert_eq!(sel.complement_within(&bounds).interval_iter()
+    ///     .collect::<Vec<_>>(),
+    ///     [Interval::closed(6, 7), Interval::closed(13, 20)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn complement_within(&self, bounds: &Interval<T>) -> Self {
+        self.gaps_within(bounds.clone()).collect()
+    }
+
+    /// Returns the `Selection` containing all points in both the given
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.intersect(&b).iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(4, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
+    /// assert_eq!(a.intersect(&b).iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(5, 6)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect(&self, other: &Self) -> Self {
+        Selection::renormalized(self.0.intersect(&other.0))
+    }
+
+    /// Returns the `Selection` containing all points in every one of the given
+    /// `Selection`s, or a full `Selection` if none are given.
+    ///
+    /// The `Selection`s are intersected in a single merge over all of their
+    /// intervals, rather than one pair at a time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// let c: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    /// assert_eq!(Selection::intersect_all(&[a, b, c]).interval_iter()
+    ///         .collect::<Vec<_>>(),
+    ///     vec![Interval::closed(4, 5)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_all<'t, I>(selections: I) -> Self
+        where
+            I: IntoIterator<Item=&'t Selection<T>>,
+            T: 't,
+    {
+        Selection::renormalized(TineTree::intersect_all(selections
+            .into_iter()
+            .map(|selection| &selection.0)))
+    }
+
+    /// Returns the `Selection` containing all points in either of the given
+    /// `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-3, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::open(4, 13));
+    /// assert_eq!(a.union(&b).iter().collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-2, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        Selection(self.0.union(&other.0))
+    }
+
+    /// Returns the `Selection` containing all points in the `Selection` which
+    /// are not in the given `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.minus(&b).iter().collect::<Vec<_>>(),
+    ///     vec![Interval::right_open(-3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus(&self, other: &Self) -> Self {
+        Selection::renormalized(self.0.minus(&other.0))
+    }
+
+    /// Returns the `Selection` containing all points in exactly one of the
+    /// given `Selection`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// assert_eq!(a.symmetric_difference(&b).interval_iter()
+    ///     .collect::<Vec<_>>(),
+    ///     vec![Interval::closed(-3, 3), Interval::closed(8, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        let mut res = Vec::new();
+        self.for_each_exclusive(other, |_, interval| res.push(interval));
+        Selection::from_sorted_disjoint(res)
+    }
+
+    /// Returns a [`SelectionDiff`] of the `Interval`s which are only in this
+    /// `Selection` and only in the other, computed in a single pass over both.
+    ///
+    /// [`SelectionDiff`]: struct.SelectionDiff.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    /// let diff = a.diff(&b);
+    ///
+    /// assert_eq!(diff.removed, Interval::closed(-3, 3));
+    /// assert_eq!(diff.added, Interval::closed(8, 13));
+    ///
+    /// let mut sel = a.clone();
+    /// diff.apply(&mut sel);
+    /// assert_eq!(sel, b);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn diff(&self, other: &Self) -> SelectionDiff<T> {
+        let mut removed = Vec::new();
+        let mut added = Vec::new();
+        self.for_each_exclusive(other, |in_self, interval| if in_self {
+            removed.push(interval);
+        } else {
+            added.push(interval);
+        });
+        SelectionDiff {
+            removed: Selection::from_sorted_disjoint(removed),
+            added: Selection::from_sorted_disjoint(added),
+        }
+    }
+
+    /// Converts the `Selection` into a [`JournaledSelection`] which records
+    /// each subsequent change, so that it can be undone.
+    ///
+    /// [`JournaledSelection`]: ../journal/struct.JournaledSelection.html
+    pub fn journaled(self) -> JournaledSelection<T> {
+        JournaledSelection::new(self)
+    }
+
+    /// Calls the given function with each `Interval` of points in exactly one
+    /// of the given `Selection`s, along with whether it is in this one. The
+    /// `Interval`s are given in order.
+    fn for_each_exclusive<F>(&self, other: &Self, mut f: F)
+        where F: FnMut(bool, Interval<T>)
+    {
+        // Walk both selections in order, emitting the parts of each interval
+        // below their overlap and carrying forward whatever remains above it.
+        let mut a_iter = self.interval_iter();
+        let mut b_iter = other.interval_iter();
+        let mut a = a_iter.next();
+        let mut b = b_iter.next();
+
+        loop {
+            match (a.take(), b.take()) {
+                (Some(x), Some(y)) if !x.intersects(&y) => {
+                    if lower_of(&x) < lower_of(&y) {
+                        f(true, x);
+                        a = a_iter.next();
+                        b = Some(y);
+                    } else {
+                        f(false, y);
+                        a = Some(x);
+                        b = b_iter.next();
+                    }
+                },
+                (Some(x), Some(y)) => {
+                    let overlap = x.intersect(&y);
+                    let overlap_lower = lower_of(&overlap);
+                    let mut above = None;
+                    for piece in x.enclose(&y).minus(&overlap) {
+                        if piece.is_empty() { continue; }
+                        if lower_of(&piece) < overlap_lower {
+                            f(x.intersects(&piece), piece);
+                        } else {
+                            above = Some(piece);
+                        }
+                    }
+                    // Whichever interval extends past the overlap continues.
+                    match above {
+                        Some(rest) if x.intersects(&rest) => {
+                            a = Some(rest);
+                            b = b_iter.next();
+                        },
+                        Some(rest) => {
+                            a = a_iter.next();
+                            b = Some(rest);
+                        },
+                        None => {
+                            a = a_iter.next();
+                            b = b_iter.next();
+                        },
+                    }
+                },
+                (Some(x), None) => {
+                    f(true, x);
+                    a = a_iter.next();
+                },
+                (None, Some(y)) => {
+                    f(false, y);
+                    b = b_iter.next();
+                },
+                (None, None) => break,
+            }
+        }
+    }
+
+    /// Returns the smallest `Interval` containing all of the points in the 
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
+    /// let sel = a.union(&b);
+    ///
+    /// assert_eq!(sel.enclose(), Interval::left_open(-3, 13));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn enclose(&self) -> Interval<T> {
+        Interval(self.0.enclose().normalized())
+    }
+
+    /// Returns the `Selection` formed by replacing each of its `Interval`s
+    /// with its closure. Unlike [`enclose`], the `Interval`s are only merged
+    /// if their closures overlap.
+    ///
+    /// [`enclose`]: #method.enclose
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let a: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(9, 13));
+    /// let sel = a.union(&b);
+    ///
+    /// assert_eq!(sel.closure().interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-2, 4), Interval::closed(9, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn closure(&self) -> Self {
+        self.interval_iter()
+            .map(|interval| interval.closure())
+            .collect()
+    }
+
+    /// Returns the `Selection` formed by adding every point of the given
+    /// `Interval` to every point of the `Selection`, dilating each of its
+    /// `Interval`s by the given one. Sums outside of the range of `T` are
+    /// dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let events: Selection<i32> = [10, 14, 30].iter().copied().collect();
+    /// let windows = events.minkowski_add(&Interval::closed(-2, 2));
+    ///
+    /// assert_eq!(windows.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(8, 16), Interval::closed(28, 32)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minkowski_add(&self, interval: &Interval<T>) -> Self
+        where T: Default + CheckedArithmetic
+    {
+        Selection::union_all(self
+            .interval_iter()
+            .map(|c| c + interval.clone()))
+    }
+
+    /// Returns the `Selection` of points which, when every point of the given
+    /// `Interval` is added to them, remain within the `Selection`. This erodes
+    /// each of the `Selection`'s `Interval`s by the given one.
+    ///
+    /// Eroding by an empty `Interval` returns the full `Selection`. Sums
+    /// outside of the range of `T` are never within the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(8, 16));
+    /// sel.union_in_place(Interval::closed(28, 30));
+    /// let inner = sel.minkowski_sub(&Interval::closed(-2, 2));
+    ///
+    /// assert_eq!(inner.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(10, 14)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minkowski_sub(&self, interval: &Interval<T>) -> Self
+        where T: Default + CheckedArithmetic
+    {
+        if interval.is_empty() { return Selection::full(); }
+        // The given interval is connected, so each eroded point's translation
+        // lies within a single one of the `Selection`'s intervals.
+        Selection::union_all(self
+            .interval_iter()
+            .map(|c| erode(&c, interval)))
+    }
+
+    /// Returns the `Selection` formed by mapping the bounds of each of its
+    /// `Interval`s through the given strictly increasing function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function is found not to be strictly increasing at the
+    /// bounds of the `Selection`'s `Interval`s.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// let sel: Selection<i64> = sel.map_monotone(|p| i64::from(*p) * 100);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 500), Interval::closed(800, 1200)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn map_monotone<U, F>(&self, f: F) -> Selection<U>
+        where
+            U: Ord + Clone,
+            RawInterval<U>: Normalize,
+            F: FnMut(&T) -> U,
+    {
+        self.checked_map_monotone(f)
+            .expect("map_monotone with function that is not increasing")
+    }
+
+    /// Returns the `Selection` formed by mapping the bounds of each of its
+    /// `Interval`s through the given strictly increasing function, or `None`
+    /// if the function is found not to be strictly increasing at those
+    /// bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-5, 0));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    ///
+    /// assert!(sel.checked_map_monotone(|p| p * 2).is_some());
+    /// assert!(sel.checked_map_monotone(|p| p * p).is_none());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn checked_map_monotone<U, F>(&self, mut f: F) -> Option<Selection<U>>
+        where
+            U: Ord + Clone,
+            RawInterval<U>: Normalize,
+            F: FnMut(&T) -> U,
+    {
+        // The intervals are mapped in order, so the results are already
+        // sorted and can be collected in a single pass.
+        let mut mapped = Vec::with_capacity(self.interval_count());
+        let mut prev_upper: Option<U> = None;
+        for interval in self.interval_iter() {
+            let lower = interval.lower_bound()?.map(|p| f(&p));
+            let upper = interval.upper_bound()?.map(|p| f(&p));
+            let increasing = match (lower.as_ref(), upper.as_ref()) {
+                (Some(l), Some(u)) => interval.is_degenerate() || l < u,
+                _                  => true,
+            };
+            let ordered = match (prev_upper.as_ref(), lower.as_ref()) {
+                (Some(prev), Some(l)) => prev < l,
+                _                     => true,
+            };
+            if !increasing || !ordered { return None; }
+
+            prev_upper = upper.as_ref().cloned();
+            mapped.push(Interval(RawInterval::new(lower, upper).normalized()));
+        }
+        Some(Selection::from_sorted_disjoint(mapped))
+    }
+
+    /// Splits the `Selection` at the given point, returning the parts below
+    /// and at-or-above the point. Any `Interval` containing the point is
+    /// divided between them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// let (lower, upper) = sel.split_at(&2);
+    ///
+    /// assert_eq!(lower.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 1)]);
+    /// assert_eq!(upper.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(2, 5), Interval::closed(8, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn split_at(&self, point: &T) -> (Self, Self) {
+        let mut lower = self.clone();
+        let mut upper = self.clone();
+        lower.intersect_in_place(Interval::unbounded_up_to(point.clone()));
+        upper.intersect_in_place(Interval::unbounded_from(point.clone()));
+        (lower, upper)
+    }
+
+    // In-place operations
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Reduces the `Selection` to only those points contained in the given
+    /// `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.intersect_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    ///     [Interval::open(2, 5)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.intersect_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(3, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<T>>
+    {
+        let interval = interval.into();
+        // Intersecting with the denormalized interval may leave intervals
+        // which are empty after normalization, so the normalized interval is
+        // used and its bounds are denormalized afterward.
+        if let RawInterval::Point(ref p) = interval.0 {
+            let contained = self.contains(p);
+            *self = if contained { interval.into() } else { Selection::new() };
+            return;
+        }
+        self.0.intersect_in_place(&interval.0);
+        self.denormalize_tines_at(&interval.0);
+    }
+
+    /// Adds all of the points in the given `Interval` to the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.union_in_place(Interval::open(12, 15));
+    ///
+    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 7), Interval::open(12, 15)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// As with the other in-place operations, anything convertible into an
+    /// `Interval`, such as a standard range or a single point, may be given.
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::new();
+    /// sel.union_in_place(3..10);
+    /// sel.union_in_place(..=-5);
+    /// sel.minus_in_place(4..=6);
+    /// sel.union_in_place(20);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+    ///     Interval::unbounded_to(-5),
+    ///     Interval::closed(3, 3),
+    ///     Interval::closed(7, 9),
+    ///     Interval::closed(20, 20),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 8));
+    /// sel.union_in_place(Interval::open(7, 10));
+    ///
+    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-2, 9)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<T>>
+    {
+        self.0.union_in_place(&interval.into().0.denormalized());
+    }
+
+    /// Removes all of the points in the given `Interval` from the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.minus_in_place(Interval::open(2, 5));
+    ///
+    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 2), Interval::closed(5, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Finite`] types will have their bounds closed:
+    ///
+    /// [`Finite`]: ../normalize/trait.Finite.html
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.minus_in_place(Interval::closed(2, 5));
+    ///
+    /// assert_eq!(sel.iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 1), Interval::closed(6, 7)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<T>>
+    {
+        // Removing the denormalized interval leaves its inverted bounds in
+        // the tree, which must be denormalized to merge properly later.
+        let removed = interval.into().0.denormalized();
+        self.0.minus_in_place(&removed);
+        self.denormalize_tines_at(&removed);
+    }
+
+    /// Toggles all of the points in the given `Interval`, removing those in
+    /// the `Selection` and adding those which are not.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use interval::Interval;
+    /// # use interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 7));
+    /// sel.symmetric_difference_in_place(Interval::closed(5, 10));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 4), Interval::closed(8, 10)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn symmetric_difference_in_place<I>(&mut self, interval: I)
+        where I: Into<Interval<T>>
+    {
+        // Only the intervals overlapping the given one change, so they are
+        // toggled on their own and put back in place of the originals.
+        let interval = interval.into();
+        let affected = Selection::from_sorted_disjoint(
+            self.overlapping(&interval));
+        let toggled = affected
+            .symmetric_difference(&Selection::from(interval.clone()));
+        self.minus_in_place(affected.enclose().enclose(&interval));
+        for piece in toggled.into_interval_iter() {
+            self.union_in_place(piece);
+        }
+    }
+
+    /// Adds the given point to the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.insert_point(6);
+    /// sel.insert_point(9);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 6), Interval::point(9)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn insert_point(&mut self, point: T) {
+        self.0.union_in_place(&RawInterval::Point(point).denormalized());
+    }
+
+    /// Removes the given point from the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.remove_point(&5);
+    /// sel.remove_point(&0);
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, -1), Interval::closed(1, 4)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn remove_point(&mut self, point: &T) {
+        let removed = RawInterval::Point(point.clone()).denormalized();
+        self.0.minus_in_place(&removed);
+        self.denormalize_tines_at(&removed);
+    }
+
+    /// Retains only the `Interval`s of the `Selection` for which the given
+    /// predicate returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 9));
+    /// sel.union_in_place(Interval::closed(15, 25));
+    /// sel.retain(|interval| interval.size().map_or(true, |s| s >= 5));
+    ///
+    /// assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 5), Interval::closed(15, 25)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F) where F: FnMut(&Interval<T>) -> bool {
+        // The intervals in the tree are already denormalized, so removing
+        // them exactly leaves their neighbors intact.
+        let removed: Vec<_> = self.0
+            .interval_iter()
+            .filter(|raw| !f(&Interval(raw.clone().normalized())))
+            .collect();
+        for raw in removed {
+            self.0.minus_in_place(&raw);
+        }
+    }
+
+    /// Denormalizes any inclusive tines left at the bounds of the given
+    /// interval by an in-place operation.
+    fn denormalize_tines_at(&mut self, interval: &RawInterval<T>) {
+        let bounds = interval.lower_bound().into_iter()
+            .chain(interval.upper_bound());
+        for bound in bounds {
+            if let Some(p) = bound.as_ref() {
+                let at = Tine::Point(Bound::Include(p.clone()));
+                self.0.replace_tine(&at, denormalize_tine);
+            }
+        }
+    }
+
+    /// Constructs a `Selection` from a `TineTree` produced by a set operation,
+    /// whose intervals may not be properly denormalized.
+    fn renormalized(tree: TineTree<T>) -> Self {
+        tree.into_iter()
+            .map(Normalize::normalized)
+            .filter(|interval| !interval.is_empty())
+            .map(Interval)
+            .collect()
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Iterator conversions
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns an iterator over each of the `Interval`s in the `Selection`.
+    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
+        IntervalIter(self.0.interval_iter())
+    }
+
+    /// Returns an iterator over each of the `Interval`s in the `Selection`.
+    pub fn into_interval_iter(self) -> IntoIntervalIter<T> {
+        IntoIntervalIter(self.0.into_iter())
+    }
+
+    /// Returns an iterator over borrowed views of each of the `Interval`s in
+    /// the `Selection`, which avoids cloning their bounds.
+    ///
+    /// The views carry the bounds stored by the `Selection`, which may differ
+    /// from those of the normalized `Interval`s. Converting a view into an
+    /// `Interval` will normalize it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    ///
+    /// let views = sel.interval_ref_iter().collect::<Vec<_>>();
+    /// assert!(views[0].contains(&5));
+    /// assert!(!views[0].contains(&6));
+    /// assert_eq!(Interval::from(views[1]), Interval::closed(8, 12));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn interval_ref_iter(&self) -> IntervalRefIter<'_, T> {
+        IntervalRefIter(self.0.interval_ref_iter())
+    }
+
+    /// Returns an iterator over the `Interval`s of the `Selection` which
+    /// intersect the given `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// sel.union_in_place(Interval::closed(15, 17));
+    /// let query = Interval::closed(4, 10);
+    ///
+    /// assert_eq!(sel.overlapping(&query).collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 5), Interval::closed(8, 12)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn overlapping(&self, interval: &Interval<T>) -> Overlapping<'_, T> {
+        let intervals = match interval.lower_bound() {
+            Some(Bound::Include(p)) |
+            Some(Bound::Exclude(p)) => self.0.interval_iter_from(&p),
+            _                       => self.0.interval_iter(),
+        };
+        let query = if interval.is_empty() { None } else {
+            Some(interval.clone())
+        };
+        Overlapping { intervals, query }
+    }
+
+    /// Returns an iterator over the gaps between the `Interval`s of the
+    /// `Selection`. The unbounded regions below and above the `Selection` are
+    /// not included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// sel.union_in_place(Interval::closed(15, 17));
+    ///
+    /// assert_eq!(sel.gaps().collect::<Vec<_>>(),
+    ///     [Interval::closed(6, 7), Interval::closed(13, 14)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gaps(&self) -> Gaps<'_, T> {
+        let mut intervals = self.0.interval_iter();
+        let lower = intervals
+            .next()
+            .and_then(|first| first.normalized().upper_bound())
+            .and_then(invert_bound);
+        Gaps { intervals, lower, bounds: None }
+    }
+
+    /// Returns an iterator over the gaps in the `Selection` within the given
+    /// `Interval`. This includes the regions between the bounds of the
+    /// `Interval` and the nearest `Interval`s of the `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    ///
+    /// assert_eq!(sel.gaps_within(Interval::closed(0, 20)).collect::<Vec<_>>(),
+    ///     [Interval::closed(6, 7), Interval::closed(13, 20)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn gaps_within(&self, bounds: Interval<T>) -> Gaps<'_, T> {
+        // Skip the intervals below the bounds.
+        let intervals = match bounds.lower_bound() {
+            Some(Bound::Include(p)) |
+            Some(Bound::Exclude(p)) => self.0.interval_iter_from(&p),
+            _                       => self.0.interval_iter(),
+        };
+        Gaps {
+            intervals,
+            lower: if bounds.is_empty() { None } else { Some(Bound::Infinite) },
+            bounds: Some(bounds.0),
+        }
+    }
+
+    /// Returns an iterator over the boundary events of the `Selection`, in
+    /// order. Each `Interval` produces an [`Event::Enter`] at its lower bound
+    /// followed by an [`Event::Exit`] at its upper bound, so the events
+    /// alternate between entering and leaving the `Selection`.
+    ///
+    /// The bounds are those of the normalized `Interval`s returned by
+    /// [`interval_iter`].
+    ///
+    /// [`Event::Enter`]: enum.Event.html#variant.Enter
+    /// [`Event::Exit`]: enum.Event.html#variant.Exit
+    /// [`interval_iter`]: #method.interval_iter
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Bound;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::selection::Event;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::unbounded_from(8));
+    ///
+    /// assert_eq!(sel.events().collect::<Vec<_>>(), [
+    ///     Event::Enter(Bound::Include(-3)),
+    ///     Event::Exit(Bound::Include(5)),
+    ///     Event::Enter(Bound::Include(8)),
+    ///     Event::Exit(Bound::Include(i32::MAX)),
+    /// ]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn events(&self) -> Events<'_, T> {
+        Events {
+            intervals: self.0.interval_iter(),
+            front: None,
+            back: None,
+        }
+    }
+
+    /// Returns an iterator over the `Interval`s of the union of the given
+    /// `Selection`s, computed lazily without constructing a new `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// a.union_in_place(Interval::closed(12, 15));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(6, 9));
+    ///
+    /// assert_eq!(a.union_iter(&b).collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 9), Interval::closed(12, 15)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn union_iter<'t>(&'t self, other: &'t Self) -> UnionIter<'t, T> {
+        let mut a_iter = self.0.interval_iter();
+        let mut b_iter = other.0.interval_iter();
+        UnionIter {
+            a: a_iter.next(),
+            b: b_iter.next(),
+            a_iter,
+            b_iter,
+        }
+    }
+
+    /// Returns an iterator over the `Interval`s of the intersection of the
+    /// given `Selection`s, computed lazily without constructing a new
+    /// `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// a.union_in_place(Interval::closed(12, 15));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    ///
+    /// assert_eq!(a.intersect_iter(&b).collect::<Vec<_>>(),
+    ///     [Interval::closed(4, 5), Interval::closed(12, 13)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn intersect_iter<'t>(&'t self, other: &'t Self)
+        -> IntersectIter<'t, T>
+    {
+        let mut a_iter = self.0.interval_iter();
+        let mut b_iter = other.0.interval_iter();
+        IntersectIter {
+            a: a_iter.next(),
+            b: b_iter.next(),
+            a_iter,
+            b_iter,
+        }
+    }
+
+    /// Returns an iterator over the `Interval`s of the points in the
+    /// `Selection` which are not in the given `Selection`, computed lazily
+    /// without constructing a new `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut a: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// a.union_in_place(Interval::closed(12, 15));
+    /// let b: Selection<i32> = Selection::from(Interval::closed(4, 13));
+    ///
+    /// assert_eq!(a.minus_iter(&b).collect::<Vec<_>>(),
+    ///     [Interval::closed(-3, 3), Interval::closed(14, 15)]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn minus_iter<'t>(&'t self, other: &'t Self) -> MinusIter<'t, T> {
+        let mut a_iter = self.0.interval_iter();
+        let mut b_iter = other.0.interval_iter();
+        MinusIter {
+            a: a_iter.next(),
+            b: b_iter.next(),
+            a_iter,
+            b_iter,
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////
+    // Cursors
+    ////////////////////////////////////////////////////////////////////////////
+
+    /// Returns a [`Cursor`] positioned on the `Interval` or gap of the
+    /// `Selection` containing the given point.
+    ///
+    /// [`Cursor`]: struct.Cursor.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(10, 12));
+    ///
+    /// let mut cursor = sel.cursor_at(&7);
+    /// assert_eq!(cursor.segment(), &Interval::closed(6, 9));
+    /// assert!(!cursor.is_selected());
+    ///
+    /// assert!(cursor.move_next());
+    /// assert_eq!(cursor.segment(), &Interval::closed(10, 12));
+    /// assert!(cursor.is_selected());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn cursor_at(&mut self, point: &T) -> Cursor<'_, T> {
+        let (segment, selected) = self.segment_at(point);
+        Cursor {
+            selection: self,
+            segment,
+            selected,
+            preceding: Vec::new(),
+            following: Vec::new(),
+        }
+    }
+
+    /// Returns the `Interval` or gap containing the given point, and whether
+    /// it is an `Interval`.
+    fn segment_at(&self, point: &T) -> (Interval<T>, bool) {
+        let at = LowerBound(Bound::Include(point.clone()));
+        match self.neighbors(&at) {
+            (_, Some(c), _)                         => (c, true),
+            (Some(b), None, _) if b.contains(point) => (b, true),
+            (before, None, after)                   => {
+                (gap_between(before.as_ref(), after.as_ref()), false)
+            },
+        }
+    }
+
+    /// Returns the last `Interval` whose lower bound is below the given one,
+    /// the `Interval` whose lower bound is equal to it, and the first
+    /// `Interval` whose lower bound is above it.
+    fn neighbors(&self, lower: &LowerBound<T>) -> Neighbors<T> {
+        // Only the intervals on either side of the bound need be checked.
+        let candidates: Vec<_> = match lower.0 {
+            Bound::Include(ref p) |
+            Bound::Exclude(ref p) => self.0
+                .interval_iter_before(p)
+                .next_back()
+                .into_iter()
+                .chain(self.0.interval_iter_from(p).take(3))
+                .collect(),
+            Bound::Infinite => self.0.interval_iter().take(2).collect(),
+        };
+
+        let mut neighbors = (None, None, None);
+        for raw in candidates {
+            let interval = Interval(raw.normalized());
+            match lower_of(&interval).cmp(lower) {
+                Ordering::Less    => neighbors.0 = Some(interval),
+                Ordering::Equal   => neighbors.1 = Some(interval),
+                Ordering::Greater => if neighbors.2.is_none() {
+                    neighbors.2 = Some(interval);
+                },
+            }
+        }
+        neighbors
+    }
+
+    /// Returns up to `count` of the `Interval`s whose lower bounds are below
+    /// the given one, in order, ending with the nearest.
+    fn intervals_below(&self, lower: &LowerBound<T>, count: usize)
+        -> Vec<Interval<T>>
+    {
+        let p = match lower.0 {
+            Bound::Include(ref p) |
+            Bound::Exclude(ref p) => p,
+            Bound::Infinite       => return Vec::new(),
+        };
+        // The first intervals from the point may also start below the bound.
+        let from: Vec<_> = self.0
+            .interval_iter_from(p)
+            .map(|raw| Interval(raw.normalized()))
+            .take_while(|interval| lower_of(interval) < *lower)
+            .collect();
+        let mut below: Vec<_> = self.0
+            .interval_iter_before(p)
+            .rev()
+            .take(count.saturating_sub(from.len()))
+            .map(|raw| Interval(raw.normalized()))
+            .collect();
+        below.reverse();
+        below.extend(from);
+        let excess = below.len().saturating_sub(count);
+        let _ = below.drain(..excess);
+        below
+    }
+
+    /// Returns up to `count` of the `Interval`s whose lower bounds are above
+    /// the given one, in reverse order, ending with the nearest.
+    fn intervals_above(&self, lower: &LowerBound<T>, count: usize)
+        -> Vec<Interval<T>>
+    {
+        let iter = match lower.0 {
+            Bound::Include(ref p) |
+            Bound::Exclude(ref p) => self.0.interval_iter_from(p),
+            Bound::Infinite       => self.0.interval_iter(),
+        };
+        let mut above: Vec<_> = iter
+            .map(|raw| Interval(raw.normalized()))
+            .skip_while(|interval| lower_of(interval) <= *lower)
+            .take(count)
+            .collect();
+        above.reverse();
+        above
+    }
+}
+
+impl<T> Selection<T> 
+    where 
+        T: Ord + Clone + Finite, 
+{
+    /// Returns an iterator over each of the points in the `Selection`.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.iter_points()
+    }
+
+    /// Returns an iterator over each of the points in the `Selection`.
+    pub fn into_iter(self) -> IntoIter<T> {
+        IntoIter {
+            intervals: self.0.into_iter(),
+            front: Interval::empty().iter(),
+            back: Interval::empty().iter(),
+        }
+    }
+
+    /// Returns an iterator over each of the points in the `Selection`, in
+    /// order from lowest to highest.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, -1));
+    /// sel.union_in_place(Interval::open(4, 8));
+    ///
+    /// assert_eq!(sel.iter_points().collect::<Vec<_>>(),
+    ///     [-3, -2, -1, 5, 6, 7]);
+    /// assert_eq!(sel.iter_points().rev().collect::<Vec<_>>(),
+    ///     [7, 6, 5, -1, -2, -3]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn iter_points(&self) -> Iter<'_, T> {
+        Iter {
+            intervals: self.0.interval_iter(),
+            front: Interval::empty().iter(),
+            back: Interval::empty().iter(),
+        }
+    }
+
+    /// Returns the `Selection` with each point moved by the given number of
+    /// steps, or `None` if any point would move past the bounds of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u8> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    ///
+    /// let moved = sel.translate(10).unwrap();
+    /// assert_eq!(moved.interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(10, 15), Interval::closed(18, 22)]);
+    ///
+    /// assert_eq!(sel.translate(-1), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn translate(&self, offset: isize) -> Option<Self> {
+        // Translation preserves the order of the tines, so each may be moved
+        // independently.
+        self.0
+            .try_map_tines(|tine| translate_tine(tine, offset))
+            .map(Selection)
+    }
+
+    /// Removes and returns the `n` lowest points in the `Selection`. If the
+    /// `Selection` has fewer than `n` points, all of them are removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut free: Selection<u16> = Selection::from(Interval::closed(3, 5));
+    /// free.union_in_place(Interval::closed(8, 100));
+    ///
+    /// let taken = free.take_points(5);
+    /// assert_eq!(taken.iter().collect::<Vec<_>>(), [3, 4, 5, 8, 9]);
+    /// assert_eq!(free, Interval::closed(10, 100));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn take_points(&mut self, n: usize) -> Self {
+        let mut remaining = n;
+        let mut taken = Vec::new();
+        for interval in self.interval_iter() {
+            if remaining == 0 { break; }
+            let lower = interval.infimum().expect("nonempty interval");
+            let upper = interval.supremum().expect("nonempty interval");
+            match lower.succ_n(remaining - 1) {
+                Some(last) if last < upper => {
+                    taken.push(Interval::closed(lower, last));
+                    remaining = 0;
+                },
+                _ => {
+                    // The whole interval is taken, so it has fewer than
+                    // `remaining` points.
+                    remaining -= lower.steps_between(&upper)
+                        .expect("steps less than remaining") + 1;
+                    taken.push(interval);
+                },
+            }
+        }
+
+        let taken = Selection::from_sorted_disjoint(taken);
+        if let (Some(lower), Some(upper))
+            = (taken.infimum(), taken.supremum())
+        {
+            self.minus_in_place(Interval::closed(lower, upper));
+        }
+        taken
+    }
+
+    /// Returns the index of the given point among the points of the
+    /// `Selection` in ascending order, or `None` if the point is not in the
+    /// `Selection` or its index is too large to represent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u32> = Selection::from(Interval::closed(10, 19));
+    /// sel.union_in_place(Interval::closed(1000, 1999));
+    ///
+    /// assert_eq!(sel.rank(&10), Some(0));
+    /// assert_eq!(sel.rank(&1005), Some(15));
+    /// assert_eq!(sel.rank(&500), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn rank(&self, point: &T) -> Option<u128> {
+        let mut preceding: u128 = 0;
+        for interval in self.interval_iter() {
+            let lower = interval.infimum().expect("nonempty interval");
+            if *point < lower { return None; }
+            if interval.contains(point) {
+                let offset = lower.steps_between(point)? as u128;
+                return preceding.checked_add(offset);
+            }
+            preceding = preceding.checked_add(interval.point_count()?)?;
+        }
+        None
+    }
+
+    /// Returns the point at the given index among the points of the
+    /// `Selection` in ascending order, or `None` if the `Selection` has too
+    /// few points or the index within its interval overflows a `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u32> = Selection::from(Interval::closed(10, 19));
+    /// sel.union_in_place(Interval::closed(1000, 1999));
+    ///
+    /// assert_eq!(sel.select(0), Some(10));
+    /// assert_eq!(sel.select(15), Some(1005));
+    /// assert_eq!(sel.select(1010), None);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn select(&self, index: u128) -> Option<T> {
+        let mut remaining = index;
+        for interval in self.interval_iter() {
+            // An interval with too many points to count contains the index.
+            match interval.point_count() {
+                Some(count) if remaining >= count => remaining -= count,
+                _ => {
+                    let lower = interval.infimum().expect("nonempty interval");
+                    return lower.succ_n(usize::try_from(remaining).ok()?);
+                },
+            }
+        }
+        None
+    }
+
+    /// Returns the first gap in the `Selection` at or after the given point
+    /// containing at least `min_size` points, or `None` if there is no such
+    /// gap. The whole gap is returned, starting no earlier than the given
+    /// point.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u16> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::closed(8, 12));
+    /// sel.union_in_place(Interval::closed(20, 30));
+    ///
+    /// assert_eq!(sel.find_gap(3, &0), Some(Interval::closed(13, 19)));
+    /// assert_eq!(sel.find_gap(1, &7), Some(Interval::point(7)));
+    /// assert_eq!(sel.find_gap(2, &7), Some(Interval::closed(13, 19)));
+    /// assert_eq!(sel.find_gap(1, &25), Some(Interval::closed(31, u16::MAX)));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn find_gap(&self, min_size: usize, search_from: &T)
+        -> Option<Interval<T>>
+    {
+        let min_size = min_size as u128;
+        self.gaps_within(Interval::unbounded_from(search_from.clone()))
+            .find(|gap| gap.point_count().is_none_or(|n| n >= min_size))
+    }
+
+    /// Returns the `Selection` formed by filling each gap between its
+    /// `Interval`s which contains at most `max_gap` points, merging the
+    /// `Interval`s on either side. This gives a coarser `Selection` with fewer
+    /// `Interval`s, containing every point of the original.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::closed(7, 10));
+    /// sel.union_in_place(Interval::closed(13, 20));
+    /// sel.union_in_place(Interval::closed(40, 45));
+    ///
+    /// assert_eq!(sel.simplify(2).interval_iter().collect::<Vec<_>>(),
+    ///     [Interval::closed(0, 20), Interval::closed(40, 45)]);
+    /// assert_eq!(sel.simplify(0), sel);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn simplify(&self, max_gap: usize) -> Self {
+        let max_gap = max_gap as u128;
+        let mut intervals = self.interval_iter();
+        let first = intervals.next();
+        // Each gap lies between the intervals on either side of it, so
+        // interleaving the filled gaps keeps the intervals sorted.
+        let rest = self.gaps()
+            .zip(intervals)
+            .flat_map(|(gap, interval)| {
+                let fill = matches!(gap.point_count(), Some(n) if n <= max_gap);
+                let gap = if fill { Some(gap) } else { None };
+                gap.into_iter().chain(Some(interval))
+            });
+        Selection::from_sorted_disjoint(first.into_iter().chain(rest))
+    }
+
+    /// Returns the point in the `Selection` nearest to the given point, or
+    /// `None` if the `Selection` is empty. If two points are equally near, the
+    /// lesser is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(10, 12));
+    ///
+    /// assert_eq!(sel.nearest_point(&2), Some(2));
+    /// assert_eq!(sel.nearest_point(&8), Some(10));
+    /// assert_eq!(sel.nearest_point(&-20), Some(-3));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn nearest_point(&self, point: &T) -> Option<T> {
+        self.nearest(point).map(|(nearest, _)| nearest)
+    }
+
+    /// Returns the number of steps from the given point to the nearest point
+    /// in the `Selection`, or `None` if the `Selection` is empty or the
+    /// distance is too large to represent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::closed(10, 12));
+    ///
+    /// assert_eq!(sel.distance_to(&2), Some(0));
+    /// assert_eq!(sel.distance_to(&8), Some(2));
+    /// assert_eq!(sel.distance_to(&-20), Some(17));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn distance_to(&self, point: &T) -> Option<usize> {
+        self.nearest(point).and_then(|(_, distance)| distance)
+    }
+
+    /// Returns the nearest point in the `Selection` to the given point, along
+    /// with its distance.
+    fn nearest(&self, point: &T) -> Option<(T, Option<usize>)> {
+        // Only the intervals on either side of the point need be checked.
+        let before = self.0.interval_iter_before(point).next_back();
+        let after = self.0.interval_iter_from(point).take(2);
+
+        let mut nearest: Option<(T, Option<usize>)> = None;
+        for raw in before.into_iter().chain(after) {
+            let interval = Interval(raw.normalized());
+            let (candidate, distance) = match (interval.infimum(),
+                interval.supremum())
+            {
+                (Some(l), _) if *point < l => {
+                    let distance = point.steps_between(&l);
+                    (l, distance)
+                },
+                (_, Some(u)) if u < *point => {
+                    let distance = u.steps_between(point);
+                    (u, distance)
+                },
+                (Some(_), Some(_)) => return Some((point.clone(), Some(0))),
+                _                  => continue,
+            };
+            // Unrepresentable distances are treated as the farthest.
+            let closer = match nearest {
+                None                  => true,
+                Some((_, None))       => distance.is_some(),
+                Some((_, Some(best))) => distance.is_some_and(|d| d < best),
+            };
+            if closer { nearest = Some((candidate, distance)); }
+        }
+        nearest
+    }
+
+    /// Returns the number of points in the `Selection`, or `None` if the
+    /// count is too large to represent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::open(10, 15));
+    /// assert_eq!(sel.point_count(), Some(13));
+    ///
+    /// let sel: Selection<u64> = Selection::full();
+    /// assert_eq!(sel.point_count(), Some(1 << 64));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn point_count(&self) -> Option<u128> {
+        self.interval_iter()
+            .try_fold(0u128, |sum, i| sum.checked_add(i.point_count()?))
+    }
+
+    /// Converts the `Selection` into a `Vec` of half-open `Range`s, one for
+    /// each of its intervals, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Selection` contains the maximum point of the type, as
+    /// it has no successor to use as an exclusive upper bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::open(10, 15));
+    ///
+    /// assert_eq!(sel.into_ranges(), [-3..6, 11..15]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn into_ranges(self) -> Vec<Range<T>> {
+        match self.try_into_ranges() {
+            Ok(ranges) => ranges,
+            Err(_)     => panic!("into_ranges of selection containing the \
+                maximum point"),
+        }
+    }
+
+    /// Converts the `Selection` into a `Vec` of half-open `Range`s, one for
+    /// each of its intervals, in ascending order, or returns an error if the
+    /// `Selection` contains the maximum point of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::IntervalError;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u8> = Selection::from(Interval::closed(3, 9));
+    /// assert_eq!(sel.try_into_ranges()?, [3..10]);
+    ///
+    /// let sel: Selection<u8> = Selection::from(Interval::closed(250, 255));
+    /// assert_eq!(sel.try_into_ranges(), Err(IntervalError::BoundOverflow));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn try_into_ranges(self) -> Result<Vec<Range<T>>, IntervalError> {
+        self.into_interval_iter()
+            .map(|interval| match (interval.infimum(), interval.supremum()) {
+                (Some(l), Some(u)) => u.succ()
+                    .map(|end| l..end)
+                    .ok_or(IntervalError::BoundOverflow),
+                _ => unreachable!("empty interval in selection"),
+            })
+            .collect()
+    }
+
+    /// Converts the `Selection` into a `Vec` of `RangeInclusive`s, one for
+    /// each of its intervals, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<u8> = Selection::from(Interval::closed(0, 5));
+    /// sel.union_in_place(Interval::left_open(200, 255));
+    ///
+    /// assert_eq!(sel.into_range_inclusives(), [0..=5, 201..=255]);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn into_range_inclusives(self) -> Vec<RangeInclusive<T>> {
+        self.into_interval_iter()
+            .filter_map(|interval| match (interval.infimum(),
+                interval.supremum())
+            {
+                (Some(l), Some(u)) => Some(l..=u),
+                _                  => None,
+            })
+            .collect()
+    }
+}
+
+impl<T> Selection<T> 
+    where
+        T: Ord + Clone + PointBytes,
+        RawInterval<T>: Normalize,
+{
+    /// Returns a compact binary encoding of the `Selection`, which can be
+    /// decoded using [`Selection::from_bytes`].
+    ///
+    /// [`Selection::from_bytes`]: #method.from_bytes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    /// sel.union_in_place(Interval::unbounded_from(12));
+    ///
+    /// let bytes = sel.to_bytes();
+    /// assert_eq!(Selection::from_bytes(&bytes)?, sel);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![crate::bytes::FORMAT_VERSION];
+        for tine in self.0.tine_iter() {
+            crate::bytes::write_tine(tine, &mut buf);
+        }
+        buf
+    }
+
+    /// Decodes a `Selection` from the binary encoding returned by
+    /// [`Selection::to_bytes`].
+    ///
+    /// [`Selection::to_bytes`]: #method.to_bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if the bytes are not a valid encoding, or
+    /// if the bounds they contain are out of order or do not form intervals.
+    ///
+    /// [`FromBytesError`]: ../bytes/enum.FromBytesError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u8> = Selection::from(Interval::closed(3, 5));
+    /// let mut bytes = sel.to_bytes();
+    ///
+    /// // Truncated encodings are rejected.
+    /// let _ = bytes.pop();
+    /// assert!(Selection::<u8>::from_bytes(&bytes).is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let intervals = crate::bytes::read_intervals(bytes)?;
+        Ok(Selection::from_sorted_disjoint(intervals
+            .into_iter()
+            .map(Interval::from)))
+    }
+}
+
+impl<T> Selection<T> 
+    where
+        T: Ord + Clone + DeltaPoint,
+        RawInterval<T>: Normalize,
+{
+    /// Returns the delta encoding of the `Selection`, which can be decoded
+    /// using [`Selection::from_delta_bytes`] or a [`DeltaDecoder`].
+    ///
+    /// Each bound is written as a varint giving its distance from the previous
+    /// bound, so large selections of many small intervals or gaps encode to
+    /// far fewer bytes than with [`Selection::to_bytes`].
+    ///
+    /// [`Selection::from_delta_bytes`]: #method.from_delta_bytes
+    /// [`DeltaDecoder`]: ../bytes/struct.DeltaDecoder.html
+    /// [`Selection::to_bytes`]: #method.to_bytes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u64> = Selection::union_all((0..1000)
+    ///     .map(|i| Interval::closed(i * 4096, i * 4096 + 1023)));
+    ///
+    /// let bytes = sel.to_delta_bytes();
+    /// assert!(bytes.len() < sel.to_bytes().len() / 4);
+    /// assert_eq!(Selection::from_delta_bytes(&bytes)?, sel);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn to_delta_bytes(&self) -> Vec<u8> {
+        let mut buf = vec![crate::bytes::DELTA_FORMAT];
+        let mut next = 0;
+        for interval in self.interval_iter() {
+            let bounds = (interval.infimum(), interval.supremum());
+            if let (Some(l), Some(u)) = bounds {
+                crate::bytes::write_delta_interval(&l, &u, &mut next, &mut buf);
+            }
+        }
+        buf
+    }
+
+    /// Decodes a `Selection` from the delta encoding returned by
+    /// [`Selection::to_delta_bytes`].
+    ///
+    /// [`Selection::to_delta_bytes`]: #method.to_delta_bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`FromBytesError`] if the bytes are not a valid encoding, or
+    /// if the points they contain are out of range for the type.
+    ///
+    /// [`FromBytesError`]: ../bytes/enum.FromBytesError.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let sel: Selection<u16> = Selection::from(Interval::closed(300, 900));
+    /// let bytes = sel.to_delta_bytes();
+    ///
+    /// // Points out of range for the type are rejected.
+    /// assert!(Selection::<u8>::from_delta_bytes(&bytes).is_err());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from_delta_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let mut decoder = DeltaDecoder::new();
+        decoder.feed(bytes)?;
+        decoder.finish()
+    }
+}
+
+/// The `Interval`s below, at, and above a lower bound, as returned by
+/// `Selection::neighbors`.
+type Neighbors<T> = (
+    Option<Interval<T>>,
+    Option<Interval<T>>,
+    Option<Interval<T>>,
+);
+
+/// Returns the `Bound` at the same point with the opposite inclusivity, or
+/// `None` if the `Bound` is `Infinite`.
+fn invert_bound<T>(bound: Bound<T>) -> Option<Bound<T>> {
+    match bound {
+        Bound::Include(p) => Some(Bound::Exclude(p)),
+        Bound::Exclude(p) => Some(Bound::Include(p)),
+        Bound::Infinite   => None,
+    }
+}
+
+/// Returns the gap between the given `Interval`s, either of which may be
+/// `None` to leave that side of the gap unbounded. The gap is empty if the
+/// `Interval`s leave no points between them.
+fn gap_between<T>(before: Option<&Interval<T>>, after: Option<&Interval<T>>)
+    -> Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    // A neighbor with an infinite bound leaves no room for a gap.
+    let lower = match before.map(|b| b.upper_bound().and_then(invert_bound)) {
+        Some(Some(lower)) => lower,
+        Some(None)        => return Interval::empty(),
+        None              => Bound::Infinite,
+    };
+    let upper = match after.map(|a| a.lower_bound().and_then(invert_bound)) {
+        Some(Some(upper)) => upper,
+        Some(None)        => return Interval::empty(),
+        None              => Bound::Infinite,
+    };
+    Interval(RawInterval::new(lower, upper).normalized())
+}
+
+/// Moves a denormalized `Tine` of a `Finite` type by the given number of
+/// steps.
+fn translate_tine<T>(tine: &Tine<T>, offset: isize) -> Option<Tine<T>>
+    where T: Ord + Clone + Finite,
+{
+    use Bound::*;
+    let shift = |p: &T| if offset < 0 {
+        p.pred_n(offset.unsigned_abs())
+    } else {
+        p.succ_n(offset.unsigned_abs())
+    };
+    // Bounds are moved by their nearest included point, as the excluded
+    // points outside of the type's range are represented as infinite.
+    Some(match *tine {
+        Tine::Lower(ref bound) => {
+            let point = match *bound {
+                Include(ref p) => p.clone(),
+                Exclude(ref p) => p.succ()?,
+                Infinite       => T::MINIMUM,
+            };
+            Tine::Lower(shift(&point)?.pred().map_or(Infinite, Exclude))
+        },
+        Tine::Upper(ref bound) => {
+            let point = match *bound {
+                Include(ref p) => p.clone(),
+                Exclude(ref p) => p.pred()?,
+                Infinite       => T::MAXIMUM,
+            };
+            Tine::Upper(shift(&point)?.succ().map_or(Infinite, Exclude))
+        },
+        Tine::Point(Include(ref p)) => Tine::Point(Include(shift(p)?)),
+        Tine::Point(Exclude(ref p)) => Tine::Point(Exclude(shift(p)?)),
+        Tine::Point(Infinite)       => unreachable!("invalid Tine value"),
+    })
+}
+
+/// Returns the lower bound of a nonempty `Interval` for ordering.
+fn lower_of<T>(interval: &Interval<T>) -> LowerBound<T>
+    where T: Ord + Clone
+{
+    LowerBound(interval.0.lower_bound().expect("nonempty interval"))
+}
+
+/// Returns the lower bound of a nonempty `RawInterval`, ordered by the points
+/// it includes.
+fn raw_lower_of<T>(interval: &RawInterval<T>) -> LowerBound<T>
+    where T: Ord + Clone
+{
+    LowerBound(interval.lower_bound().expect("nonempty interval"))
+}
+
+/// Denormalizes an inclusive `Tine` left in the tree by an in-place
+/// operation.
+fn denormalize_tine<T>(tine: Tine<T>) -> Tine<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    use Bound::*;
+    match tine {
+        Tine::Upper(Include(p)) => RawInterval::To(p)
+            .normalized()
+            .denormalized()
+            .upper_bound()
+            .map(Tine::Upper)
+            .expect("nonempty interval"),
+        Tine::Lower(Include(p)) => RawInterval::From(p)
+            .normalized()
+            .denormalized()
+            .lower_bound()
+            .map(Tine::Lower)
+            .expect("nonempty interval"),
+        tine => tine,
+    }
+}
+
+impl<T> Default for Selection<T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        Selection::new()
+    }
+}
+
+// Compares the points selected with those in the `Interval`.
+impl<T> PartialEq<Interval<T>> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Interval<T>) -> bool {
+        match self.interval_count() {
+            0 => other.is_empty(),
+            1 => self.first().as_ref() == Some(other),
+            _ => false,
+        }
+    }
+}
+
+impl<T> PartialEq<Selection<T>> for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn eq(&self, other: &Selection<T>) -> bool {
+        other == self
+    }
+}
+
+impl<T> Extend<Interval<T>> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        let added: Vec<_> = iter
+            .into_iter()
+            .map(|interval| interval.0.denormalized())
+            .collect();
+        if added.is_empty() { return; }
+
+        // Merge the new intervals with the existing ones in a single sweep.
+        let existing = std::mem::take(&mut self.0);
+        self.0 = TineTree::union_all(existing.into_iter().chain(added));
+    }
+}
+
+impl<T> Extend<(Bound<T>, Bound<T>)> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I)
+        where I: IntoIterator<Item=(Bound<T>, Bound<T>)>
+    {
+        self.extend(iter.into_iter().map(|(l, u)| Interval::new(l, u)));
+    }
+}
+
+impl<T> Extend<T> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=T> {
+        self.extend(iter.into_iter().map(Interval::point));
+    }
+}
+
+impl<T> From<Interval<T>> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from(interval: Interval<T>) -> Self {
+        let raw = interval.0.denormalized();
+        Selection(TineTree::from_raw_interval(raw))
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
+        Selection::union_all(iter)
+    }
+}
+
+impl<T> FromIterator<T> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
+        Selection::union_all(iter.into_iter().map(Interval::point))
+    }
+}
+
+impl<T> IntoIterator for Selection<T>
+    where T: Ord + Clone + Finite,
+{
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_iter()
+    }
+}
+
+// Display using interval notation, joining the intervals with unions.
+impl<T> std::fmt::Display for Selection<T>
+    where
+        T: Ord + Clone + std::fmt::Display,
+        RawInterval<T>: Normalize,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut intervals = self.interval_iter();
+        match intervals.next() {
+            Some(first) => write!(f, "{}", first)?,
+            None        => return write!(f, "∅"),
+        }
+        for interval in intervals {
+            write!(f, " ∪ {}", interval)?;
+        }
+        Ok(())
+    }
+}
+
+// Parse a union of intervals in interval notation. The intervals may be
+// separated by `∪`, `u`, `U`, or commas outside of their brackets.
+impl<T> FromStr for Selection<T>
+    where
+        T: Ord + Clone + FromStr,
+        RawInterval<T>: Normalize,
+{
+    type Err = ParseIntervalError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut intervals = Vec::new();
+        let mut depth = 0;
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match c {
+                '[' | '(' | '{' => depth += 1,
+                ']' | ')' | '}' => depth -= 1,
+                '∪' | 'u' | 'U' | ',' if depth == 0 => {
+                    intervals.push(Interval::from_str(&s[start..i])?);
+                    start = i + c.len_utf8();
+                },
+                _ => (),
+            }
+        }
+        intervals.push(Interval::from_str(&s[start..])?);
+        Ok(Selection::union_all(intervals))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionBuilder
+////////////////////////////////////////////////////////////////////////////////
+/// Accumulates `Interval`s and points to add to and remove from a new
+/// `Selection`, then builds it in a few sorted sweeps rather than modifying
+/// the `Selection` for each of them.
+///
+/// Additions and removals apply in the order they are made, but each run of
+/// consecutive additions or removals is merged in a single sweep.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// let sel: Selection<i32> = Selection::builder()
+///     .insert(Interval::closed(8, 12))
+///     .insert(Interval::closed(-3, 5))
+///     .insert_point(6)
+///     .remove_point(0)
+///     .build();
+///
+/// assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+///     Interval::closed(-3, -1),
+///     Interval::closed(1, 6),
+///     Interval::closed(8, 12),
+/// ]);
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SelectionBuilder<T> {
+    /// The runs of consecutive `Interval`s to add or remove, paired with
+    /// `true` if they are to be removed.
+    runs: Vec<(bool, Vec<Interval<T>>)>,
+}
+
+impl<T> SelectionBuilder<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new `SelectionBuilder` for an empty `Selection`.
+    pub const fn new() -> Self {
+        SelectionBuilder { runs: Vec::new() }
+    }
+
+    /// Adds all of the points in the given `Interval` to the `Selection`.
+    pub fn insert(self, interval: Interval<T>) -> Self {
+        self.push(false, interval)
+    }
+
+    /// Adds the given point to the `Selection`.
+    pub fn insert_point(self, point: T) -> Self {
+        self.push(false, Interval::point(point))
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `Selection`.
+    pub fn remove(self, interval: Interval<T>) -> Self {
+        self.push(true, interval)
+    }
+
+    /// Removes the given point from the `Selection`.
+    pub fn remove_point(self, point: T) -> Self {
+        self.push(true, Interval::point(point))
+    }
+
+    /// Builds the `Selection`.
+    pub fn build(self) -> Selection<T> {
+        let mut res = Selection::new();
+        for (remove, intervals) in self.runs {
+            let run = Selection::union_all(intervals);
+            res = match (remove, res.is_empty()) {
+                (true,  _)     => res.minus(&run),
+                (false, true)  => run,
+                (false, false) => res.union(&run),
+            };
+        }
+        res
+    }
+
+    /// Appends the given `Interval` to the run of additions or removals.
+    fn push(mut self, remove: bool, interval: Interval<T>) -> Self {
+        match self.runs.last_mut() {
+            Some((r, intervals)) if *r == remove => intervals.push(interval),
+            _ => self.runs.push((remove, vec![interval])),
+        }
+        self
+    }
+}
+
+impl<T> Default for SelectionBuilder<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        SelectionBuilder::new()
+    }
+}
+
+impl<T> Extend<Interval<T>> for SelectionBuilder<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
+        let mut iter = iter.into_iter().peekable();
+        if iter.peek().is_none() { return; }
+        match self.runs.last_mut() {
+            Some((false, intervals)) => intervals.extend(iter),
+            _ => self.runs.push((false, iter.collect())),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionDiff
+////////////////////////////////////////////////////////////////////////////////
+/// The points which differ between two `Selection`s, as returned by
+/// [`Selection::diff`].
+///
+/// [`Selection::diff`]: struct.Selection.html#method.diff
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SelectionDiff<T>
+    where T: Ord + Clone
+{
+    /// The points only in the first `Selection`.
+    pub removed: Selection<T>,
+    /// The points only in the second `Selection`.
+    pub added: Selection<T>,
+}
+
+impl<T> SelectionDiff<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Returns `true` if the `Selection`s contained the same points.
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+
+    /// Applies the changes to the given `Selection`, removing the points of
+    /// `removed` and adding the points of `added`.
+    ///
+    /// Applying the diff to the first `Selection` produces the second.
+    pub fn apply(&self, selection: &mut Selection<T>) {
+        *selection = selection.minus(&self.removed).union(&self.added);
+    }
+
+    /// Reverts the changes from the given `Selection`, removing the points of
+    /// `added` and restoring the points of `removed`.
+    ///
+    /// Reverting the diff from the second `Selection` produces the first.
+    pub fn revert(&self, selection: &mut Selection<T>) {
+        *selection = selection.minus(&self.added).union(&self.removed);
+    }
+
+    /// Returns the `SelectionDiff` which undoes this one.
+    pub fn inverse(self) -> Self {
+        SelectionDiff { removed: self.added, added: self.removed }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IntoIntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An owning `Iterator` over the `Interval`s of a `Selection`.
+#[derive(Debug)]
+pub struct IntoIntervalIter<T>(crate::tine_tree::IntoIter<T>);
+
+impl<T> Iterator for IntoIntervalIter<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIntervalIter<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0
+            .next_back()
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+}
+
+
+impl<T> FusedIterator for IntoIntervalIter<T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+impl<T> ExactSizeIterator for IntoIntervalIter<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `Selection`.
+#[derive(Debug)]
+pub struct IntervalIter<'t, T>(crate::tine_tree::Iter<'t, T>)
+    where T: Ord + Clone;
+
+impl<'t, T> Iterator for IntervalIter<'t, T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0
+            .next()
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+
+impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0
+            .next_back()
+            .map(Normalize::normalized)
+            .map(Interval::from)
+    }
+}
+
+impl<'t, T> FusedIterator for IntervalIter<'t, T> 
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+impl<'t, T> ExactSizeIterator for IntervalIter<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// IntervalRefIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over borrowed views of the intervals of a `Selection`.
+#[derive(Debug)]
+pub struct IntervalRefIter<'t, T>(crate::tine_tree::RefIter<'t, T>);
+
+impl<'t, T> Iterator for IntervalRefIter<'t, T> 
+    where T: Ord + Clone,
+{
+    type Item = RawIntervalRef<'t, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'t, T> DoubleEndedIterator for IntervalRefIter<'t, T> 
+    where T: Ord + Clone,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<'t, T> FusedIterator for IntervalRefIter<'t, T> 
+    where T: Ord + Clone,
+{}
+
+impl<'t, T> ExactSizeIterator for IntervalRefIter<'t, T>
+    where T: Ord + Clone,
+{}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Overlapping
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of a `Selection` which intersect a given
+/// `Interval`.
+#[derive(Debug)]
+pub struct Overlapping<'t, T>
+    where T: Ord + Clone
+{
+    intervals: crate::tine_tree::Iter<'t, T>,
+    /// The `Interval` to intersect, or `None` if the iterator is exhausted.
+    query: Option<Interval<T>>,
+}
+
+impl<'t, T> Iterator for Overlapping<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let query = self.query.as_ref()?;
+        // The iterator may start just before the query, but once an interval
+        // is found past it, none of the rest can intersect.
+        for raw in &mut self.intervals {
+            let interval = Interval(raw.normalized());
+            if interval.intersects(query) { return Some(interval); }
+            if lower_of(&interval) > lower_of(query) { break; }
+        }
+        self.query = None;
+        None
+    }
+}
+
+impl<'t, T> FusedIterator for Overlapping<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+////////////////////////////////////////////////////////////////////////////////
+// Gaps
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the gaps between the `Interval`s of a `Selection`.
+#[derive(Debug)]
+pub struct Gaps<'t, T>
+    where T: Ord + Clone
+{
+    intervals: crate::tine_tree::Iter<'t, T>,
+    /// The lower bound of the next gap, or `None` if there are no more gaps.
+    lower: Option<Bound<T>>,
+    /// The bounds to restrict the gaps to, if any.
+    bounds: Option<RawInterval<T>>,
+}
+
+impl<'t, T> Iterator for Gaps<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let lower = self.lower.take()?;
+            if let Some(ref bounds) = self.bounds {
+                // Stop once the gaps are past the bounds.
+                let rest = RawInterval::new(lower.clone(), Bound::Infinite);
+                if !rest.intersects(bounds) { return None; }
+            }
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Iterator conversions
-    ////////////////////////////////////////////////////////////////////////////
+            let gap = match self.intervals.next().map(Normalize::normalized) {
+                Some(interval) => {
+                    self.lower = interval.upper_bound().and_then(invert_bound);
+                    match interval.lower_bound().and_then(invert_bound) {
+                        Some(upper) => RawInterval::new(lower, upper),
+                        None        => continue,
+                    }
+                },
+                // The final gap is unbounded, so it is only included if it is
+                // to be restricted.
+                None if self.bounds.is_some() => {
+                    RawInterval::new(lower, Bound::Infinite)
+                },
+                None => return None,
+            };
 
-    /// Returns an iterator over each of the `Interval`s in the `Selection`.
-    pub fn interval_iter(&self) -> IntervalIter<'_, T> {
-        IntervalIter(self.0.interval_iter())
-    }
+            let gap = match self.bounds {
+                Some(ref bounds) => gap.intersect(bounds).normalized(),
+                None             => gap.normalized(),
+            };
 
-    /// Returns an iterator over each of the `Interval`s in the `Selection`.
-    pub fn into_interval_iter(self) -> IntoIntervalIter<T> {
-        IntoIntervalIter(self.0.into_iter())
+            if !gap.is_empty() { return Some(Interval(gap)); }
+        }
     }
 }
 
-impl<T> Selection<T> 
-    where 
-        T: Ord + Clone + Finite, 
-{
-    /// Returns an iterator over each of the points in the `Selection`.
-    pub fn iter(&self) -> Iter<'_, T> {
-        Iter {
-            intervals: self.0.interval_iter(),
-            current: Interval::empty().iter(),
+impl<'t, T> FusedIterator for Gaps<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
+
+////////////////////////////////////////////////////////////////////////////////
+// Event
+////////////////////////////////////////////////////////////////////////////////
+/// A transition into or out of a `Selection` at a `Bound`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event<T> {
+    /// The `Selection` is entered at the lower `Bound` of an `Interval`.
+    Enter(Bound<T>),
+    /// The `Selection` is left at the upper `Bound` of an `Interval`.
+    Exit(Bound<T>),
+}
+
+impl<T> Event<T> where T: Ord + Clone {
+    /// Returns `true` if the `Event` is an `Enter` event.
+    pub fn is_enter(&self) -> bool {
+        matches!(self, Event::Enter(_))
+    }
+
+    /// Returns `true` if the `Event` is an `Exit` event.
+    pub fn is_exit(&self) -> bool {
+        matches!(self, Event::Exit(_))
+    }
+
+    /// Returns a reference to the `Bound` of the `Event`.
+    pub fn bound(&self) -> &Bound<T> {
+        match self {
+            Event::Enter(bound) | Event::Exit(bound) => bound,
         }
     }
 
-    /// Returns an iterator over each of the points in the `Selection`.
-    pub fn into_iter(self) -> IntoIter<T> {
-        IntoIter {
-            intervals: self.0.into_iter(),
-            current: Interval::empty().iter(),
+    /// Returns the `Bound` of the `Event`.
+    pub fn into_bound(self) -> Bound<T> {
+        match self {
+            Event::Enter(bound) | Event::Exit(bound) => bound,
         }
     }
+
+    /// Returns a reference to the point of the `Event`, or `None` if its
+    /// `Bound` is `Infinite`.
+    pub fn point(&self) -> Option<&T> {
+        self.bound().as_ref()
+    }
 }
 
-impl<T> Default for Selection<T> 
+
+////////////////////////////////////////////////////////////////////////////////
+// Events
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the boundary `Event`s of a `Selection`.
+#[derive(Debug)]
+pub struct Events<'t, T>
+    where T: Ord + Clone
+{
+    intervals: crate::tine_tree::Iter<'t, T>,
+    /// The upper bound of the last interval taken from the front.
+    front: Option<Bound<T>>,
+    /// The lower bound of the last interval taken from the back.
+    back: Option<Bound<T>>,
+}
+
+impl<'t, T> Iterator for Events<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {
-    fn default() -> Self {
-        Selection::new()
+    type Item = Event<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(upper) = self.front.take() {
+            return Some(Event::Exit(upper));
+        }
+        match self.intervals.next().map(Normalize::normalized) {
+            Some(interval) => {
+                self.front = interval.upper_bound();
+                interval.lower_bound().map(Event::Enter)
+            },
+            None => self.back.take().map(Event::Enter),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let pending = self.front.iter().count() + self.back.iter().count();
+        let (lower, upper) = self.intervals.size_hint();
+        (2 * lower + pending, upper.map(|upper| 2 * upper + pending))
     }
 }
 
-impl<T> Extend<Interval<T>> for Selection<T>
+impl<'t, T> DoubleEndedIterator for Events<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {
-    fn extend<I>(&mut self, iter: I) where I: IntoIterator<Item=Interval<T>> {
-        for interval in iter.into_iter() {
-            let raw = interval.0.denormalized();
-            self.0.union_in_place(&raw);
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some(lower) = self.back.take() {
+            return Some(Event::Enter(lower));
+        }
+        match self.intervals.next_back().map(Normalize::normalized) {
+            Some(interval) => {
+                self.back = interval.lower_bound();
+                interval.upper_bound().map(Event::Exit)
+            },
+            None => self.front.take().map(Event::Exit),
         }
     }
 }
 
-impl<T> From<Interval<T>> for Selection<T>
+impl<'t, T> FusedIterator for Events<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
-{
-    fn from(interval: Interval<T>) -> Self {
-        let raw = interval.0.denormalized();
-        Selection(TineTree::from_raw_interval(raw))
-    }
-}
+{}
 
-impl<T> FromIterator<Interval<T>> for Selection<T>
+impl<'t, T> ExactSizeIterator for Events<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
+{}
+
+////////////////////////////////////////////////////////////////////////////////
+// Cursor
+////////////////////////////////////////////////////////////////////////////////
+/// The number of `Interval`s a `Cursor` reads from its `Selection` at once.
+const CURSOR_READ_AHEAD: usize = 16;
+
+/// A cursor over the `Interval`s of a `Selection` and the gaps between them,
+/// which can step between neighboring segments and modify the `Selection`
+/// around its position.
+#[derive(Debug)]
+pub struct Cursor<'s, T>
+    where T: Ord + Clone
 {
-    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Interval<T>> {
-        let mut selection = Selection::new();
-        for interval in iter.into_iter() {
-            let raw = interval.0.denormalized();
-            selection.0.union_in_place(&raw);
-        }
-        selection
-    }
+    selection: &'s mut Selection<T>,
+    /// The `Interval` or gap the cursor is positioned on.
+    segment: Interval<T>,
+    /// Whether the segment is an `Interval` of the `Selection`.
+    selected: bool,
+    /// The nearest `Interval`s before the segment which have been read from
+    /// the `Selection`, ending with the nearest. Empty if none have been read.
+    preceding: Vec<Interval<T>>,
+    /// The nearest `Interval`s after the segment which have been read from
+    /// the `Selection`, ending with the nearest. Empty if none have been read.
+    following: Vec<Interval<T>>,
 }
 
-impl<T> FromIterator<T> for Selection<T>
+impl<'s, T> Cursor<'s, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {
-    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=T> {
-        let mut selection = Selection::new();
-        for item in iter.into_iter() {
-            let raw = Interval::point(item).0.denormalized();
-            selection.0.union_in_place(&raw);
+    /// Returns the `Interval` or gap the cursor is positioned on.
+    #[inline]
+    pub fn segment(&self) -> &Interval<T> {
+        &self.segment
+    }
+
+    /// Returns `true` if the cursor is positioned on an `Interval` of the
+    /// `Selection`, or `false` if it is positioned on a gap.
+    #[inline]
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// Returns the `Selection` the cursor is over.
+    #[inline]
+    pub fn selection(&self) -> &Selection<T> {
+        self.selection
+    }
+
+    /// Moves the cursor to the `Interval` or gap containing the given point.
+    pub fn seek(&mut self, point: &T) {
+        let (segment, selected) = self.selection.segment_at(point);
+        self.segment = segment;
+        self.selected = selected;
+        self.preceding.clear();
+        self.following.clear();
+    }
+
+    /// Moves the cursor to the following `Interval` or gap. Returns `false`
+    /// and leaves the cursor in place if there is none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    ///
+    /// let mut cursor = sel.cursor_at(&0);
+    /// assert!(cursor.move_next());
+    /// assert_eq!(cursor.segment(), &Interval::closed(6, i32::MAX));
+    /// assert!(!cursor.move_next());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn move_next(&mut self) -> bool {
+        if self.following.is_empty() {
+            self.following = self.selection
+                .intervals_above(&lower_of(&self.segment), CURSOR_READ_AHEAD);
+        }
+        if self.selected {
+            let gap = gap_between(Some(&self.segment), self.following.last());
+            if gap.is_empty() { return false; }
+            let interval = std::mem::replace(&mut self.segment, gap);
+            push_read(&mut self.preceding, interval);
+        } else {
+            match self.following.pop() {
+                Some(interval) => self.segment = interval,
+                None           => return false,
+            }
+        }
+        self.selected = !self.selected;
+        true
+    }
+
+    /// Moves the cursor to the preceding `Interval` or gap. Returns `false`
+    /// and leaves the cursor in place if there is none.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    ///
+    /// let mut cursor = sel.cursor_at(&0);
+    /// assert!(cursor.move_prev());
+    /// assert_eq!(cursor.segment(), &Interval::closed(i32::MIN, -4));
+    /// assert!(!cursor.move_prev());
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn move_prev(&mut self) -> bool {
+        if self.preceding.is_empty() {
+            self.preceding = self.selection
+                .intervals_below(&lower_of(&self.segment), CURSOR_READ_AHEAD);
+        }
+        if self.selected {
+            let gap = gap_between(self.preceding.last(), Some(&self.segment));
+            if gap.is_empty() { return false; }
+            let interval = std::mem::replace(&mut self.segment, gap);
+            push_read(&mut self.following, interval);
+        } else {
+            match self.preceding.pop() {
+                Some(interval) => self.segment = interval,
+                None           => return false,
+            }
         }
-        selection
+        self.selected = !self.selected;
+        true
+    }
+
+    /// Adds all of the points in the given `Interval` to the `Selection`, and
+    /// moves the cursor to the `Interval` containing them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    ///
+    /// let mut cursor = sel.cursor_at(&0);
+    /// cursor.extend(Interval::closed(6, 9));
+    /// assert_eq!(cursor.segment(), &Interval::closed(-3, 9));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn extend(&mut self, interval: Interval<T>) {
+        if interval.is_empty() { return; }
+        self.selection.union_in_place(interval.clone());
+        self.segment = self.selection
+            .overlapping(&interval)
+            .next()
+            .expect("interval in selection");
+        self.selected = true;
+        self.preceding.clear();
+        self.following.clear();
+    }
+
+    /// Removes all of the points in the given `Interval` from the
+    /// `Selection`, and moves the cursor to the gap containing them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let mut sel: Selection<i32> = Selection::from(Interval::closed(-3, 5));
+    ///
+    /// let mut cursor = sel.cursor_at(&0);
+    /// cursor.carve(Interval::closed(0, 2));
+    /// assert_eq!(cursor.segment(), &Interval::closed(0, 2));
+    ///
+    /// assert!(cursor.move_next());
+    /// assert_eq!(cursor.segment(), &Interval::closed(3, 5));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn carve(&mut self, interval: Interval<T>) {
+        if interval.is_empty() { return; }
+        let lower = lower_of(&interval);
+        self.selection.minus_in_place(interval);
+        // No remaining interval starts within the removed points.
+        self.preceding = self.selection
+            .intervals_below(&lower, CURSOR_READ_AHEAD);
+        self.following = self.selection
+            .intervals_above(&lower, CURSOR_READ_AHEAD);
+        self.segment = gap_between(
+            self.preceding.last(),
+            self.following.last());
+        self.selected = false;
     }
 }
 
-impl<T> IntoIterator for Selection<T>
-    where T: Ord + Clone + Finite,
-{
-    type Item = T;
-    type IntoIter = IntoIter<T>;
-    
-    fn into_iter(self) -> Self::IntoIter {
-        self.into_iter()
+/// Pushes an `Interval` the cursor has stepped past onto the read `Interval`s
+/// on that side, discarding the farthest ones if too many are held.
+fn push_read<T>(read: &mut Vec<Interval<T>>, interval: Interval<T>) {
+    if read.len() >= 2 * CURSOR_READ_AHEAD {
+        let _ = read.drain(..CURSOR_READ_AHEAD);
     }
+    read.push(interval);
 }
 
 ////////////////////////////////////////////////////////////////////////////////
-// IntoIntervalIter
+// UnionIter
 ////////////////////////////////////////////////////////////////////////////////
-/// An owning `Iterator` over the `Interval`s of a `Selection`.
+/// An `Iterator` over the `Interval`s of the union of two `Selection`s.
 #[derive(Debug)]
-pub struct IntoIntervalIter<T>(crate::tine_tree::IntoIter<T>);
+pub struct UnionIter<'t, T>
+    where T: Ord + Clone
+{
+    a_iter: crate::tine_tree::Iter<'t, T>,
+    b_iter: crate::tine_tree::Iter<'t, T>,
+    /// The next interval of the first `Selection`.
+    a: Option<RawInterval<T>>,
+    /// The next interval of the second `Selection`.
+    b: Option<RawInterval<T>>,
+}
 
-impl<T> Iterator for IntoIntervalIter<T>
+impl<'t, T> Iterator for UnionIter<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
@@ -937,42 +4033,74 @@ impl<T> Iterator for IntoIntervalIter<T>
     type Item = Interval<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-    }
-}
+        // Start from whichever interval is lowest.
+        let mut union = match (self.a.take(), self.b.take()) {
+            (Some(x), Some(y)) => if raw_lower_of(&x) <= raw_lower_of(&y) {
+                self.a = self.a_iter.next();
+                self.b = Some(y);
+                x
+            } else {
+                self.a = Some(x);
+                self.b = self.b_iter.next();
+                y
+            },
+            (Some(x), None) => {
+                self.a = self.a_iter.next();
+                x
+            },
+            (None, Some(y)) => {
+                self.b = self.b_iter.next();
+                y
+            },
+            (None, None) => return None,
+        };
 
-impl<T> DoubleEndedIterator for IntoIntervalIter<T>
-    where
-        T: Ord + Clone,
-        RawInterval<T>: Normalize,
-{
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.0
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
+        // Absorb the following intervals of either `Selection` until neither
+        // touches the union.
+        let touches = |u: &RawInterval<T>, i: &RawInterval<T>| {
+            u.intersects(i) || u.adjacent(i)
+        };
+        loop {
+            match (&self.a, &self.b) {
+                (Some(x), _) if touches(&union, x) => {
+                    union = union.enclose(x);
+                    self.a = self.a_iter.next();
+                },
+                (_, Some(y)) if touches(&union, y) => {
+                    union = union.enclose(y);
+                    self.b = self.b_iter.next();
+                },
+                _ => break,
+            }
+        }
+        Some(Interval(union.normalized()))
     }
 }
 
-
-impl<T> FusedIterator for IntoIntervalIter<T> 
+impl<'t, T> FusedIterator for UnionIter<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {}
 
 ////////////////////////////////////////////////////////////////////////////////
-// IntervalIter
+// IntersectIter
 ////////////////////////////////////////////////////////////////////////////////
-/// An `Iterator` over the `Interval`s of a `Selection`.
+/// An `Iterator` over the `Interval`s of the intersection of two
+/// `Selection`s.
 #[derive(Debug)]
-pub struct IntervalIter<'t, T>(crate::tine_tree::Iter<'t, T>)
-    where T: Ord + Clone;
+pub struct IntersectIter<'t, T>
+    where T: Ord + Clone
+{
+    a_iter: crate::tine_tree::Iter<'t, T>,
+    b_iter: crate::tine_tree::Iter<'t, T>,
+    /// The next interval of the first `Selection`.
+    a: Option<RawInterval<T>>,
+    /// The next interval of the second `Selection`.
+    b: Option<RawInterval<T>>,
+}
 
-impl<'t, T> Iterator for IntervalIter<'t, T> 
+impl<'t, T> Iterator for IntersectIter<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
@@ -980,34 +4108,109 @@ impl<'t, T> Iterator for IntervalIter<'t, T>
     type Item = Interval<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.0
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
+        while let (Some(x), Some(y)) = (&self.a, &self.b) {
+            let i = x.intersect(y).normalized();
+
+            // Advance whichever interval ends first, as it cannot overlap
+            // anything further in the other `Selection`.
+            let x_upper = x.upper_bound().expect("nonempty interval");
+            let y_upper = y.upper_bound().expect("nonempty interval");
+            if x_upper.least_intersect(&y_upper) == x_upper {
+                self.a = self.a_iter.next();
+            } else {
+                self.b = self.b_iter.next();
+            }
+
+            if !i.is_empty() { return Some(Interval(i)); }
+        }
+        None
     }
 }
 
+impl<'t, T> FusedIterator for IntersectIter<'t, T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{}
 
-impl<'t, T> DoubleEndedIterator for IntervalIter<'t, T> 
+////////////////////////////////////////////////////////////////////////////////
+// MinusIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over the `Interval`s of the points of one `Selection` which
+/// are not in another.
+#[derive(Debug)]
+pub struct MinusIter<'t, T>
+    where T: Ord + Clone
+{
+    a_iter: crate::tine_tree::Iter<'t, T>,
+    b_iter: crate::tine_tree::Iter<'t, T>,
+    /// The remaining part of the next interval of the first `Selection`.
+    a: Option<RawInterval<T>>,
+    /// The next interval of the second `Selection`.
+    b: Option<RawInterval<T>>,
+}
+
+impl<'t, T> Iterator for MinusIter<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        self.0
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
+    type Item = Interval<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let x = self.a.take()?;
+            let y = match self.b {
+                Some(ref y) => y,
+                None        => {
+                    self.a = self.a_iter.next();
+                    return Some(Interval(x.normalized()));
+                },
+            };
+
+            if !x.intersects(y) {
+                if raw_lower_of(y) < raw_lower_of(&x) {
+                    // Skip the intervals below the remaining interval.
+                    self.a = Some(x);
+                    self.b = self.b_iter.next();
+                    continue;
+                }
+                self.a = self.a_iter.next();
+                let x = x.normalized();
+                if !x.is_empty() { return Some(Interval(x)); }
+                continue;
+            }
+
+            // The part below the overlap is finished, while the part above it
+            // may overlap the next interval of the other `Selection`.
+            let y_lower = raw_lower_of(y);
+            let mut below = None;
+            for piece in x.minus(y) {
+                if raw_lower_of(&piece) < y_lower {
+                    below = Some(piece);
+                } else {
+                    self.a = Some(piece);
+                }
+            }
+            if self.a.is_some() {
+                self.b = self.b_iter.next();
+            } else {
+                self.a = self.a_iter.next();
+            }
+
+            if let Some(below) = below.map(Normalize::normalized) {
+                if !below.is_empty() { return Some(Interval(below)); }
+            }
+        }
     }
 }
 
-impl<'t, T> FusedIterator for IntervalIter<'t, T> 
+impl<'t, T> FusedIterator for MinusIter<'t, T>
     where
         T: Ord + Clone,
         RawInterval<T>: Normalize,
 {}
 
-
 ////////////////////////////////////////////////////////////////////////////////
 // IntoIter
 ////////////////////////////////////////////////////////////////////////////////
@@ -1018,7 +4221,8 @@ pub struct IntoIter<T>
         T: Ord + Clone
 {
     intervals: crate::tine_tree::IntoIter<T>,
-    current: crate::interval::Iter<T>,
+    front: crate::interval::Iter<T>,
+    back: crate::interval::Iter<T>,
 }
 
 impl<T> Iterator for IntoIter<T>
@@ -1027,20 +4231,21 @@ impl<T> Iterator for IntoIter<T>
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.current.next() {
-            return Some(next);
-        }
-
-        self.current = match self.intervals
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
+        loop {
+            if let Some(next) = self.front.next() {
+                return Some(next);
+            }
 
-        self.current.next()
+            // Once the intervals run out, finish the back interval.
+            self.front = match self.intervals
+                .next()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.back.next(),
+            };
+        }
     }
 }
 
@@ -1048,20 +4253,21 @@ impl<T> DoubleEndedIterator for IntoIter<T>
     where T: Ord + Clone + Finite,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(next_back) = self.current.next_back() {
-            return Some(next_back);
-        }
-
-        self.current = match self.intervals
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
+        loop {
+            if let Some(next_back) = self.back.next_back() {
+                return Some(next_back);
+            }
 
-        self.current.next_back()
+            // Once the intervals run out, finish the front interval.
+            self.back = match self.intervals
+                .next_back()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.front.next_back(),
+            };
+        }
     }
 }
 
@@ -1079,7 +4285,8 @@ pub struct Iter<'t, T>
         T: Ord + Clone + Finite
 {
     intervals: crate::tine_tree::Iter<'t, T>,
-    current: crate::interval::Iter<T>,
+    front: crate::interval::Iter<T>,
+    back: crate::interval::Iter<T>,
 }
 
 impl<'t, T> Iterator for Iter<'t, T>
@@ -1088,20 +4295,21 @@ impl<'t, T> Iterator for Iter<'t, T>
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(next) = self.current.next() {
-            return Some(next);
-        }
-
-        self.current = match self.intervals
-            .next()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
+        loop {
+            if let Some(next) = self.front.next() {
+                return Some(next);
+            }
 
-        self.current.next()
+            // Once the intervals run out, finish the back interval.
+            self.front = match self.intervals
+                .next()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.back.next(),
+            };
+        }
     }
 }
 
@@ -1109,20 +4317,21 @@ impl<'t, T> DoubleEndedIterator for Iter<'t, T>
     where T: Ord + Clone + Finite,
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if let Some(next_back) = self.current.next_back() {
-            return Some(next_back);
-        }
-
-        self.current = match self.intervals
-            .next_back()
-            .map(Normalize::normalized)
-            .map(Interval::from)
-        {
-            Some(interval) => interval.iter(),
-            None           => return None,
-        };
+        loop {
+            if let Some(next_back) = self.back.next_back() {
+                return Some(next_back);
+            }
 
-        self.current.next_back()
+            // Once the intervals run out, finish the front interval.
+            self.back = match self.intervals
+                .next_back()
+                .map(Normalize::normalized)
+                .map(Interval::from)
+            {
+                Some(interval) => interval.iter(),
+                None           => return self.front.next_back(),
+            };
+        }
     }
 }
 
@@ -0,0 +1,691 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides [`serde`] representations of `Bound`s, `Interval`s, and
+//! `Selection`s.
+//!
+//! `Interval`s and `Selection`s are written in one of three forms, shown here
+//! for `Interval::closed(1, 4)` written as JSON:
+//!
+//! + [`Structured`]: a struct of optional bounds, as in
+//!   `{"lower": {"Include": 1}, "upper": {"Include": 4}}`. Both bounds of an
+//!   empty `Interval` are `null`.
+//! + [`Compact`]: an optional pair of bounds, as in
+//!   `[{"Include": 1}, {"Include": 4}]`. An empty `Interval` is `null`.
+//! + [`Notation`]: a string in interval notation, as in `"[1, 4]"`.
+//!
+//! A `Selection` is written as a sequence of its `Interval`s in the structured
+//! and compact forms, and as a union of intervals in the notation form. When
+//! reading either sequence, each `Interval` is added to the `Selection` as it
+//! is read, and a [`SelectionSeed`] can add them to an existing `Selection`.
+//!
+//! By default, the structured form is used for human-readable formats and the
+//! compact form is used for all others, as determined by the serializer's
+//! [`is_human_readable`] flag. Wrapping a value in `Structured`, `Compact`, or
+//! `Notation` selects its form regardless of the format. The notation form
+//! requires a point type implementing `Display` and `FromStr`, and is never
+//! used by default.
+//!
+//! [`serde`]: https://docs.rs/serde
+//! [`Structured`]: struct.Structured.html
+//! [`Compact`]: struct.Compact.html
+//! [`Notation`]: struct.Notation.html
+//! [`SelectionSeed`]: struct.SelectionSeed.html
+//! [`is_human_readable`]: https://docs.rs/serde/1/serde/trait.Serializer.html#method.is_human_readable
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use serde::de;
+use serde::de::Deserialize;
+use serde::de::DeserializeSeed;
+use serde::de::Deserializer;
+use serde::de::EnumAccess;
+use serde::de::MapAccess;
+use serde::de::SeqAccess;
+use serde::de::VariantAccess;
+use serde::de::Visitor;
+use serde::ser::Serialize;
+use serde::ser::SerializeSeq;
+use serde::ser::SerializeStruct;
+use serde::ser::Serializer;
+
+// Standard library imports.
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Structured
+////////////////////////////////////////////////////////////////////////////////
+/// Wraps an `Interval` or `Selection` to serialize it in the self-describing
+/// struct form, regardless of the format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Structured<T>(pub T);
+
+impl<T> Serialize for Structured<Interval<T>>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serialize_structured_interval(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Structured<Interval<T>>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserialize_structured_interval(deserializer).map(Structured)
+    }
+}
+
+impl<T> Serialize for Structured<Selection<T>>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serialize_structured_selection(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Structured<Selection<T>>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserialize_structured_selection(deserializer).map(Structured)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Compact
+////////////////////////////////////////////////////////////////////////////////
+/// Wraps an `Interval` or `Selection` to serialize it in the compact tuple
+/// form, regardless of the format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Compact<T>(pub T);
+
+impl<T> Serialize for Compact<Interval<T>>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serialize_compact_interval(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Compact<Interval<T>>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserialize_compact_interval(deserializer).map(Compact)
+    }
+}
+
+impl<T> Serialize for Compact<Selection<T>>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serialize_compact_selection(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Compact<Selection<T>>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserialize_compact_selection(deserializer).map(Compact)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Notation
+////////////////////////////////////////////////////////////////////////////////
+/// Wraps an `Interval` or `Selection` to serialize it as a string in interval
+/// notation, as written by its `Display` impl and read by its `FromStr` impl.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Notation<T>(pub T);
+
+impl<T> Serialize for Notation<T> where T: Display {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Notation<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer
+            .deserialize_str(NotationVisitor(PhantomData))
+            .map(Notation)
+    }
+}
+
+/// Visitor for parsing a value in interval notation.
+struct NotationVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for NotationVisitor<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a string in interval notation")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<T, E> where E: de::Error {
+        v.parse().map_err(E::custom)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bound
+////////////////////////////////////////////////////////////////////////////////
+/// The variant names of `Bound`.
+const BOUND_VARIANTS: &[&str] = &["Include", "Exclude", "Infinite"];
+
+// Serialize as an externally tagged enum.
+impl<T> Serialize for Bound<T> where T: Serialize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match self {
+            Bound::Include(p) => serializer
+                .serialize_newtype_variant("Bound", 0, "Include", p),
+            Bound::Exclude(p) => serializer
+                .serialize_newtype_variant("Bound", 1, "Exclude", p),
+            Bound::Infinite   => serializer
+                .serialize_unit_variant("Bound", 2, "Infinite"),
+        }
+    }
+}
+
+// Deserialize from an externally tagged enum.
+impl<'de, T> Deserialize<'de> for Bound<T> where T: Deserialize<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_enum(
+            "Bound",
+            BOUND_VARIANTS,
+            BoundVisitor(PhantomData))
+    }
+}
+
+/// Visitor for deserializing a `Bound`.
+struct BoundVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for BoundVisitor<T> where T: Deserialize<'de> {
+    type Value = Bound<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("enum Bound")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Bound<T>, A::Error>
+        where A: EnumAccess<'de>
+    {
+        let (index, variant) = data
+            .variant_seed(Identifier::variant(BOUND_VARIANTS))?;
+        match index {
+            0 => variant.newtype_variant().map(Bound::Include),
+            1 => variant.newtype_variant().map(Bound::Exclude),
+            _ => variant.unit_variant().map(|_| Bound::Infinite),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Identifier
+////////////////////////////////////////////////////////////////////////////////
+/// Deserializes a variant or field identifier to its index in a list of names.
+#[derive(Clone, Copy)]
+struct Identifier {
+    names: &'static [&'static str],
+    variant: bool,
+}
+
+impl Identifier {
+    /// Returns an `Identifier` for the given variant names.
+    fn variant(names: &'static [&'static str]) -> Self {
+        Identifier { names, variant: true }
+    }
+
+    /// Returns an `Identifier` for the given field names.
+    fn field(names: &'static [&'static str]) -> Self {
+        Identifier { names, variant: false }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for Identifier {
+    type Value = usize;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<usize, D::Error>
+        where D: Deserializer<'de>
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for Identifier {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = if self.variant { "variant" } else { "field" };
+        write!(f, "{} identifier", kind)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<usize, E> where E: de::Error {
+        match v as usize {
+            index if index < self.names.len() => Ok(index),
+            _ => Err(E::invalid_value(de::Unexpected::Unsigned(v), &self)),
+        }
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<usize, E> where E: de::Error {
+        match self.names.iter().position(|name| *name == v) {
+            Some(index)          => Ok(index),
+            None if self.variant => Err(E::unknown_variant(v, self.names)),
+            None                 => Err(E::unknown_field(v, self.names)),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval
+////////////////////////////////////////////////////////////////////////////////
+/// The field names of the structured form of `Interval`.
+const INTERVAL_FIELDS: &[&str] = &["lower", "upper"];
+
+// Serialize in the structured form for human-readable formats and the compact
+// form otherwise.
+impl<T> Serialize for Interval<T>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        if serializer.is_human_readable() {
+            serialize_structured_interval(self, serializer)
+        } else {
+            serialize_compact_interval(self, serializer)
+        }
+    }
+}
+
+// Deserialize from the structured form for human-readable formats and the
+// compact form otherwise.
+impl<'de, T> Deserialize<'de> for Interval<T>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        if deserializer.is_human_readable() {
+            deserialize_structured_interval(deserializer)
+        } else {
+            deserialize_compact_interval(deserializer)
+        }
+    }
+}
+
+/// Constructs an `Interval` from its serialized bounds, which are either both
+/// present or both missing for an empty `Interval`.
+fn interval_from_bounds<T, E>(
+    lower: Option<Bound<T>>,
+    upper: Option<Bound<T>>)
+    -> Result<Interval<T>, E>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+        E: de::Error,
+{
+    match (lower, upper) {
+        (Some(l), Some(u)) => Interval::try_new(l, u).map_err(E::custom),
+        (None,    None)    => Ok(Interval::empty()),
+        _                  => Err(E::custom(
+            "interval must have both bounds or neither")),
+    }
+}
+
+/// Serializes an `Interval` as a struct of its optional bounds.
+fn serialize_structured_interval<T, S>(interval: &Interval<T>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+        S: Serializer,
+{
+    let mut state = serializer.serialize_struct("Interval", 2)?;
+    state.serialize_field("lower", &interval.lower_bound())?;
+    state.serialize_field("upper", &interval.upper_bound())?;
+    state.end()
+}
+
+/// Deserializes an `Interval` from a struct of its optional bounds.
+fn deserialize_structured_interval<'de, T, D>(deserializer: D)
+    -> Result<Interval<T>, D::Error>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+        D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct(
+        "Interval",
+        INTERVAL_FIELDS,
+        StructuredIntervalVisitor(PhantomData))
+}
+
+/// Visitor for deserializing an `Interval` in the structured form.
+struct StructuredIntervalVisitor<T>(PhantomData<T>);
+
+impl<'de, T> Visitor<'de> for StructuredIntervalVisitor<T>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    type Value = Interval<T>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("struct Interval")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Interval<T>, A::Error>
+        where A: SeqAccess<'de>
+    {
+        let lower = seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let upper = seq.next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        interval_from_bounds(lower, upper)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Interval<T>, A::Error>
+        where A: MapAccess<'de>
+    {
+        let mut lower = None;
+        let mut upper = None;
+        while let Some(index) = map
+            .next_key_seed(Identifier::field(INTERVAL_FIELDS))?
+        {
+            let field = if index == 0 { &mut lower } else { &mut upper };
+            if field.is_some() {
+                return Err(de::Error::duplicate_field(INTERVAL_FIELDS[index]));
+            }
+            *field = Some(map.next_value()?);
+        }
+        let lower = lower
+            .ok_or_else(|| de::Error::missing_field(INTERVAL_FIELDS[0]))?;
+        let upper = upper
+            .ok_or_else(|| de::Error::missing_field(INTERVAL_FIELDS[1]))?;
+        interval_from_bounds(lower, upper)
+    }
+}
+
+/// Serializes an `Interval` as an optional pair of bounds.
+fn serialize_compact_interval<T, S>(interval: &Interval<T>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+        S: Serializer,
+{
+    interval.lower_bound()
+        .zip(interval.upper_bound())
+        .serialize(serializer)
+}
+
+/// Deserializes an `Interval` from an optional pair of bounds.
+fn deserialize_compact_interval<'de, T, D>(deserializer: D)
+    -> Result<Interval<T>, D::Error>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+        D: Deserializer<'de>,
+{
+    match Option::<(Bound<T>, Bound<T>)>::deserialize(deserializer)? {
+        Some((l, u)) => interval_from_bounds(Some(l), Some(u)),
+        None         => Ok(Interval::empty()),
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection
+////////////////////////////////////////////////////////////////////////////////
+// Serialize in the structured form for human-readable formats and the compact
+// form otherwise.
+impl<T> Serialize for Selection<T>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        if serializer.is_human_readable() {
+            serialize_structured_selection(self, serializer)
+        } else {
+            serialize_compact_selection(self, serializer)
+        }
+    }
+}
+
+// Deserialize from the structured form for human-readable formats and the
+// compact form otherwise.
+impl<'de, T> Deserialize<'de> for Selection<T>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        if deserializer.is_human_readable() {
+            deserialize_structured_selection(deserializer)
+        } else {
+            deserialize_compact_selection(deserializer)
+        }
+    }
+}
+
+/// Serializes a `Selection` as a sequence of structured `Interval`s.
+fn serialize_structured_selection<T, S>(selection: &Selection<T>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+        S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(selection.interval_count()))?;
+    for interval in selection.interval_iter() {
+        seq.serialize_element(&Structured(interval))?;
+    }
+    seq.end()
+}
+
+/// Deserializes a `Selection` from a sequence of structured `Interval`s.
+fn deserialize_structured_selection<'de, T, D>(deserializer: D)
+    -> Result<Selection<T>, D::Error>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+        D: Deserializer<'de>,
+{
+    let mut selection = Selection::new();
+    deserializer.deserialize_seq(SelectionVisitor {
+        selection: &mut selection,
+        structured: true,
+    })?;
+    Ok(selection)
+}
+
+/// Serializes a `Selection` as a sequence of bound pairs.
+fn serialize_compact_selection<T, S>(selection: &Selection<T>, serializer: S)
+    -> Result<S::Ok, S::Error>
+    where
+        T: Ord + Clone + Serialize,
+        RawInterval<T>: Normalize,
+        S: Serializer,
+{
+    let mut seq = serializer.serialize_seq(Some(selection.interval_count()))?;
+    for interval in selection.interval_iter() {
+        if let Some(bounds) = interval.lower_bound()
+            .zip(interval.upper_bound())
+        {
+            seq.serialize_element(&bounds)?;
+        }
+    }
+    seq.end()
+}
+
+/// Deserializes a `Selection` from a sequence of bound pairs.
+fn deserialize_compact_selection<'de, T, D>(deserializer: D)
+    -> Result<Selection<T>, D::Error>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+        D: Deserializer<'de>,
+{
+    let mut selection = Selection::new();
+    deserializer.deserialize_seq(SelectionVisitor {
+        selection: &mut selection,
+        structured: false,
+    })?;
+    Ok(selection)
+}
+
+/// Visitor for deserializing the `Interval`s of a `Selection`, adding each to
+/// the `Selection` as it is read.
+struct SelectionVisitor<'s, T> {
+    selection: &'s mut Selection<T>,
+    structured: bool,
+}
+
+impl<'de, 's, T> Visitor<'de> for SelectionVisitor<'s, T>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    type Value = ();
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a sequence of intervals")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+        where A: SeqAccess<'de>
+    {
+        if self.structured {
+            while let Some(interval) = seq
+                .next_element::<Structured<Interval<T>>>()?
+            {
+                self.selection.union_in_place(interval.0);
+            }
+        } else {
+            while let Some((l, u)) = seq
+                .next_element::<(Bound<T>, Bound<T>)>()?
+            {
+                let interval = interval_from_bounds(Some(l), Some(u))?;
+                self.selection.union_in_place(interval);
+            }
+        }
+        Ok(())
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SelectionSeed
+////////////////////////////////////////////////////////////////////////////////
+/// A [`DeserializeSeed`] which adds the `Interval`s of a serialized
+/// `Selection` to an existing `Selection`.
+///
+/// Each `Interval` is added to the `Selection` as soon as it is read, so no
+/// intermediate collection of intervals is built. Either form of `Selection`
+/// is read, as chosen by the deserializer's [`is_human_readable`] flag.
+///
+/// [`DeserializeSeed`]: https://docs.rs/serde/1/serde/de/trait.DeserializeSeed.html
+/// [`is_human_readable`]: https://docs.rs/serde/1/serde/trait.Deserializer.html#method.is_human_readable
+#[derive(Debug)]
+pub struct SelectionSeed<'s, T>(pub &'s mut Selection<T>);
+
+impl<'de, 's, T> DeserializeSeed<'de> for SelectionSeed<'s, T>
+    where
+        T: Ord + Clone + Deserialize<'de>,
+        RawInterval<T>: Normalize,
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+        where D: Deserializer<'de>
+    {
+        let structured = deserializer.is_human_readable();
+        deserializer.deserialize_seq(SelectionVisitor {
+            selection: self.0,
+            structured,
+        })
+    }
+}
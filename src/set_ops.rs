@@ -0,0 +1,278 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a common set of operations for writing code generic over the
+//! interval and selection types.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::byte_selection::ByteSelection;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::tine_tree::TineTree;
+
+// Standard library imports.
+use std::iter::FromIterator;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SetOps
+////////////////////////////////////////////////////////////////////////////////
+/// Set operations shared by the interval and selection types.
+///
+/// The result of an operation is the `Output` type, which must be able to hold
+/// any number of disjoint intervals. For `Interval`s, this is a `Selection`
+/// over the same type.
+///
+/// Note that `Interval` and `Selection` also provide inherent methods with
+/// the same names, which take precedence in method call syntax on a concrete
+/// type. Call these through the trait, as in `SetOps::union(&a, &b)`, to use
+/// them outside of generic code.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::error::Error;
+/// # use normalize_interval::Interval;
+/// # use normalize_interval::Selection;
+/// # use normalize_interval::set_ops::SetOps;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # //-------------------------------------------------------------------
+/// fn in_exactly_one<S: SetOps>(a: &S, b: &S, point: &S::Point) -> bool {
+///     a.contains(point) != b.contains(point)
+/// }
+///
+/// let a = Interval::closed(0, 5);
+/// let b = Interval::closed(3, 9);
+/// assert!(in_exactly_one(&a, &b, &1));
+/// assert!(!in_exactly_one(&a, &b, &4));
+///
+/// let sel = SetOps::minus(&a, &b);
+/// assert_eq!(sel, Selection::from(Interval::closed(0, 2)));
+/// # //-------------------------------------------------------------------
+/// #     Ok(())
+/// # }
+/// ```
+pub trait SetOps<Rhs = Self> {
+    /// The type of the points in the set.
+    type Point;
+
+    /// The type of the result of the set operations.
+    type Output;
+
+    /// Returns `true` if the given point is in the set.
+    fn contains(&self, point: &Self::Point) -> bool;
+
+    /// Returns the points not in the set.
+    fn complement(&self) -> Self::Output;
+
+    /// Returns the points in either set.
+    fn union(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns the points in both sets.
+    fn intersect(&self, other: &Rhs) -> Self::Output;
+
+    /// Returns the points in this set which are not in the other.
+    fn minus(&self, other: &Rhs) -> Self::Output;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval impls
+////////////////////////////////////////////////////////////////////////////////
+impl<T> SetOps for Interval<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Point = T;
+    type Output = Selection<T>;
+
+    fn contains(&self, point: &T) -> bool {
+        Interval::contains(self, point)
+    }
+
+    fn complement(&self) -> Selection<T> {
+        Selection::from(self.clone()).complement()
+    }
+
+    fn union(&self, other: &Self) -> Selection<T> {
+        let mut sel = Selection::from(self.clone());
+        sel.union_in_place(other.clone());
+        sel
+    }
+
+    fn intersect(&self, other: &Self) -> Selection<T> {
+        Selection::from(Interval::intersect(self, other))
+    }
+
+    fn minus(&self, other: &Self) -> Selection<T> {
+        let mut sel = Selection::from(self.clone());
+        sel.minus_in_place(other.clone());
+        sel
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection impls
+////////////////////////////////////////////////////////////////////////////////
+impl<T> SetOps for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Point = T;
+    type Output = Selection<T>;
+
+    fn contains(&self, point: &T) -> bool {
+        Selection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self {
+        Selection::complement(self)
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        Selection::union(self, other)
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        Selection::intersect(self, other)
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        Selection::minus(self, other)
+    }
+}
+
+impl<T> SetOps<Interval<T>> for Selection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    type Point = T;
+    type Output = Selection<T>;
+
+    fn contains(&self, point: &T) -> bool {
+        Selection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self {
+        Selection::complement(self)
+    }
+
+    fn union(&self, other: &Interval<T>) -> Self {
+        let mut sel = self.clone();
+        sel.union_in_place(other.clone());
+        sel
+    }
+
+    fn intersect(&self, other: &Interval<T>) -> Self {
+        let mut sel = self.clone();
+        sel.intersect_in_place(other.clone());
+        sel
+    }
+
+    fn minus(&self, other: &Interval<T>) -> Self {
+        let mut sel = self.clone();
+        sel.minus_in_place(other.clone());
+        sel
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// RawInterval impls
+////////////////////////////////////////////////////////////////////////////////
+impl<T> SetOps for RawInterval<T> where T: Ord + Clone {
+    type Point = T;
+    type Output = TineTree<T>;
+
+    fn contains(&self, point: &T) -> bool {
+        RawInterval::contains(self, point)
+    }
+
+    fn complement(&self) -> TineTree<T> {
+        TineTree::from_iter(RawInterval::complement(self))
+    }
+
+    fn union(&self, other: &Self) -> TineTree<T> {
+        TineTree::from_iter(RawInterval::union(self, other))
+    }
+
+    fn intersect(&self, other: &Self) -> TineTree<T> {
+        TineTree::from(RawInterval::intersect(self, other))
+    }
+
+    fn minus(&self, other: &Self) -> TineTree<T> {
+        TineTree::from_iter(RawInterval::minus(self, other))
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// TineTree impls
+////////////////////////////////////////////////////////////////////////////////
+impl<T> SetOps for TineTree<T> where T: Ord + Clone {
+    type Point = T;
+    type Output = TineTree<T>;
+
+    fn contains(&self, point: &T) -> bool {
+        TineTree::contains(self, point)
+    }
+
+    fn complement(&self) -> Self {
+        TineTree::complement(self)
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        TineTree::union(self, other)
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        TineTree::intersect(self, other)
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        TineTree::minus(self, other)
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// ByteSelection impls
+////////////////////////////////////////////////////////////////////////////////
+impl SetOps for ByteSelection {
+    type Point = u8;
+    type Output = ByteSelection;
+
+    fn contains(&self, point: &u8) -> bool {
+        ByteSelection::contains(self, point)
+    }
+
+    fn complement(&self) -> Self {
+        ByteSelection::complement(self)
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        ByteSelection::union(self, other)
+    }
+
+    fn intersect(&self, other: &Self) -> Self {
+        ByteSelection::intersect(self, other)
+    }
+
+    fn minus(&self, other: &Self) -> Self {
+        ByteSelection::minus(self, other)
+    }
+}
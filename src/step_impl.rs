@@ -0,0 +1,73 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides normalization support for types implementing the unstable
+//! `std::iter::Step` trait.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::normalize::Finite;
+
+// Standard library imports.
+use std::iter::Step;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// StepBounds
+////////////////////////////////////////////////////////////////////////////////
+/// Provides the extreme values of a [`Step`] type, opting it in to the blanket
+/// [`Finite`] impl for `Step` types.
+///
+/// `Step` does not give the extremes of a type, and the builtin types which
+/// implement it already implement `Finite` directly, so the blanket impl only
+/// applies to types which also implement `StepBounds`. Such types must not
+/// implement `Finite` themselves.
+///
+/// [`Step`]: https://doc.rust-lang.org/std/iter/trait.Step.html
+/// [`Finite`]: trait.Finite.html
+pub trait StepBounds: Step {
+    /// The least value of the type.
+    const LEAST: Self;
+
+    /// The greatest value of the type.
+    const GREATEST: Self;
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Finite
+////////////////////////////////////////////////////////////////////////////////
+
+// Each `Finite` method forwards to the checked `Step` method of the same
+// stride.
+impl<T> Finite for T where T: StepBounds {
+    const MINIMUM: Self = T::LEAST;
+    const MAXIMUM: Self = T::GREATEST;
+
+    fn pred(&self) -> Option<Self> {
+        Step::backward_checked(self.clone(), 1)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        Step::forward_checked(self.clone(), 1)
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        Step::backward_checked(self.clone(), n)
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        Step::forward_checked(self.clone(), n)
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        <T as Step>::steps_between(self, other).1
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides [`proptest`] strategies for generating intervals and selections.
+//!
+//! The generated values cover every combination of bound types, including
+//! empty, degenerate, and unbounded intervals.
+//!
+//! [`proptest`]: https://docs.rs/proptest
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// External library imports.
+use proptest::arbitrary::any;
+use proptest::arbitrary::Arbitrary;
+use proptest::collection::SizeRange;
+use proptest::collection::vec;
+use proptest::prop_oneof;
+use proptest::strategy::Just;
+use proptest::strategy::Strategy;
+
+// Standard library imports.
+use std::sync::Arc;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Bound strategies
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a `Strategy` generating [`Bound`]s with points drawn from the given
+/// `Strategy`.
+///
+/// [`Bound`]: ../bound/enum.Bound.html
+pub fn bound<S>(point: S) -> impl Strategy<Value=Bound<S::Value>>
+    where
+        S: Strategy,
+        S::Value: Clone,
+{
+    let point = Arc::new(point);
+    prop_oneof![
+        point.clone().prop_map(Bound::Include),
+        point.prop_map(Bound::Exclude),
+        Just(Bound::Infinite),
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Interval strategies
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a `Strategy` generating [`Interval`]s with bound points drawn from
+/// the given `Strategy`.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+pub fn interval<S>(point: S) -> impl Strategy<Value=Interval<S::Value>>
+    where
+        S: Strategy,
+        S::Value: Ord + Clone,
+        RawInterval<S::Value>: Normalize,
+{
+    raw_interval(point).prop_map(Interval::from)
+}
+
+/// Returns a `Strategy` generating arbitrary [`Interval`]s.
+///
+/// [`Interval`]: ../interval/struct.Interval.html
+pub fn any_interval<T>() -> impl Strategy<Value=Interval<T>>
+    where
+        T: Arbitrary + Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    interval(any::<T>())
+}
+
+/// Returns a `Strategy` generating `RawInterval`s with bound points drawn from
+/// the given `Strategy`.
+fn raw_interval<S>(point: S) -> impl Strategy<Value=RawInterval<S::Value>>
+    where
+        S: Strategy,
+        S::Value: Ord + Clone,
+{
+    let point = Arc::new(point);
+    let bounds = (bound(point.clone()), bound(point.clone()));
+    prop_oneof![
+        1 => Just(RawInterval::Empty),
+        2 => point.prop_map(RawInterval::Point),
+        7 => bounds.prop_map(|(l, u)| ordered_raw_interval(l, u)),
+    ]
+}
+
+/// Constructs a `RawInterval` from the given bounds, swapping their points if
+/// they are out of order.
+fn ordered_raw_interval<T>(lower: Bound<T>, upper: Bound<T>) -> RawInterval<T>
+    where T: Ord + Clone
+{
+    let swap = match (lower.as_ref(), upper.as_ref()) {
+        (Some(l), Some(u)) => l > u,
+        _                  => false,
+    };
+
+    if swap {
+        let l = lower.as_ref().cloned().expect("finite lower bound");
+        let u = upper.as_ref().cloned().expect("finite upper bound");
+        RawInterval::new(lower.map(|_| u), upper.map(|_| l))
+    } else {
+        RawInterval::new(lower, upper)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection strategies
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns a `Strategy` generating [`Selection`]s from unions of `Interval`s
+/// with bound points drawn from the given `Strategy`. The number of unioned
+/// `Interval`s is drawn from the given size range.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+pub fn selection<S, R>(point: S, size: R)
+    -> impl Strategy<Value=Selection<S::Value>>
+    where
+        S: Strategy,
+        S::Value: Ord + Clone,
+        R: Into<SizeRange>,
+        RawInterval<S::Value>: Normalize,
+{
+    vec(interval(point), size)
+        .prop_map(|intervals| intervals.into_iter().collect())
+}
+
+/// Returns a `Strategy` generating arbitrary [`Selection`]s.
+///
+/// [`Selection`]: ../selection/struct.Selection.html
+pub fn any_selection<T>() -> impl Strategy<Value=Selection<T>>
+    where
+        T: Arbitrary + Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    selection(any::<T>(), 0..8)
+}
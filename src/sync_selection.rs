@@ -0,0 +1,181 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides a selection which can be shared between threads.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// SyncSelection<T>
+////////////////////////////////////////////////////////////////////////////////
+/// A `Selection` of the type `T` which can be read and modified concurrently.
+///
+/// Readers take cheap snapshots of the current `Selection`, which are never
+/// modified. Writers apply their changes to a copy of the current `Selection`
+/// and then swap it in, so readers never wait on a writer's changes, and
+/// writers never disturb existing snapshots.
+///
+/// Because each update copies the whole `Selection`, a write takes time
+/// proportional to the number of intervals, however small the change. For
+/// large selections which are written to often, consider sharing a
+/// [`PersistentSelection`] instead, whose copies share their structure.
+///
+/// [`PersistentSelection`]: ../persistent/struct.PersistentSelection.html
+#[derive(Debug)]
+pub struct SyncSelection<T> {
+    /// The current snapshot.
+    current: RwLock<Arc<Selection<T>>>,
+    /// A lock held by writers to serialize their changes.
+    writer: Mutex<()>,
+}
+
+// The locks only guard swapping the snapshot, which cannot be left partially
+// done, so a poisoned lock can be safely recovered.
+impl<T> SyncSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    /// Constructs a new empty `SyncSelection`.
+    pub fn new() -> Self {
+        SyncSelection::from(Selection::new())
+    }
+
+    /// Returns a snapshot of the current `Selection`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::SyncSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let shared: SyncSelection<i32> = SyncSelection::new();
+    /// let before = shared.snapshot();
+    ///
+    /// shared.update(|sel| sel.union_in_place(Interval::closed(-3, 5)));
+    ///
+    /// assert!(before.is_empty());
+    /// assert!(shared.snapshot().contains(&4));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> Arc<Selection<T>> {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns `true` if the current `Selection` contains the given point.
+    pub fn contains(&self, point: &T) -> bool {
+        self.snapshot().contains(point)
+    }
+
+    /// Replaces the current `Selection`, returning the previous snapshot.
+    pub fn replace(&self, selection: Selection<T>) -> Arc<Selection<T>> {
+        let _writer = self.writer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        self.swap(Arc::new(selection))
+    }
+
+    /// Applies the given function to a copy of the current `Selection`, then
+    /// makes it current, returning the function's result.
+    ///
+    /// Concurrent updates are applied one at a time, so none are lost. Each
+    /// update copies the current `Selection`, taking O(n) time in its number
+    /// of intervals.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use std::sync::Arc;
+    /// # use std::thread;
+    /// # use normalize_interval::Interval;
+    /// # use normalize_interval::SyncSelection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let shared: Arc<SyncSelection<i32>> = Arc::new(SyncSelection::new());
+    ///
+    /// let writers: Vec<_> = (0..4).map(|i| {
+    ///     let shared = shared.clone();
+    ///     thread::spawn(move || {
+    ///         shared.update(|sel| sel.insert_point(i));
+    ///     })
+    /// }).collect();
+    /// for writer in writers { writer.join().unwrap(); }
+    ///
+    /// assert_eq!(*shared.snapshot(), Interval::right_open(0, 4));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn update<F, R>(&self, f: F) -> R
+        where F: FnOnce(&mut Selection<T>) -> R
+    {
+        let _writer = self.writer
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut selection = Selection::clone(&self.snapshot());
+        let res = f(&mut selection);
+        let _ = self.swap(Arc::new(selection));
+        res
+    }
+
+    /// Consumes the `SyncSelection`, returning the current `Selection`.
+    pub fn into_inner(self) -> Selection<T> {
+        let current = self.current
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        Arc::try_unwrap(current).unwrap_or_else(|shared| (*shared).clone())
+    }
+
+    /// Makes the given snapshot current, returning the previous one.
+    fn swap(&self, snapshot: Arc<Selection<T>>) -> Arc<Selection<T>> {
+        let mut current = self.current
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        std::mem::replace(&mut *current, snapshot)
+    }
+}
+
+impl<T> Default for SyncSelection<T>
+    where
+        T: Ord + Clone,
+        RawInterval<T>: Normalize,
+{
+    fn default() -> Self {
+        SyncSelection::new()
+    }
+}
+
+impl<T> From<Selection<T>> for SyncSelection<T> {
+    fn from(selection: Selection<T>) -> Self {
+        SyncSelection {
+            current: RwLock::new(Arc::new(selection)),
+            writer: Mutex::new(()),
+        }
+    }
+}
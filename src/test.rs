@@ -11,6 +11,16 @@
 //!
 ////////////////////////////////////////////////////////////////////////////////
 
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
 // Needs to be defined before submodule declarations.
 
 /// Variant of `assert_eq` that collects items into a HashSet before comparing.
@@ -39,5 +49,126 @@ macro_rules! assert_eq_i {
 }
 
 // Module declarations.
+mod arithmetic;
+mod bound;
+mod byte_selection;
+mod bytes;
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+mod circular;
+mod format;
+mod granular;
+mod interval_index;
+mod interval_map;
+mod journal;
+mod lattice;
+mod layered_selection;
+mod measure;
+mod merge;
+mod naive;
+mod normalize;
+mod persistent;
 mod raw_interval;
+mod region;
+#[cfg(feature = "rand")]
+mod sample;
+mod selection;
+#[cfg(feature = "serde")]
+mod serde_repr;
+mod set_ops;
+#[cfg(feature = "proptest")]
+mod strategy;
+mod sync_selection;
+#[cfg(feature = "time")]
+mod time_impl;
 mod tine_tree;
+#[cfg(feature = "unicode")]
+mod unicode;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Pseudorandom inputs
+////////////////////////////////////////////////////////////////////////////////
+/// A linear congruential generator, giving tests reproducible pseudorandom
+/// inputs.
+#[derive(Debug, Clone)]
+pub(in crate) struct Lcg(u32);
+
+impl Lcg {
+    /// Constructs a generator for the given seed.
+    pub(in crate) fn new(seed: u32) -> Self {
+        // Spread out consecutive seeds.
+        Lcg(seed.wrapping_mul(2_654_435_761).wrapping_add(1))
+    }
+
+    /// Returns a pseudorandom number in `[0, n)`.
+    pub(in crate) fn below(&mut self, n: u32) -> i32 {
+        self.0 = self.0.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+        ((self.0 >> 16) % n) as i32
+    }
+
+    /// Returns a pseudorandom point in `[lo, lo + n)`.
+    pub(in crate) fn point<T>(&mut self, lo: i32, n: u32) -> T
+        where T: TryFrom<i32>, T::Error: Debug
+    {
+        T::try_from(lo + self.below(n)).expect("point in range of type")
+    }
+
+    /// Returns a pseudorandom bounded `Interval` of any shape with bounds in
+    /// `[lo, lo + n)`.
+    pub(in crate) fn bounded_interval<T>(&mut self, lo: i32, n: u32)
+        -> Interval<T>
+        where
+            T: Ord + Clone + TryFrom<i32>,
+            T::Error: Debug,
+            RawInterval<T>: Normalize,
+    {
+        let (a, b) = (self.point(lo, n), self.point(lo, n));
+        let (l, r) = if a <= b { (a, b) } else { (b, a) };
+        match self.below(5) {
+            0 => Interval::open(l, r),
+            1 => Interval::left_open(l, r),
+            2 => Interval::right_open(l, r),
+            3 => Interval::point(l),
+            _ => Interval::closed(l, r),
+        }
+    }
+
+    /// Returns a pseudorandom `Interval` of any shape, which if bounded has
+    /// bounds in `[lo, lo + n)`.
+    pub(in crate) fn interval<T>(&mut self, lo: i32, n: u32) -> Interval<T>
+        where
+            T: Ord + Clone + TryFrom<i32>,
+            T::Error: Debug,
+            RawInterval<T>: Normalize,
+    {
+        match self.below(6) {
+            0 => Interval::unbounded_to(self.point(lo, n)),
+            1 => Interval::unbounded_from(self.point(lo, n)),
+            _ => self.bounded_interval(lo, n),
+        }
+    }
+}
+
+/// Returns a pseudorandom `Selection` within `[lo, lo + n)` for the given
+/// seed.
+pub(in crate) fn pseudorandom_selection<T>(seed: u32, lo: i32, n: u32)
+    -> Selection<T>
+    where
+        T: Ord + Clone + TryFrom<i32>,
+        T::Error: Debug,
+        RawInterval<T>: Normalize,
+{
+    // Removing some intervals leaves small gaps and isolated points.
+    let mut lcg = Lcg::new(seed);
+    let mut sel = Selection::new();
+    for i in 0..lcg.below(12) {
+        let interval = lcg.bounded_interval(lo, n);
+        if i % 3 == 2 {
+            sel.minus_in_place(interval);
+        } else {
+            sel.union_in_place(interval);
+        }
+    }
+    sel
+}
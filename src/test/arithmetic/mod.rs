@@ -0,0 +1,245 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for `Interval` arithmetic.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+
+
+/// Returns the `Interval`s with bounds in `[-4, 4]`, including the empty one.
+fn small_intervals() -> Vec<Interval<i32>> {
+    let mut intervals = vec![Interval::empty()];
+    for l in -4..=4 {
+        for u in l..=4 {
+            intervals.push(Interval::closed(l, u));
+        }
+    }
+    intervals
+}
+
+/// Returns the smallest `Interval` containing every result of the given
+/// operation on the points of the given `Interval`s.
+fn hull_of<F>(a: Interval<i32>, b: Interval<i32>, f: F) -> Interval<i32>
+    where F: Fn(i32, i32) -> i32
+{
+    let results: Vec<_> = a.iter()
+        .flat_map(|p| b.iter().map(move |q| (p, q)))
+        .map(|(p, q)| f(p, q))
+        .collect();
+    match (results.iter().min(), results.iter().max()) {
+        (Some(&l), Some(&u)) => Interval::closed(l, u),
+        _                    => Interval::empty(),
+    }
+}
+
+#[test]
+fn operators_match_pointwise_results() {
+    for a in small_intervals() {
+        assert_eq!(-a, hull_of(a, Interval::point(0), |p, _| -p));
+        for b in small_intervals() {
+            assert_eq!(a + b, hull_of(a, b, |p, q| p + q));
+            assert_eq!(a - b, hull_of(a, b, |p, q| p - q));
+            assert_eq!(a * b, hull_of(a, b, |p, q| p * q));
+        }
+    }
+}
+
+#[test]
+fn open_bounds() {
+    // Open `Finite` intervals normalize to closed ones before any arithmetic.
+    assert_eq!(Interval::open(0, 4) + Interval::left_open(1, 3),
+        Interval::closed(3, 6));
+    assert_eq!(Interval::open(-3, 2) * Interval::closed(2, 3),
+        Interval::closed(-6, 3));
+}
+
+#[test]
+fn unbounded_operands_saturate() {
+    // Unbounded `Finite` intervals normalize to bounds at the type's extremes.
+    assert_eq!(Interval::unbounded_from(0) + Interval::point(1),
+        Interval::closed(1, i32::MAX));
+    assert_eq!(Interval::unbounded_to(0) - Interval::point(1),
+        Interval::closed(i32::MIN, -1));
+    assert_eq!(Interval::<i32>::full() + Interval::closed(-1, 1),
+        Interval::full());
+    assert_eq!(Interval::<i32>::full() - Interval::closed(-1, 1),
+        Interval::full());
+    assert_eq!(Interval::<i32>::full() * Interval::point(2),
+        Interval::full());
+    assert_eq!(Interval::unbounded_from(1) * Interval::point(-1),
+        Interval::closed(-i32::MAX, -1));
+}
+
+#[test]
+fn extreme_operands() {
+    // Bounds overflowing away from the interval saturate.
+    assert_eq!(Interval::closed(i32::MAX - 1, i32::MAX)
+            + Interval::closed(1, 2),
+        Interval::point(i32::MAX));
+    assert_eq!(Interval::closed(i32::MIN, 0) - Interval::closed(1, 2),
+        Interval::closed(i32::MIN, -1));
+    assert_eq!(-Interval::closed(i32::MIN, 0), Interval::closed(0, i32::MAX));
+    assert_eq!(Interval::closed(i32::MIN, 1) * Interval::point(-1),
+        Interval::closed(-1, i32::MAX));
+
+    // Bounds overflowing toward the interval leave no representable points.
+    assert_eq!(Interval::point(i32::MAX) + Interval::point(1),
+        Interval::empty());
+    assert_eq!(Interval::point(i32::MIN) - Interval::closed(1, 2),
+        Interval::empty());
+    assert_eq!(-Interval::point(i32::MIN), Interval::empty());
+    assert_eq!(Interval::closed(i32::MAX / 2 + 1, i32::MAX)
+            * Interval::closed(2, 3),
+        Interval::empty());
+
+    // Unsigned results below zero.
+    assert_eq!(-Interval::<u8>::closed(0, 5), Interval::point(0));
+    assert_eq!(-Interval::<u8>::closed(1, 5), Interval::empty());
+    assert_eq!(Interval::<u8>::closed(0, 5) - Interval::closed(3, 10),
+        Interval::closed(0, 2));
+    assert_eq!(Interval::<u8>::closed(0, 5) - Interval::closed(6, 10),
+        Interval::empty());
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_bounds() {
+    use crate::bound::Bound;
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 0);
+
+    assert_eq!(Interval::open(d(0), d(4)) + Interval::left_open(d(1), d(3)),
+        Interval::open(d(1), d(7)));
+    assert_eq!(Interval::closed(d(0), d(4)) - Interval::right_open(d(1), d(3)),
+        Interval::left_open(d(-3), d(3)));
+    assert_eq!(-Interval::right_open(d(1), d(3)),
+        Interval::left_open(d(-3), d(-1)));
+
+    // An included zero includes the product's zero bound.
+    assert_eq!(Interval::closed(d(0), d(1)) * Interval::open(d(2), d(3)),
+        Interval::right_open(d(0), d(3)));
+    assert_eq!(Interval::left_open(d(0), d(1)) * Interval::open(d(2), d(3)),
+        Interval::open(d(0), d(3)));
+
+    // Infinite bounds.
+    let up_from = |p| Interval::new(Bound::Include(p), Bound::Infinite);
+    assert_eq!(Interval::left_open(d(0), d(1)) * up_from(d(1)),
+        Interval::new(Bound::Exclude(d(0)), Bound::Infinite));
+    assert_eq!(Interval::closed(d(-1), d(1)) * up_from(d(1)),
+        Interval::full());
+    assert_eq!(Interval::point(d(0)) * Interval::full(),
+        Interval::point(d(0)));
+    assert_eq!(up_from(d(2)) + Interval::closed(d(1), d(3)), up_from(d(3)));
+    assert_eq!(up_from(d(2)) - Interval::closed(d(1), d(3)), up_from(d(-1)));
+    assert_eq!(up_from(d(2)) - up_from(d(1)), Interval::full());
+
+    // Overflowing bounds.
+    assert_eq!(Interval::closed(d(0), Decimal::MAX) + Interval::point(d(1)),
+        up_from(d(1)));
+    assert_eq!(Interval::point(Decimal::MAX) + Interval::point(d(1)),
+        Interval::empty());
+}
+
+#[test]
+fn lerp_round_trips_fraction() {
+    for interval in small_intervals() {
+        for point in interval.iter() {
+            let t = interval.fraction_of(&point).unwrap();
+            assert!((0.0..=1.0).contains(&t));
+            assert_eq!(interval.lerp(t), Some(point));
+        }
+        assert_eq!(interval.fraction_of(&5), None);
+    }
+    assert_eq!(Interval::<i32>::empty().lerp(0.5), None);
+    assert_eq!(Interval::closed(0i32, 10).lerp(f64::NAN), None);
+    assert_eq!(Interval::closed(0i32, 10).lerp(-1.0), Some(0));
+
+    let full: Interval<i128> = Interval::full();
+    assert_eq!(full.lerp(0.0), Some(i128::MIN));
+    assert_eq!(full.lerp(1.0), Some(i128::MAX));
+    assert_eq!(full.fraction_of(&i128::MAX), Some(1.0));
+    assert_eq!(Interval::<u8>::full().lerp(0.5), Some(128));
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_lerp() {
+    use crate::bound::Bound;
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 1);
+
+    let interval = Interval::open(d(-10), d(30));
+    assert_eq!(interval.lerp(0.0), Some(d(-10)));
+    assert_eq!(interval.lerp(0.75), Some(d(20)));
+    assert_eq!(interval.fraction_of(&d(20)), Some(0.75));
+    assert_eq!(interval.fraction_of(&d(-10)), None);
+    assert_eq!(Interval::point(d(5)).fraction_of(&d(5)), Some(0.0));
+
+    let unbounded = Interval::new(Bound::Include(d(0)), Bound::Infinite);
+    assert_eq!(unbounded.lerp(0.5), None);
+    assert_eq!(unbounded.fraction_of(&d(1)), None);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_translate() {
+    use crate::bound::Bound;
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 1);
+
+    assert_eq!(Interval::left_open(d(1), d(10)).translate(d(-5)),
+        Some(Interval::left_open(d(-4), d(5))));
+    assert_eq!(Interval::point(d(3)).translate(d(2)),
+        Some(Interval::point(d(5))));
+    assert_eq!(Interval::<Decimal>::empty().translate(Decimal::MAX),
+        Some(Interval::empty()));
+
+    let up_from = |p| Interval::new(Bound::Exclude(p), Bound::Infinite);
+    assert_eq!(up_from(d(0)).translate(d(15)), Some(up_from(d(15))));
+    assert_eq!(Interval::<Decimal>::full().translate(Decimal::MAX),
+        Some(Interval::full()));
+
+    // Overflowing bounds.
+    assert_eq!(Interval::closed(d(0), Decimal::MAX).translate(Decimal::ONE),
+        None);
+    assert_eq!(Interval::closed(Decimal::MIN, d(0)).translate(-Decimal::ONE),
+        None);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_scale() {
+    use crate::bound::Bound;
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 1);
+
+    assert_eq!(Interval::right_open(d(10), d(20)).scale(d(15)),
+        Some(Interval::right_open(d(15), d(30))));
+    // Negative factors reverse the bounds.
+    assert_eq!(Interval::right_open(d(10), d(20)).scale(d(-15)),
+        Some(Interval::left_open(d(-30), d(-15))));
+    assert_eq!(Interval::open(d(-10), d(20)).scale(Decimal::ZERO),
+        Some(Interval::point(Decimal::ZERO)));
+    assert_eq!(Interval::<Decimal>::empty().scale(Decimal::ZERO),
+        Some(Interval::empty()));
+
+    let up_from = |p| Interval::new(Bound::Include(p), Bound::Infinite);
+    let down_to = |p| Interval::new(Bound::Infinite, Bound::Include(p));
+    assert_eq!(up_from(d(10)).scale(d(20)), Some(up_from(d(20))));
+    assert_eq!(up_from(d(10)).scale(d(-20)), Some(down_to(d(-20))));
+
+    // Overflowing bounds.
+    assert_eq!(Interval::closed(d(0), Decimal::MAX).scale(Decimal::TWO),
+        None);
+    assert_eq!(Interval::closed(Decimal::MIN, d(0)).scale(-Decimal::TWO),
+        None);
+}
@@ -0,0 +1,63 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Bound`] utilities.
+//!
+//! [`Bound`] ../../bound/enum.Bound.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+
+
+#[test]
+fn std_bound_round_trip() {
+    let bounds = [Bound::Include(3), Bound::Exclude(-4), Bound::Infinite];
+    for &bound in &bounds {
+        let std_bound: std::ops::Bound<i32> = bound.into();
+        assert_eq!(Bound::from(std_bound), bound);
+    }
+
+    assert_eq!(std::ops::Bound::from(Bound::Include('a')),
+        std::ops::Bound::Included('a'));
+    assert_eq!(Bound::from(std::ops::Bound::Excluded(7u8)),
+        Bound::Exclude(7));
+    assert_eq!(Bound::<u8>::from(std::ops::Bound::Unbounded),
+        Bound::Infinite);
+}
+
+#[test]
+fn bound_references_keep_type() {
+    let mut bound = Bound::Exclude(10);
+    assert_eq!(bound.as_bound_ref(), Bound::Exclude(&10));
+    assert_eq!(bound.as_bound_ref().cloned(), bound);
+
+    if let Bound::Exclude(p) = bound.as_bound_mut() { *p = 20; }
+    assert_eq!(bound, Bound::Exclude(20));
+
+    let mut infinite: Bound<i32> = Bound::Infinite;
+    assert_eq!(infinite.as_bound_ref(), Bound::Infinite);
+    assert_eq!(infinite.as_bound_mut(), Bound::Infinite);
+}
+
+#[test]
+fn transpose_option_and_result() {
+    assert_eq!(Bound::Include(Some(1)).transpose(), Some(Bound::Include(1)));
+    assert_eq!(Bound::Exclude(None::<i32>).transpose(), None);
+    assert_eq!(Bound::<Option<i32>>::Infinite.transpose(),
+        Some(Bound::Infinite));
+
+    let ok: Bound<Result<i32, ()>> = Bound::Exclude(Ok(4));
+    assert_eq!(ok.transpose(), Ok(Bound::Exclude(4)));
+    let err: Bound<Result<i32, &str>> = Bound::Include(Err("bad"));
+    assert_eq!(err.transpose(), Err("bad"));
+    let inf: Bound<Result<i32, ()>> = Bound::Infinite;
+    assert_eq!(inf.transpose(), Ok(Bound::Infinite));
+}
@@ -0,0 +1,95 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`ByteSelection`].
+//!
+//! [`ByteSelection`] ../../byte_selection/struct.ByteSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::byte_selection::ByteSelection;
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::test::pseudorandom_selection;
+
+
+#[test]
+fn interval_masks_at_word_edges() {
+    for &(l, u) in &[(0, 0), (0, 63), (63, 64), (64, 127), (100, 200),
+        (0, 255), (255, 255), (128, 191)]
+    {
+        let bytes = ByteSelection::from(Interval::closed(l, u));
+        assert_eq!(bytes.point_count(), usize::from(u - l) + 1);
+        assert_eq!(bytes.infimum(), Some(l));
+        assert_eq!(bytes.supremum(), Some(u));
+        assert_eq!(bytes.interval_iter().collect::<Vec<_>>(),
+            [Interval::closed(l, u)]);
+    }
+    assert!(ByteSelection::from(Interval::empty()).is_empty());
+    assert!(ByteSelection::from(Interval::full()).is_full());
+}
+
+#[test]
+fn selection_round_trip() {
+    for seed in 0..64 {
+        let sel = pseudorandom_selection(seed, 0, 256);
+        let bytes = ByteSelection::from(&sel);
+
+        assert_eq!(Selection::from(bytes), sel);
+        assert_eq!(bytes.interval_count(), sel.interval_count());
+        assert_eq!(bytes.point_count() as u128, sel.point_count().unwrap());
+        assert!(bytes.iter().eq(sel.iter()));
+        assert!(bytes.iter().rev().eq(sel.iter().rev()));
+        assert!(bytes.interval_iter().rev().eq(sel.interval_iter().rev()));
+        assert_eq!(bytes.to_string(), sel.to_string());
+    }
+}
+
+#[test]
+fn operators_match_selection() {
+    for seed in 0..32 {
+        let (a, b) = (
+            pseudorandom_selection(seed, 0, 256),
+            pseudorandom_selection(seed + 100, 0, 256));
+        let (x, y) = (ByteSelection::from(&a), ByteSelection::from(&b));
+
+        assert_eq!(Selection::from(x | y), a.union(&b));
+        assert_eq!(Selection::from(x & y), a.intersect(&b));
+        assert_eq!(Selection::from(x - y), a.minus(&b));
+        assert_eq!(Selection::from(x ^ y), a.symmetric_difference(&b));
+        assert_eq!(Selection::from(!x), a.complement());
+        assert_eq!(x.is_subset(&y), a.is_subset(&b));
+        assert_eq!(x.is_disjoint(&y), a.is_disjoint(&b));
+
+        let mut z = x;
+        z |= y;
+        z -= x;
+        assert_eq!(z, y - x);
+    }
+}
+
+#[test]
+fn in_place_operations() {
+    let mut bytes = ByteSelection::new();
+    bytes.union_in_place(b'a'..=b'z');
+    bytes.minus_in_place(b'm'..b'p');
+    bytes.symmetric_difference_in_place(b'x'..);
+    bytes.intersect_in_place(..=b'x');
+    assert_eq!(bytes.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(b'a', b'l'),
+        Interval::closed(b'p', b'w'),
+    ]);
+
+    assert!(bytes.insert(b'0'));
+    assert!(!bytes.insert(b'0'));
+    assert!(bytes.remove(b'0'));
+    assert!(!bytes.remove(b'0'));
+    assert_eq!(bytes, b"abcdefghijklpqrstuvw".iter().copied().collect());
+}
@@ -0,0 +1,241 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the binary encoding of [`Selection`].
+//!
+//! [`Selection`] struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bytes::DeltaDecoder;
+use crate::bytes::FromBytesError;
+use crate::bytes::PointBytes;
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::test::Lcg;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Round trip tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn round_trip_i32() {
+    let mut lcg = Lcg::new(11);
+
+    for _ in 0..64 {
+        let mut sel: Selection<i32> = Selection::new();
+        for _ in 0..lcg.below(12) {
+            sel.union_in_place(lcg.interval(-20, 40));
+        }
+
+        assert_eq!(Selection::from_bytes(&sel.to_bytes()), Ok(sel));
+    }
+}
+
+#[test]
+fn round_trip_extremes() {
+    let sel: Selection<u8> = Selection::full();
+    assert_eq!(Selection::from_bytes(&sel.to_bytes()), Ok(sel));
+
+    let sel: Selection<u8> = Selection::new();
+    assert_eq!(sel.to_bytes(), [1]);
+    assert_eq!(Selection::from_bytes(&sel.to_bytes()), Ok(sel));
+
+    let mut sel: Selection<i64> = Selection::from(Interval::point(i64::MIN));
+    sel.union_in_place(Interval::closed(i64::MAX - 3, i64::MAX));
+    assert_eq!(Selection::from_bytes(&sel.to_bytes()), Ok(sel));
+
+    let mut sel: Selection<char> = Selection::from(Interval::closed('a', 'z'));
+    sel.union_in_place(Interval::point('\u{E000}'));
+    assert_eq!(Selection::from_bytes(&sel.to_bytes()), Ok(sel));
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn round_trip_decimal() {
+    use rust_decimal::Decimal;
+    let mut sel: Selection<Decimal> = Selection::from(
+        Interval::right_open(Decimal::new(-15, 1), Decimal::new(25, 1)));
+    sel.union_in_place(Interval::open(Decimal::new(25, 1), Decimal::new(4, 0)));
+    sel.union_in_place(Interval::unbounded_from(Decimal::new(10, 0)));
+    assert_eq!(Selection::from_bytes(&sel.to_bytes()), Ok(sel));
+}
+
+#[test]
+fn point_bytes_round_trip() {
+    let mut buf = Vec::new();
+    String::from("héllo").write_bytes(&mut buf);
+    (-7isize).write_bytes(&mut buf);
+    assert_eq!(buf.len(), 8 + 6 + 8);
+
+    let (text, read) = String::read_bytes(&buf).unwrap();
+    assert_eq!((text.as_str(), read), ("héllo", 14));
+    assert_eq!(isize::read_bytes(&buf[read..]), Some((-7, 8)));
+    assert_eq!(String::read_bytes(&buf[..10]), None);
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Validation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn rejects_invalid_encodings() {
+    use FromBytesError::*;
+    let decode = |bytes: &[u8]| Selection::<u8>::from_bytes(bytes);
+
+    assert_eq!(decode(&[]), Err(UnexpectedEnd));
+    assert_eq!(decode(&[2]), Err(UnsupportedVersion(2)));
+    // Lower bound with no upper bound.
+    assert_eq!(decode(&[1, 0, 3]), Err(UnexpectedEnd));
+    // Tag with no point.
+    assert_eq!(decode(&[1, 0]), Err(UnexpectedEnd));
+    // Unknown tags.
+    assert_eq!(decode(&[1, 3, 5]), Err(InvalidTag(1)));
+    assert_eq!(decode(&[1, 12, 5]), Err(InvalidTag(1)));
+    // Infinite point tine.
+    assert_eq!(decode(&[1, 6]), Err(InvalidTag(1)));
+    // Upper bound with no lower bound.
+    assert_eq!(decode(&[1, 8, 3]), Err(InvalidBound(1)));
+    // Bounds out of order.
+    assert_eq!(decode(&[1, 0, 5, 8, 3]), Err(InvalidBound(3)));
+    assert_eq!(decode(&[1, 4, 5, 4, 5]), Err(InvalidBound(3)));
+    // Infinite lower bound after another bound.
+    assert_eq!(decode(&[1, 4, 5, 2, 10]), Err(InvalidBound(3)));
+    // Bound after an infinite upper bound.
+    assert_eq!(decode(&[1, 0, 5, 10, 4, 9]), Err(InvalidBound(4)));
+}
+
+#[test]
+fn accepts_unnormalized_encodings() {
+    // (2, 6) is normalized to [3, 5].
+    let sel = Selection::<u8>::from_bytes(&[1, 1, 2, 9, 6, 0, 7, 8, 8]);
+    assert_eq!(sel, Ok(Selection::from_sorted_disjoint(vec![
+        Interval::closed(3, 5),
+        Interval::closed(7, 8),
+    ])));
+
+    // [3, 5] and [6, 8] are merged.
+    let sel = Selection::<u8>::from_bytes(&[1, 0, 3, 8, 5, 0, 6, 8, 8]);
+    assert_eq!(sel, Ok(Selection::from(Interval::closed(3, 8))));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Delta encoding tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn delta_round_trip_i32() {
+    let mut lcg = Lcg::new(23);
+
+    for _ in 0..64 {
+        let mut sel: Selection<i32> = Selection::new();
+        for _ in 0..lcg.below(12) {
+            sel.union_in_place(lcg.interval(-20, 40));
+        }
+
+        let bytes = sel.to_delta_bytes();
+        assert_eq!(Selection::from_delta_bytes(&bytes), Ok(sel.clone()));
+
+        let mut decoder = DeltaDecoder::new();
+        for byte in &bytes {
+            decoder.feed(std::slice::from_ref(byte)).unwrap();
+        }
+        assert_eq!(decoder.finish(), Ok(sel));
+    }
+}
+
+#[test]
+fn delta_round_trip_extremes() {
+    let sel: Selection<u8> = Selection::full();
+    assert_eq!(sel.to_delta_bytes(), [0x81, 0, 0xFF, 0x01]);
+    assert_eq!(Selection::from_delta_bytes(&sel.to_delta_bytes()), Ok(sel));
+
+    let sel: Selection<u8> = Selection::new();
+    assert_eq!(sel.to_delta_bytes(), [0x81]);
+    assert_eq!(Selection::from_delta_bytes(&sel.to_delta_bytes()), Ok(sel));
+
+    let mut sel: Selection<i128> = Selection::from(Interval::point(i128::MIN));
+    sel.union_in_place(Interval::closed(i128::MAX - 3, i128::MAX));
+    assert_eq!(Selection::from_delta_bytes(&sel.to_delta_bytes()), Ok(sel));
+
+    let sel: Selection<u128> = Selection::full();
+    assert_eq!(sel.to_delta_bytes().len(), 2 + 19);
+    assert_eq!(Selection::from_delta_bytes(&sel.to_delta_bytes()), Ok(sel));
+}
+
+#[test]
+fn delta_encodes_gaps_and_lengths() {
+    let mut sel: Selection<u8> = Selection::from(Interval::closed(3, 5));
+    sel.union_in_place(Interval::point(7));
+    sel.union_in_place(Interval::closed(200, 255));
+    assert_eq!(sel.to_delta_bytes(), [0x81, 3, 2, 0, 0, 191, 0x01, 55]);
+
+    let sel: Selection<i8> = Selection::from(Interval::closed(-128, -127));
+    assert_eq!(sel.to_delta_bytes(), [0x81, 0, 1]);
+}
+
+#[test]
+fn delta_decoder_unions_into_selection() {
+    let mut received: Selection<u32> = Selection::from(Interval::closed(0, 9));
+    received.union_in_place(Interval::closed(40, 49));
+    let mut incoming: Selection<u32> = Selection::from(Interval::closed(10, 19));
+    incoming.union_in_place(Interval::closed(60, 69));
+    let bytes = incoming.to_delta_bytes();
+
+    let mut decoder = DeltaDecoder::with_selection(received.clone());
+    decoder.feed(&bytes[..3]).unwrap();
+    assert_eq!(decoder.selection().interval_count(), 2);
+    assert!(decoder.selection().contains(&15));
+    assert!(!decoder.selection().contains(&65));
+
+    let copied = std::io::copy(&mut &bytes[3..], &mut decoder).unwrap();
+    assert_eq!(copied as usize, bytes.len() - 3);
+    assert_eq!(decoder.finish(), Ok(received.union(&incoming)));
+}
+
+#[test]
+fn delta_rejects_invalid_encodings() {
+    use FromBytesError::*;
+    let decode = |bytes: &[u8]| Selection::<u8>::from_delta_bytes(bytes);
+
+    assert_eq!(decode(&[]), Err(UnexpectedEnd));
+    assert_eq!(decode(&[1]), Err(UnsupportedVersion(1)));
+    // Lower bound with no length.
+    assert_eq!(decode(&[0x81, 3]), Err(UnexpectedEnd));
+    // Unterminated varint.
+    assert_eq!(decode(&[0x81, 3, 0x80]), Err(UnexpectedEnd));
+    // Points out of range.
+    assert_eq!(decode(&[0x81, 0x80, 0x02, 0]), Err(InvalidPoint(1)));
+    assert_eq!(decode(&[0x81, 100, 0xC8, 0x01]), Err(InvalidPoint(2)));
+    // Interval after the greatest point.
+    assert_eq!(decode(&[0x81, 0xFA, 0x01, 5, 0]), Err(InvalidPoint(4)));
+
+    // Varint with more than 128 bits.
+    let mut bytes = vec![0x81];
+    bytes.extend_from_slice(&[0xFF; 18]);
+    bytes.push(0x04);
+    assert_eq!(Selection::<u128>::from_delta_bytes(&bytes),
+        Err(InvalidPoint(1)));
+
+    // Interval after an interval ending at the greatest point.
+    let mut bytes = Selection::<u128>::full().to_delta_bytes();
+    let end = bytes.len();
+    bytes.push(0);
+    assert_eq!(Selection::<u128>::from_delta_bytes(&bytes),
+        Err(InvalidBound(end)));
+
+    // The io::Write impl reports errors as invalid data.
+    let mut decoder = DeltaDecoder::<u8>::new();
+    let err = std::io::Write::write(&mut decoder, &[0]).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
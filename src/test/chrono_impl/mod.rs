@@ -0,0 +1,147 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for `chrono` date and time normalization.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// External library imports.
+use chrono::Duration;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+
+
+/// Constructs a `NaiveDate` from a valid calendar date.
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).expect("valid date")
+}
+
+/// Constructs a `NaiveDateTime` from a valid calendar date and time.
+fn date_time(year: i32, month: u32, day: u32, hour: u32) -> NaiveDateTime {
+    date(year, month, day).and_hms_opt(hour, 0, 0).expect("valid time")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// NaiveDate tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn naive_date_steps() {
+    // `NaiveDate` has deprecated inherent `pred` and `succ` methods.
+    let leap = date(2020, 2, 29);
+    assert_eq!(Finite::pred(&leap), Some(date(2020, 2, 28)));
+    assert_eq!(Finite::succ(&leap), Some(date(2020, 3, 1)));
+    assert_eq!(leap.succ_n(366), Some(date(2021, 3, 1)));
+    assert_eq!(leap.pred_n(366), Some(date(2019, 2, 28)));
+    assert_eq!(date(2019, 2, 28).steps_between(&leap), Some(366));
+    assert_eq!(leap.steps_between(&date(2019, 2, 28)), None);
+
+    assert_eq!(Finite::pred(&NaiveDate::MIN), None);
+    assert_eq!(Finite::succ(&NaiveDate::MAX), None);
+    assert_eq!(NaiveDate::MAX.succ_n(0), Some(NaiveDate::MAX));
+    assert_eq!(NaiveDate::MIN.pred_n(1), None);
+}
+
+#[test]
+fn naive_date_normalize() {
+    assert_eq!(Interval::open(date(2020, 2, 27), date(2020, 3, 2)),
+        Interval::closed(date(2020, 2, 28), date(2020, 3, 1)));
+    assert_eq!(Interval::unbounded_to(date(2020, 2, 27)),
+        Interval::closed(NaiveDate::MIN, date(2020, 2, 27)));
+    assert_eq!(Interval::<NaiveDate>::full(),
+        Interval::closed(NaiveDate::MIN, NaiveDate::MAX));
+    assert!(Interval::open(date(2020, 2, 28), date(2020, 2, 29)).is_empty());
+}
+
+#[test]
+fn naive_date_complement() {
+    let interval = Interval::closed(date(2020, 2, 27), date(2020, 3, 1));
+    assert_eq!(interval.complement().collect::<Vec<_>>(), [
+        Interval::closed(NaiveDate::MIN, date(2020, 2, 26)),
+        Interval::closed(date(2020, 3, 2), NaiveDate::MAX),
+    ]);
+    assert!(Selection::<NaiveDate>::full().complement().is_empty());
+
+    let sel = Selection::from(
+        Interval::closed(NaiveDate::MIN, date(2020, 1, 1)));
+    assert_eq!(sel.complement(),
+        Selection::from(Interval::unbounded_from(date(2020, 1, 2))));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// NaiveDateTime tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn naive_date_time_steps() {
+    let t = date_time(2020, 7, 18, 9);
+    let ns = Duration::nanoseconds(1);
+    assert_eq!(t.succ(), Some(t + ns));
+    assert_eq!(t.pred(), Some(t - ns));
+    assert_eq!(t.succ_n(1_000), Some(t + Duration::microseconds(1)));
+    assert_eq!(t.pred_n(1_000), Some(t - Duration::microseconds(1)));
+    assert_eq!(t.steps_between(&(t + Duration::seconds(1))),
+        Some(1_000_000_000));
+    assert_eq!((t + Duration::seconds(1)).steps_between(&t), None);
+
+    assert_eq!(NaiveDateTime::MIN.pred(), None);
+    assert_eq!(NaiveDateTime::MAX.succ(), None);
+    assert_eq!(NaiveDateTime::MIN.succ().unwrap().pred(),
+        Some(NaiveDateTime::MIN));
+    assert_eq!(NaiveDateTime::MAX.pred().unwrap().succ(),
+        Some(NaiveDateTime::MAX));
+}
+
+#[test]
+fn naive_date_time_normalize() {
+    let t = date_time(2020, 7, 18, 9);
+    let u = date_time(2020, 7, 18, 10);
+    let ns = Duration::nanoseconds(1);
+    assert_eq!(Interval::open(t, u), Interval::closed(t + ns, u - ns));
+    assert_eq!(Interval::right_open(t, u).duration(), Duration::hours(1));
+    assert_eq!(Interval::unbounded_from(t),
+        Interval::closed(t, NaiveDateTime::MAX));
+    assert_eq!(Interval::<NaiveDateTime>::full(),
+        Interval::closed(NaiveDateTime::MIN, NaiveDateTime::MAX));
+    assert!(Interval::open(t, t + ns).is_empty());
+}
+
+#[test]
+fn naive_date_time_complement() {
+    let t = date_time(2020, 7, 18, 9);
+    let ns = Duration::nanoseconds(1);
+    assert_eq!(Interval::point(t).complement().collect::<Vec<_>>(), [
+        Interval::closed(NaiveDateTime::MIN, t - ns),
+        Interval::closed(t + ns, NaiveDateTime::MAX),
+    ]);
+
+    let sel = Selection::from(Interval::unbounded_to(t));
+    assert_eq!(sel.complement(),
+        Selection::from(Interval::unbounded_from(t + ns)));
+    assert_eq!(sel.complement().complement(), sel);
+}
+
+#[test]
+fn duration_measure() {
+    let interval = Interval::open(Duration::minutes(30), Duration::hours(2));
+    assert_eq!(interval.measure(), Some(Duration::minutes(90)));
+    assert_eq!(Interval::point(Duration::hours(1)).measure(),
+        Some(Duration::zero()));
+    assert_eq!(Interval::unbounded_to(Duration::hours(1)).measure(), None);
+
+    let mut sel = Selection::from(interval);
+    let (l, u) = (Duration::hours(3), Duration::hours(4));
+    sel.union_in_place(Interval::closed(l, u));
+    assert_eq!(sel.measure(), Some(Duration::minutes(150)));
+}
@@ -0,0 +1,107 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`CircularInterval`].
+//!
+//! [`CircularInterval`] struct.CircularInterval.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound::*;
+use crate::circular::CircularInterval;
+use crate::circular::CircularSelection;
+use crate::interval::Interval;
+
+
+/// Returns the domain of hours in a day.
+fn hours() -> Interval<i32> {
+    Interval::closed(0, 23)
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// CircularInterval tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn circular_wrapping_bounds() {
+    let arc = CircularInterval::closed(hours(), 22, 3);
+    assert!(arc.is_wrapping());
+    assert_eq!(arc.start(), Some(Include(22)));
+    assert_eq!(arc.end(), Some(Include(3)));
+    assert_eq!((0..24).filter(|h| arc.contains(h)).count(), 6);
+
+    let arc = CircularInterval::closed(hours(), 3, 22);
+    assert!(!arc.is_wrapping());
+    assert_eq!(arc.start(), Some(Include(3)));
+    assert_eq!(arc.end(), Some(Include(22)));
+}
+
+#[test]
+fn circular_clipped_to_domain() {
+    let arc = CircularInterval::closed(hours(), 20, 30);
+    assert_eq!(arc, CircularInterval::closed(hours(), 20, 23));
+    assert!(!arc.is_wrapping());
+
+    let arc = CircularInterval::new(hours(), Infinite, Infinite);
+    assert!(arc.is_full());
+    assert!(arc.complement().is_empty());
+}
+
+#[test]
+fn circular_complement() {
+    let arc = CircularInterval::closed(hours(), 3, 5);
+    let complement = arc.complement();
+    assert!(complement.is_wrapping());
+    assert_eq!(complement.start(), Some(Include(6)));
+    assert_eq!(complement.end(), Some(Include(2)));
+    assert_eq!(complement.complement(), arc);
+    assert!(!arc.intersects(&complement));
+}
+
+#[test]
+fn circular_set_operations() {
+    let a = CircularInterval::closed(hours(), 20, 10);
+    let b = CircularInterval::closed(hours(), 8, 22);
+
+    assert_eq!(a.intersect(&b).arcs().collect::<Vec<_>>(), [
+        CircularInterval::closed(hours(), 8, 10),
+        CircularInterval::closed(hours(), 20, 22),
+    ]);
+    assert!(a.union(&b).is_full());
+    assert_eq!(a.minus(&b).arcs().collect::<Vec<_>>(), [
+        CircularInterval::closed(hours(), 23, 7),
+    ]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// CircularSelection tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn circular_selection_arcs() {
+    let mut sel = CircularSelection::new(hours());
+    sel.union_in_place(&CircularInterval::closed(hours(), 23, 1));
+    sel.union_in_place(&CircularInterval::closed(hours(), 12, 13));
+    assert_eq!(sel.arcs().collect::<Vec<_>>(), [
+        CircularInterval::closed(hours(), 12, 13),
+        CircularInterval::closed(hours(), 23, 1),
+    ]);
+
+    sel.minus_in_place(&CircularInterval::closed(hours(), 0, 0));
+    assert_eq!(sel.arcs().collect::<Vec<_>>(), [
+        CircularInterval::closed(hours(), 1, 1),
+        CircularInterval::closed(hours(), 12, 13),
+        CircularInterval::closed(hours(), 23, 23),
+    ]);
+
+    assert_eq!(sel.complement().complement(), sel);
+    assert!(sel.union(&sel.complement()).is_full());
+}
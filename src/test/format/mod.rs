@@ -0,0 +1,86 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`IntervalFormat`].
+//!
+//! [`IntervalFormat`] ../../format/struct.IntervalFormat.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::format::BoundStyle;
+use crate::format::IntervalFormat;
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+
+#[test]
+fn default_format_matches_display() {
+    let format = IntervalFormat::default();
+    let intervals: [Interval<i32>; 5] = [
+        Interval::empty(),
+        Interval::point(3),
+        Interval::open(-4, 9),
+        Interval::unbounded_to(0),
+        Interval::full(),
+    ];
+    for interval in &intervals {
+        assert_eq!(format.interval(interval).to_string(), interval.to_string());
+        let sel = Selection::from(*interval);
+        assert_eq!(format.selection(&sel).to_string(), sel.to_string());
+    }
+
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 2));
+    sel.union_in_place(Interval::point(6));
+    assert_eq!(format.selection(&sel).to_string(), sel.to_string());
+}
+
+#[test]
+fn bound_styles_step_outward() {
+    let interval: Interval<u8> = Interval::closed(3, 7);
+    let styled = |style| IntervalFormat::ascii()
+        .bound_style(style)
+        .interval(&interval)
+        .to_string();
+    assert_eq!(styled(BoundStyle::Normalized), "[3, 7]");
+    assert_eq!(styled(BoundStyle::LeftOpen), "(2, 7]");
+    assert_eq!(styled(BoundStyle::RightOpen), "[3, 8)");
+    assert_eq!(styled(BoundStyle::Open), "(2, 8)");
+
+    // Bounds at the extremes become infinite.
+    let format = IntervalFormat::ascii().bound_style(BoundStyle::Open);
+    assert_eq!(format.interval(&Interval::<u8>::full()).to_string(),
+        "(-inf, inf)");
+    assert_eq!(format.interval(&Interval::<u8>::point(0)).to_string(),
+        "(-inf, 1)");
+    assert_eq!(format.interval(&Interval::<u8>::empty()).to_string(), "{}");
+}
+
+#[test]
+fn custom_symbols() {
+    let format = IntervalFormat::new()
+        .infinity("oo")
+        .empty("none")
+        .union(" or ")
+        .separator("..")
+        .include_delimiters("<", ">")
+        .exclude_delimiters("]", "[")
+        .point_delimiters("=", "")
+        .bound_style(BoundStyle::Normalized);
+
+    let mut sel: Selection<i8> = Selection::from(Interval::closed(-3, 4));
+    sel.union_in_place(Interval::point(10));
+    assert_eq!(format.selection(&sel).to_string(), "<-3..4> or =10");
+
+    let format = format.bound_style(BoundStyle::RightOpen);
+    assert_eq!(format.selection(&sel).to_string(), "<-3..5[ or <10..11[");
+    assert_eq!(format.selection(&Selection::<i8>::new()).to_string(), "none");
+    assert_eq!(format.interval(&Interval::<i8>::unbounded_from(5))
+        .to_string(), "<5..oo[");
+}
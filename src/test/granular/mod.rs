@@ -0,0 +1,138 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Granular`].
+//!
+//! [`Granular`] struct.Granular.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::granular::Granular;
+use crate::granular::SnapDirection;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+
+type Quarter = Granular<i32, 15>;
+
+/// Constructs a `Quarter` from a multiple of 15.
+fn q(value: i32) -> Quarter {
+    Quarter::new(value).expect("multiple of 15")
+}
+
+#[test]
+fn construction() {
+    assert_eq!(Quarter::new(30), Some(q(30)));
+    assert_eq!(Quarter::new(-45), Some(q(-45)));
+    assert_eq!(Quarter::new(20), None);
+
+    assert_eq!(Quarter::floor(20), Some(q(15)));
+    assert_eq!(Quarter::floor(-20), Some(q(-30)));
+    assert_eq!(Quarter::ceil(20), Some(q(30)));
+    assert_eq!(Quarter::ceil(-20), Some(q(-15)));
+    assert_eq!(Quarter::ceil(30), Some(q(30)));
+}
+
+#[test]
+fn steps() {
+    assert_eq!(q(30).succ(), Some(q(45)));
+    assert_eq!(q(30).pred(), Some(q(15)));
+    assert_eq!(q(30).succ_n(4), Some(q(90)));
+    assert_eq!(q(30).pred_n(4), Some(q(-30)));
+    assert_eq!(q(-30).steps_between(&q(90)), Some(8));
+    assert_eq!(q(90).steps_between(&q(-30)), None);
+}
+
+#[test]
+fn extremes() {
+    type Byte = Granular<i8, 15>;
+    assert_eq!(Byte::MINIMUM.get(), -120);
+    assert_eq!(Byte::MAXIMUM.get(), 120);
+    assert_eq!(Byte::MAXIMUM.succ(), None);
+    assert_eq!(Byte::MINIMUM.pred(), None);
+    assert_eq!(Byte::MAXIMUM.succ_n(1), None);
+    assert_eq!(Byte::MINIMUM.steps_between(&Byte::MAXIMUM), Some(16));
+    assert_eq!(Byte::floor(-128), None);
+    assert_eq!(Byte::ceil(127), None);
+
+    let full: Interval<Byte> = Interval::full();
+    assert_eq!(full.iter().count(), 17);
+    assert_eq!(full.point_count(), Some(17));
+}
+
+#[test]
+fn intervals_normalize_onto_grid() {
+    // Open bounds step to the adjacent multiples.
+    assert_eq!(Interval::open(q(0), q(60)), Interval::closed(q(15), q(45)));
+    assert_eq!(Interval::open(q(0), q(15)), Interval::empty());
+
+    // Adjacent grid intervals merge.
+    let mut sel = Selection::from(Interval::closed(q(0), q(30)));
+    sel.union_in_place(Interval::closed(q(45), q(60)));
+    sel.union_in_place(Interval::closed(q(90), q(120)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(q(0), q(60)),
+        Interval::closed(q(90), q(120)),
+    ]);
+    assert_eq!(sel.iter_points().map(Granular::get).collect::<Vec<_>>(),
+        [0, 15, 30, 45, 60, 90, 105, 120]);
+}
+
+#[test]
+fn snap_matches_grid_cells() {
+    // The cell containing `p`, clipped to the range of `i8`.
+    let cell = |p: i16, step: i16| {
+        let start = p.div_euclid(step) * step;
+        (start.max(-128), (start + step - 1).min(127))
+    };
+    for step in 1..=9 {
+        for l in (-128..=127).step_by(5) {
+            for u in (l..=127).step_by(7) {
+                let interval = Interval::closed(l as i8, u as i8);
+                let outward = interval.snap(step as i8, SnapDirection::Outward);
+                let inward = interval.snap(step as i8, SnapDirection::Inward);
+                for p in -128..=127 {
+                    let (start, end) = cell(p, step);
+                    let overlaps = start <= u && l <= end;
+                    let within = l <= start && end <= u;
+                    assert_eq!(outward.contains(&(p as i8)), overlaps,
+                        "outward {:?} step {} point {}", interval, step, p);
+                    assert_eq!(inward.contains(&(p as i8)), within,
+                        "inward {:?} step {} point {}", interval, step, p);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn snap_at_extremes() {
+    let interval: Interval<u8> = Interval::closed(250, 255);
+    assert_eq!(interval.snap(16, SnapDirection::Outward),
+        Interval::closed(240, 255));
+    assert_eq!(interval.snap(16, SnapDirection::Inward), Interval::empty());
+    assert_eq!(Interval::<u8>::full().snap(16, SnapDirection::Inward),
+        Interval::full());
+    assert_eq!(Interval::<u8>::empty().snap(16, SnapDirection::Outward),
+        Interval::empty());
+
+    let interval: Interval<i8> = Interval::closed(-128, -120);
+    assert_eq!(interval.snap(10, SnapDirection::Outward),
+        Interval::closed(-128, -111));
+    assert_eq!(interval.snap(10, SnapDirection::Inward),
+        Interval::closed(-128, -121));
+}
+
+#[test]
+#[should_panic]
+fn snap_non_positive_step() {
+    let _ = Interval::closed(-5, 5).snap(0, SnapDirection::Outward);
+}
@@ -0,0 +1,177 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`IntervalIndex`].
+//!
+//! [`IntervalIndex`] struct.IntervalIndex.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_index::IntervalIndex;
+use crate::test::Lcg;
+
+
+/// Constructs an `IntervalIndex` of closed intervals labelled by their order.
+fn index_of(bounds: &[(i32, i32)]) -> IntervalIndex<i32, usize> {
+    bounds.iter()
+        .enumerate()
+        .map(|(n, &(l, u))| (Interval::closed(l, u), n))
+        .collect()
+}
+
+/// Returns the sorted labels of the intervals overlapping the query.
+fn overlapping(index: &IntervalIndex<i32, usize>, query: Interval<i32>)
+    -> Vec<usize>
+{
+    let mut labels: Vec<_> = index.overlapping(&query)
+        .map(|(_, n)| *n)
+        .collect();
+    labels.sort();
+    labels
+}
+
+/// Returns the sorted labels of the given intervals overlapping the query,
+/// computed by brute force.
+fn naive_overlapping(bounds: &[(i32, i32)], query: Interval<i32>)
+    -> Vec<usize>
+{
+    bounds.iter()
+        .enumerate()
+        .filter(|&(_, &(l, u))| Interval::closed(l, u).intersects(&query))
+        .map(|(n, _)| n)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn containing_nested() {
+    let index = index_of(&[(0, 100), (10, 20), (15, 16), (50, 60), (0, 100)]);
+
+    assert_eq!(overlapping(&index, Interval::point(15)), [0, 1, 2, 4]);
+    assert_eq!(overlapping(&index, Interval::point(55)), [0, 3, 4]);
+    assert_eq!(overlapping(&index, Interval::point(101)), Vec::<usize>::new());
+    assert_eq!(index.containing(&15).count(), 4);
+}
+
+#[test]
+fn overlapping_matches_naive() {
+    let bounds = [
+        (5, 9), (0, 3), (12, 40), (6, 6), (20, 22), (1, 30), (33, 35),
+        (-10, -5), (8, 8), (25, 50), (2, 2), (41, 45),
+    ];
+    let index = index_of(&bounds);
+    for l in -12..52 {
+        for u in l..52 {
+            let query = Interval::closed(l, u);
+            assert_eq!(overlapping(&index, query),
+                naive_overlapping(&bounds, query),
+                "query {:?}", query);
+        }
+    }
+}
+
+#[test]
+fn overlapping_sorted_by_lower_bound() {
+    let index = index_of(&[(30, 40), (10, 50), (20, 25), (0, 100)]);
+
+    let lowers: Vec<_> = index.overlapping(&Interval::closed(22, 35))
+        .map(|(i, _)| i.infimum().unwrap())
+        .collect();
+    assert_eq!(lowers, [0, 10, 20, 30]);
+}
+
+#[test]
+fn overlapping_unbounded() {
+    let mut index = IntervalIndex::new();
+    index.insert(Interval::unbounded_to(0), 'a');
+    index.insert(Interval::unbounded_from(10), 'b');
+    index.insert(Interval::closed(3, 5), 'c');
+    index.insert(Interval::empty(), 'd');
+
+    assert_eq!(index.len(), 3);
+    assert_eq!(index.overlapping(&Interval::full())
+        .map(|(_, v)| *v)
+        .collect::<String>(), "acb");
+    assert_eq!(index.containing(&i32::MIN).map(|(_, v)| *v).collect::<String>(),
+        "a");
+    assert_eq!(index.overlapping(&Interval::empty()).count(), 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Mutation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn insert_remove() {
+    let mut index = IntervalIndex::new();
+    index.insert(Interval::closed(0, 10), 'a');
+    index.insert(Interval::closed(5, 15), 'b');
+    index.insert(Interval::closed(0, 10), 'c');
+
+    assert_eq!(index.remove(&Interval::closed(0, 10)), Some('a'));
+    assert_eq!(index.containing(&3).map(|(_, v)| *v).collect::<String>(), "c");
+    assert_eq!(index.remove(&Interval::closed(0, 11)), None);
+
+    index.extend(vec![(Interval::point(7), 'd')]);
+    assert_eq!(index.containing(&7).map(|(_, v)| *v).collect::<String>(),
+        "cbd");
+}
+
+#[test]
+fn interleaved_edits_match_naive() {
+    let mut bounds = Vec::new();
+    let mut index = IntervalIndex::new();
+    let mut lcg = Lcg::new(3);
+
+    for n in 0..200 {
+        if bounds.is_empty() || lcg.below(3) != 0 {
+            let (l, r) = (lcg.below(40), lcg.below(40));
+            bounds.push((l.min(r), l.max(r)));
+            index.insert(Interval::closed(l.min(r), l.max(r)), n);
+        } else {
+            let at = lcg.below(bounds.len() as u32) as usize;
+            let (l, u) = bounds.swap_remove(at);
+            assert!(index.remove(&Interval::closed(l, u)).is_some());
+        }
+        assert_eq!(index.len(), bounds.len());
+
+        // Labels are not positions in `bounds`, so compare intervals.
+        let query = Interval::closed(lcg.below(40), lcg.below(40) + 5);
+        let mut found: Vec<_> = index.overlapping(&query)
+            .map(|(i, _)| (i.infimum().unwrap(), i.supremum().unwrap()))
+            .collect();
+        let mut expected: Vec<_> = bounds.iter()
+            .copied()
+            .filter(|&(l, u)| Interval::closed(l, u).intersects(&query))
+            .collect();
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected, "query {:?}", query);
+    }
+}
+
+#[test]
+fn equality_ignores_query_history() {
+    let a = index_of(&[(0, 10), (5, 15)]);
+    let b = index_of(&[(0, 10), (5, 15)]);
+    assert_eq!(a.containing(&7).count(), 2);
+    assert_eq!(a, b);
+
+    let mut c = IntervalIndex::new();
+    c.insert(Interval::closed(5, 15), 1);
+    c.insert(Interval::closed(0, 10), 0);
+    assert_eq!(c, a);
+    assert_eq!(c.remove(&Interval::closed(5, 15)), Some(1));
+    assert_ne!(c, a);
+}
@@ -0,0 +1,131 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`IntervalMap`].
+//!
+//! [`IntervalMap`] struct.IntervalMap.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::interval_map::IntervalMap;
+
+
+/// Collects the entries of an `IntervalMap` with owned values.
+fn entries<V: Clone>(map: &IntervalMap<i32, V>) -> Vec<(Interval<i32>, V)> {
+    map.iter().map(|(i, v)| (i, v.clone())).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Insert tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn insert_disjoint() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::closed(10, 15), 'b');
+    map.insert(Interval::closed(0, 5), 'a');
+    map.insert(Interval::empty(), 'x');
+
+    assert_eq!(entries(&map), [
+        (Interval::closed(0, 5), 'a'),
+        (Interval::closed(10, 15), 'b'),
+    ]);
+}
+
+#[test]
+fn insert_overwrite_inside() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::closed(0, 10), 'a');
+    map.insert(Interval::point(5), 'b');
+
+    assert_eq!(entries(&map), [
+        (Interval::closed(0, 4), 'a'),
+        (Interval::point(5), 'b'),
+        (Interval::closed(6, 10), 'a'),
+    ]);
+}
+
+#[test]
+fn insert_overwrite_spanning() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::closed(0, 4), 'a');
+    map.insert(Interval::closed(5, 9), 'b');
+    map.insert(Interval::closed(10, 14), 'c');
+    map.insert(Interval::closed(20, 24), 'd');
+    map.insert(Interval::closed(2, 11), 'e');
+
+    assert_eq!(entries(&map), [
+        (Interval::closed(0, 1), 'a'),
+        (Interval::closed(2, 11), 'e'),
+        (Interval::closed(12, 14), 'c'),
+        (Interval::closed(20, 24), 'd'),
+    ]);
+}
+
+#[test]
+fn insert_overwrite_exact() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::closed(0, 4), 'a');
+    map.insert(Interval::closed(0, 4), 'b');
+
+    assert_eq!(entries(&map), [(Interval::closed(0, 4), 'b')]);
+}
+
+#[test]
+fn insert_unbounded() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::closed(-5, 5), 'a');
+    map.insert(Interval::unbounded_to(0), 'b');
+    map.insert(Interval::unbounded_from(3), 'c');
+
+    assert_eq!(entries(&map), [
+        (Interval::unbounded_to(0), 'b'),
+        (Interval::closed(1, 2), 'a'),
+        (Interval::unbounded_from(3), 'c'),
+    ]);
+
+    map.insert(Interval::full(), 'd');
+    assert_eq!(entries(&map), [(Interval::full(), 'd')]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Query tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn get() {
+    let map: IntervalMap<i32, char> = vec![
+        (Interval::closed(0, 10), 'a'),
+        (Interval::closed(3, 5), 'b'),
+        (Interval::point(20), 'c'),
+    ].into_iter().collect();
+
+    assert_eq!(map.get(&-1), None);
+    assert_eq!(map.get(&0), Some(&'a'));
+    assert_eq!(map.get(&2), Some(&'a'));
+    assert_eq!(map.get(&3), Some(&'b'));
+    assert_eq!(map.get(&5), Some(&'b'));
+    assert_eq!(map.get(&6), Some(&'a'));
+    assert_eq!(map.get(&10), Some(&'a'));
+    assert_eq!(map.get(&11), None);
+    assert_eq!(map.get(&20), Some(&'c'));
+    assert_eq!(map.get(&21), None);
+}
+
+#[test]
+fn iter_rev() {
+    let mut map = IntervalMap::new();
+    map.insert(Interval::closed(0, 10), 'a');
+    map.insert(Interval::closed(3, 5), 'b');
+
+    assert_eq!(map.iter().len(), 3);
+    assert_eq!(map.iter().rev().map(|(_, v)| *v).collect::<String>(), "aba");
+}
@@ -0,0 +1,88 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`JournaledSelection`].
+//!
+//! [`JournaledSelection`] struct.JournaledSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::journal::JournaledSelection;
+use crate::selection::Selection;
+
+
+#[test]
+fn undo_restores_each_version() {
+    let mut sel: JournaledSelection<i32> = JournaledSelection::default();
+    let mut versions = vec![sel.selection().clone()];
+
+    sel.union_in_place(Interval::closed(0, 10));
+    versions.push(sel.selection().clone());
+    sel.minus_in_place(Interval::open(3, 6));
+    versions.push(sel.selection().clone());
+    sel.insert_point(5);
+    versions.push(sel.selection().clone());
+    sel.symmetric_difference_in_place(Interval::closed(8, 14));
+    versions.push(sel.selection().clone());
+    sel.intersect_in_place(Interval::closed(2, 12));
+    versions.push(sel.selection().clone());
+    sel.remove_point(&2);
+    versions.push(sel.selection().clone());
+
+    for version in versions.iter().rev().skip(1) {
+        assert!(sel.undo());
+        assert_eq!(sel.selection(), version);
+    }
+    assert!(!sel.undo());
+
+    for version in versions.iter().skip(1) {
+        assert!(sel.redo());
+        assert_eq!(sel.selection(), version);
+    }
+    assert!(!sel.redo());
+}
+
+#[test]
+fn unchanged_edits_are_not_recorded() {
+    let mut sel = Selection::from(Interval::closed(0, 10)).journaled();
+    sel.union_in_place(Interval::closed(2, 4));
+    sel.minus_in_place(Interval::closed(20, 24));
+    sel.insert_point(3);
+    assert!(!sel.can_undo());
+}
+
+#[test]
+fn edits_discard_undone_changes() {
+    let mut sel = Selection::from(Interval::closed(0, 10)).journaled();
+    sel.remove_point(&4);
+    assert!(sel.undo());
+    assert!(sel.can_redo());
+
+    sel.remove_point(&6);
+    assert!(!sel.can_redo());
+    assert_eq!(sel.patches().len(), 1);
+}
+
+#[test]
+fn patches_replay_changes() {
+    let start = Selection::from(Interval::closed(0, 10));
+    let mut sel = start.clone().journaled();
+    sel.minus_in_place(Interval::closed(3, 5));
+    sel.union_in_place(Interval::closed(20, 30));
+    sel.edit(|s| s.retain(|i| i.contains(&0)));
+
+    let mut replayed = start;
+    for patch in sel.take_patches() {
+        patch.apply(&mut replayed);
+    }
+    assert_eq!(&replayed, sel.selection());
+    assert!(!sel.can_undo());
+}
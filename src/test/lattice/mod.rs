@@ -0,0 +1,97 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Join`] and [`Meet`] implementations.
+//!
+//! [`Join`] ../../lattice/trait.Join.html
+//! [`Meet`] ../../lattice/trait.Meet.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::lattice::Join;
+use crate::lattice::Meet;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::fmt::Debug;
+
+
+fn check_lattice_laws<L>(items: &[L]) where L: Join + Meet + PartialEq + Debug {
+    for a in items {
+        assert_eq!(a.join(&L::bottom()), *a);
+        assert_eq!(a.meet(&L::top()), *a);
+        assert_eq!(a.join(a), *a);
+        assert_eq!(a.meet(a), *a);
+        for b in items {
+            assert_eq!(a.join(b), b.join(a));
+            assert_eq!(a.meet(b), b.meet(a));
+            // Absorption.
+            assert_eq!(a.join(&a.meet(b)), *a);
+            assert_eq!(a.meet(&a.join(b)), *a);
+            for c in items {
+                assert_eq!(a.join(b).join(c), a.join(&b.join(c)));
+                assert_eq!(a.meet(b).meet(c), a.meet(&b.meet(c)));
+            }
+        }
+    }
+}
+
+#[test]
+fn interval_lattice_laws() {
+    check_lattice_laws(&[
+        Interval::empty(),
+        Interval::point(3),
+        Interval::closed(-2, 5),
+        Interval::open(4, 12),
+        Interval::unbounded_to(0),
+        Interval::unbounded_from(7),
+        Interval::full(),
+    ]);
+}
+
+#[test]
+fn selection_lattice_laws() {
+    let mut disjoint = Selection::from(Interval::closed(-8, -4));
+    disjoint.union_in_place(Interval::closed(10, 14));
+    check_lattice_laws(&[
+        Selection::new(),
+        Selection::from(Interval::point(3)),
+        Selection::from(Interval::closed(-2, 5)),
+        Selection::from(Interval::open(4, 12)),
+        Selection::from(Interval::unbounded_from(7)),
+        disjoint,
+        Selection::full(),
+    ]);
+}
+
+#[test]
+fn selection_fixed_point() {
+    // Least fixed point of x = {0} ∪ (x + 3) restricted to [0, 12].
+    let step = |x: &Selection<i32>| {
+        let mut next = Selection::from(Interval::point(0));
+        for i in x.interval_iter() {
+            let shifted = Interval::closed(
+                i.infimum().unwrap() + 3,
+                i.supremum().unwrap() + 3);
+            next.union_in_place(shifted);
+        }
+        next.meet(&Selection::from(Interval::closed(0, 12)))
+    };
+
+    let mut x = Selection::bottom();
+    loop {
+        let next = x.join(&step(&x));
+        if next == x { break; }
+        x = next;
+    }
+    let expected: Selection<i32> = [0, 3, 6, 9, 12].iter().cloned().collect();
+    assert_eq!(x, expected);
+}
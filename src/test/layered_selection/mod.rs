@@ -0,0 +1,171 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`LayeredSelection`].
+//!
+//! [`LayeredSelection`] struct.LayeredSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::layered_selection::LayeredSelection;
+use crate::layered_selection::coverage;
+use crate::selection::Selection;
+use crate::test::Lcg;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Coverage tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn coverage_layers() {
+    let layers: LayeredSelection<i32> = vec![
+        Interval::closed(0, 10),
+        Interval::closed(5, 15),
+        Interval::closed(8, 9),
+        Interval::point(20),
+    ].into_iter().collect();
+
+    assert_eq!(layers.iter().collect::<Vec<_>>(), [
+        (Interval::closed(0, 4), 1),
+        (Interval::closed(5, 7), 2),
+        (Interval::closed(8, 9), 3),
+        (Interval::point(10), 2),
+        (Interval::closed(11, 15), 1),
+        (Interval::point(20), 1),
+    ]);
+    assert_eq!(layers.max_coverage(), 3);
+    assert_eq!(layers.coverage_at(&-1), 0);
+    assert_eq!(layers.coverage_at(&9), 3);
+    assert_eq!(layers.coverage_at(&16), 0);
+}
+
+#[test]
+fn coverage_merges_adjacent() {
+    let mut layers = LayeredSelection::new();
+    layers.insert(Interval::closed(0, 4));
+    layers.insert(Interval::closed(10, 14));
+    layers.insert(Interval::closed(5, 9));
+
+    assert_eq!(layers.iter().collect::<Vec<_>>(),
+        [(Interval::closed(0, 14), 1)]);
+}
+
+#[test]
+fn remove_restores() {
+    let mut layers = LayeredSelection::new();
+    layers.insert(Interval::closed(0, 10));
+    layers.insert(Interval::closed(3, 6));
+    layers.remove(Interval::closed(3, 6));
+
+    let mut expected = LayeredSelection::new();
+    expected.insert(Interval::closed(0, 10));
+    assert_eq!(layers, expected);
+
+    layers.remove(Interval::closed(-5, 20));
+    layers.remove(Interval::closed(-5, 20));
+    assert!(layers.is_empty());
+    assert_eq!(layers, LayeredSelection::new());
+}
+
+#[test]
+fn remove_uncovered() {
+    let mut layers = LayeredSelection::new();
+    layers.insert(Interval::closed(0, 10));
+    layers.remove(Interval::closed(5, 15));
+    layers.insert(Interval::closed(8, 12));
+
+    assert_eq!(layers.iter().collect::<Vec<_>>(), [
+        (Interval::closed(0, 4), 1),
+        (Interval::closed(8, 12), 1),
+    ]);
+}
+
+#[test]
+fn at_least() {
+    let layers: LayeredSelection<i32> = vec![
+        Interval::closed(0, 10),
+        Interval::closed(5, 15),
+        Interval::closed(8, 20),
+    ].into_iter().collect();
+
+    assert_eq!(layers.at_least(0), Selection::full());
+    assert_eq!(layers.at_least(1), Selection::from(Interval::closed(0, 20)));
+    assert_eq!(layers.at_least(2), Selection::from(Interval::closed(5, 15)));
+    assert_eq!(layers.at_least(3), Selection::from(Interval::closed(8, 10)));
+    assert_eq!(layers.at_least(4), Selection::new());
+}
+
+#[test]
+fn unbounded_coverage() {
+    let mut layers = LayeredSelection::new();
+    layers.insert(Interval::full());
+    layers.insert(Interval::unbounded_from(0));
+
+    assert_eq!(layers.coverage_at(&i32::MIN), 1);
+    assert_eq!(layers.coverage_at(&i32::MAX), 2);
+    assert_eq!(layers.at_least(2),
+        Selection::from(Interval::unbounded_from(0)));
+}
+
+/// Returns possibly overlapping intervals generated from the seed.
+fn overlapping_intervals(seed: u32) -> Vec<Interval<i32>> {
+    let mut lcg = Lcg::new(seed);
+    (0..12)
+        .map(|_| lcg.interval(0, 24))
+        .collect()
+}
+
+#[test]
+fn coverage_matches_layers() {
+    for seed in 0..100 {
+        let intervals = overlapping_intervals(seed);
+        let layers: LayeredSelection<i32> = intervals.iter().cloned().collect();
+        assert_eq!(coverage(intervals), layers.iter().collect::<Vec<_>>(),
+            "seed {}", seed);
+    }
+}
+
+#[test]
+fn coverage_edge_cases() {
+    assert_eq!(coverage(Vec::<Interval<i32>>::new()), []);
+    assert_eq!(coverage(vec![Interval::<i32>::empty()]), []);
+    assert_eq!(coverage(vec![Interval::full(), Interval::full()]),
+        [(Interval::<u8>::full(), 2)]);
+
+    // Adjacent intervals with the same coverage are merged.
+    assert_eq!(coverage(vec![
+        Interval::closed(0, 4),
+        Interval::closed(5, 9),
+        Interval::closed(12, 14),
+    ]), [
+        (Interval::closed(0, 9), 1),
+        (Interval::closed(12, 14), 1),
+    ]);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn coverage_dense() {
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 0);
+
+    assert_eq!(coverage(vec![
+        Interval::closed(d(0), d(2)),
+        Interval::right_open(d(2), d(4)),
+        Interval::open(d(4), d(6)),
+    ]), [
+        (Interval::right_open(d(0), d(2)), 1),
+        (Interval::point(d(2)), 2),
+        (Interval::open(d(2), d(4)), 1),
+        (Interval::open(d(4), d(6)), 1),
+    ]);
+}
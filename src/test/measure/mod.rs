@@ -0,0 +1,155 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Measurable`] implementations.
+//!
+//! [`Measurable`] ../../measure/trait.Measurable.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::selection::Selection;
+
+
+#[test]
+fn finite_measure_counts_points() {
+    assert_eq!(Interval::closed(3, 7).measure(), Some(5u128));
+    assert_eq!(Interval::open(3, 4).measure(), Some(0u128));
+    assert_eq!(Interval::point('x').measure(), Some(1));
+    assert_eq!(Interval::<u8>::full().measure(), Some(256));
+    assert_eq!(Interval::<u128>::full().measure(), None);
+
+    // Bounds which would not normalize are measured the same way.
+    assert_eq!(i8::measure_between(&Bound::Exclude(126), &Bound::Infinite),
+        Some(1));
+    assert_eq!(i8::measure_between(&Bound::Exclude(127), &Bound::Infinite),
+        Some(0));
+    assert_eq!(u8::measure_between(&Bound::Exclude(3), &Bound::Exclude(4)),
+        Some(0));
+}
+
+#[test]
+fn selection_measure_sums_intervals() {
+    let mut sel: Selection<i64> = Selection::new();
+    assert_eq!(sel.measure(), Some(0));
+
+    sel.union_in_place(Interval::closed(-10, 10));
+    sel.union_in_place(Interval::right_open(100, 200));
+    assert_eq!(sel.measure(), Some(121));
+    assert_eq!(sel.measure(), sel.point_count());
+
+    let sel: Selection<u128> = Selection::full();
+    assert_eq!(sel.measure(), None);
+}
+
+#[test]
+fn overlap_measure_matches_intersection() {
+    // Builds a selection of short intervals from the bits of a seed.
+    let sel = |seed: u32| -> Selection<i32> {
+        (0..16)
+            .filter(|i| seed & (1 << i) != 0)
+            .map(|i| Interval::right_open(i * 5, i * 5 + 3 + (i % 3)))
+            .collect()
+    };
+    for i in 0..64u32 {
+        for j in 0..64u32 {
+            let a = sel(i.wrapping_mul(2_654_435_761));
+            let b = sel(j.wrapping_mul(40_503) ^ 0x5A5A);
+            assert_eq!(a.overlap_measure(&b), a.intersect(&b).measure());
+            assert_eq!(a.overlap_measure(&b), b.overlap_measure(&a));
+        }
+    }
+
+    let full: Selection<u128> = Selection::full();
+    assert_eq!(full.overlap_measure(&Selection::new()), Some(0));
+    assert_eq!(full.overlap_measure(&full), None);
+}
+
+#[test]
+fn jaccard_is_ratio_of_measures() {
+    // Builds a selection of short intervals from the bits of a seed.
+    let sel = |seed: u32| -> Selection<i32> {
+        (0..16)
+            .filter(|i| seed & (1 << i) != 0)
+            .map(|i| Interval::right_open(i * 5, i * 5 + 3 + (i % 3)))
+            .collect()
+    };
+    for i in 0..64u32 {
+        for j in 0..64u32 {
+            let a = sel(i.wrapping_mul(2_654_435_761));
+            let b = sel(j.wrapping_mul(40_503) ^ 0x5A5A);
+            let shared = a.intersect(&b).measure().unwrap() as f64;
+            let union = a.union(&b).measure().unwrap() as f64;
+            let jaccard = a.jaccard(&b).unwrap();
+            assert!((jaccard - shared / union).abs() < 1e-12);
+            assert_eq!(a.jaccard(&b), b.jaccard(&a));
+        }
+    }
+
+    let empty: Selection<i32> = Selection::new();
+    assert_eq!(empty.jaccard(&empty), Some(1.0));
+    let full: Selection<u128> = Selection::full();
+    assert_eq!(full.jaccard(&Selection::new()), None);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_measure_is_difference() {
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 1);
+
+    let interval = Interval::right_open(d(105), d(127));
+    assert_eq!(interval.measure(), Some(d(22)));
+    assert_eq!(Interval::open(d(0), d(10)).measure(), Some(d(10)));
+    assert_eq!(Interval::point(d(5)).measure(), Some(d(0)));
+    assert_eq!(Interval::<Decimal>::empty().measure(), Some(d(0)));
+    assert_eq!(Interval::unbounded_from(d(0)).measure(), None);
+
+    let mut sel = Selection::from(Interval::closed(d(0), d(10)));
+    sel.union_in_place(Interval::open(d(20), d(25)));
+    assert_eq!(sel.measure(), Some(d(15)));
+    sel.union_in_place(Interval::unbounded_to(d(-5)));
+    assert_eq!(sel.measure(), None);
+
+    // Intervals meeting at an excluded point share no measure.
+    let other = Selection::from(Interval::left_open(d(10), d(22)));
+    assert_eq!(sel.overlap_measure(&other), Some(d(2)));
+    let other = Selection::from(Interval::unbounded_from(d(-10)));
+    assert_eq!(sel.overlap_measure(&other), Some(d(20)));
+    assert_eq!(sel.jaccard(&other), None);
+
+    // Selections of points have zero measure.
+    let a = Selection::from(Interval::point(d(5)));
+    let b = Selection::from(Interval::point(d(7)));
+    assert_eq!(a.jaccard(&a), Some(1.0));
+    assert_eq!(a.jaccard(&b), Some(0.0));
+    let c = Selection::from(Interval::closed(d(0), d(10)));
+    assert_eq!(a.jaccard(&c), Some(0.0));
+}
+
+#[test]
+fn duration_measure_takes_differences() {
+    use std::time::Duration;
+    let s = Duration::from_secs;
+
+    assert_eq!(Interval::open(s(3), s(7)).measure(), Some(s(4)));
+    assert_eq!(Interval::point(s(3)).measure(), Some(Duration::ZERO));
+    assert_eq!(Interval::<Duration>::empty().measure(), Some(Duration::ZERO));
+    assert_eq!(Interval::unbounded_from(s(3)).measure(), None);
+
+    let mut sel = Selection::from(Interval::right_open(s(0), s(10)));
+    sel.union_in_place(Interval::closed(s(20), s(30)));
+    assert_eq!(sel.measure(), Some(s(20)));
+    let other = Selection::from(Interval::closed(s(0), s(40)));
+    assert_eq!(sel.overlap_measure(&other), Some(s(20)));
+    assert_eq!(sel.jaccard(&other), Some(0.5));
+}
@@ -0,0 +1,115 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for merging sorted interval streams.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::merge::kmerge_sorted;
+use crate::merge::merge_sorted;
+use crate::selection::Selection;
+use crate::test::Lcg;
+
+
+/// Returns a sorted stream of disjoint intervals generated from the seed.
+fn stream(seed: u32, len: usize) -> Vec<Interval<i32>> {
+    let mut lcg = Lcg::new(seed);
+    let mut next = move || lcg.below(8);
+    let mut lower = -50;
+    (0..len)
+        .map(|_| {
+            lower += next();
+            let upper = lower + next();
+            let interval = match next() % 4 {
+                0 => Interval::closed(lower, upper),
+                1 => Interval::open(lower, upper),
+                2 => Interval::left_open(lower, upper),
+                _ => Interval::right_open(lower, upper),
+            };
+            lower = upper + 1;
+            interval
+        })
+        .collect()
+}
+
+/// Returns the intervals of the union of the given streams.
+fn expected(streams: &[Vec<Interval<i32>>]) -> Vec<Interval<i32>> {
+    let sel: Selection<i32> = streams.iter().flatten().cloned().collect();
+    sel.into_interval_iter().collect()
+}
+
+
+#[test]
+fn merge_sorted_matches_selection() {
+    for seed in 0..50 {
+        let a = stream(seed, 20);
+        let b = stream(seed + 100, 15);
+        let merged: Vec<_> = merge_sorted(a.clone(), b.clone()).collect();
+        assert_eq!(merged, expected(&[a, b]), "seed {}", seed);
+    }
+}
+
+#[test]
+fn kmerge_sorted_matches_selection() {
+    for seed in 0..50 {
+        let streams: Vec<_> = (0..5)
+            .map(|k| stream(seed * 5 + k, 4 + k as usize * 3))
+            .collect();
+        let merged: Vec<_> = kmerge_sorted(streams.clone()).collect();
+        assert_eq!(merged, expected(&streams), "seed {}", seed);
+    }
+}
+
+#[test]
+fn merge_coalesces_adjacent_points() {
+    let a = vec![Interval::closed(0, 2), Interval::closed(6, 7)];
+    let b = vec![Interval::closed(3, 5), Interval::closed(9, 9)];
+    assert_eq!(merge_sorted(a, b).collect::<Vec<_>>(), [
+        Interval::closed(0, 7),
+        Interval::closed(9, 9),
+    ]);
+}
+
+#[test]
+fn merge_unbounded_and_empty() {
+    let a = vec![Interval::empty(), Interval::unbounded_to(0), Interval::empty()];
+    let b = vec![Interval::closed(-5, 3), Interval::unbounded_from(10)];
+    assert_eq!(merge_sorted(a, b).collect::<Vec<_>>(), [
+        Interval::closed(i32::MIN, 3),
+        Interval::closed(10, i32::MAX),
+    ]);
+
+    let a = vec![Interval::full()];
+    let b = vec![Interval::closed(-5, 3), Interval::unbounded_from(10)];
+    assert_eq!(merge_sorted(a, b).collect::<Vec<_>>(), [Interval::full()]);
+
+    let none: Vec<Vec<Interval<i32>>> = Vec::new();
+    assert_eq!(kmerge_sorted(none).next(), None);
+    assert_eq!(kmerge_sorted(vec![vec![Interval::<i32>::empty()]]).next(),
+        None);
+}
+
+#[test]
+fn merge_is_lazy() {
+    let a = (0..).map(|i| Interval::closed(i * 10, i * 10 + 2));
+    let b = (0..).map(|i| Interval::closed(i * 10 + 3, i * 10 + 4));
+    let merged: Vec<_> = merge_sorted(a, b).take(3).collect();
+    assert_eq!(merged, [
+        Interval::closed(0, 4),
+        Interval::closed(10, 14),
+        Interval::closed(20, 24),
+    ]);
+
+    let streams = (0..3).map(|k| (0..)
+        .map(move |i| Interval::closed(i * 10 + k, i * 10 + k)));
+    let merged: Vec<_> = kmerge_sorted(streams).take(2).collect();
+    assert_eq!(merged, [Interval::closed(0, 2), Interval::closed(10, 12)]);
+}
@@ -0,0 +1,89 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for `Selection` against `NaiveSelection`.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::naive::NaiveSelection;
+use crate::selection::Selection;
+use crate::test::Lcg;
+
+
+#[test]
+fn in_place_operations_match() {
+    for seed in 0..32 {
+        let mut sel: Selection<i8> = Selection::new();
+        let mut naive = NaiveSelection::new();
+        let mut lcg = Lcg::new(seed);
+        for i in 0..12 {
+            let interval = lcg.interval(-5, 31);
+            match i % 4 {
+                0 => {
+                    sel.union_in_place(interval);
+                    naive.union_in_place(interval);
+                },
+                1 => {
+                    sel.minus_in_place(interval);
+                    naive.minus_in_place(interval);
+                },
+                2 => {
+                    sel.symmetric_difference_in_place(interval);
+                    naive.symmetric_difference_in_place(interval);
+                },
+                _ => {
+                    sel.intersect_in_place(interval);
+                    naive.intersect_in_place(interval);
+                },
+            }
+            assert_eq!(naive, sel);
+            assert_eq!(naive.interval_count(), sel.interval_count());
+            assert_eq!(naive.point_count(), sel.point_count());
+        }
+        assert_eq!(naive.to_selection(), sel);
+        assert_eq!(NaiveSelection::from(&sel), naive);
+    }
+}
+
+#[test]
+fn set_operations_match() {
+    let build = |seed| {
+        let mut lcg = Lcg::new(seed);
+        let intervals: Vec<Interval<i8>> = (0..4)
+            .map(|_| lcg.interval(-5, 31))
+            .collect();
+        (Selection::union_all(intervals.clone()),
+            intervals.into_iter().fold(NaiveSelection::new(), |mut n, i| {
+                n.union_in_place(i);
+                n
+            }))
+    };
+    let bounds = Interval::closed(0, 20);
+    for i in 0..16 {
+        for j in 0..16 {
+            let (a, na) = build(i);
+            let (b, nb) = build(j + 100);
+            assert_eq!(na, a);
+            assert_eq!(na.intersect(&nb), a.intersect(&b));
+            assert_eq!(na.union(&nb), a.union(&b));
+            assert_eq!(na.minus(&nb), a.minus(&b));
+            assert_eq!(na.symmetric_difference(&nb),
+                a.symmetric_difference(&b));
+            assert_eq!(na.complement_within(&bounds),
+                a.complement_within(&bounds));
+            assert_eq!(na.is_subset(&nb), a.is_subset(&b));
+            assert_eq!(na.is_disjoint(&nb), a.is_disjoint(&b));
+            assert_eq!(na.intersects_selection(&nb),
+                a.intersects_selection(&b));
+            assert_eq!(na.intersects(&bounds), a.intersects(&bounds));
+        }
+    }
+}
@@ -0,0 +1,554 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Finite`] and [`Normalize`] implementations.
+//!
+//! [`Finite`] trait.Finite.html
+//! [`Normalize`] trait.Normalize.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+
+// Standard library imports.
+use std::num::NonZeroI8;
+use std::num::NonZeroU8;
+use std::num::Wrapping;
+
+
+/// Constructs a `NonZeroI8` from a nonzero value.
+fn nzi8(value: i8) -> NonZeroI8 {
+    NonZeroI8::new(value).expect("nonzero value")
+}
+
+/// Constructs a `NonZeroU8` from a nonzero value.
+fn nzu8(value: u8) -> NonZeroU8 {
+    NonZeroU8::new(value).expect("nonzero value")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Integer tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn integer_steps() {
+    assert_eq!(120i8.succ_n(7), Some(127));
+    assert_eq!(120i8.succ_n(8), None);
+    assert_eq!((-120i8).pred_n(8), Some(-128));
+    assert_eq!((-120i8).pred_n(9), None);
+    assert_eq!(0u8.succ_n(300), None);
+    assert_eq!((-128i8).steps_between(&127), Some(255));
+    assert_eq!(5u32.steps_between(&3), None);
+    assert_eq!(0u128.steps_between(&u128::MAX), None);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// NonZero tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn nonzero_unsigned_bounds() {
+    assert_eq!(NonZeroU8::MINIMUM, nzu8(1));
+    assert_eq!(NonZeroU8::MAXIMUM, nzu8(255));
+    assert_eq!(nzu8(1).pred(), None);
+    assert_eq!(nzu8(2).pred(), Some(nzu8(1)));
+    assert_eq!(nzu8(255).succ(), None);
+}
+
+#[test]
+fn nonzero_signed_skips_zero() {
+    assert_eq!(nzi8(1).pred(), Some(nzi8(-1)));
+    assert_eq!(nzi8(-1).succ(), Some(nzi8(1)));
+    assert_eq!(nzi8(-3).succ_n(5), Some(nzi8(3)));
+    assert_eq!(nzi8(3).pred_n(5), Some(nzi8(-3)));
+    assert_eq!(nzi8(-3).steps_between(&nzi8(3)), Some(5));
+    assert_eq!(nzi8(-128).pred(), None);
+    assert_eq!(nzi8(127).succ(), None);
+}
+
+#[test]
+fn nonzero_signed_iter() {
+    let interval = Interval::open(nzi8(-3), nzi8(3));
+    assert_eq!(interval.iter().collect::<Vec<_>>(),
+        [nzi8(-2), nzi8(-1), nzi8(1), nzi8(2)]);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Wrapping tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn wrapping_does_not_wrap() {
+    assert_eq!(Wrapping(255u8).succ(), None);
+    assert_eq!(Wrapping(0u8).pred(), None);
+    assert_eq!(Wrapping(3u8).succ_n(4), Some(Wrapping(7)));
+
+    let interval = Interval::open(Wrapping(3u8), Wrapping(7u8));
+    assert_eq!(interval, Interval::closed(Wrapping(4u8), Wrapping(6u8)));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Character tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn char_skips_surrogates() {
+    assert_eq!('\u{D7FF}'.succ(), Some('\u{E000}'));
+    assert_eq!('\u{E000}'.pred(), Some('\u{D7FF}'));
+    assert_eq!('\u{D7FE}'.succ_n(3), Some('\u{E001}'));
+    assert_eq!('\u{E001}'.pred_n(3), Some('\u{D7FE}'));
+    assert_eq!('\u{D7FF}'.steps_between(&'\u{E000}'), Some(1));
+    assert_eq!('\0'.steps_between(&char::MAX), Some(0x10FFFF - 0x800));
+}
+
+#[test]
+fn char_bounds() {
+    assert_eq!('\0'.pred(), None);
+    assert_eq!(char::MAX.succ(), None);
+    assert_eq!('a'.succ_n(0x110000), None);
+    assert_eq!('z'.steps_between(&'a'), None);
+}
+
+#[test]
+fn char_interval() {
+    let interval = Interval::open('a', 'e');
+    assert_eq!(interval, Interval::closed('b', 'd'));
+    assert_eq!(interval.iter().collect::<String>(), "bcd");
+
+    let gap = Interval::open('\u{D7FE}', '\u{E001}');
+    assert_eq!(gap.iter().collect::<Vec<_>>(), ['\u{D7FF}', '\u{E000}']);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// IP address tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn ipv4_steps() {
+    use std::net::Ipv4Addr;
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 255).succ(),
+        Some(Ipv4Addr::new(10, 0, 1, 0)));
+    assert_eq!(Ipv4Addr::UNSPECIFIED.pred(), None);
+    assert_eq!(Ipv4Addr::BROADCAST.succ(), None);
+    assert_eq!(Ipv4Addr::new(10, 0, 0, 0)
+            .steps_between(&Ipv4Addr::new(10, 0, 1, 0)),
+        Some(256));
+}
+
+#[test]
+fn ipv4_prefix_round_trip() {
+    use std::net::Ipv4Addr;
+    let interval = Interval::from_ipv4_prefix(Ipv4Addr::new(192, 168, 7, 9), 22)
+        .unwrap();
+    assert_eq!(interval, Interval::closed(
+        Ipv4Addr::new(192, 168, 4, 0),
+        Ipv4Addr::new(192, 168, 7, 255)));
+    assert_eq!(interval.prefixes().collect::<Vec<_>>(),
+        [(Ipv4Addr::new(192, 168, 4, 0), 22)]);
+
+    assert_eq!(Interval::from_ipv4_prefix(Ipv4Addr::BROADCAST, 0),
+        Some(Interval::full()));
+    assert_eq!(Interval::from_ipv4_prefix(Ipv4Addr::BROADCAST, 33), None);
+    assert_eq!(Interval::<Ipv4Addr>::full().prefixes().collect::<Vec<_>>(),
+        [(Ipv4Addr::UNSPECIFIED, 0)]);
+    assert_eq!(Interval::<Ipv4Addr>::empty().prefixes().count(), 0);
+}
+
+#[test]
+fn ipv4_prefixes() {
+    use std::net::Ipv4Addr;
+    let interval = Interval::closed(
+        Ipv4Addr::new(10, 0, 0, 3),
+        Ipv4Addr::new(10, 0, 1, 0));
+    assert_eq!(interval.prefixes().collect::<Vec<_>>(), [
+        (Ipv4Addr::new(10, 0, 0, 3), 32),
+        (Ipv4Addr::new(10, 0, 0, 4), 30),
+        (Ipv4Addr::new(10, 0, 0, 8), 29),
+        (Ipv4Addr::new(10, 0, 0, 16), 28),
+        (Ipv4Addr::new(10, 0, 0, 32), 27),
+        (Ipv4Addr::new(10, 0, 0, 64), 26),
+        (Ipv4Addr::new(10, 0, 0, 128), 25),
+        (Ipv4Addr::new(10, 0, 1, 0), 32),
+    ]);
+
+    let interval = Interval::unbounded_from(Ipv4Addr::new(128, 0, 0, 0));
+    assert_eq!(interval.prefixes().collect::<Vec<_>>(),
+        [(Ipv4Addr::new(128, 0, 0, 0), 1)]);
+}
+
+#[test]
+fn ipv6_prefixes() {
+    use std::net::Ipv6Addr;
+    let interval = Interval::from_ipv6_prefix(
+        Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 0), 127).unwrap();
+    assert_eq!(interval.iter().count(), 2);
+    assert_eq!(interval.prefixes().collect::<Vec<_>>(),
+        [(Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 0), 127)]);
+    assert_eq!(Interval::<Ipv6Addr>::full().prefixes().collect::<Vec<_>>(),
+        [(Ipv6Addr::UNSPECIFIED, 0)]);
+    assert_eq!(Interval::from_ipv6_prefix(Ipv6Addr::UNSPECIFIED, 129), None);
+}
+
+#[test]
+fn ipv4_prefix_round_trip_all_lengths() {
+    use std::net::Ipv4Addr;
+    let addr = Ipv4Addr::new(203, 0, 113, 77);
+    for len in 0..=32 {
+        let interval = Interval::from_ipv4_prefix(addr, len).unwrap();
+        let mask = u32::MAX.checked_shl(32 - u32::from(len)).unwrap_or(0);
+        let network = Ipv4Addr::from(u32::from(addr) & mask);
+        assert!(interval.contains(&addr));
+        assert_eq!(interval.point_count(), Some(1u128 << (32 - len)));
+        assert_eq!(interval.prefixes().collect::<Vec<_>>(), [(network, len)]);
+    }
+
+    assert_eq!(Interval::from_ipv4_prefix(addr, 32),
+        Some(Interval::point(addr)));
+    assert_eq!(Interval::from_ipv4_prefix(addr, 0), Some(Interval::full()));
+}
+
+#[test]
+fn ipv6_prefix_round_trip_all_lengths() {
+    use std::net::Ipv6Addr;
+    let addr = Ipv6Addr::new(
+        0x2001, 0xDB8, 0x85A3, 0, 0, 0x8A2E, 0x370, 0x7334);
+    for len in 0..=128 {
+        let interval = Interval::from_ipv6_prefix(addr, len).unwrap();
+        let mask = u128::MAX.checked_shl(128 - u32::from(len)).unwrap_or(0);
+        let network = Ipv6Addr::from(u128::from(addr) & mask);
+        assert!(interval.contains(&addr));
+        assert_eq!(interval.prefixes().collect::<Vec<_>>(), [(network, len)]);
+    }
+
+    assert_eq!(Interval::from_ipv6_prefix(addr, 128),
+        Some(Interval::point(addr)));
+    assert_eq!(Interval::from_ipv6_prefix(addr, 0), Some(Interval::full()));
+}
+
+#[test]
+fn ipv4_selection_minimal_prefixes() {
+    use std::net::Ipv4Addr;
+    let prefixes = [
+        (Ipv4Addr::new(10, 0, 0, 0), 25),
+        (Ipv4Addr::new(10, 0, 0, 128), 25),
+        (Ipv4Addr::new(10, 0, 1, 0), 24),
+        (Ipv4Addr::new(192, 168, 0, 1), 32),
+        (Ipv4Addr::new(192, 168, 0, 2), 31),
+    ];
+    let sel: Selection<Ipv4Addr> = prefixes
+        .iter()
+        .map(|&(addr, len)| Interval::from_ipv4_prefix(addr, len).unwrap())
+        .collect();
+
+    // Adjacent prefixes are merged into the fewest covering prefixes.
+    let minimal: Vec<_> = sel
+        .interval_iter()
+        .flat_map(|interval| interval.prefixes())
+        .collect();
+    assert_eq!(minimal, [
+        (Ipv4Addr::new(10, 0, 0, 0), 23),
+        (Ipv4Addr::new(192, 168, 0, 1), 32),
+        (Ipv4Addr::new(192, 168, 0, 2), 31),
+    ]);
+
+    let round_trip: Selection<Ipv4Addr> = minimal
+        .iter()
+        .map(|&(addr, len)| Interval::from_ipv4_prefix(addr, len).unwrap())
+        .collect();
+    assert_eq!(round_trip, sel);
+
+    let full: Selection<Ipv4Addr> = Selection::full();
+    assert_eq!(full.interval_iter()
+            .flat_map(|interval| interval.prefixes())
+            .collect::<Vec<_>>(),
+        [(Ipv4Addr::UNSPECIFIED, 0)]);
+}
+
+#[test]
+fn ipv6_selection_minimal_prefixes() {
+    use std::net::Ipv6Addr;
+    let base = Ipv6Addr::new(0x2001, 0xDB8, 0, 0, 0, 0, 0, 0);
+    let mut sel: Selection<Ipv6Addr> = Selection::full();
+    sel.minus_in_place(Interval::from_ipv6_prefix(base, 32).unwrap());
+    sel.remove_point(&Ipv6Addr::UNSPECIFIED);
+
+    let minimal: Vec<_> = sel
+        .interval_iter()
+        .flat_map(|interval| interval.prefixes())
+        .collect();
+    // Covering the space after the removed first address starts with a /128.
+    assert_eq!(minimal[0], (Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1), 128));
+    assert!(minimal.iter().all(|&(_, len)| len > 0));
+    assert!(minimal.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+    let round_trip: Selection<Ipv6Addr> = minimal
+        .iter()
+        .map(|&(addr, len)| Interval::from_ipv6_prefix(addr, len).unwrap())
+        .collect();
+    assert_eq!(round_trip, sel);
+    assert!(!round_trip.contains(&base));
+    assert!(!round_trip.contains(&Ipv6Addr::UNSPECIFIED));
+}
+
+#[test]
+fn normalization_at_extremes() {
+    let points = [i8::MIN, i8::MIN + 1, -1, 0, 1, i8::MAX - 1, i8::MAX];
+    let mut bounds = vec![Bound::Infinite];
+    for &p in &points {
+        bounds.push(Bound::Include(p));
+        bounds.push(Bound::Exclude(p));
+    }
+
+    for lower in &bounds {
+        for upper in &bounds {
+            let raw = RawInterval::new(*lower, *upper);
+            let normalized = raw.normalized();
+            let denormalized = raw.denormalized();
+            for p in i8::MIN..=i8::MAX {
+                assert_eq!(normalized.contains(&p), raw.contains(&p));
+                assert_eq!(denormalized.normalized().contains(&p),
+                    raw.contains(&p));
+            }
+            assert_eq!(normalized.denormalized().normalized(), normalized);
+
+            let interval = Interval::from(raw);
+            let count = (i8::MIN..=i8::MAX).filter(|p| raw.contains(p)).count();
+            assert_eq!(interval.iter().count(), count);
+            assert_eq!(interval.iter().rev().count(), count);
+            assert_eq!(interval.point_count(), Some(count as u128));
+            assert_eq!(interval.iter_step(3).count(), count.div_ceil(3));
+        }
+    }
+
+    assert_eq!(RawInterval::UpFrom(i8::MAX).normalized(), RawInterval::Empty);
+    assert_eq!(RawInterval::UpTo(i8::MIN).normalized(), RawInterval::Empty);
+    assert_eq!(RawInterval::To(i8::MIN).denormalized(),
+        RawInterval::UpTo(i8::MIN + 1));
+    assert_eq!(RawInterval::From(i8::MAX).denormalized(),
+        RawInterval::UpFrom(i8::MAX - 1));
+    assert_eq!(Interval::from(RawInterval::<usize>::Full).iter_step(2).len(),
+        usize::MAX / 2 + 1);
+}
+
+#[test]
+fn form_conversions_round_trip() {
+    let points = [i8::MIN, i8::MIN + 1, -1, 0, 1, i8::MAX - 1, i8::MAX];
+    for &l in &points {
+        for &u in &points {
+            let interval = Interval::closed(l, u);
+            let forms = [
+                interval.as_closed(),
+                interval.as_open(),
+                interval.as_left_open(),
+                interval.as_right_open(),
+            ];
+            for form in &forms {
+                match form {
+                    Some((lower, upper)) => assert_eq!(
+                        Interval::new(*lower, *upper), interval),
+                    None => assert!(interval.is_empty()),
+                }
+            }
+        }
+    }
+
+    let full: Interval<i8> = Interval::full();
+    assert_eq!(full.as_open(), Some((Bound::Infinite, Bound::Infinite)));
+    assert_eq!(full.as_closed(),
+        Some((Bound::Include(i8::MIN), Bound::Include(i8::MAX))));
+    assert_eq!(Interval::<i8>::empty().as_right_open(), None);
+}
+
+#[test]
+fn try_variants_do_not_panic() {
+    use crate::error::IntervalError;
+
+    assert_eq!(Interval::try_new(Bound::Include(3), Bound::Include(2)),
+        Err(IntervalError::InvertedBounds));
+    assert_eq!(Interval::try_new(Bound::Exclude(3), Bound::Exclude(3)),
+        Ok(Interval::empty()));
+    assert_eq!(Interval::try_new(Bound::Infinite, Bound::Include(-5i8)),
+        Ok(Interval::closed(i8::MIN, -5)));
+
+    let interval: Interval<u64> = Interval::full();
+    assert_eq!(interval.try_iter_step(0).err(), Some(IntervalError::ZeroStep));
+    assert!(interval.try_iter_step(2).is_ok());
+    assert_eq!(Interval::<u128>::full().try_iter_step(1).err(),
+        Some(IntervalError::TooManyPoints));
+}
+
+#[test]
+fn try_from_bound_pair() {
+    use crate::error::IntervalError;
+    use std::convert::TryFrom;
+    use std::convert::TryInto;
+
+    for l in -3..3 {
+        for u in -3..3 {
+            for &(lb, ub) in &[
+                (Bound::Include(l), Bound::Include(u)),
+                (Bound::Include(l), Bound::Exclude(u)),
+                (Bound::Exclude(l), Bound::Include(u)),
+                (Bound::Exclude(l), Bound::Exclude(u)),
+            ] {
+                let res = Interval::<i32>::try_from((lb, ub));
+                if l > u {
+                    assert_eq!(res, Err(IntervalError::InvertedBounds));
+                } else {
+                    assert_eq!(res, Ok(Interval::new(lb, ub)));
+                }
+            }
+        }
+    }
+
+    let res: Result<Interval<i32>, _> =
+        (Bound::Infinite, Bound::Exclude(0)).try_into();
+    assert_eq!(res, Ok(Interval::unbounded_up_to(0)));
+    assert_eq!(Interval::try_from((Bound::Infinite, Bound::<u8>::Infinite)),
+        Ok(Interval::full()));
+}
+
+#[test]
+fn btree_range_matches_contains() {
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+
+    let set: BTreeSet<i32> = (-10..10).collect();
+    let map: BTreeMap<i32, i32> = set.iter().map(|&p| (p, p * p)).collect();
+    let mut intervals = vec![
+        Interval::empty(),
+        Interval::full(),
+        Interval::unbounded_to(-3),
+        Interval::unbounded_up_to(-3),
+        Interval::unbounded_from(4),
+        Interval::unbounded_up_from(4),
+    ];
+    for l in -12..12 {
+        for u in l..12 {
+            intervals.push(Interval::closed(l, u));
+            intervals.push(Interval::open(l, u));
+            intervals.push(Interval::left_open(l, u));
+            intervals.push(Interval::right_open(l, u));
+        }
+    }
+
+    for interval in &intervals {
+        let expected: Vec<_> = set.iter()
+            .filter(|p| interval.contains(p))
+            .collect();
+        assert_eq!(interval.btree_set_range(&set).collect::<Vec<_>>(),
+            expected);
+        assert_eq!(interval.btree_map_range(&map)
+                .map(|(k, _)| k)
+                .collect::<Vec<_>>(),
+            expected);
+        assert_eq!(interval.as_range_bounds().is_none(), interval.is_empty());
+    }
+}
+
+#[test]
+fn iter_from_either_bound() {
+    let interval: Interval<u8> = Interval::unbounded_from(250);
+    assert_eq!(interval.iter_from_lower().collect::<Vec<_>>(),
+        [250, 251, 252, 253, 254, 255]);
+    assert_eq!(interval.iter_from_upper().take(2).collect::<Vec<_>>(),
+        [255, 254]);
+
+    let full: Interval<u128> = Interval::full();
+    assert_eq!(full.iter_from_lower().nth(1_000), Some(1_000));
+    assert_eq!(full.iter_from_upper().next(), Some(u128::MAX));
+
+    let mut iter = Interval::point('a').iter_from_lower();
+    assert_eq!(iter.next(), Some('a'));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let gap = Interval::open('\u{D7FE}', '\u{E001}');
+    assert_eq!(gap.iter_from_upper().collect::<Vec<_>>(),
+        ['\u{E000}', '\u{D7FF}']);
+    assert_eq!(Interval::<i8>::empty().iter_from_lower().next(), None);
+    assert_eq!(Interval::<i8>::empty().iter_from_upper().next(), None);
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn derived_finite_forwards() {
+    #[derive(Finite, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Id(u8);
+
+    #[derive(Finite, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Offset { value: i16 }
+
+    assert_eq!(Id::MINIMUM, Id(0));
+    assert_eq!(Id::MAXIMUM, Id(255));
+    assert_eq!(Id(3).succ(), Some(Id(4)));
+    assert_eq!(Id(0).pred(), None);
+    assert_eq!(Id(250).succ_n(5), Some(Id(255)));
+    assert_eq!(Id(250).succ_n(6), None);
+    assert_eq!(Id(3).steps_between(&Id(10)), Some(7));
+
+    assert_eq!(Offset::MINIMUM, Offset { value: i16::MIN });
+    assert_eq!(Offset { value: -1 }.succ(), Some(Offset { value: 0 }));
+    assert_eq!(Offset { value: 5 }.pred_n(10), Some(Offset { value: -5 }));
+
+    let ids = Interval::open(Id(3), Id(7));
+    assert_eq!(ids.iter().collect::<Vec<_>>(), [Id(4), Id(5), Id(6)]);
+    assert_eq!(Interval::<Offset>::full().point_count(), Some(1 << 16));
+}
+
+#[cfg(feature = "step")]
+#[test]
+fn step_types_are_finite() {
+    use crate::normalize::StepBounds;
+    use std::iter::Step;
+
+    // A day of the month, in `1..=31`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Day(u8);
+
+    impl Step for Day {
+        fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+            <u8 as Step>::steps_between(&start.0, &end.0)
+        }
+
+        fn forward_checked(start: Self, count: usize) -> Option<Self> {
+            u8::forward_checked(start.0, count)
+                .filter(|d| *d <= 31)
+                .map(Day)
+        }
+
+        fn backward_checked(start: Self, count: usize) -> Option<Self> {
+            u8::backward_checked(start.0, count)
+                .filter(|d| *d >= 1)
+                .map(Day)
+        }
+    }
+
+    impl StepBounds for Day {
+        const LEAST: Self = Day(1);
+        const GREATEST: Self = Day(31);
+    }
+
+    assert_eq!(Day(1).pred(), None);
+    assert_eq!(Day(30).succ(), Some(Day(31)));
+    assert_eq!(Day(31).succ(), None);
+    assert_eq!(Day(20).succ_n(11), Some(Day(31)));
+    assert_eq!(Day(3).steps_between(&Day(10)), Some(7));
+    assert_eq!(Day(10).steps_between(&Day(3)), None);
+
+    let days: Interval<Day> = Interval::from(Day(29)..);
+    assert_eq!(days.iter().collect::<Vec<_>>(), [Day(29), Day(30), Day(31)]);
+    assert_eq!(Interval::<Day>::full().point_count(), Some(31));
+}
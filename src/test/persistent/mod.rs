@@ -0,0 +1,106 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`PersistentSelection`].
+//!
+//! [`PersistentSelection`] struct.PersistentSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::persistent::PersistentSelection;
+use crate::selection::Selection;
+use crate::test::Lcg;
+
+
+#[test]
+fn operations_match_selection() {
+    for seed in 0..32 {
+        let mut lcg = Lcg::new(seed);
+        let mut persistent: PersistentSelection<i32> =
+            PersistentSelection::new();
+        let mut selection: Selection<i32> = Selection::new();
+
+        for step in 0..60 {
+            let interval = lcg.interval(-5, 40);
+            match step % 5 {
+                0..=2 => {
+                    persistent.union_in_place(interval);
+                    selection.union_in_place(interval);
+                },
+                3 => {
+                    persistent.minus_in_place(interval);
+                    selection.minus_in_place(interval);
+                },
+                _ => if step % 15 == 14 {
+                    persistent.intersect_in_place(interval);
+                    selection.intersect_in_place(interval);
+                },
+            }
+
+            assert_eq!(persistent.to_selection(), selection);
+            assert_eq!(persistent.interval_count(),
+                selection.interval_iter().count());
+            for p in -6..36 {
+                assert_eq!(persistent.contains(&p), selection.contains(&p));
+            }
+        }
+    }
+}
+
+#[test]
+fn clones_are_unaffected_by_changes() {
+    let mut lcg = Lcg::new(7);
+    let mut history: Vec<(PersistentSelection<i32>, Selection<i32>)> =
+        Vec::new();
+    let mut persistent = PersistentSelection::new();
+    let mut selection = Selection::new();
+
+    for step in 0..40 {
+        history.push((persistent.clone(), selection.clone()));
+        let interval = lcg.interval(-5, 40);
+        if step % 3 == 2 {
+            persistent.minus_in_place(interval);
+            selection.minus_in_place(interval);
+        } else {
+            persistent.union_in_place(interval);
+            selection.union_in_place(interval);
+        }
+    }
+
+    for (persistent, selection) in history {
+        assert_eq!(persistent.to_selection(), selection);
+    }
+}
+
+#[test]
+fn conversions_round_trip() {
+    let selection: Selection<i32> = vec![
+        Interval::closed(-3, 5),
+        Interval::open(10, 14),
+        Interval::from(20),
+    ].into_iter().collect();
+
+    let persistent = PersistentSelection::from(&selection);
+    assert_eq!(persistent.interval_count(), 3);
+    assert_eq!(persistent.to_selection(), selection);
+    assert_eq!(persistent,
+        selection.interval_iter().collect::<PersistentSelection<_>>());
+
+    let copy = persistent.clone();
+    assert!(copy.ptr_eq(&persistent));
+}
+
+#[test]
+fn intersect_with_empty_interval() {
+    let mut persistent = PersistentSelection::from(Interval::closed(0, 9));
+    persistent.intersect_in_place(Interval::empty());
+    assert!(persistent.is_empty());
+}
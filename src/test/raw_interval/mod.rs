@@ -373,3 +373,63 @@ fn complement_as_full_minus() {
     assert_eq_u!(a.minus(&From(0)),         From(0).complement().collect::<Vec<_>>());
     assert_eq_u!(a.minus(&Full),            Full.complement().collect::<Vec<_>>());
 }
+
+////////////////////////////////////////////////////////////////////////////
+// Formatting tests
+////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", Empty::<i32>),      "∅");
+    assert_eq!(format!("{}", Point(3)),          "{3}");
+    assert_eq!(format!("{}", Open(0, 3)),        "(0, 3)");
+    assert_eq!(format!("{}", LeftOpen(0, 3)),    "(0, 3]");
+    assert_eq!(format!("{}", RightOpen(0, 3)),   "[0, 3)");
+    assert_eq!(format!("{}", Closed(0, 3)),      "[0, 3]");
+    assert_eq!(format!("{}", UpTo(3)),           "(-∞, 3)");
+    assert_eq!(format!("{}", UpFrom(3)),         "(3, ∞)");
+    assert_eq!(format!("{}", To(3)),             "(-∞, 3]");
+    assert_eq!(format!("{}", From(3)),           "[3, ∞)");
+    assert_eq!(format!("{}", Full::<i32>),       "(-∞, ∞)");
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("∅".parse(),        Ok(Empty::<i32>));
+    assert_eq!("empty".parse(),    Ok(Empty::<i32>));
+    assert_eq!("{3}".parse(),      Ok(Point(3)));
+    assert_eq!("(0, 3)".parse(),   Ok(Open(0, 3)));
+    assert_eq!("(0,3]".parse(),    Ok(LeftOpen(0, 3)));
+    assert_eq!("[0, 3)".parse(),   Ok(RightOpen(0, 3)));
+    assert_eq!("[0, 3]".parse(),   Ok(Closed(0, 3)));
+    assert_eq!("(-∞, 3)".parse(),  Ok(UpTo(3)));
+    assert_eq!("(3,)".parse(),     Ok(UpFrom(3)));
+    assert_eq!("(,3]".parse(),     Ok(To(3)));
+    assert_eq!("[3, inf)".parse(), Ok(From(3)));
+    assert_eq!("(-∞, ∞)".parse(),  Ok(Full::<i32>));
+    assert_eq!("[3, 0]".parse(),   Ok(Empty::<i32>));
+}
+
+#[test]
+fn from_str_display_round_trip() {
+    let intervals: [RawInterval<i32>; 11] = [
+        Empty, Point(3), Open(0, 3), LeftOpen(0, 3), RightOpen(0, 3),
+        Closed(0, 3), UpTo(3), UpFrom(3), To(3), From(3), Full,
+    ];
+    for interval in intervals.iter() {
+        assert_eq!(interval.to_string().parse(), Ok(interval.clone()));
+    }
+}
+
+#[test]
+fn from_str_errors() {
+    use crate::interval::ParseIntervalError::*;
+    assert_eq!("0, 3".parse::<RawInterval<i32>>(),   Err(InvalidDelimiters));
+    assert_eq!("<0, 3>".parse::<RawInterval<i32>>(), Err(InvalidDelimiters));
+    assert_eq!("[0; 3]".parse::<RawInterval<i32>>(), Err(InvalidSeparator));
+    assert_eq!("[0,1,3]".parse::<RawInterval<i32>>(), Err(InvalidSeparator));
+    assert!(match "[a, 3]".parse::<RawInterval<i32>>() {
+        Err(InvalidPoint(_)) => true,
+        _                    => false,
+    });
+}
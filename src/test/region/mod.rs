@@ -0,0 +1,171 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Region2`].
+//!
+//! [`Region2`] struct.Region2.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::region::Orthotope;
+use crate::region::Region2;
+use crate::region::RegionSelection2;
+
+
+/// Constructs a closed `Region2` over the given bounds.
+fn rect(x0: i32, x1: i32, y0: i32, y1: i32) -> Region2<i32> {
+    Region2::new(Interval::closed(x0, x1), Interval::closed(y0, y1))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Region2 tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn region_empty_axes() {
+    let region = Region2::new(Interval::empty(), Interval::closed(0, 5));
+    assert!(region.is_empty());
+    assert_eq!(region, Region2::empty());
+    assert_eq!(region.area(), Some(0));
+    assert!(!region.contains(&(0, 0)));
+    assert!(rect(0, 1, 0, 1).contains_region(&region));
+}
+
+#[test]
+fn region_intersect() {
+    let a = rect(0, 5, 0, 5);
+    assert_eq!(a.intersect(&rect(3, 8, -2, 2)), rect(3, 5, 0, 2));
+    assert_eq!(a.intersect(&rect(6, 8, 0, 5)), Region2::empty());
+    assert!(a.intersects(&rect(5, 8, 5, 8)));
+    assert!(!a.intersects(&rect(6, 8, 0, 5)));
+}
+
+#[test]
+fn region_minus_is_disjoint() {
+    let a = rect(0, 9, 0, 9);
+    let b = rect(3, 5, 4, 6);
+    let pieces: Vec<_> = a.minus(&b).collect();
+    assert_eq!(pieces.len(), 4);
+
+    for x in -1..11 {
+        for y in -1..11 {
+            let count = pieces.iter().filter(|r| r.contains(&(x, y))).count();
+            let expected = a.contains(&(x, y)) && !b.contains(&(x, y));
+            assert_eq!(count, expected as usize, "({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn region_area() {
+    assert_eq!(rect(0, 3, 5, 9).area(), Some(20));
+    assert_eq!(Region2::<i64>::full().area(), None);
+    assert_eq!(Region2::<u8>::full().area(), Some(256 * 256));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// RegionSelection2 tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn region_selection_union() {
+    let selection: RegionSelection2<i32> = vec![
+        rect(0, 4, 0, 4),
+        rect(2, 6, 2, 6),
+        rect(1, 3, 1, 3),
+    ].into_iter().collect();
+
+    assert_eq!(selection.area(), Some(25 + 25 - 9));
+    for x in -1..8 {
+        for y in -1..8 {
+            let count = selection.iter()
+                .filter(|r| r.contains(&(x, y)))
+                .count();
+            let expected = rect(0, 4, 0, 4).contains(&(x, y))
+                || rect(2, 6, 2, 6).contains(&(x, y));
+            assert_eq!(count, expected as usize, "({}, {})", x, y);
+        }
+    }
+}
+
+#[test]
+fn region_selection_minus_and_intersect() {
+    let mut selection = RegionSelection2::from(rect(0, 9, 0, 9));
+    selection.minus_in_place(&rect(0, 9, 3, 5));
+    assert_eq!(selection.area(), Some(70));
+    assert!(!selection.contains(&(4, 4)));
+
+    let clipped = selection.intersect(&rect(0, 0, 0, 9));
+    assert_eq!(clipped.area(), Some(7));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Orthotope tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn orthotope_empty_axis() {
+    let o = Orthotope::new([
+        Interval::closed(0, 3),
+        Interval::empty(),
+        Interval::closed(0, 3),
+    ]);
+    assert!(o.is_empty());
+    assert_eq!(o, Orthotope::empty());
+    assert_eq!(o.volume(), Some(0));
+}
+
+#[test]
+fn orthotope_minus_is_disjoint() {
+    let a = Orthotope::new([Interval::closed(0, 5); 3]);
+    let b = Orthotope::new([
+        Interval::closed(2, 3),
+        Interval::closed(-1, 2),
+        Interval::closed(4, 9),
+    ]);
+    let pieces: Vec<_> = a.minus(&b).collect();
+
+    for x in -1..7 {
+        for y in -1..7 {
+            for z in -1..7 {
+                let p = [x, y, z];
+                let count = pieces.iter().filter(|o| o.contains(&p)).count();
+                let expected = a.contains(&p) && !b.contains(&p);
+                assert_eq!(count, expected as usize, "{:?}", p);
+            }
+        }
+    }
+}
+
+#[test]
+fn orthotope_split_and_contain() {
+    let a = Orthotope::new([Interval::closed(0, 9); 2]);
+    let (lower, upper) = a.split_at(1, &0);
+    assert!(lower.is_empty());
+    assert_eq!(upper, a);
+
+    let (lower, upper) = a.split_at(1, &5);
+    assert!(a.contains_orthotope(&lower));
+    assert!(a.contains_orthotope(&upper));
+    assert!(!lower.intersects(&upper));
+    assert_eq!(lower.volume().unwrap() + upper.volume().unwrap(), 100);
+    assert_eq!(a.intersect(&upper), upper);
+    assert_eq!(lower.enclose(&upper), a);
+}
+
+#[test]
+fn orthotope_region_conversion() {
+    let region = rect(0, 3, 5, 9);
+    let o: Orthotope<i32, 2> = region.clone().into();
+    assert_eq!(o.volume(), region.area());
+    assert_eq!(Region2::from(o), region);
+}
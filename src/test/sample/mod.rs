@@ -0,0 +1,120 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for random sampling.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+
+// External library imports.
+use rand::Rng;
+
+// Standard library imports.
+use std::collections::BTreeSet;
+
+
+#[test]
+fn interval_samples_fall_inside() {
+    let mut rng = rand::thread_rng();
+    let intervals: [Interval<i32>; 5] = [
+        Interval::open(-3, 7),
+        Interval::left_open(0, 2),
+        Interval::point(5),
+        Interval::unbounded_to(-10),
+        Interval::full(),
+    ];
+    for interval in intervals.iter() {
+        for _ in 0..100 {
+            let point = interval.sample(&mut rng).unwrap();
+            assert!(interval.contains(&point));
+            assert!(interval.contains(&rng.gen_range(*interval)));
+        }
+    }
+    assert_eq!(Interval::point(5).sample(&mut rng), Some(5));
+    assert_eq!(Interval::<i32>::empty().sample(&mut rng), None);
+    assert_eq!(Interval::<i32>::open(1, 2).sample(&mut rng), None);
+}
+
+#[test]
+fn interval_samples_reach_extremes() {
+    let mut rng = rand::thread_rng();
+    let mut seen = BTreeSet::new();
+    for _ in 0..300 {
+        let _ = seen.insert(Interval::<u8>::closed(0, 3).sample(&mut rng));
+        let _ = seen.insert(Interval::<u8>::closed(252, 255).sample(&mut rng));
+    }
+    assert_eq!(seen.into_iter().flatten().collect::<Vec<_>>(),
+        [0, 1, 2, 3, 252, 253, 254, 255]);
+}
+
+#[test]
+fn selection_samples_fall_inside() {
+    let mut rng = rand::thread_rng();
+    let mut sel: Selection<i32> = Selection::from(Interval::unbounded_to(-50));
+    sel.union_in_place(Interval::point(0));
+    sel.union_in_place(Interval::closed(10, 12));
+    sel.union_in_place(Interval::unbounded_from(100));
+
+    for _ in 0..300 {
+        assert!(sel.contains(&sel.sample(&mut rng).unwrap()));
+    }
+
+    let sel = Selection::from(Interval::point(7));
+    assert_eq!(sel.sample(&mut rng), Some(7));
+    assert_eq!(Selection::<i32>::new().sample(&mut rng), None);
+    assert!(Selection::<u8>::full().sample(&mut rng).is_some());
+}
+
+#[test]
+fn interval_subintervals_fit_and_cover_placements() {
+    let mut rng = rand::thread_rng();
+    let interval: Interval<u8> = Interval::closed(10, 19);
+
+    let mut starts = BTreeSet::new();
+    for _ in 0..300 {
+        let sub = interval.sample_subinterval(&mut rng, 8).unwrap();
+        assert_eq!(sub.point_count(), Some(8));
+        assert_eq!(sub.intersect(&interval), sub);
+        let _ = starts.insert(sub.infimum().unwrap());
+    }
+    assert_eq!(starts.into_iter().collect::<Vec<_>>(), [10, 11, 12]);
+
+    assert_eq!(interval.sample_subinterval(&mut rng, 10),
+        Some(interval));
+    assert_eq!(interval.sample_subinterval(&mut rng, 11), None);
+    assert_eq!(interval.sample_subinterval(&mut rng, 0),
+        Some(Interval::empty()));
+    assert_eq!(Interval::<u8>::empty().sample_subinterval(&mut rng, 1), None);
+    assert_eq!(Interval::<u8>::full().sample_subinterval(&mut rng, 256),
+        Some(Interval::full()));
+}
+
+#[test]
+fn selection_subintervals_fit_and_cover_placements() {
+    let mut rng = rand::thread_rng();
+    let mut sel: Selection<i32> = Selection::new();
+    sel.union_in_place(Interval::closed(0, 2));
+    sel.union_in_place(Interval::closed(10, 14));
+    sel.union_in_place(Interval::closed(20, 23));
+
+    let mut starts = BTreeSet::new();
+    for _ in 0..500 {
+        let sub = sel.sample_subinterval(&mut rng, 4).unwrap();
+        assert_eq!(sub.point_count(), Some(4));
+        assert!(sel.overlapping(&sub).any(|i| i.intersect(&sub) == sub));
+        let _ = starts.insert(sub.infimum().unwrap());
+    }
+    assert_eq!(starts.into_iter().collect::<Vec<_>>(), [10, 11, 20]);
+
+    assert_eq!(sel.sample_subinterval(&mut rng, 6), None);
+    assert_eq!(Selection::<i32>::new().sample_subinterval(&mut rng, 1), None);
+}
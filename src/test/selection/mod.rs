@@ -0,0 +1,1474 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`Selection`].
+//!
+//! [`Selection`] struct.Selection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::error::IntervalError;
+use crate::interval::Interval;
+use crate::naive::NaiveSelection;
+use crate::selection::Event;
+use crate::selection::Selection;
+use crate::test::Lcg;
+use crate::test::pseudorandom_selection;
+
+// Standard library imports.
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::mem::size_of;
+
+
+/// Returns the hash of the given value.
+fn hash_of<H>(value: &H) -> u64 where H: Hash {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Hash tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn hash_independent_of_construction() {
+    let a: Selection<i32> = vec![
+        Interval::closed(0, 3),
+        Interval::closed(8, 9),
+        Interval::open(3, 6),
+    ].into_iter().collect();
+
+    let b: Selection<i32> = vec![
+        Interval::closed(8, 9),
+        Interval::closed(0, 1),
+        Interval::right_open(2, 6),
+    ].into_iter().collect();
+
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+#[test]
+fn hash_independent_of_size_history() {
+    // Growing a selection past a few intervals and shrinking it again
+    // changes how it is stored, but not how it compares.
+    let a: Selection<i32> = (0..8)
+        .map(|i| Interval::closed(i * 10, i * 10 + 3))
+        .collect();
+
+    let mut b: Selection<i32> = (0..12)
+        .map(|i| Interval::closed(i * 10, i * 10 + 3))
+        .collect();
+    b.minus_in_place(Interval::closed(80, 200));
+
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+
+    let mut c = b.clone();
+    c.minus_in_place(Interval::closed(20, 60));
+    c.union_in_place(Interval::closed(20, 23));
+    c.union_in_place(Interval::closed(30, 33));
+    c.union_in_place(Interval::closed(40, 43));
+    c.union_in_place(Interval::closed(50, 53));
+    c.union_in_place(Interval::closed(60, 63));
+    assert_eq!(c, a);
+    assert_eq!(hash_of(&c), hash_of(&a));
+    assert_eq!(c.interval_count(), 8);
+}
+
+#[test]
+fn hash_normalized_interval() {
+    let a: Interval<i32> = Interval::open(0, 4);
+    let b: Interval<i32> = Interval::closed(1, 3);
+
+    assert_eq!(a, b);
+    assert_eq!(hash_of(&a), hash_of(&b));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Set operation tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn set_operations_are_canonical() {
+    let domain = Interval::right_open(0, 20);
+    for i in 0..16 {
+        for j in 0..16 {
+            let a = pseudorandom_selection(i, 0, 20);
+            let b = pseudorandom_selection(j + 100, 0, 20);
+            let (naive_a, naive_b) =
+                (NaiveSelection::from(&a), NaiveSelection::from(&b));
+
+            assert_eq!(a.intersect(&b), naive_a.intersect(&naive_b));
+            assert_eq!(a.union(&b), naive_a.union(&naive_b));
+            assert_eq!(a.minus(&b), naive_a.minus(&naive_b));
+            assert_eq!(a.symmetric_difference(&b),
+                naive_a.symmetric_difference(&naive_b));
+            let diff = a.diff(&b);
+            assert_eq!(diff.removed, a.minus(&b));
+            assert_eq!(diff.added, b.minus(&a));
+            let mut patched = a.clone();
+            diff.apply(&mut patched);
+            assert_eq!(patched, b);
+            assert_eq!(a.intersects_selection(&b),
+                naive_a.intersects_selection(&naive_b));
+            assert_eq!(a.is_disjoint(&b), naive_a.is_disjoint(&naive_b));
+            assert_eq!(a.is_subset(&b), naive_a.is_subset(&naive_b));
+            assert_eq!(a.is_superset(&b), naive_a.is_superset(&naive_b));
+            assert_eq!(a.intersect_iter(&b).collect::<Vec<_>>(),
+                a.intersect(&b).interval_iter().collect::<Vec<_>>());
+            assert_eq!(a.union_iter(&b).collect::<Vec<_>>(),
+                a.union(&b).interval_iter().collect::<Vec<_>>());
+            assert_eq!(a.minus_iter(&b).collect::<Vec<_>>(),
+                a.minus(&b).interval_iter().collect::<Vec<_>>());
+            for sel in &[a.intersect(&b), a.union(&b), a.minus(&b)] {
+                assert_eq!(sel.interval_count(), sel.interval_iter().count());
+
+                let intervals: Vec<_> = sel.interval_iter().collect();
+                let mut reversed: Vec<_> = sel.interval_iter().rev().collect();
+                reversed.reverse();
+                assert_eq!(intervals, reversed);
+                for index in 0..=intervals.len() {
+                    assert_eq!(sel.get(index), intervals.get(index).cloned());
+                }
+                assert_eq!(sel.first(), intervals.first().cloned());
+                assert_eq!(sel.iter_points().collect::<Vec<_>>(),
+                    (0..20).filter(|p| sel.contains(p)).collect::<Vec<_>>());
+                assert_eq!(sel.last(), intervals.last().cloned());
+            }
+            assert!(a.intersect(&b).is_subset(&a));
+            assert!(a.union(&b).is_superset(&b));
+
+            let mut complement = a.complement();
+            complement.intersect_in_place(domain);
+            assert_eq!(complement, naive_a.complement_within(&domain));
+            assert_eq!(a.gaps_within(domain).collect::<Selection<_>>(),
+                complement);
+            assert_eq!(a.complement_within(&domain), complement);
+            assert_eq!(a.gaps_within(domain).count(),
+                complement.interval_count());
+
+            for (l, r) in [(0, 19), (3, 3), (5, 12), (-4, 2), (18, 25)] {
+                let query = Interval::closed(l, r);
+                assert_eq!(a.overlapping(&query).collect::<Vec<_>>(),
+                    a.interval_iter()
+                        .filter(|i| i.intersects(&query))
+                        .collect::<Vec<_>>());
+            }
+
+            let inner = a.enclose();
+            assert_eq!(a.gaps().collect::<Vec<_>>(),
+                a.gaps_within(inner).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn symmetric_difference_unbounded() {
+    let a: Selection<i32> = Selection::full();
+    let b: Selection<i32> = Selection::from(Interval::closed(0, 5));
+
+    assert_eq!(a.symmetric_difference(&b), b.complement());
+    assert_eq!(b.symmetric_difference(&a), b.complement());
+    assert_eq!(a.symmetric_difference(&a), Selection::new());
+    assert_eq!(b.symmetric_difference(&Selection::new()), b);
+}
+
+#[test]
+fn diff_unbounded() {
+    let a: Selection<i32> = Selection::full();
+    let b: Selection<i32> = Selection::from(Interval::closed(0, 5));
+
+    let diff = a.diff(&b);
+    assert_eq!(diff.removed, b.complement());
+    assert!(diff.added.is_empty());
+    assert_eq!(diff.clone().inverse(), b.diff(&a));
+    assert!(a.diff(&a).is_empty());
+}
+
+#[test]
+fn builder_matches_sequential_operations() {
+    let mut lcg = Lcg::new(17);
+
+    for _ in 0..32 {
+        let mut builder = Selection::builder();
+        let mut expected = Selection::new();
+        for _ in 0..24 {
+            let l = lcg.point(-5, 30);
+            let interval = lcg.bounded_interval(-5, 30);
+            match lcg.below(4) {
+                0 => {
+                    builder = builder.remove(interval);
+                    expected.minus_in_place(interval);
+                },
+                1 => {
+                    builder = builder.remove_point(l);
+                    expected.remove_point(&l);
+                },
+                2 => {
+                    builder = builder.insert_point(l);
+                    expected.insert_point(l);
+                },
+                _ => {
+                    builder = builder.insert(interval);
+                    expected.union_in_place(interval);
+                },
+            }
+        }
+        builder.extend(vec![Interval::closed(40, 42), Interval::point(44)]);
+        expected.union_in_place(Interval::closed(40, 42));
+        expected.insert_point(44);
+
+        assert_eq!(builder.build(), expected);
+    }
+}
+
+#[test]
+fn minkowski_operations_match_pointwise() {
+    for seed in 0..16 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        for (l, u) in [(0, 0), (-2, 1), (1, 3), (-3, -1), (0, 4)] {
+            let w = Interval::closed(l, u);
+            let in_sel = |p: i32| (0..20).contains(&p) && sel.contains(&p);
+
+            let dilated = sel.minkowski_add(&w);
+            for p in -10..30 {
+                assert_eq!(dilated.contains(&p),
+                    (l..=u).any(|q| in_sel(p - q)));
+            }
+
+            let eroded = sel.minkowski_sub(&w);
+            for p in -10..30 {
+                assert_eq!(eroded.contains(&p),
+                    (l..=u).all(|q| in_sel(p + q)));
+            }
+            assert!(eroded.minkowski_add(&w).is_subset(&sel));
+        }
+    }
+
+    let sel: Selection<i32> = Selection::from(Interval::closed(0, 5));
+    assert_eq!(sel.minkowski_add(&Interval::empty()), Selection::new());
+    assert_eq!(sel.minkowski_sub(&Interval::empty()), Selection::full());
+    assert_eq!(sel.minkowski_sub(&Interval::closed(0, 6)), Selection::new());
+}
+
+#[test]
+fn minkowski_operations_at_extremes() {
+    let w = Interval::closed(-1, 1);
+    let mut sel: Selection<i32> = Interval::closed(i32::MIN, 0).into();
+    sel.union_in_place(Interval::closed(10, i32::MAX));
+
+    assert_eq!(Selection::<i32>::full().minkowski_add(&w), Selection::full());
+    assert_eq!(sel.minkowski_add(&w).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(i32::MIN, 1),
+        Interval::closed(9, i32::MAX),
+    ]);
+    assert_eq!(sel.minkowski_sub(&w).interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(i32::MIN + 1, -1),
+        Interval::closed(11, i32::MAX - 1),
+    ]);
+
+    // Eroded bounds past the extremes saturate.
+    let sel: Selection<i32> = Selection::from(Interval::closed(i32::MIN, 0));
+    assert_eq!(sel.minkowski_sub(&Interval::closed(1, 2)),
+        Selection::from(Interval::closed(i32::MIN, -2)));
+    let sel: Selection<i32> = Selection::from(Interval::closed(0, i32::MAX));
+    assert_eq!(sel.minkowski_sub(&Interval::closed(-2, -1)),
+        Selection::from(Interval::closed(2, i32::MAX)));
+
+    // Dilating past the extremes drops the unrepresentable points.
+    let sel: Selection<i32> = Selection::from(Interval::point(i32::MAX));
+    assert_eq!(sel.minkowski_add(&Interval::closed(1, 2)), Selection::new());
+    let sel: Selection<u8> = Selection::from(Interval::closed(250, 255));
+    assert_eq!(sel.minkowski_add(&Interval::closed(0, 10)),
+        Selection::from(Interval::closed(250, 255)));
+}
+
+#[test]
+fn partial_cmp_by_inclusion_matches_subsets() {
+    use std::cmp::Ordering;
+    for i in 0..16 {
+        for j in 0..16 {
+            let a = pseudorandom_selection::<i32>(i, 0, 20).intersect(
+                &pseudorandom_selection(j + 50, 0, 20));
+            let b: Selection<i32> = pseudorandom_selection(j, 0, 20);
+            let expected = match (a.is_subset(&b), b.is_subset(&a)) {
+                (true,  true)  => Some(Ordering::Equal),
+                (true,  false) => Some(Ordering::Less),
+                (false, true)  => Some(Ordering::Greater),
+                (false, false) => None,
+            };
+            assert_eq!(a.partial_cmp_by_inclusion(&b), expected);
+            assert_eq!(b.partial_cmp_by_inclusion(&a),
+                expected.map(Ordering::reverse));
+        }
+    }
+
+    let intervals = [
+        Interval::empty(),
+        Interval::point(3),
+        Interval::closed(0, 5),
+        Interval::open(-1, 6),
+        Interval::closed(4, 9),
+        Interval::unbounded_from(2),
+        Interval::full(),
+    ];
+    for a in &intervals {
+        for b in &intervals {
+            let (sa, sb) = (Selection::from(*a), Selection::from(*b));
+            assert_eq!(a.partial_cmp_by_inclusion(b),
+                sa.partial_cmp_by_inclusion(&sb));
+        }
+    }
+}
+
+#[test]
+fn in_place_operations_accept_ranges() {
+    let mut by_range: Selection<i32> = Selection::new();
+    by_range.union_in_place(0..10);
+    by_range.union_in_place(15..);
+    by_range.minus_in_place(3..=5);
+    by_range.intersect_in_place(..=17);
+    by_range.symmetric_difference_in_place(8);
+
+    let mut by_interval: Selection<i32> = Selection::new();
+    by_interval.union_in_place(Interval::right_open(0, 10));
+    by_interval.union_in_place(Interval::unbounded_from(15));
+    by_interval.minus_in_place(Interval::closed(3, 5));
+    by_interval.intersect_in_place(Interval::unbounded_to(17));
+    by_interval.symmetric_difference_in_place(Interval::point(8));
+
+    assert_eq!(by_range, by_interval);
+    assert_eq!(Interval::from(3..=3), Interval::point(3));
+}
+
+#[test]
+fn display_union_notation() {
+    let mut sel: Selection<i32> = Selection::from(Interval::right_open(1, 3));
+    sel.union_in_place(Interval::point(5));
+    sel.union_in_place(Interval::unbounded_up_from(6));
+    assert_eq!(sel.to_string(), format!("[1, 2] ∪ {{5}} ∪ [7, {}]", i32::MAX));
+
+    assert_eq!(Selection::<i32>::new().to_string(), "∅");
+    assert_eq!(Selection::from(Interval::closed(-4, 4)).to_string(),
+        "[-4, 4]");
+}
+
+#[test]
+fn from_str_unions() {
+    use crate::interval::ParseIntervalError::*;
+
+    let mut expected: Selection<i32> = Selection::from(Interval::closed(1, 2));
+    expected.union_in_place(Interval::point(5));
+    expected.union_in_place(Interval::unbounded_from(7));
+
+    assert_eq!("[1, 3) ∪ {5} ∪ [7, ∞)".parse(), Ok(expected.clone()));
+    assert_eq!("[1,3)u{5}U[7,]".parse(), Ok(expected.clone()));
+    assert_eq!(" [7, inf) , {5}, (0, 2] ".parse(), Ok(expected));
+    assert_eq!("∅".parse(), Ok(Selection::<i32>::new()));
+    assert_eq!("[0, 4] ∪ [2, 6]".parse(),
+        Ok(Selection::from(Interval::closed(0, 6))));
+
+    assert_eq!("".parse::<Selection<i32>>(), Err(InvalidDelimiters));
+    assert_eq!("[1, 3) ∪ ".parse::<Selection<i32>>(), Err(InvalidDelimiters));
+    assert!("[1, 3) [5, 6]".parse::<Selection<i32>>().is_err());
+    assert_eq!("[1; 3)".parse::<Selection<i32>>(), Err(InvalidSeparator));
+    assert!(matches!("{x}".parse::<Selection<i32>>(), Err(InvalidPoint(_))));
+}
+
+#[test]
+fn from_str_display_round_trip() {
+    for seed in 0..32 {
+        let sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+        assert_eq!(sel.to_string().parse(), Ok(sel));
+    }
+}
+
+#[test]
+fn construction_macros() {
+    assert_eq!(crate::interval!(2..6), Interval::closed(2, 5));
+    assert_eq!(crate::interval!(-3..=3), Interval::closed(-3, 3));
+    assert_eq!(crate::interval!(4..), Interval::unbounded_from(4));
+    assert_eq!(crate::interval!(..4), Interval::unbounded_up_to(4));
+    assert_eq!(crate::interval!(..), Interval::<i32>::full());
+    assert_eq!(crate::interval!('q'), Interval::point('q'));
+
+    let sel: Selection<i32> = crate::selection![0..4, 9, 2..=5, ..=-10,];
+    let mut expected = Selection::from(Interval::closed(0, 5));
+    expected.union_in_place(Interval::point(9));
+    expected.union_in_place(Interval::unbounded_to(-10));
+    assert_eq!(sel, expected);
+    assert_eq!(crate::selection![7], Selection::from(Interval::point(7)));
+    assert_eq!(crate::selection![], Selection::<i32>::new());
+}
+
+#[test]
+fn subset_unbounded() {
+    let full: Selection<i32> = Selection::full();
+    let empty: Selection<i32> = Selection::new();
+    let sel: Selection<i32> = Selection::from(Interval::unbounded_from(3));
+
+    assert!(sel.is_subset(&full));
+    assert!(!full.is_subset(&sel));
+    assert!(empty.is_subset(&sel));
+    assert!(empty.is_disjoint(&full));
+    assert!(sel.is_disjoint(&sel.complement()));
+    assert!(!sel.is_disjoint(&full));
+}
+
+#[test]
+fn point_count_large() {
+    let sel: Selection<i128> = Selection::full();
+    assert_eq!(sel.point_count(), None);
+
+    let sel: Selection<u64> = Selection::from(Interval::closed(0, 9))
+        .union(&Selection::from(Interval::unbounded_from(u64::MAX - 4)));
+    assert_eq!(sel.point_count(), Some(15));
+}
+
+#[test]
+fn iter_points_from_both_ends() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 2));
+    sel.union_in_place(Interval::closed(5, 6));
+    sel.union_in_place(Interval::point(9));
+
+    let mut iter = sel.iter_points();
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.next_back(), Some(6));
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(5));
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let mut iter = sel.into_iter();
+    assert_eq!(iter.next_back(), Some(9));
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 5, 6]);
+}
+
+#[test]
+fn gaps_unbounded() {
+    let mut sel: Selection<i32> = Selection::from(Interval::unbounded_to(0));
+    sel.union_in_place(Interval::closed(5, 10));
+    sel.union_in_place(Interval::unbounded_from(20));
+
+    assert_eq!(sel.gaps().collect::<Vec<_>>(),
+        [Interval::closed(1, 4), Interval::closed(11, 19)]);
+    assert_eq!(sel.gaps_within(Interval::closed(3, 12)).collect::<Vec<_>>(),
+        [Interval::closed(3, 4), Interval::closed(11, 12)]);
+    assert_eq!(sel.gaps_within(Interval::empty()).count(), 0);
+    assert_eq!(Selection::<i32>::new().gaps().count(), 0);
+    assert_eq!(Selection::<i32>::new()
+        .gaps_within(Interval::closed(1, 3))
+        .collect::<Vec<_>>(),
+        [Interval::closed(1, 3)]);
+}
+
+#[test]
+fn closure_keeps_components() {
+    let mut sel: Selection<i32> = Selection::from(Interval::open(0, 5));
+    sel.union_in_place(Interval::right_open(7, 9));
+    sel.union_in_place(Interval::unbounded_from(20));
+
+    assert_eq!(sel.closure(), sel);
+    assert_eq!(sel.closure().interval_iter().collect::<Vec<_>>(), [
+        Interval::closed(1, 4),
+        Interval::closed(7, 8),
+        Interval::closed(20, i32::MAX),
+    ]);
+    assert_eq!(Selection::<i32>::new().closure(), Selection::new());
+}
+
+#[test]
+fn retain_keeps_neighbors() {
+    for seed in 0..64 {
+        let sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+        let intervals: Vec<_> = sel.interval_iter().collect();
+        for skip in 0..intervals.len() {
+            let mut retained = sel.clone();
+            retained.retain(|interval| interval != &intervals[skip]);
+
+            let mut expected = intervals.clone();
+            let _ = expected.remove(skip);
+            assert_eq!(retained.interval_iter().collect::<Vec<_>>(), expected);
+            let expected: Selection<_> = expected.into_iter().collect();
+            assert_eq!(retained, expected);
+        }
+    }
+}
+
+#[test]
+fn insert_and_remove_extreme_points() {
+    let mut sel: Selection<u8> = Selection::new();
+    sel.insert_point(0);
+    sel.insert_point(255);
+    sel.insert_point(1);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, 1), Interval::point(255)]);
+
+    sel.remove_point(&0);
+    sel.remove_point(&255);
+    assert_eq!(sel, Selection::from(Interval::point(1)));
+
+    let mut sel: Selection<u8> = Selection::full();
+    sel.remove_point(&0);
+    sel.remove_point(&255);
+    assert_eq!(sel, Selection::from(Interval::closed(1, 254)));
+    sel.insert_point(255);
+    assert_eq!(sel, Selection::from(Interval::closed(1, 255)));
+}
+
+#[test]
+fn insert_and_remove_points_in_large_selection() {
+    // Enough intervals that the tines are stored in a tree.
+    let mut sel: Selection<i32> = (0..20)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+    sel.insert_point(5);
+    sel.insert_point(7);
+    sel.insert_point(6);
+    sel.remove_point(&12);
+    sel.remove_point(&10);
+    sel.remove_point(&50);
+    sel.insert_point(50);
+    sel.remove_point(&52);
+    sel.insert_point(52);
+
+    let mut expected: Selection<i32> = (0..20)
+        .map(|i| Interval::closed(i * 10, i * 10 + 4))
+        .collect();
+    expected.union_in_place(Interval::closed(5, 7));
+    expected.minus_in_place(Interval::point(10));
+    expected.minus_in_place(Interval::point(12));
+    assert_eq!(sel, expected);
+    assert_eq!(sel.interval_iter().take(4).collect::<Vec<_>>(), [
+        Interval::closed(0, 7),
+        Interval::point(11),
+        Interval::closed(13, 14),
+        Interval::closed(20, 24),
+    ]);
+    for p in -1..=200 {
+        assert_eq!(sel.contains(&p), expected.contains(&p));
+    }
+}
+
+#[test]
+fn split_at_partitions_points() {
+    for seed in 0..32 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        for pivot in -1..=21 {
+            let (lower, upper) = sel.split_at(&pivot);
+            let naive = NaiveSelection::from(&sel);
+            assert_eq!(lower, naive.iter()
+                .filter(|p| *p < pivot)
+                .collect::<NaiveSelection<_>>());
+            assert_eq!(upper, naive.iter()
+                .filter(|p| *p >= pivot)
+                .collect::<NaiveSelection<_>>());
+        }
+    }
+
+    let (lower, upper) = Selection::<u8>::full().split_at(&0);
+    assert!(lower.is_empty());
+    assert!(upper.is_full());
+}
+
+#[test]
+fn overlapping_unbounded() {
+    let mut sel: Selection<i32> = Selection::from(Interval::unbounded_to(0));
+    sel.union_in_place(Interval::closed(5, 10));
+    sel.union_in_place(Interval::unbounded_from(20));
+
+    assert_eq!(sel.overlapping(&Interval::full()).count(), 3);
+    assert_eq!(sel.overlapping(&Interval::empty()).count(), 0);
+    assert_eq!(sel.overlapping(&Interval::unbounded_from(10))
+        .collect::<Vec<_>>(),
+        [Interval::closed(5, 10), Interval::closed(20, i32::MAX)]);
+    assert_eq!(sel.overlapping(&Interval::closed(11, 19)).count(), 0);
+    assert_eq!(sel.overlapping(&Interval::point(-50)).collect::<Vec<_>>(),
+        [Interval::closed(i32::MIN, 0)]);
+}
+
+#[test]
+fn nearest_point_matches_scan() {
+    for seed in 0..64 {
+        let sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+        for p in -3..23 {
+            let nearest = (0..20)
+                .filter(|q| sel.contains(q))
+                .min_by_key(|q| ((q - p).abs(), *q));
+            assert_eq!(sel.nearest_point(&p), nearest);
+            assert_eq!(sel.distance_to(&p),
+                nearest.map(|q| (q - p).abs() as usize));
+        }
+    }
+
+    let sel: Selection<u8> = Selection::from(Interval::point(255));
+    assert_eq!(sel.nearest_point(&0), Some(255));
+    assert_eq!(sel.distance_to(&0), Some(255));
+    assert_eq!(Selection::<u8>::new().nearest_point(&0), None);
+    assert_eq!(Selection::<u8>::full().distance_to(&9), Some(0));
+}
+
+#[test]
+fn find_gap_matches_scan() {
+    for seed in 0..64 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        for from in 0..20 {
+            for size in 1..4 {
+                let domain = Interval::unbounded_from(from);
+                let gap = sel.find_gap(size, &from);
+                let expected = sel.complement()
+                    .overlapping(&domain)
+                    .map(|gap| gap.intersect(&domain))
+                    .find(|gap| gap.point_count() >= Some(size as u128));
+                assert_eq!(gap, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn translate_moves_points() {
+    for seed in 0..32 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        for offset in -3..3 {
+            let moved = sel.translate(offset).unwrap();
+            let offset = offset as i32;
+            assert_eq!(moved, (-3..23)
+                .filter(|p| sel.contains(&(p - offset)))
+                .collect::<Selection<_>>());
+        }
+    }
+}
+
+#[test]
+fn translate_extreme_points() {
+    let sel: Selection<u8> = Selection::from(Interval::closed(250, 255));
+    assert_eq!(sel.translate(-250),
+        Some(Selection::from(Interval::closed(0, 5))));
+    assert_eq!(sel.translate(1), None);
+    assert_eq!(sel.translate(-251), None);
+
+    let sel: Selection<u8> = Selection::from(Interval::closed(0, 3))
+        .union(&Selection::from(Interval::closed(5, 9)));
+    let moved = Selection::from(Interval::closed(246, 249))
+        .union(&Selection::from(Interval::closed(251, 255)));
+    assert_eq!(sel.translate(246), Some(moved));
+    assert_eq!(Selection::<u8>::full().translate(0),
+        Some(Selection::full()));
+    assert_eq!(Selection::<u8>::full().translate(1), None);
+}
+
+#[test]
+fn map_monotone_maps_bounds() {
+    for seed in 0..32 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        let mapped = sel.map_monotone(|p| p * 3 + 1);
+        let expected: Selection<i32> = sel.interval_iter()
+            .map(|i| Interval::closed(
+                i.infimum().unwrap() * 3 + 1,
+                i.supremum().unwrap() * 3 + 1))
+            .collect();
+        assert_eq!(mapped, expected);
+        assert_eq!(sel.map_monotone(|p| *p), sel);
+    }
+}
+
+#[test]
+fn map_monotone_merges_adjacent() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 3));
+    sel.union_in_place(Interval::closed(6, 9));
+
+    // Intervals separated by a gap may become adjacent.
+    let mapped = sel.map_monotone(|p| if *p < 5 { *p } else { p - 2 });
+    assert_eq!(mapped, Selection::from(Interval::closed(0, 7)));
+
+    assert_eq!(sel.checked_map_monotone(|p| p / 4), None);
+    assert_eq!(sel.checked_map_monotone(|p| -p), None);
+    assert_eq!(Selection::<i32>::new().checked_map_monotone(|p| -p),
+        Some(Selection::new()));
+}
+
+#[test]
+fn interval_map_monotone_matches_selection() {
+    let intervals = [
+        Interval::empty(),
+        Interval::point(4),
+        Interval::open(-3, 8),
+        Interval::left_open(2, 3),
+        Interval::unbounded_to(5),
+        Interval::unbounded_from(-1),
+        Interval::full(),
+    ];
+    for interval in &intervals {
+        let mapped: Interval<i64> = interval
+            .map_monotone(|p| i64::from(*p) * 10 - 3);
+        let expected = Selection::from(*interval)
+            .map_monotone(|p| i64::from(*p) * 10 - 3);
+        assert_eq!(mapped, expected);
+    }
+
+    let cents: Interval<u32> = Interval::closed(150, 1999);
+    assert_eq!(cents.map_monotone(|c| c / 100), Interval::closed(1, 19));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn interval_map_monotone_decreasing() {
+    let _ = Interval::closed(0, 5).map_monotone(|p: &i32| -p);
+}
+
+#[test]
+fn complement_within_unbounded() {
+    let mut sel: Selection<i32> = Selection::from(Interval::unbounded_to(0));
+    sel.union_in_place(Interval::closed(5, 10));
+
+    assert_eq!(sel.complement_within(&Interval::full()), sel.complement());
+    assert_eq!(sel.complement_within(&Interval::unbounded_to(7)),
+        Selection::from(Interval::closed(1, 4)));
+    assert_eq!(sel.complement_within(&Interval::empty()), Selection::new());
+}
+
+#[test]
+fn cross_type_equality() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(1, 5));
+    assert_eq!(sel, Interval::closed(1, 5));
+    assert_eq!(Interval::right_open(1, 6), sel);
+    assert_eq!(sel, Interval::open(0, 6));
+    assert_ne!(sel, Interval::closed(1, 4));
+
+    sel.union_in_place(Interval::closed(7, 8));
+    assert_ne!(sel, Interval::closed(1, 8));
+    assert_ne!(sel, sel.enclose());
+
+    let empty: Selection<i32> = Selection::new();
+    assert_eq!(empty, Interval::empty());
+    assert_eq!(empty, Interval::open(3, 4));
+    assert_ne!(empty, Interval::point(3));
+}
+
+#[test]
+fn union_all_matches_union_in_place() {
+    let mut lcg = Lcg::new(11);
+
+    for _ in 0..200 {
+        let intervals: Vec<Interval<i32>> = (0..lcg.below(8))
+            .map(|_| lcg.interval(0, 20))
+            .collect();
+
+        let mut expected = Selection::new();
+        for interval in intervals.iter().cloned() {
+            expected.union_in_place(interval);
+        }
+        let sel = Selection::union_all(intervals);
+        assert_eq!(sel, expected);
+        assert_eq!(sel.interval_count(), expected.interval_count());
+    }
+}
+
+#[test]
+fn from_sorted_disjoint_matches_union_all() {
+    for seed in 0..32 {
+        for sel in &[
+            pseudorandom_selection(seed, 0, 20),
+            pseudorandom_selection(seed, 0, 20).complement(),
+        ] {
+            let intervals: Vec<_> = sel.interval_iter().collect();
+            assert_eq!(&Selection::from_sorted_disjoint(intervals), sel);
+
+            // Splitting each interval in two leaves adjacent intervals.
+            let split: Vec<_> = sel
+                .interval_iter()
+                .flat_map(|interval| {
+                    let (l, r) = (interval.infimum().unwrap(),
+                        interval.supremum().unwrap());
+                    let mid = (i64::from(l) + i64::from(r))
+                        .div_euclid(2) as i32;
+                    vec![
+                        Interval::closed(l, mid),
+                        Interval::right_open(mid, r),
+                        Interval::point(r),
+                    ]
+                })
+                .collect();
+            let built = Selection::from_sorted_disjoint(split);
+            assert_eq!(&built, sel);
+            assert_eq!(built.interval_count(), sel.interval_count());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn from_sorted_disjoint_unsorted() {
+    let _ = Selection::from_sorted_disjoint(vec![
+        Interval::closed(5, 8),
+        Interval::closed(0, 2),
+    ]);
+}
+
+#[test]
+fn pop_drains_intervals_in_order() {
+    for seed in 0..16 {
+        let sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+        let intervals: Vec<_> = sel.interval_iter().collect();
+
+        let mut front = sel.clone();
+        let mut popped = Vec::new();
+        while let Some(interval) = front.pop_first() {
+            popped.push(interval);
+            assert_eq!(front.interval_count(), intervals.len() - popped.len());
+        }
+        assert_eq!(popped, intervals);
+
+        let mut back = sel.clone();
+        let mut popped = Vec::new();
+        while let Some(interval) = back.pop_last() { popped.push(interval); }
+        popped.reverse();
+        assert_eq!(popped, intervals);
+    }
+
+    // Intervals separated by a single excluded point.
+    let mut sel = Selection::from(Interval::open(0, 10));
+    sel.remove_point(&5);
+    assert_eq!(sel.pop_first(), Some(Interval::open(0, 5)));
+    assert_eq!(sel, Interval::open(5, 10));
+
+    let mut sel = Selection::full();
+    sel.remove_point(&0i8);
+    assert_eq!(sel.pop_last(), Some(Interval::unbounded_up_from(0)));
+    assert_eq!(sel, Interval::unbounded_up_to(0));
+}
+
+#[test]
+fn take_points_removes_lowest() {
+    for seed in 0..16 {
+        for n in 0..22 {
+            let mut sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+            let points: Vec<_> = sel.iter().collect();
+            let taken = sel.take_points(n);
+
+            let split = n.min(points.len());
+            assert_eq!(taken.iter().collect::<Vec<_>>(), points[..split]);
+            assert_eq!(sel.iter().collect::<Vec<_>>(), points[split..]);
+        }
+    }
+
+    let mut sel: Selection<u128> = Selection::full();
+    assert_eq!(sel.take_points(usize::MAX),
+        Interval::closed(0, usize::MAX as u128 - 1));
+    assert_eq!(sel.infimum(), Some(usize::MAX as u128));
+
+    let mut sel = Selection::from(Interval::closed(250u8, 255));
+    assert_eq!(sel.take_points(10), Interval::closed(250, 255));
+    assert!(sel.is_empty());
+}
+
+#[test]
+fn rank_and_select_are_inverses() {
+    for seed in 0..16 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        for (index, point) in sel.iter().enumerate() {
+            assert_eq!(sel.rank(&point), Some(index as u128));
+            assert_eq!(sel.select(index as u128), Some(point));
+        }
+        let count = sel.point_count().unwrap();
+        assert_eq!(sel.select(count), None);
+        for point in -5..25 {
+            assert_eq!(sel.rank(&point).is_some(), sel.contains(&point));
+        }
+    }
+
+    let mut sel: Selection<u64> = Selection::full();
+    sel.remove_point(&5);
+    assert_eq!(sel.rank(&6), Some(5));
+    assert_eq!(sel.select(5), Some(6));
+    assert_eq!(sel.select(u64::MAX as u128 - 1), Some(u64::MAX));
+    assert_eq!(sel.rank(&u64::MAX), Some(u64::MAX as u128 - 1));
+    assert_eq!(sel.select(u64::MAX as u128), None);
+}
+
+#[test]
+fn infimum_and_supremum_are_normalized() {
+    let mut sel: Selection<i32> = Selection::from(Interval::open(-3, 5));
+    sel.union_in_place(Interval::open(8, 12));
+    assert_eq!(sel.infimum(), Some(-2));
+    assert_eq!(sel.supremum(), Some(11));
+    assert_eq!(Selection::<i32>::new().infimum(), None);
+}
+
+#[test]
+fn try_from_sorted_disjoint_unsorted() {
+    assert_eq!(Selection::try_from_sorted_disjoint(vec![
+            Interval::closed(5, 8),
+            Interval::closed(0, 2),
+        ]),
+        Err(IntervalError::UnsortedIntervals));
+    assert_eq!(Selection::try_from_sorted_disjoint(vec![
+            Interval::closed(0, 2),
+            Interval::closed(1, 8),
+        ]),
+        Ok(Selection::from(Interval::closed(0, 8))));
+}
+
+#[test]
+fn intersect_all_matches_pairwise() {
+    let selections: Vec<Selection<i32>> = (0..8)
+        .map(|seed| pseudorandom_selection(seed, 0, 20))
+        .chain((0..4).map(|seed| pseudorandom_selection::<i32>(seed, 0, 20)
+            .complement()))
+        .collect();
+
+    for start in 0..selections.len() {
+        for end in start..=selections.len() {
+            let group = &selections[start..end];
+            let expected = group
+                .iter()
+                .fold(Selection::full(), |acc, sel| acc.intersect(sel));
+            let sel = Selection::intersect_all(group);
+            assert_eq!(sel, expected);
+            assert_eq!(sel.interval_count(), expected.interval_count());
+        }
+    }
+}
+
+#[test]
+fn extend_with_points_and_intervals() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 3));
+    sel.extend(vec![5, 4, 9]);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, 5), Interval::point(9)]);
+
+    sel.extend(vec![
+        Interval::open(5, 9),
+        Interval::empty(),
+        Interval::new(Bound::Infinite, Bound::Exclude(-5)),
+    ]);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::unbounded_to(-6), Interval::closed(0, 9)]);
+
+    sel.extend(vec![
+        (Bound::Exclude(9), Bound::Include(12)),
+        (Bound::Include(20), Bound::Exclude(15)),
+    ]);
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::unbounded_to(-6), Interval::closed(0, 12)]);
+    sel.extend(vec![(Bound::Exclude(-6), Bound::Infinite)]);
+    assert_eq!(sel, Selection::full());
+}
+
+#[test]
+fn set_operation_iters_unbounded() {
+    for i in 0..8 {
+        for j in 0..8 {
+            let a = pseudorandom_selection::<i32>(i, 0, 20).complement();
+            let b: Selection<i32> = pseudorandom_selection(j + 100, 0, 20);
+            for (a, b) in [(&a, &b), (&b, &a), (&a, &a.complement())] {
+                assert_eq!(a.intersect_iter(b).collect::<Vec<_>>(),
+                    a.intersect(b).interval_iter().collect::<Vec<_>>());
+                assert_eq!(a.union_iter(b).collect::<Vec<_>>(),
+                    a.union(b).interval_iter().collect::<Vec<_>>());
+                assert_eq!(a.minus_iter(b).collect::<Vec<_>>(),
+                    a.minus(b).interval_iter().collect::<Vec<_>>());
+            }
+        }
+    }
+}
+
+#[test]
+fn cursor_steps_through_segments() {
+    for seed in 0..16 {
+        for sel in &[
+            pseudorandom_selection(seed, 0, 20),
+            pseudorandom_selection(seed, 0, 20).complement(),
+        ] {
+            let mut sel = sel.clone();
+            let intervals: Vec<_> = sel.interval_iter().collect();
+            let gaps: Vec<_> = sel.complement().interval_iter().collect();
+
+            let mut segments = Vec::new();
+            let mut cursor = sel.cursor_at(&i32::MIN);
+            assert!(!cursor.move_prev());
+            loop {
+                segments.push((*cursor.segment(), cursor.is_selected()));
+                if !cursor.move_next() { break; }
+            }
+            assert_eq!(segments.iter()
+                    .filter(|(_, selected)| *selected)
+                    .map(|(segment, _)| *segment)
+                    .collect::<Vec<_>>(),
+                intervals);
+            assert_eq!(segments.iter()
+                    .filter(|(_, selected)| !*selected)
+                    .map(|(segment, _)| *segment)
+                    .collect::<Vec<_>>(),
+                gaps);
+
+            let mut reversed = Vec::new();
+            let mut cursor = sel.cursor_at(&i32::MAX);
+            loop {
+                reversed.push((*cursor.segment(), cursor.is_selected()));
+                if !cursor.move_prev() { break; }
+            }
+            reversed.reverse();
+            assert_eq!(reversed, segments);
+
+            for p in -2..22 {
+                let cursor = sel.cursor_at(&p);
+                assert!(cursor.segment().contains(&p));
+                assert_eq!(cursor.is_selected(), sel.contains(&p));
+            }
+        }
+    }
+}
+
+#[test]
+fn cursor_walks_many_segments() {
+    let mut sel: Selection<i32> = Selection::new();
+    for i in 0..100 {
+        sel.union_in_place(Interval::closed(4 * i, 4 * i + i % 3));
+    }
+    let mut segments = Vec::new();
+    let mut cursor = sel.cursor_at(&i32::MIN);
+    loop {
+        segments.push(*cursor.segment());
+        if !cursor.move_next() { break; }
+    }
+    assert_eq!(segments.len(), 201);
+    assert_eq!(segments.iter().skip(1).step_by(2).copied().collect::<Vec<_>>(),
+        sel.interval_iter().collect::<Vec<_>>());
+
+    // Turn around at varying points so the read intervals are reused.
+    let mut cursor = sel.cursor_at(&i32::MIN);
+    let mut index = 0;
+    for turn in 1..60 {
+        let forward = turn % 2 == 1;
+        for _ in 0..(turn * 7 % 45) {
+            let moved = if forward {
+                cursor.move_next()
+            } else {
+                cursor.move_prev()
+            };
+            match (moved, forward) {
+                (true,  true)  => index += 1,
+                (true,  false) => index -= 1,
+                (false, true)  => assert_eq!(index, segments.len() - 1),
+                (false, false) => assert_eq!(index, 0),
+            }
+            assert_eq!(cursor.segment(), &segments[index]);
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn cursor_steps_over_excluded_points() {
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 0);
+
+    let mut sel = Selection::new();
+    for i in 0..40 {
+        sel.union_in_place(Interval::open(d(i), d(i + 1)));
+    }
+    let mut cursor = sel.cursor_at(&d(-1));
+    let mut forward = vec![*cursor.segment()];
+    while cursor.move_next() {
+        forward.push(*cursor.segment());
+    }
+    assert_eq!(forward.len(), 81);
+    assert_eq!(forward[1], Interval::open(d(0), d(1)));
+    assert_eq!(forward[2], Interval::point(d(1)));
+    assert_eq!(forward[79], Interval::open(d(39), d(40)));
+
+    let mut backward = vec![*cursor.segment()];
+    while cursor.move_prev() {
+        backward.push(*cursor.segment());
+    }
+    backward.reverse();
+    assert_eq!(backward, forward);
+}
+
+#[test]
+fn cursor_modifies_selection() {
+    let mut lcg = Lcg::new(5);
+
+    for seed in 0..16 {
+        let mut sel = pseudorandom_selection(seed, 0, 20);
+        let mut expected = sel.clone();
+        let mut cursor = sel.cursor_at(&10);
+        for _ in 0..20 {
+            let (l, r) = (lcg.below(20), lcg.below(20));
+            let interval = Interval::closed(l.min(r), l.max(r));
+            if lcg.below(2) == 0 {
+                expected.union_in_place(interval);
+                cursor.extend(interval);
+                assert!(cursor.is_selected());
+            } else {
+                expected.minus_in_place(interval);
+                cursor.carve(interval);
+                assert!(!cursor.is_selected());
+            }
+            assert_eq!(cursor.selection(), &expected);
+            assert_eq!(interval.intersect(cursor.segment()), interval);
+            let point = lcg.below(20);
+            cursor.seek(&point);
+            assert_eq!(cursor.is_selected(), expected.contains(&point));
+        }
+    }
+}
+
+#[test]
+fn minus_in_place_merges_with_insert() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(0, 10));
+    sel.minus_in_place(Interval::closed(3, 5));
+    sel.union_in_place(Interval::closed(3, 5));
+
+    assert_eq!(sel, Selection::from(Interval::closed(0, 10)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::closed(0, 10)]);
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn decimal_point_removal_at_excluded_point() {
+    use rust_decimal::Decimal;
+    let d = |n: i64| Decimal::new(n, 0);
+
+    // Zero separates the two intervals but is not itself selected.
+    let mut base = Selection::from(Interval::right_open(d(-8), d(0)));
+    base.union_in_place(Interval::open(d(0), d(5)));
+    let expected = [
+        Interval::right_open(d(-8), d(0)),
+        Interval::open(d(0), d(5)),
+    ];
+
+    let mut sel = base.clone();
+    sel.remove_point(&d(0));
+    assert!(!sel.contains(&d(0)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), expected);
+    assert_eq!(sel, base);
+
+    let mut sel = base.clone();
+    sel.minus_in_place(Interval::point(d(0)));
+    assert!(!sel.contains(&d(0)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), expected);
+
+    let mut sel = base.clone();
+    sel.symmetric_difference_in_place(Interval::point(d(0)));
+    assert!(sel.contains(&d(0)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(),
+        [Interval::right_open(d(-8), d(5))]);
+    sel.symmetric_difference_in_place(Interval::point(d(0)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), expected);
+
+    let mut sel = base.clone();
+    let mut cursor = sel.cursor_at(&d(0));
+    assert!(!cursor.is_selected());
+    cursor.carve(Interval::point(d(0)));
+    assert!(!cursor.is_selected());
+    assert_eq!(cursor.segment(), &Interval::point(d(0)));
+    assert!(cursor.move_next());
+    assert_eq!(cursor.segment(), &Interval::open(d(0), d(5)));
+    assert_eq!(sel.interval_iter().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn in_place_operations_are_canonical() {
+    let mut lcg = Lcg::new(7);
+
+    let mut sel: Selection<i32> = Selection::new();
+    let mut naive = NaiveSelection::new();
+    for _ in 0..500 {
+        let interval = lcg.bounded_interval(0, 20);
+        let l = lcg.point(0, 20);
+        match lcg.below(6) {
+            0 => {
+                sel.union_in_place(interval);
+                naive.union_in_place(interval);
+            },
+            1 => {
+                sel.minus_in_place(interval);
+                naive.minus_in_place(interval);
+            },
+            2 => {
+                sel.symmetric_difference_in_place(interval);
+                naive.symmetric_difference_in_place(interval);
+            },
+            3 => {
+                sel.insert_point(l);
+                naive.insert_point(l);
+            },
+            4 => {
+                sel.remove_point(&l);
+                naive.remove_point(&l);
+            },
+            _ => {
+                sel.intersect_in_place(interval);
+                naive.intersect_in_place(interval);
+            },
+        }
+
+        assert_eq!(sel, naive);
+        assert_eq!(sel.interval_count(), sel.interval_iter().count());
+        assert_eq!(sel.point_count(), naive.point_count());
+    }
+}
+
+#[test]
+fn interval_iter_len_matches_count() {
+    for i in 0..32 {
+        let sel: Selection<i32> = pseudorandom_selection(i, 0, 20);
+        let count = sel.interval_count();
+
+        let mut iter = sel.interval_iter();
+        let mut into_iter = sel.clone().into_interval_iter();
+        for remaining in (0..=count).rev() {
+            assert_eq!(iter.len(), remaining);
+            assert_eq!(into_iter.size_hint(), (remaining, Some(remaining)));
+            if remaining % 2 == 0 {
+                assert_eq!(iter.next(), into_iter.next());
+            } else {
+                assert_eq!(iter.next_back(), into_iter.next_back());
+            }
+        }
+    }
+}
+
+#[test]
+fn mixed_point_iteration_terminates() {
+    for i in 0..32 {
+        let sel = pseudorandom_selection(i, 0, 20);
+        let expected: Vec<i32> = (0..20).filter(|p| sel.contains(p)).collect();
+
+        for pattern in 1..8u32 {
+            let mut iter = sel.iter();
+            let mut into_iter = sel.clone().into_iter();
+            let mut front = Vec::new();
+            let mut back = Vec::new();
+            for step in 0.. {
+                let from_back = pattern & (1 << (step % 3)) != 0;
+                let (next, into_next) = if from_back {
+                    (iter.next_back(), into_iter.next_back())
+                } else {
+                    (iter.next(), into_iter.next())
+                };
+                assert_eq!(next, into_next);
+                match next {
+                    Some(p) if from_back => back.push(p),
+                    Some(p)              => front.push(p),
+                    None                 => break,
+                }
+            }
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, expected);
+
+            // The iterators stay exhausted from both ends.
+            for _ in 0..3 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+                assert_eq!(into_iter.next(), None);
+                assert_eq!(into_iter.next_back(), None);
+            }
+        }
+    }
+}
+
+#[test]
+fn interval_ref_iter_matches_interval_iter() {
+    for i in 0..32 {
+        let sel = pseudorandom_selection(i, 0, 20);
+
+        assert_eq!(sel.interval_ref_iter().len(), sel.interval_count());
+        assert_eq!(sel.interval_ref_iter()
+                .map(Interval::from)
+                .collect::<Vec<_>>(),
+            sel.interval_iter().collect::<Vec<_>>());
+        assert_eq!(sel.interval_ref_iter()
+                .rev()
+                .map(Interval::from)
+                .collect::<Vec<_>>(),
+            sel.interval_iter().rev().collect::<Vec<_>>());
+
+        for p in -2..22 {
+            assert_eq!(sel.interval_ref_iter().any(|view| view.contains(&p)),
+                sel.contains(&p), "point {}", p);
+        }
+    }
+}
+
+#[test]
+fn tine_count_and_heap_size_estimate() {
+    let mut sel: Selection<i32> = Selection::new();
+    assert_eq!(sel.tine_count(), 0);
+    assert_eq!(sel.heap_size_estimate(), 0);
+
+    let mut prev = 0;
+    for i in 0..40 {
+        sel.union_in_place(Interval::closed(i * 10, i * 10 + 5));
+        assert_eq!(sel.tine_count(), 2 * sel.interval_count());
+
+        // The estimate covers at least the bounds themselves, and grows with
+        // the selection once it is large.
+        let size = sel.heap_size_estimate();
+        assert!(size >= sel.tine_count() * size_of::<Bound<i32>>());
+        if i > 10 { assert!(size >= prev); }
+        prev = size;
+    }
+
+    sel.union_in_place(Interval::point(1000));
+    assert_eq!(sel.tine_count(), 2 * sel.interval_count());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn par_reductions_match_sequential() {
+    let selections: Vec<Selection<i32>> = (0..24)
+        .map(|seed| pseudorandom_selection(seed, 0, 20))
+        .collect();
+
+    assert_eq!(Selection::par_union_all(selections.clone()),
+        selections.iter()
+            .map(NaiveSelection::from)
+            .fold(NaiveSelection::new(), |acc, s| acc.union(&s)));
+    assert_eq!(Selection::par_intersect_all(selections[..3].to_vec()),
+        Selection::intersect_all(&selections[..3]));
+    assert_eq!(Selection::<i32>::par_union_all(Vec::new()), Selection::new());
+    assert_eq!(Selection::<i32>::par_intersect_all(Vec::new()),
+        Selection::full());
+}
+
+#[test]
+fn into_ranges_round_trip() {
+    for seed in 0..40 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        if sel.contains(&i32::MAX) {
+            assert_eq!(sel.clone().try_into_ranges(),
+                Err(IntervalError::BoundOverflow));
+        } else {
+            let ranges = sel.clone().into_ranges();
+            assert_eq!(ranges.len(), sel.interval_count());
+            assert_eq!(Selection::union_all(ranges.into_iter()
+                .map(Interval::from)), sel);
+        }
+
+        let ranges = sel.clone().into_range_inclusives();
+        assert_eq!(ranges.len(), sel.interval_count());
+        assert_eq!(Selection::union_all(ranges.into_iter()
+            .map(Interval::from)), sel);
+    }
+
+    assert_eq!(Selection::<i32>::new().into_ranges(), []);
+    assert_eq!(Selection::<u8>::full().into_range_inclusives(), [0..=255]);
+    assert_eq!(Selection::<u8>::full().try_into_ranges(),
+        Err(IntervalError::BoundOverflow));
+}
+
+#[test]
+#[should_panic]
+fn into_ranges_panics_at_maximum() {
+    let _ = Selection::from(Interval::point(u8::MAX)).into_ranges();
+}
+
+#[test]
+fn simplify_fills_small_gaps() {
+    for seed in 0..40 {
+        let sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+        for max_gap in 0..6 {
+            let simplified = sel.simplify(max_gap);
+            let max_points = max_gap as u128;
+            let mut expected = sel.clone();
+            for gap in sel.gaps() {
+                if matches!(gap.point_count(), Some(n) if n <= max_points) {
+                    expected.union_in_place(gap);
+                }
+            }
+            assert_eq!(simplified, expected, "seed {} gap {}", seed, max_gap);
+            assert!(simplified.is_superset(&sel));
+        }
+        assert_eq!(sel.simplify(0), sel);
+        assert!(sel.simplify(usize::MAX).interval_count() <= 1);
+    }
+
+    let mut sel: Selection<u8> = Selection::from(Interval::closed(0, 10));
+    sel.union_in_place(Interval::closed(200, 255));
+    assert_eq!(sel.simplify(188), sel);
+    assert_eq!(sel.simplify(189), Selection::full());
+}
+
+#[test]
+fn intersects_interval_matches_points() {
+    for seed in 0..40 {
+        let sel = pseudorandom_selection(seed, 0, 20);
+        for l in -2..22 {
+            for u in l..22 {
+                for query in [
+                    Interval::closed(l, u),
+                    Interval::open(l, u),
+                    Interval::unbounded_from(l),
+                    Interval::unbounded_to(u),
+                ].iter() {
+                    let shared = (-2..22)
+                        .any(|p| sel.contains(&p) && query.contains(&p));
+                    assert_eq!(sel.intersects(query), shared,
+                        "seed {} query {:?}", seed, query);
+                }
+            }
+        }
+        assert!(!sel.intersects(&Interval::empty()));
+        assert_eq!(sel.intersects(&Interval::full()), !sel.is_empty());
+    }
+}
+
+#[test]
+fn events_match_intervals() {
+    for seed in 0..40 {
+        let sel: Selection<i32> = pseudorandom_selection(seed, 0, 20);
+        let expected: Vec<_> = sel
+            .interval_iter()
+            .flat_map(|i| vec![
+                Event::Enter(i.lower_bound().unwrap()),
+                Event::Exit(i.upper_bound().unwrap()),
+            ])
+            .collect();
+        assert_eq!(sel.events().collect::<Vec<_>>(), expected);
+        assert_eq!(sel.events().len(), expected.len());
+
+        let mut reversed: Vec<_> = sel.events().rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+}
+
+#[test]
+fn events_from_both_ends() {
+    let mut sel: Selection<i32> = Selection::from(Interval::unbounded_to(0));
+    sel.union_in_place(Interval::closed(5, 5));
+
+    let mut events = sel.events();
+    assert_eq!(events.next(), Some(Event::Enter(Bound::Include(i32::MIN))));
+    assert_eq!(events.next_back(), Some(Event::Exit(Bound::Include(5))));
+    assert_eq!(events.len(), 2);
+    assert_eq!(events.next_back(), Some(Event::Enter(Bound::Include(5))));
+    assert_eq!(events.next(), Some(Event::Exit(Bound::Include(0))));
+    assert_eq!(events.next(), None);
+    assert_eq!(events.next_back(), None);
+
+    assert_eq!(Selection::<i32>::new().events().count(), 0);
+    assert!(Selection::<i32>::full().events().all(|e| e.point().is_some()));
+}
@@ -0,0 +1,283 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the `serde` representations.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::serde_repr::Compact;
+use crate::serde_repr::Notation;
+use crate::serde_repr::SelectionSeed;
+use crate::serde_repr::Structured;
+
+// External library imports.
+use serde_test::assert_de_tokens;
+use serde_test::assert_de_tokens_error;
+use serde_test::assert_tokens;
+use serde_test::Configure;
+use serde_test::Token;
+
+
+#[test]
+fn bound_enum_form() {
+    assert_tokens(&Bound::Include(3), &[
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(3),
+    ]);
+    assert_tokens(&Bound::Exclude(3), &[
+        Token::NewtypeVariant { name: "Bound", variant: "Exclude" },
+        Token::I32(3),
+    ]);
+    assert_tokens(&Bound::<i32>::Infinite, &[
+        Token::UnitVariant { name: "Bound", variant: "Infinite" },
+    ]);
+}
+
+#[test]
+fn interval_readable_uses_structured_form() {
+    assert_tokens(&Interval::closed(1, 4).readable(), &[
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(1),
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::StructEnd,
+    ]);
+    assert_tokens(&Interval::<i32>::empty().readable(), &[
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::None,
+        Token::Str("upper"),
+        Token::None,
+        Token::StructEnd,
+    ]);
+}
+
+#[test]
+fn interval_compact_uses_tuple_form() {
+    assert_tokens(&Interval::unbounded_to(4).compact(), &[
+        Token::Some,
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(i32::MIN),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::TupleEnd,
+    ]);
+    assert_tokens(&Interval::<i32>::empty().compact(), &[Token::None]);
+}
+
+#[test]
+fn wrappers_override_format() {
+    assert_tokens(&Compact(Interval::point(2)).readable(), &[
+        Token::Some,
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(2),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(2),
+        Token::TupleEnd,
+    ]);
+    assert_tokens(&Structured(Interval::<i32>::empty()).compact(), &[
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::None,
+        Token::Str("upper"),
+        Token::None,
+        Token::StructEnd,
+    ]);
+    assert_tokens(&Notation(Interval::closed(1, 4)).compact(), &[
+        Token::Str("[1, 4]"),
+    ]);
+}
+
+#[test]
+fn selection_forms() {
+    let mut sel: Selection<i32> = Selection::from(Interval::closed(1, 4));
+    sel.union_in_place(Interval::closed(9, 12));
+
+    assert_tokens(&sel.clone().readable(), &[
+        Token::Seq { len: Some(2) },
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(1),
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::StructEnd,
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(9),
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(12),
+        Token::StructEnd,
+        Token::SeqEnd,
+    ]);
+    assert_tokens(&sel.clone().compact(), &[
+        Token::Seq { len: Some(2) },
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(1),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::TupleEnd,
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(9),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(12),
+        Token::TupleEnd,
+        Token::SeqEnd,
+    ]);
+    assert_tokens(&Notation(sel), &[Token::Str("[1, 4] ∪ [9, 12]")]);
+}
+
+#[test]
+fn deserialize_normalizes_and_merges() {
+    assert_de_tokens(&Interval::closed(1, 4).compact(), &[
+        Token::Some,
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Exclude" },
+        Token::I32(0),
+        Token::NewtypeVariant { name: "Bound", variant: "Exclude" },
+        Token::I32(5),
+        Token::TupleEnd,
+    ]);
+    assert_de_tokens(&Interval::closed(1, 4).readable(), &[
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::Str("lower"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(1),
+        Token::StructEnd,
+    ]);
+    assert_de_tokens(
+        &Selection::from(Interval::closed(1, 9)).compact(),
+        &[
+            Token::Seq { len: Some(2) },
+            Token::Tuple { len: 2 },
+            Token::NewtypeVariant { name: "Bound", variant: "Include" },
+            Token::I32(5),
+            Token::NewtypeVariant { name: "Bound", variant: "Include" },
+            Token::I32(9),
+            Token::TupleEnd,
+            Token::Tuple { len: 2 },
+            Token::NewtypeVariant { name: "Bound", variant: "Include" },
+            Token::I32(1),
+            Token::NewtypeVariant { name: "Bound", variant: "Include" },
+            Token::I32(6),
+            Token::TupleEnd,
+            Token::SeqEnd,
+        ]);
+}
+
+#[test]
+fn deserialize_rejects_invalid_bounds() {
+    assert_de_tokens_error::<Compact<Interval<i32>>>(&[
+        Token::Some,
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(7),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(-7),
+        Token::TupleEnd,
+    ], "interval lower bound is greater than its upper bound");
+    assert_de_tokens_error::<Structured<Interval<i32>>>(&[
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::None,
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::StructEnd,
+    ], "interval must have both bounds or neither");
+}
+
+/// A `Selection` deserialized by `SelectionSeed` into a `Selection` which
+/// already contains `[0, 3]`.
+#[derive(Debug, PartialEq)]
+struct SeededSelection(Selection<i32>);
+
+impl<'de> serde::Deserialize<'de> for SeededSelection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: serde::Deserializer<'de>
+    {
+        use serde::de::DeserializeSeed;
+        let mut sel = Selection::from(Interval::closed(0, 3));
+        SelectionSeed(&mut sel).deserialize(deserializer)?;
+        Ok(SeededSelection(sel))
+    }
+}
+
+#[test]
+fn selection_seed_unions_into_existing() {
+    let mut expected = Selection::from(Interval::closed(0, 6));
+    expected.union_in_place(Interval::point(9));
+
+    assert_de_tokens(&SeededSelection(expected.clone()).compact(), &[
+        Token::Seq { len: Some(2) },
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(9),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(9),
+        Token::TupleEnd,
+        Token::Tuple { len: 2 },
+        Token::NewtypeVariant { name: "Bound", variant: "Exclude" },
+        Token::I32(2),
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(6),
+        Token::TupleEnd,
+        Token::SeqEnd,
+    ]);
+    assert_de_tokens(&SeededSelection(expected).readable(), &[
+        Token::Seq { len: None },
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(4),
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(6),
+        Token::StructEnd,
+        Token::Struct { name: "Interval", len: 2 },
+        Token::Str("lower"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(9),
+        Token::Str("upper"),
+        Token::Some,
+        Token::NewtypeVariant { name: "Bound", variant: "Include" },
+        Token::I32(9),
+        Token::StructEnd,
+        Token::SeqEnd,
+    ]);
+}
@@ -0,0 +1,79 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`SetOps`] implementations.
+//!
+//! [`SetOps`] ../../set_ops/trait.SetOps.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::set_ops::SetOps;
+use crate::tine_tree::TineTree;
+
+
+fn check_pointwise<S, R>(a: &S, b: &R)
+    where
+        S: SetOps<R, Point=i32>,
+        S::Output: SetOps<Point=i32>,
+        R: SetOps<Point=i32>,
+{
+    let union = a.union(b);
+    let intersect = a.intersect(b);
+    let minus = a.minus(b);
+    let complement = a.complement();
+    for p in -20..20 {
+        let (in_a, in_b) = (a.contains(&p), b.contains(&p));
+        assert_eq!(union.contains(&p), in_a || in_b);
+        assert_eq!(intersect.contains(&p), in_a && in_b);
+        assert_eq!(minus.contains(&p), in_a && !in_b);
+        assert_eq!(complement.contains(&p), !in_a);
+    }
+}
+
+fn raw_intervals() -> Vec<RawInterval<i32>> {
+    vec![
+        RawInterval::Empty,
+        RawInterval::Point(3),
+        RawInterval::closed(-5, 4),
+        RawInterval::open(2, 9),
+        RawInterval::left_open(-9, -2),
+        RawInterval::right_open(6, 15),
+        RawInterval::UpTo(0),
+        RawInterval::UpFrom(-8),
+        RawInterval::From(8),
+        RawInterval::Full,
+    ]
+}
+
+#[test]
+fn set_ops_pointwise() {
+    let raws = raw_intervals();
+    for a in &raws {
+        for b in &raws {
+            check_pointwise(a, b);
+
+            let ta = TineTree::from(*a);
+            let tb = TineTree::from(*b);
+            check_pointwise(&ta, &tb);
+
+            let ia = Interval::from(*a);
+            let ib = Interval::from(*b);
+            check_pointwise(&ia, &ib);
+
+            let mut sa = Selection::from(ia);
+            sa.union_in_place(Interval::closed(12, 14));
+            check_pointwise(&sa, &Selection::from(ib));
+            check_pointwise(&sa, &ib);
+        }
+    }
+}
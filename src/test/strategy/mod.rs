@@ -0,0 +1,78 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for proptest strategies.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::raw_interval::RawInterval;
+use crate::selection::Selection;
+use crate::strategy::any_interval;
+use crate::strategy::any_selection;
+use crate::strategy::interval;
+use crate::strategy::selection;
+
+// External library imports.
+use proptest::prop_assert;
+use proptest::prop_assert_eq;
+use proptest::proptest;
+
+
+// Returns true if the interval is in the normalized form of a `Finite` type.
+fn is_finite_normal<T>(interval: &Interval<T>) -> bool where T: Ord {
+    match &interval.0 {
+        RawInterval::Empty        => true,
+        RawInterval::Point(_)     => true,
+        RawInterval::Closed(l, r) => l < r,
+        _                         => false,
+    }
+}
+
+proptest! {
+    #[test]
+    fn generated_intervals_are_normalized(i in interval(-20i32..20)) {
+        prop_assert!(is_finite_normal(&i));
+        prop_assert_eq!(Interval::from(i.0), i);
+    }
+
+    #[test]
+    fn arbitrary_intervals_are_normalized(i in any_interval::<u8>()) {
+        prop_assert!(is_finite_normal(&i));
+        prop_assert_eq!(Interval::from(i.0), i);
+    }
+
+    #[test]
+    fn generated_selections_are_normalized(s in selection(-20i32..20, 0..8)) {
+        let intervals: Vec<_> = s.interval_iter().collect();
+        for i in &intervals {
+            prop_assert!(!i.is_empty());
+            prop_assert!(is_finite_normal(i));
+        }
+        // Intervals are sorted and separated by at least one point.
+        for pair in intervals.windows(2) {
+            let upper = pair[0].supremum().expect("nonempty interval");
+            let lower = pair[1].infimum().expect("nonempty interval");
+            prop_assert!(upper + 1 < lower);
+        }
+        prop_assert_eq!(intervals.into_iter().collect::<Selection<_>>(), s);
+    }
+
+    #[test]
+    fn arbitrary_selections_are_normalized(s in any_selection::<u8>()) {
+        let intervals: Vec<_> = s.interval_iter().collect();
+        for pair in intervals.windows(2) {
+            let upper = pair[0].supremum().expect("nonempty interval");
+            let lower = pair[1].infimum().expect("nonempty interval");
+            prop_assert!(upper < lower && lower - upper > 1);
+        }
+        prop_assert!(intervals.iter().all(is_finite_normal));
+    }
+}
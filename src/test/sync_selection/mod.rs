@@ -0,0 +1,81 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for [`SyncSelection`].
+//!
+//! [`SyncSelection`] struct.SyncSelection.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::sync_selection::SyncSelection;
+
+// Standard library imports.
+use std::sync::Arc;
+use std::thread;
+
+
+#[test]
+fn concurrent_updates_are_not_lost() {
+    let shared: Arc<SyncSelection<i32>> = Arc::new(SyncSelection::new());
+
+    let writers: Vec<_> = (0..4).map(|t| {
+        let shared = shared.clone();
+        thread::spawn(move || {
+            for i in 0..50 {
+                shared.update(|sel| sel.insert_point(i * 4 + t));
+            }
+        })
+    }).collect();
+
+    // Readers only ever see the points growing.
+    let readers: Vec<_> = (0..2).map(|_| {
+        let shared = shared.clone();
+        thread::spawn(move || {
+            let mut prev = shared.snapshot();
+            for _ in 0..200 {
+                let next = shared.snapshot();
+                assert!(next.is_superset(&prev));
+                prev = next;
+            }
+        })
+    }).collect();
+
+    for handle in writers.into_iter().chain(readers) {
+        handle.join().unwrap();
+    }
+
+    let shared = Arc::try_unwrap(shared).unwrap();
+    assert_eq!(shared.into_inner(), Interval::right_open(0, 200));
+}
+
+#[test]
+fn snapshots_are_unaffected_by_writes() {
+    let shared = SyncSelection::from(Selection::from(Interval::closed(0, 9)));
+    let before = shared.snapshot();
+
+    let old = shared.replace(Selection::from(Interval::closed(20, 29)));
+    assert!(Arc::ptr_eq(&before, &old));
+    let count = shared.update(|sel| {
+        sel.minus_in_place(Interval::closed(25, 26));
+        sel.interval_count()
+    });
+
+    assert_eq!(count, 2);
+    assert_eq!(*before, Interval::closed(0, 9));
+    assert!(!shared.contains(&5));
+    assert!(!shared.contains(&25));
+    assert!(shared.contains(&27));
+
+    // A snapshot still held is cloned rather than unwrapped.
+    let held = shared.snapshot();
+    assert_eq!(shared.into_inner(), *held);
+}
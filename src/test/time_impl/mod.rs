@@ -0,0 +1,182 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for `time` date and time normalization.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::normalize::Finite;
+use crate::selection::Selection;
+
+// External library imports.
+use time::Date;
+use time::Duration;
+use time::Month;
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+use time::UtcOffset;
+
+
+/// Constructs a `Date` from a valid calendar date.
+fn date(year: i32, month: Month, day: u8) -> Date {
+    Date::from_calendar_date(year, month, day).expect("valid date")
+}
+
+/// Constructs an `OffsetDateTime` from a unix timestamp.
+fn timestamp(seconds: i64) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(seconds).expect("valid timestamp")
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Date tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn date_steps() {
+    let leap = date(2020, Month::February, 29);
+    assert_eq!(leap.pred(), Some(date(2020, Month::February, 28)));
+    assert_eq!(leap.succ(), Some(date(2020, Month::March, 1)));
+    assert_eq!(leap.succ_n(366), Some(date(2021, Month::March, 1)));
+    assert_eq!(leap.pred_n(366), Some(date(2019, Month::February, 28)));
+    assert_eq!(date(2019, Month::February, 28).steps_between(&leap),
+        Some(366));
+    assert_eq!(leap.steps_between(&date(2019, Month::February, 28)), None);
+
+    assert_eq!(Date::MIN.pred(), None);
+    assert_eq!(Date::MAX.succ(), None);
+    assert_eq!(Date::MAX.succ_n(0), Some(Date::MAX));
+    assert_eq!(Date::MIN.pred_n(1), None);
+}
+
+#[test]
+fn date_normalize() {
+    let start = date(2020, Month::February, 27);
+    let end = date(2020, Month::March, 2);
+    assert_eq!(Interval::open(start, end),
+        Interval::closed(date(2020, Month::February, 28),
+            date(2020, Month::March, 1)));
+    assert_eq!(Interval::unbounded_to(start),
+        Interval::closed(Date::MIN, start));
+    assert_eq!(Interval::<Date>::full(),
+        Interval::closed(Date::MIN, Date::MAX));
+    assert!(Interval::open(start, start.succ().unwrap()).is_empty());
+}
+
+#[test]
+fn date_complement() {
+    let start = date(2020, Month::February, 27);
+    let end = date(2020, Month::March, 1);
+    assert_eq!(Interval::closed(start, end).complement().collect::<Vec<_>>(), [
+        Interval::closed(Date::MIN, date(2020, Month::February, 26)),
+        Interval::closed(date(2020, Month::March, 2), Date::MAX),
+    ]);
+    assert!(Selection::<Date>::full().complement().is_empty());
+    assert_eq!(Selection::from(Interval::closed(Date::MIN, start))
+            .complement(),
+        Selection::from(Interval::unbounded_from(start.succ().unwrap())));
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// OffsetDateTime tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn offset_date_time_steps() {
+    let t = timestamp(1_595_062_800);
+    assert_eq!(t.succ(), Some(t + Duration::NANOSECOND));
+    assert_eq!(t.pred(), Some(t - Duration::NANOSECOND));
+    assert_eq!(t.succ_n(1_000), Some(t + Duration::MICROSECOND));
+    assert_eq!(t.pred_n(1_000), Some(t - Duration::MICROSECOND));
+    assert_eq!(t.steps_between(&(t + Duration::SECOND)),
+        Some(1_000_000_000));
+    assert_eq!((t + Duration::SECOND).steps_between(&t), None);
+
+    // Values in different offsets compare by instant.
+    let east = t.to_offset(UtcOffset::from_hms(5, 30, 0).unwrap());
+    assert_eq!(east.succ(), t.succ());
+    assert_eq!(east.steps_between(&t), Some(0));
+}
+
+#[test]
+fn offset_date_time_extremes() {
+    let utc_min = PrimitiveDateTime::MIN.assume_utc();
+    let utc_max = PrimitiveDateTime::MAX.assume_utc();
+
+    assert!(OffsetDateTime::MINIMUM < utc_min);
+    assert!(OffsetDateTime::MAXIMUM > utc_max);
+    assert_eq!(OffsetDateTime::MINIMUM.pred(), None);
+    assert_eq!(OffsetDateTime::MAXIMUM.succ(), None);
+
+    // Stepping past the extremes of UTC continues in another offset.
+    let below = utc_min.pred().unwrap();
+    assert_eq!(utc_min - below, Duration::NANOSECOND);
+    assert_eq!(below.succ(), Some(utc_min));
+    let above = utc_max.succ().unwrap();
+    assert_eq!(above - utc_max, Duration::NANOSECOND);
+    assert_eq!(above.pred(), Some(utc_max));
+    let far_below = utc_min.pred_n(1_000).unwrap();
+    assert_eq!(far_below.steps_between(&utc_min), Some(1_000));
+    let far_above = utc_max.succ_n(1_000).unwrap();
+    assert_eq!(utc_max.steps_between(&far_above), Some(1_000));
+
+    assert_eq!(OffsetDateTime::MINIMUM.succ_n(5).unwrap().pred_n(5),
+        Some(OffsetDateTime::MINIMUM));
+    assert_eq!(OffsetDateTime::MAXIMUM.pred_n(5).unwrap().succ_n(5),
+        Some(OffsetDateTime::MAXIMUM));
+}
+
+#[test]
+fn offset_date_time_normalize() {
+    let t = timestamp(1_595_062_800);
+    let u = t + Duration::HOUR;
+    assert_eq!(Interval::open(t, u),
+        Interval::closed(t + Duration::NANOSECOND, u - Duration::NANOSECOND));
+    assert_eq!(Interval::right_open(t, u).duration(), Duration::HOUR);
+    assert_eq!(Interval::unbounded_from(t),
+        Interval::closed(t, OffsetDateTime::MAXIMUM));
+    assert_eq!(Interval::<OffsetDateTime>::full(),
+        Interval::closed(OffsetDateTime::MINIMUM, OffsetDateTime::MAXIMUM));
+    assert!(Interval::open(t, t + Duration::NANOSECOND).is_empty());
+}
+
+#[test]
+fn offset_date_time_complement() {
+    let utc_min = PrimitiveDateTime::MIN.assume_utc();
+    let utc_max = PrimitiveDateTime::MAX.assume_utc();
+
+    // The complement of the UTC range is not empty, as other offsets reach
+    // beyond it on both sides.
+    let utc = Interval::closed(utc_min, utc_max);
+    assert_eq!(utc.complement().collect::<Vec<_>>(), [
+        Interval::closed(OffsetDateTime::MINIMUM, utc_min.pred().unwrap()),
+        Interval::closed(utc_max.succ().unwrap(), OffsetDateTime::MAXIMUM),
+    ]);
+    assert!(Selection::<OffsetDateTime>::full().complement().is_empty());
+
+    let t = timestamp(0);
+    let sel = Selection::from(Interval::unbounded_to(t));
+    assert_eq!(sel.complement(),
+        Selection::from(Interval::unbounded_from(t + Duration::NANOSECOND)));
+    assert_eq!(sel.complement().complement(), sel);
+}
+
+#[test]
+fn duration_measure() {
+    let interval = Interval::open(Duration::minutes(30), Duration::hours(2));
+    assert_eq!(interval.measure(), Some(Duration::minutes(90)));
+    assert_eq!(Interval::point(Duration::HOUR).measure(), Some(Duration::ZERO));
+    assert_eq!(Interval::unbounded_to(Duration::HOUR).measure(), None);
+
+    let mut sel = Selection::from(interval);
+    let (l, u) = (Duration::hours(3), Duration::hours(4));
+    sel.union_in_place(Interval::closed(l, u));
+    assert_eq!(sel.measure(), Some(Duration::minutes(150)));
+}
@@ -0,0 +1,50 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::tine_tree::TineTree;
+
+// Local enum shortcuts.
+use crate::raw_interval::RawInterval::*;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Containment tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn contains_matches_intervals() {
+    let mut t: TineTree<i32> = TineTree::new();
+
+    t.union_in_place(&UpTo(0));
+	t.union_in_place(&Point(1));
+	t.union_in_place(&Open(2, 3));
+	t.union_in_place(&LeftOpen(4, 5));
+	t.union_in_place(&RightOpen(6, 7));
+	t.union_in_place(&Open(7, 8));
+	t.union_in_place(&Closed(9, 10));
+	t.union_in_place(&UpFrom(12));
+
+    for p in -3..16 {
+        assert_eq!(t.contains(&p),
+            t.interval_iter().any(|interval| interval.contains(&p)),
+            "point {}", p);
+    }
+}
+
+#[test]
+fn contains_empty_and_full() {
+    let empty: TineTree<i32> = TineTree::new();
+    let full: TineTree<i32> = TineTree::from_raw_interval(Full);
+
+    for p in &[i32::MIN, -1, 0, 1, i32::MAX] {
+        assert!(!empty.contains(p));
+        assert!(full.contains(p));
+    }
+}
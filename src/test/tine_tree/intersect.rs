@@ -34,7 +34,7 @@ fn disjoint_aggregation() {
 	t.intersect_in_place(&UpFrom(50));
 	t.intersect_in_place(&Empty);
 
-    assert_eq!(t.iter_intervals().collect::<Vec<_>>(), []);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), []);
 }
 
 #[test]
@@ -51,7 +51,25 @@ fn nested_aggregation() {
     t.intersect_in_place(&UpFrom(20));
     t.intersect_in_place(&Point(25));
 
-    assert_eq!(t.iter_intervals().collect::<Vec<_>>(), [Point(25)]);
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(), [Point(25)]);
+}
+
+#[test]
+fn enclosing_aggregation() {
+    let mut t: TineTree<i32> = Closed(3, 22).into();
+    t.union_in_place(&Closed(30, 35));
+
+    t.intersect_in_place(&Closed(0, 40));
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(),
+        [Closed(3, 22), Closed(30, 35)]);
+
+    t.intersect_in_place(&Closed(22, 50));
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(),
+        [Point(22), Closed(30, 35)]);
+
+    t.intersect_in_place(&Open(10, 32));
+    assert_eq!(t.interval_iter().collect::<Vec<_>>(),
+        [Point(22), RightOpen(30, 32)]);
 }
 
 
@@ -553,3 +571,28 @@ fn full() {
     assert_eq_i!(a.intersect(&TineTree::from(From(0))),           [From(0)]);
     assert_eq_i!(a.intersect(&TineTree::from(Full)),              [Full]);
 }
+
+#[test]
+fn fragmented() {
+    let a: TineTree<i32> = vec![Closed(0, 10), Closed(20, 30)]
+        .into_iter()
+        .collect();
+    let b: TineTree<i32> = vec![
+            Open(5, 7),
+            RightOpen(8, 9),
+            Open(9, 22),
+            From(25),
+        ]
+        .into_iter()
+        .collect();
+
+    let expected = [
+        Open(5, 7),
+        RightOpen(8, 9),
+        LeftOpen(9, 10),
+        RightOpen(20, 22),
+        Closed(25, 30),
+    ];
+    assert_eq_i!(a.intersect(&b), expected);
+    assert_eq_i!(b.intersect(&a), expected);
+}
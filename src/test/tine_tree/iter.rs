@@ -0,0 +1,92 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::tine_tree::TineTree;
+
+// Local enum shortcuts.
+use crate::raw_interval::RawInterval::*;
+
+
+/// Returns a `TineTree` with the given number of copies of a pattern of
+/// intervals, including some which share a point exclude tine.
+fn patterned_tree(copies: i32) -> TineTree<i32> {
+    let mut t: TineTree<i32> = TineTree::new();
+    for i in 0..copies {
+        let o = i * 20;
+        t.union_in_place(&Point(o + 1));
+        t.union_in_place(&Open(o + 2, o + 3));
+        t.union_in_place(&RightOpen(o + 6, o + 7));
+        t.union_in_place(&Open(o + 7, o + 8));
+        t.union_in_place(&Closed(o + 9, o + 10));
+    }
+    t
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Iterator size tests
+////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn interval_iter_size_hint_is_exact() {
+    for copies in 0..6 {
+        let t = patterned_tree(copies);
+        let count = t.interval_iter().count();
+        assert_eq!(t.interval_count(), count);
+
+        // Alternate between the front and back of the iterator.
+        let mut iter = t.interval_iter();
+        for remaining in (0..=count).rev() {
+            assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+            let next = if remaining % 2 == 0 {
+                iter.next()
+            } else {
+                iter.next_back()
+            };
+            assert_eq!(next.is_some(), remaining > 0);
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+#[test]
+fn into_iter_size_hint_is_exact() {
+    for copies in 0..6 {
+        let t = patterned_tree(copies);
+        let count = t.interval_iter().count();
+
+        let mut iter = t.into_iter();
+        for remaining in (0..=count).rev() {
+            assert_eq!(iter.len(), remaining);
+            let next = if remaining % 3 == 0 {
+                iter.next_back()
+            } else {
+                iter.next()
+            };
+            assert_eq!(next.is_some(), remaining > 0);
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+#[test]
+fn seek_iter_size_hint_bounds_count() {
+    let t = patterned_tree(3);
+    for p in -2..64 {
+        for iter in [t.interval_iter_from(&p), t.interval_iter_before(&p)] {
+            let (lower, upper) = iter.size_hint();
+            let count = iter.count();
+            assert!(lower <= count, "point {}", p);
+            assert!(upper.map_or(true, |upper| count <= upper),
+                "point {}", p);
+        }
+    }
+}
@@ -515,3 +515,15 @@ fn full() {
     assert_eq_i!(a.minus(&TineTree::from(From(0))),           [UpTo(0)]);
     assert_eq_i!(a.minus(&TineTree::from(Full)),              []);
 }
+
+#[test]
+fn point_at_excluded_point() {
+    let a: TineTree<i32> = TineTree::from(RightOpen(-8, 0))
+        .union(&TineTree::from(Open(0, 5)));
+
+    assert_eq_i!(a.minus(&TineTree::from(Point(0))),  [RightOpen(-8, 0), Open(0, 5)]);
+    assert_eq_i!(a.minus(&TineTree::from(Point(-8))), [Open(-8, 0), Open(0, 5)]);
+
+    let b: TineTree<i32> = TineTree::from(Full).minus(&TineTree::from(Point(0)));
+    assert_eq_i!(b.minus(&TineTree::from(Point(0))),  [UpTo(0), UpFrom(0)]);
+}
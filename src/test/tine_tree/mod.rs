@@ -15,6 +15,8 @@
 
 
 // Module declarations.
+mod contains;
 mod intersect;
+mod iter;
 mod minus;
 mod union;
@@ -0,0 +1,186 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Testing module for the Unicode property selections.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::unicode::GeneralCategory;
+use crate::unicode::ParsePropertyValueError;
+use crate::unicode::Script;
+
+
+/// Returns an iterator over the `char`s compared against the standard library.
+///
+/// The standard library may use a different version of Unicode, under which
+/// some properties of later blocks differ, so comparisons against it are
+/// limited to the Latin blocks, whose properties have long been stable.
+fn stable_chars() -> impl Iterator<Item=char> {
+    '\0'..='\u{2FF}'
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Standard library agreement tests
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests `Selection::whitespace` against `char::is_whitespace`.
+#[test]
+fn whitespace_matches_std() {
+    let sel = Selection::whitespace();
+    for c in stable_chars() {
+        assert_eq!(sel.contains(&c), c.is_whitespace(), "{:?}", c);
+    }
+}
+
+/// Tests `Selection::alphabetic` against `char::is_alphabetic`.
+#[test]
+fn alphabetic_matches_std() {
+    let sel = Selection::alphabetic();
+    for c in stable_chars() {
+        assert_eq!(sel.contains(&c), c.is_alphabetic(), "{:?}", c);
+    }
+}
+
+/// Tests `Selection::numeric` against `char::is_numeric`.
+#[test]
+fn numeric_matches_std() {
+    let sel = Selection::numeric();
+    for c in stable_chars() {
+        assert_eq!(sel.contains(&c), c.is_numeric(), "{:?}", c);
+    }
+}
+
+/// Tests `Selection::alphanumeric` against `char::is_alphanumeric`.
+#[test]
+fn alphanumeric_matches_std() {
+    let sel = Selection::alphanumeric();
+    for c in stable_chars() {
+        assert_eq!(sel.contains(&c), c.is_alphanumeric(), "{:?}", c);
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Partition tests
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests that the general categories partition the `char`s.
+#[test]
+fn general_categories_partition_chars() {
+    let mut all = Selection::new();
+    for &category in GeneralCategory::ALL.iter() {
+        let sel = Selection::general_category(category);
+        assert!(all.is_disjoint(&sel), "{:?}", category);
+        all = all.union(&sel);
+    }
+    assert!(all.is_full());
+}
+
+/// Tests that the scripts partition the `char`s.
+#[test]
+fn scripts_partition_chars() {
+    let mut all = Selection::new();
+    for &script in Script::ALL.iter() {
+        let sel = Selection::script(script);
+        assert!(!sel.is_empty(), "{:?}", script);
+        assert!(all.is_disjoint(&sel), "{:?}", script);
+        all = all.union(&sel);
+    }
+    assert!(all.is_full());
+}
+
+/// Tests that the surrogate category is empty.
+#[test]
+fn surrogate_category_is_empty() {
+    assert!(Selection::general_category(GeneralCategory::Surrogate)
+        .is_empty());
+}
+
+/// Tests that `GeneralCategory::of` and `Script::of` agree with the
+/// selections at their interval bounds.
+#[test]
+fn of_matches_selection_bounds() {
+    for &category in GeneralCategory::ALL.iter() {
+        for interval in Selection::general_category(category).interval_iter() {
+            let (lo, hi) = (interval.infimum().unwrap(),
+                interval.supremum().unwrap());
+            assert_eq!(GeneralCategory::of(lo), category);
+            assert_eq!(GeneralCategory::of(hi), category);
+        }
+    }
+    for &script in Script::ALL.iter() {
+        for interval in Selection::script(script).interval_iter() {
+            let (lo, hi) = (interval.infimum().unwrap(),
+                interval.supremum().unwrap());
+            assert_eq!(Script::of(lo), script);
+            assert_eq!(Script::of(hi), script);
+        }
+    }
+}
+
+/// Tests selections of particular scripts and categories.
+#[test]
+fn script_and_category_samples() {
+    let latin = Selection::script(Script::Latin);
+    assert!(latin.contains(&'a'));
+    assert!(latin.contains(&'é'));
+    assert!(!latin.contains(&'1'));
+    assert!(latin.is_superset(&Selection::from(Interval::closed('A', 'Z'))));
+
+    let han = Selection::script(Script::Han);
+    assert!(han.contains(&'京'));
+    assert!(Selection::alphabetic().contains(&'京'));
+    assert!(!Selection::numeric().contains(&'京'));
+
+    let ws = Selection::whitespace();
+    assert!(ws.contains(&'\u{2028}'));
+    assert!(ws.contains(&'\u{3000}'));
+    assert!(!ws.contains(&'\u{200B}'));
+
+    let digits = Selection::general_category(GeneralCategory::DecimalNumber);
+    assert!(digits.contains(&'٣'));
+    assert!(digits.is_superset(&Selection::from(Interval::closed('0', '9'))));
+    assert!(!digits.contains(&'/'));
+    assert!(!digits.contains(&':'));
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Parsing tests
+////////////////////////////////////////////////////////////////////////////////
+
+/// Tests that every value parses from its abbreviation and long name.
+#[test]
+fn parse_names() {
+    for &category in GeneralCategory::ALL.iter() {
+        assert_eq!(category.abbreviation().parse(), Ok(category));
+        assert_eq!(category.name().parse(), Ok(category));
+    }
+    for &script in Script::ALL.iter() {
+        assert_eq!(script.abbreviation().parse(), Ok(script));
+        assert_eq!(script.name().parse(), Ok(script));
+    }
+}
+
+/// Tests loose matching of value names.
+#[test]
+fn parse_loose_names() {
+    assert_eq!("uppercase letter".parse(),
+        Ok(GeneralCategory::UppercaseLetter));
+    assert_eq!("LU".parse(), Ok(GeneralCategory::UppercaseLetter));
+    assert_eq!("isLu".parse(), Ok(GeneralCategory::UppercaseLetter));
+    assert_eq!("old-italic".parse(), Ok(Script::OldItalic));
+    assert_eq!("GREEK".parse(), Ok(Script::Greek));
+    assert_eq!("Xyz".parse::<Script>(), Err(ParsePropertyValueError));
+    assert_eq!("".parse::<GeneralCategory>(), Err(ParsePropertyValueError));
+}
@@ -0,0 +1,292 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides normalization support for `time` date and time types.
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::bound::Bound;
+use crate::interval::Interval;
+use crate::measure::Measurable;
+use crate::normalize::Finite;
+use crate::normalize::Normalize;
+use crate::raw_interval::RawInterval;
+
+// External library imports.
+use time::Date;
+use time::Duration;
+use time::OffsetDateTime;
+use time::PrimitiveDateTime;
+use time::UtcOffset;
+
+// Standard library imports.
+use std::convert::TryFrom;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Date
+////////////////////////////////////////////////////////////////////////////////
+
+// `Date`s are normalized at day granularity.
+impl Finite for Date {
+    const MINIMUM: Date = Date::MIN;
+    const MAXIMUM: Date = Date::MAX;
+
+    fn pred(&self) -> Option<Self> {
+        self.previous_day()
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.next_day()
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        let n = i32::try_from(n).ok()?;
+        let day = self.to_julian_day().checked_sub(n)?;
+        Date::from_julian_day(day).ok()
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        let n = i32::try_from(n).ok()?;
+        let day = self.to_julian_day().checked_add(n)?;
+        Date::from_julian_day(day).ok()
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        let steps = i64::from(other.to_julian_day())
+            - i64::from(self.to_julian_day());
+        usize::try_from(steps).ok()
+    }
+}
+
+impl Interval<Date> {
+    /// Constructs a new `Interval` of dates starting at the given date and
+    /// spanning the whole number of days in the given `Duration`. If the
+    /// `Duration` is shorter than one day, an empty `Interval` will be
+    /// returned. If the end of the `Interval` is past the maximum date, the
+    /// `Interval` will extend to the maximum date.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use time::Date;
+    /// use time::Duration;
+    /// use time::Month;
+    ///
+    /// let start = Date::from_calendar_date(2020, Month::February, 27)?;
+    /// let end = Date::from_calendar_date(2020, Month::March, 1)?;
+    /// let interval = Interval::date_span(start, Duration::days(4));
+    ///
+    /// assert_eq!(interval, Interval::closed(start, end));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn date_span(start: Date, duration: Duration) -> Self {
+        if duration.whole_days() <= 0 { return Interval::empty(); }
+        match start.checked_add(duration) {
+            Some(end) => Interval::right_open(start, end),
+            None      => Interval::unbounded_from(start),
+        }
+    }
+
+    /// Returns the total `Duration` of the days in the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use time::Date;
+    /// use time::Duration;
+    /// use time::Month;
+    ///
+    /// let start = Date::from_calendar_date(2020, Month::February, 27)?;
+    /// let end = Date::from_calendar_date(2020, Month::March, 1)?;
+    /// let interval = Interval::closed(start, end);
+    ///
+    /// assert_eq!(interval.duration(), Duration::days(4));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn duration(&self) -> Duration {
+        match self.0 {
+            RawInterval::Empty         => Duration::ZERO,
+            RawInterval::Point(_)      => Duration::DAY,
+            RawInterval::Closed(l, u)  => (u - l) + Duration::DAY,
+            _ => unreachable!("duration for Finite interval with open bound"),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// OffsetDateTime
+////////////////////////////////////////////////////////////////////////////////
+
+// The largest offsets representable by a `UtcOffset`.
+const OFFSET_EAST: UtcOffset = match UtcOffset::from_hms(25, 59, 59) {
+    Ok(offset) => offset,
+    Err(_)     => panic!("invalid maximum offset"),
+};
+const OFFSET_WEST: UtcOffset = match UtcOffset::from_hms(-25, -59, -59) {
+    Ok(offset) => offset,
+    Err(_)     => panic!("invalid minimum offset"),
+};
+
+// `OffsetDateTime`s are normalized at nanosecond granularity, and are ordered
+// by the instant they represent. The `MINIMUM` and `MAXIMUM` are the earliest
+// and latest representable instants, which lie at the extreme local times of
+// the largest eastward and westward offsets. Stepping past the extreme local
+// time of a value's own offset continues in that offset.
+impl Finite for OffsetDateTime {
+    const MINIMUM: OffsetDateTime = PrimitiveDateTime::MIN
+        .assume_offset(OFFSET_EAST);
+    const MAXIMUM: OffsetDateTime = PrimitiveDateTime::MAX
+        .assume_offset(OFFSET_WEST);
+
+    fn pred(&self) -> Option<Self> {
+        self.pred_n(1)
+    }
+
+    fn succ(&self) -> Option<Self> {
+        self.succ_n(1)
+    }
+
+    fn pred_n(&self, n: usize) -> Option<Self> {
+        let n = Duration::nanoseconds(i64::try_from(n).ok()?);
+        // Near the minimum, the eastmost offset has the most room below. The
+        // value lies within `n` of its own local minimum, so it is always
+        // representable there.
+        self.checked_sub(n)
+            .or_else(|| self.to_offset(OFFSET_EAST).checked_sub(n))
+    }
+
+    fn succ_n(&self, n: usize) -> Option<Self> {
+        let n = Duration::nanoseconds(i64::try_from(n).ok()?);
+        self.checked_add(n)
+            .or_else(|| self.to_offset(OFFSET_WEST).checked_add(n))
+    }
+
+    fn steps_between(&self, other: &Self) -> Option<usize> {
+        usize::try_from((*other - *self).whole_nanoseconds()).ok()
+    }
+}
+
+impl Interval<OffsetDateTime> {
+    /// Constructs a new right-open `Interval` starting at the given time and
+    /// spanning the given `Duration`. If the `Duration` is not positive, an
+    /// empty `Interval` will be returned. If the end of the `Interval` is past
+    /// the maximum time, the `Interval` will extend to the maximum time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use time::Duration;
+    /// use time::OffsetDateTime;
+    ///
+    /// let start = OffsetDateTime::from_unix_timestamp(1_595_062_800)?;
+    /// let end = start + Duration::minutes(90);
+    /// let span = Duration::minutes(90);
+    /// let interval = Interval::offset_date_time_span(start, span);
+    ///
+    /// assert_eq!(interval, Interval::right_open(start, end));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn offset_date_time_span(start: OffsetDateTime, duration: Duration)
+        -> Self
+    {
+        if !duration.is_positive() { return Interval::empty(); }
+        match start.checked_add(duration) {
+            Some(end) => Interval::right_open(start, end),
+            None      => Interval::unbounded_from(start),
+        }
+    }
+
+    /// Returns the total `Duration` spanned by the `Interval`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Interval;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// use time::Duration;
+    /// use time::OffsetDateTime;
+    ///
+    /// let start = OffsetDateTime::from_unix_timestamp(1_595_062_800)?;
+    /// let interval = Interval::right_open(start, start + Duration::hours(2));
+    ///
+    /// assert_eq!(interval.duration(), Duration::hours(2));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn duration(&self) -> Duration {
+        match self.0 {
+            RawInterval::Empty         => Duration::ZERO,
+            RawInterval::Point(_)      => Duration::NANOSECOND,
+            RawInterval::Closed(l, u)  => (u - l) + Duration::NANOSECOND,
+            _ => unreachable!("duration for Finite interval with open bound"),
+        }
+    }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Duration
+////////////////////////////////////////////////////////////////////////////////
+
+// `Duration`s are treated as dense, so their intervals are already in normal
+// form.
+impl Normalize for RawInterval<Duration> {
+    fn normalize(&mut self) {/* Do nothing. */}
+    fn denormalize(&mut self) {/* Do nothing. */}
+}
+
+// `Duration` intervals are measured by the difference between their bounds.
+impl Measurable for Duration {
+    type Output = Duration;
+
+    fn empty_measure() -> Duration {
+        Duration::ZERO
+    }
+
+    fn measure_between(lower: &Bound<Self>, upper: &Bound<Self>)
+        -> Option<Duration>
+    {
+        match (lower.as_ref(), upper.as_ref()) {
+            (Some(l), Some(u)) => u.checked_sub(*l),
+            _                  => None,
+        }
+    }
+
+    fn checked_add_measures(a: Duration, b: Duration) -> Option<Duration> {
+        a.checked_add(b)
+    }
+
+    fn measure_to_f64(measure: &Duration) -> f64 {
+        measure.as_seconds_f64()
+    }
+}
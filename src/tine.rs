@@ -11,6 +11,7 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::error::IntervalError;
 use crate::raw_interval::RawInterval;
 use crate::utility::Few;
 
@@ -39,22 +40,24 @@ pub(in crate) enum Tine<T> {
 
 impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
     /// Returns the set of `Tine`s representing the given interval.
-    pub(in crate) fn from_raw_interval(interval: RawInterval<T>) -> Few<Self> {
+    pub(in crate) fn from_raw_interval(interval: &RawInterval<T>) -> Few<Self> {
         use RawInterval::*;
         use Bound::*;
         use Tine::{ Lower, Upper };
+        // Each endpoint is cloned into the `Tine` which represents it.
+        let c = T::clone;
         match interval {
             Empty           => Few::Zero,
-            Point(p)        => Few::One(Tine::Point(Include(p))),
-            Open(l, r)      => Few::Two(Lower(Exclude(l)), Upper(Exclude(r))),
-            LeftOpen(l, r)  => Few::Two(Lower(Exclude(l)), Upper(Include(r))),
-            RightOpen(l, r) => Few::Two(Lower(Include(l)), Upper(Exclude(r))),
-            Closed(l, r)    => Few::Two(Lower(Include(l)), Upper(Include(r))),
-            UpTo(r)         => Few::Two(Lower(Infinite),   Upper(Exclude(r))),
-            UpFrom(l)       => Few::Two(Lower(Exclude(l)), Upper(Infinite)),
-            To(r)           => Few::Two(Lower(Infinite),   Upper(Include(r))),
-            From(l)         => Few::Two(Lower(Include(l)), Upper(Infinite)),
-            Full            => Few::Two(Lower(Infinite),   Upper(Infinite)),
+            Point(p)        => Few::One(Tine::Point(Include(c(p)))),
+            Open(l, r)      => Few::Two(Lower(Exclude(c(l))), Upper(Exclude(c(r)))),
+            LeftOpen(l, r)  => Few::Two(Lower(Exclude(c(l))), Upper(Include(c(r)))),
+            RightOpen(l, r) => Few::Two(Lower(Include(c(l))), Upper(Exclude(c(r)))),
+            Closed(l, r)    => Few::Two(Lower(Include(c(l))), Upper(Include(c(r)))),
+            UpTo(r)         => Few::Two(Lower(Infinite),      Upper(Exclude(c(r)))),
+            UpFrom(l)       => Few::Two(Lower(Exclude(c(l))), Upper(Infinite)),
+            To(r)           => Few::Two(Lower(Infinite),      Upper(Include(c(r)))),
+            From(l)         => Few::Two(Lower(Include(c(l))), Upper(Infinite)),
+            Full            => Few::Two(Lower(Infinite),      Upper(Infinite)),
         }
     }
 
@@ -80,6 +83,14 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
         }
     }
 
+    /// Returns `true` if the `Tine` is a `Point` tine.
+    pub(in crate) fn is_point(&self) -> bool {
+        match self {
+            &Tine::Point(_) => true,
+            _               => false,
+        }
+    }
+
     /// Returns `true` if the `Tine` represents a single point.
     pub(in crate) fn is_point_include(&self) -> bool {
         use Bound::*;
@@ -122,6 +133,19 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
         }
     }
 
+    /// Returns the inner `Bound` by reference.
+    pub(in crate) fn bound(&self) -> Bound<&T> {
+        use Bound::*;
+        use Tine::*;
+        match *self {
+            Lower(ref x) | Point(ref x) | Upper(ref x) => match *x {
+                Include(ref p) => Include(p),
+                Exclude(ref p) => Exclude(p),
+                Infinite       => Infinite,
+            },
+        }
+    }
+
     /// Unifies two equal `Tines` by including any coincident points. Returns 
     /// `None` if all points in the boundry region are included.
     pub(in crate) fn union(self, other: &Self) -> Option<Self> {
@@ -231,7 +255,9 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             (Lower(_),          &Lower(_))          => None,
 
             (Lower(Include(l)), &Point(Include(_))) => Some(Lower(Exclude(l))),
-            (Lower(_),          &Point(_))          => None,
+            (Lower(Include(l)), &Point(Exclude(_))) => Some(Point(Include(l))),
+            (Lower(Exclude(l)), &Point(Include(_))) => Some(Lower(Exclude(l))),
+            (Lower(Exclude(_)), &Point(Exclude(_))) => None,
 
             (Lower(Include(l)), &Upper(Include(_))) => Some(Lower(Exclude(l))),
             (Lower(Include(l)), &Upper(Exclude(_))) => Some(Lower(Include(l))),
@@ -243,10 +269,10 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             (Point(Include(_)), &Upper(Include(_))) => None,
             (Point(Include(l)), _)                  => Some(Point(Include(l))),
 
-            (Point(Exclude(l)), &Lower(_))          => Some(Lower(Exclude(l))),
+            (Point(Exclude(l)), &Lower(_))          => Some(Upper(Exclude(l))),
             (Point(Exclude(l)), &Point(Include(_))) => Some(Point(Exclude(l))),
             (Point(Exclude(_)), &Point(Exclude(_))) => None,
-            (Point(Exclude(l)), &Upper(_))          => Some(Upper(Exclude(l))),
+            (Point(Exclude(l)), &Upper(_))          => Some(Lower(Exclude(l))),
 
             (Upper(Include(l)), &Lower(Include(_))) => Some(Upper(Exclude(l))),
             (Upper(Include(l)), &Lower(Exclude(_))) => Some(Upper(Include(l))),
@@ -254,7 +280,9 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
             (Upper(Exclude(l)), &Lower(Exclude(_))) => Some(Upper(Exclude(l))),
 
             (Upper(Include(l)), &Point(Include(_))) => Some(Upper(Exclude(l))),
-            (Upper(_),          &Point(_))          => None,
+            (Upper(Include(l)), &Point(Exclude(_))) => Some(Point(Include(l))),
+            (Upper(Exclude(l)), &Point(Include(_))) => Some(Upper(Exclude(l))),
+            (Upper(Exclude(_)), &Point(Exclude(_))) => None,
 
             (Upper(Include(l)), &Upper(Exclude(_))) => Some(Point(Include(l))),
             (Upper(_),          &Upper(_))          => None,
@@ -264,17 +292,27 @@ impl<T> Tine<T> where T: PartialOrd + Ord + Clone {
     }
 
     /// Returns the `Tine` with its boundaries inverted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `Tine` is infinite.
     pub(in crate) fn invert(self) -> Self {
+        self.try_invert().expect("cannot invert infinite Tine")
+    }
+
+    /// Returns the `Tine` with its boundaries inverted, or an error if it is
+    /// infinite.
+    pub(in crate) fn try_invert(self) -> Result<Self, IntervalError> {
         use Bound::*;
         use Tine::*;
         match self {
-            Lower(Include(p)) => Upper(Exclude(p)),
-            Lower(Exclude(p)) => Upper(Include(p)),
-            Point(Include(p)) => Point(Exclude(p)),
-            Point(Exclude(p)) => Point(Include(p)),
-            Upper(Include(p)) => Lower(Exclude(p)),
-            Upper(Exclude(p)) => Lower(Include(p)),
-            _ => panic!("cannot invert infinite Tine"),
+            Lower(Include(p)) => Ok(Upper(Exclude(p))),
+            Lower(Exclude(p)) => Ok(Upper(Include(p))),
+            Point(Include(p)) => Ok(Point(Exclude(p))),
+            Point(Exclude(p)) => Ok(Point(Include(p))),
+            Upper(Include(p)) => Ok(Lower(Exclude(p))),
+            Upper(Exclude(p)) => Ok(Lower(Include(p))),
+            _ => Err(IntervalError::InfiniteBound),
         }
     }
 }
@@ -14,15 +14,22 @@
 
 // Local imports.
 use crate::bound::Bound;
+use crate::bound::LowerBound;
+use crate::error::IntervalError;
 use crate::raw_interval::RawInterval;
+use crate::raw_interval::RawIntervalRef;
 use crate::tine::Tine;
 use crate::utility::Few;
 
 // Standard library imports.
 use std::collections::BTreeSet;
 use std::collections::btree_set;
-use std::collections;
+use std::hash::Hash;
+use std::hash::Hasher;
 use std::iter::FromIterator;
+use std::iter::FusedIterator;
+use std::mem::size_of;
+use std::ops::RangeBounds;
 
 
 ////////////////////////////////////////////////////////////////////////////////
@@ -41,7 +48,7 @@ use std::iter::FromIterator;
 /// [`Interval`]: interval/struct.Interval.html
 ///
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub(in crate) struct TineTree<T>(BTreeSet<Tine<T>>);
+pub struct TineTree<T>(TineSet<T>);
 
 impl<T> TineTree<T> where T: Ord + Clone {
     ////////////////////////////////////////////////////////////////////////////
@@ -49,33 +56,92 @@ impl<T> TineTree<T> where T: Ord + Clone {
     ////////////////////////////////////////////////////////////////////////////
 
     /// Constructs an empty `TineTree`.
-    pub(in crate) fn new() -> Self {
-        TineTree(BTreeSet::new())
+    pub(in crate) const fn new() -> Self {
+        TineTree(TineSet::new())
     }
 
     /// Constructs a `TineTree` from a `RawInterval`.
     pub(in crate) fn from_raw_interval(interval: RawInterval<T>) -> Self {
-        TineTree(BTreeSet::from_iter(Tine::from_raw_interval(interval)))
+        TineTree(TineSet::from_iter(Tine::from_raw_interval(&interval)))
     }
 
-    ////////////////////////////////////////////////////////////////////////////
-    // Bound accessors
-    ////////////////////////////////////////////////////////////////////////////
-
-    /// Returns the lower [`Bound`] of the `TineTree`, or `None` if the 
-    /// `TineTree` is empty.
-    #[inline]
-    pub(in crate) fn lower_bound(&self) -> Option<Bound<T>> {
-        self.0.iter().next().cloned().map(Tine::into_inner)
+    /// Constructs a `TineTree` containing the union of the given
+    /// `RawInterval`s by sorting them and merging them in a single sweep.
+    pub(in crate) fn union_all<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=RawInterval<T>>
+    {
+        let mut intervals: Vec<_> = intervals
+            .into_iter()
+            .filter(|interval| !interval.is_empty())
+            .collect();
+        intervals.sort_by_cached_key(|interval| LowerBound(interval
+            .lower_bound()
+            .expect("nonempty interval")));
+        Self::from_sorted_disjoint(intervals)
     }
 
-    /// Returns the upper [`Bound`] of the `TineTree`, or `None` if the 
-    /// `TineTree` is empty.
-    #[inline]
-    pub(in crate) fn upper_bound(&self) -> Option<Bound<T>> {
-        self.0.iter().next_back().cloned().map(Tine::into_inner)
+    /// Constructs a `TineTree` from `RawInterval`s sorted by their lower
+    /// bounds, without searching the tree for each of them. Any intervals
+    /// which overlap or are adjacent are merged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the intervals are not sorted by their lower bounds.
+    pub(in crate) fn from_sorted_disjoint<I>(intervals: I) -> Self
+        where I: IntoIterator<Item=RawInterval<T>>
+    {
+        Self::try_from_sorted_disjoint(intervals)
+            .expect("intervals are not sorted by their lower bounds")
     }
 
+    /// Constructs a `TineTree` from `RawInterval`s sorted by their lower
+    /// bounds, or returns an error if they are not sorted.
+    pub(in crate) fn try_from_sorted_disjoint<I>(intervals: I)
+        -> Result<Self, IntervalError>
+        where I: IntoIterator<Item=RawInterval<T>>
+    {
+        // Merge overlapping and adjacent intervals. Each interval need only
+        // be checked against the last, as the ones before it are below it.
+        let mut merged: Vec<RawInterval<T>> = Vec::new();
+        for interval in intervals {
+            let lower = match interval.lower_bound() {
+                Some(lower) => LowerBound(lower),
+                None        => continue,
+            };
+            match merged.last_mut() {
+                Some(last) => {
+                    if LowerBound(last
+                        .lower_bound()
+                        .expect("nonempty interval")) > lower
+                    {
+                        return Err(IntervalError::UnsortedIntervals);
+                    }
+                    if last.intersects(&interval) || last.adjacent(&interval) {
+                        *last = last.enclose(&interval);
+                    } else {
+                        merged.push(interval);
+                    }
+                },
+                None => merged.push(interval),
+            }
+        }
+
+        let mut tines: Vec<Tine<T>> = Vec::with_capacity(merged.len() * 2);
+        for tine in merged.iter().flat_map(Tine::from_raw_interval) {
+            // The remaining intervals may only share an excluded point.
+            match tines.last_mut() {
+                Some(last) if tine.as_ref().is_some()
+                    && last.as_ref() == tine.as_ref() =>
+                {
+                    *last = last.clone()
+                        .union(&tine)
+                        .expect("union of disjoint interval tines");
+                },
+                _ => tines.push(tine),
+            }
+        }
+        Ok(TineTree(TineSet::from_iter(tines)))
+    }
 
     ////////////////////////////////////////////////////////////////////////////
     // Query operations
@@ -86,6 +152,24 @@ impl<T> TineTree<T> where T: Ord + Clone {
         self.0.is_empty()
     }
 
+    /// Returns the number of disjoint intervals in the `TineTree`.
+    pub(in crate) fn interval_count(&self) -> usize {
+        // Every interval has a lower and upper tine, except that a point
+        // tine may stand for both, or for the bounds of adjacent intervals.
+        (self.0.len() + self.0.points) / 2
+    }
+
+    /// Returns the number of `Tine`s in the `TineTree`.
+    pub(in crate) fn tine_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an estimate of the number of bytes of heap memory used to store
+    /// the `TineTree`'s `Tine`s.
+    pub(in crate) fn heap_size_estimate(&self) -> usize {
+        self.0.heap_size_estimate()
+    }
+
     /// Returns `true` if the `TineTree` is full.
     pub(in crate) fn is_full(&self) -> bool {
         self.0.iter().collect::<Vec<_>>() == [
@@ -95,11 +179,23 @@ impl<T> TineTree<T> where T: Ord + Clone {
 
     /// Returns `true` if the `TineTree` contains the given point.
     pub(in crate) fn contains(&self, point: &T) -> bool {
-        // TODO(Sky): Could be optimized by splitting the tree and looking around.
-        for interval in self.interval_iter() {
-            if interval.contains(point) {return true;}
+        use Bound::*;
+        use Tine::*;
+
+        let probe = Point(Include(point.clone()));
+        match self.0.range(..=&probe).next_back() {
+            // A tine at the point includes it only if its bound does.
+            Some(tine) if tine.as_ref() == Some(point) => match tine {
+                &Lower(Include(_)) |
+                &Point(Include(_)) |
+                &Upper(Include(_)) => true,
+                _                  => false,
+            },
+            // Otherwise the point is inside an interval only if the
+            // preceding tine starts one.
+            Some(tine) => tine.is_lower_bound(),
+            None       => false,
         }
-        false
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -164,28 +260,83 @@ impl<T> TineTree<T> where T: Ord + Clone {
     /// Returns a `TineTree` containing all points in present in both of the 
     /// `TineTree`s.
     pub(in crate) fn intersect(&self, other: &Self) -> Self {
-        let mut intersection = Self::new();
+        // Both trees are walked once in order, so each overlap follows the
+        // last and the tines can be collected directly.
+        let mut intersection = Vec::new();
         let mut self_intervals = self.interval_iter();
         let mut other_intervals = other.interval_iter();
+        let mut self_interval = self_intervals.next();
+        let mut other_interval = other_intervals.next();
 
-        while let Some(self_interval) = self_intervals.next() {
-            'segment: loop {
-                if let Some(other_interval) = other_intervals.next() {
-                    let i = self_interval.intersect(&other_interval);
-                    if !i.is_empty() {
-                        intersection.union_in_place(&i);
-                    } else {
-                        // Nothing else overlaps in this segment.
-                        break 'segment;
-                    }
+        while let (Some(a), Some(b)) = (&self_interval, &other_interval) {
+            let i = a.intersect(b);
+            if !i.is_empty() {
+                intersection.push(i);
+            }
 
-                } else {
-                    // Nothing else overlaps anywhere.
-                    return intersection;
+            // Advance whichever interval ends first, as it cannot overlap
+            // anything further in the other tree.
+            let a_upper = a.upper_bound().expect("nonempty interval");
+            let b_upper = b.upper_bound().expect("nonempty interval");
+            if a_upper.least_intersect(&b_upper) == a_upper {
+                self_interval = self_intervals.next();
+            } else {
+                other_interval = other_intervals.next();
+            }
+        }
+        Self::from_sorted_disjoint(intersection)
+    }
+
+    /// Returns a `TineTree` containing all points present in every one of the
+    /// given `TineTree`s, or a full `TineTree` if none are given.
+    pub(in crate) fn intersect_all<'t, I>(trees: I) -> Self
+        where
+            I: IntoIterator<Item=&'t TineTree<T>>,
+            T: 't,
+    {
+        let mut iters: Vec<_> = trees
+            .into_iter()
+            .map(TineTree::interval_iter)
+            .collect();
+        if iters.is_empty() {
+            return RawInterval::Full.into();
+        }
+
+        let mut heads = Vec::with_capacity(iters.len());
+        for iter in &mut iters {
+            match iter.next() {
+                Some(interval) => heads.push(interval),
+                None           => return Self::new(),
+            }
+        }
+
+        let mut intersection = Vec::new();
+        loop {
+            let i = heads[1..]
+                .iter()
+                .fold(heads[0].clone(), |acc, head| acc.intersect(head));
+            if !i.is_empty() {
+                intersection.push(i);
+            }
+
+            // Advance whichever interval ends first, as it cannot overlap
+            // anything further in the other trees.
+            let mut first = 0;
+            let mut first_upper = heads[0].upper_bound()
+                .expect("nonempty interval");
+            for (idx, head) in heads.iter().enumerate().skip(1) {
+                let upper = head.upper_bound().expect("nonempty interval");
+                if first_upper.least_intersect(&upper) != first_upper {
+                    first = idx;
+                    first_upper = upper;
                 }
             }
+            match iters[first].next() {
+                Some(interval) => heads[first] = interval,
+                None           => break,
+            }
         }
-        intersection
+        Self::from_sorted_disjoint(intersection)
     }
 
     /// Returns a `TineTree` containing all points present in either of the 
@@ -246,10 +397,15 @@ impl<T> TineTree<T> where T: Ord + Clone {
         RawInterval::new(lb, ub)
     }
 
-    /// Returns the smallest closed `RawInterval` containing all of the points
-    /// in the `TineTree`.
-    pub(in crate) fn closure(&self) -> RawInterval<T> {
-        self.enclose().closure()
+    /// Returns a `TineTree` with each tine replaced by the result of the given
+    /// function, or `None` if the function returns `None` for any tine. The
+    /// function must preserve the order of the tines.
+    pub(in crate) fn try_map_tines<F>(&self, f: F) -> Option<Self>
+        where F: FnMut(&Tine<T>) -> Option<Tine<T>>
+    {
+        let tines = self.0.iter().map(f).collect::<Option<TineSet<T>>>()?;
+        debug_assert_eq!(tines.len(), self.0.len());
+        Some(TineTree(tines))
     }
 
     ////////////////////////////////////////////////////////////////////////////
@@ -271,7 +427,7 @@ impl<T> TineTree<T> where T: Ord + Clone {
         }
 
         // Early exit if we're intersection a point interval.
-        if let &RawInterval::Point(ref pt) = interval {
+        if let RawInterval::Point(pt) = interval {
             if self.contains(pt) {
                 *self = TineTree::from_raw_interval(interval.clone());
             } else {
@@ -280,7 +436,7 @@ impl<T> TineTree<T> where T: Ord + Clone {
             return;
         }
 
-        match Tine::from_raw_interval(interval.clone()) {
+        match Tine::from_raw_interval(interval) {
             Few::Zero                   => {
                 *self = TineTree::new();
                 return;
@@ -303,33 +459,9 @@ impl<T> TineTree<T> where T: Ord + Clone {
     fn intersect_proper_interval(&mut self, l: Tine<T>, u: Tine<T>) {
         let mut ts = self.interior_split_for_proper_interval(&l, &u);
 
-        // Merge tines if overlap or use given ones. We should only have `None`
-        // in the case of a intersection annhiliation.
-        let merged_l = if ts[2].is_some() {
-            ts[2].take().and_then(|lower| lower.intersect(&l))
-        } else {
-            Some(l)
-        };
-
-        let merged_u = if ts[3].is_some() {
-            ts[3].take().and_then(|upper| upper.intersect(&u))
-        } else {
-            Some(u)
-        };
-        
-        // Ensure inner tines have the correct bounds.
-        debug_assert!(merged_l
-            .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(true));
-        debug_assert!(merged_u
-            .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(true));
-
-        
-        // We need to detect whether the point is inside or outside an interval.
-        // To do this, we look at the tines inside and outside the interval.
+        // We need to detect whether each bound is inside or outside an
+        // interval. To do this, we look at the nearest tines outside of the
+        // given interval.
         let open_before = ts[0]
             .as_ref()
             .map(Tine::is_lower_bound)
@@ -339,61 +471,33 @@ impl<T> TineTree<T> where T: Ord + Clone {
             .map(Tine::is_upper_bound)
             .unwrap_or(false);
 
-        let in_l = ts[1]
+        // Merge tines if overlap. Otherwise the given tines are only kept if
+        // they are wrapped by a surrounding interval. We should only have
+        // `None` in the case of a intersection annhiliation or if the bound
+        // lies outside of every interval.
+        let merged_l = match ts[2].take() {
+            Some(lower)         => lower.intersect(&l),
+            None if open_before => Some(l),
+            None                => None,
+        };
+        let merged_u = match ts[3].take() {
+            Some(upper)          => upper.intersect(&u),
+            None if closed_after => Some(u),
+            None                 => None,
+        };
+
+        // Ensure inner tines have the correct bounds.
+        debug_assert!(merged_l
             .as_ref()
-            .map(Tine::is_upper_bound)
-            .unwrap_or(false);
-        let in_r = ts[4]
+            .map(|t| t.is_lower_bound() || t.is_point_include())
+            .unwrap_or(true));
+        debug_assert!(merged_u
             .as_ref()
-            .map(Tine::is_lower_bound)
-            .unwrap_or(false);
-
+            .map(|t| t.is_upper_bound() || t.is_point_include())
+            .unwrap_or(true));
 
-        // Insert tines into the tree, ignoring them if the are not wrapped by a
-        // surrounding interval, or not wrapping a surrounding interval.
-        match (open_before, merged_l, in_l, in_r, merged_u, closed_after) {
-            (_,     Some(l), true,  true,  Some(u), _   )  |
-            (_,     Some(l), false, false, Some(u), _   )  => {
-                // (   ) (   )
-                //   (     )
-                //     O R
-                // (     )
-                //   ( )
-                //     O R
-                // (     )
-                // (  )
-                //     O R
-                // (     )
-                //    (  )
-                self.0.insert(l);
-                self.0.insert(u);
-            },
-            (true, Some(l),  true,  false, _,       false) => {
-                // (   )
-                //   (   )
-                //     O R
-                // (   ) ( )
-                //   (   )
-                self.0.insert(l);
-            },
-            (false, _,       false, true,  Some(u), true)  => {
-                //   (   )
-                // (   )
-                //     O R
-                // ( ) (   )
-                //   (   )
-                self.0.insert(u);
-            },
-            (false, _,       false, false, _,       false) => {
-                // )     (
-                // (     )
-                //     O R
-                //   ( )
-                // (     )
-                /* Do nothing. */
-            },
-            _ => unreachable!("invalid bounds for intersection interval"),
-        }
+        if let Some(l) = merged_l { self.0.insert(l); }
+        if let Some(u) = merged_u { self.0.insert(u); }
     }
 
     /// Unions the given interval with the contents of the tree.
@@ -404,7 +508,7 @@ impl<T> TineTree<T> where T: Ord + Clone {
             return;
         }
 
-        match Tine::from_raw_interval(interval.clone()) {
+        match Tine::from_raw_interval(interval) {
             Few::Zero      => return,
             Few::One(p)    => self.union_point_interval(p),
             Few::Two(l, u) => self.union_proper_interval(l, u),
@@ -606,30 +710,48 @@ impl<T> TineTree<T> where T: Ord + Clone {
             return;
         }
 
-        match Tine::from_raw_interval(interval.clone()) {
+        match Tine::from_raw_interval(interval) {
             Few::Zero      => return,
             Few::One(p)    => self.minus_point_interval(p),
             Few::Two(l, u) => self.minus_proper_interval(l, u),
         }
     }
 
+    /// Replaces the tine at the given tine's point, if any, with the result of
+    /// the given function. The replacement is unioned with any tine already
+    /// present at its point.
+    pub(in crate) fn replace_tine<F>(&mut self, at: &Tine<T>, f: F)
+        where F: FnOnce(Tine<T>) -> Tine<T>
+    {
+        if let Some(tine) = self.0.take(at) {
+            let tine = f(tine);
+            match self.0.take(&tine) {
+                Some(existing) => if let Some(merged) = existing.union(&tine) {
+                    let _ = self.0.insert(merged);
+                },
+                None => { let _ = self.0.insert(tine); },
+            }
+        }
+    }
+
     fn minus_point_interval(&mut self, p: Tine<T>) {
         let mut ts = self.exterior_split_for_point_interval(&p);
 
-        let p = if ts[1].is_some() {
-            if let Some(merged) = ts[1]
-                .take()
-                .and_then(|pt| pt.minus(&p)) 
-            {
-                merged
-            } else {
-                // If the point annhilates, then we've already joined two
-                // intervals by removing the Point(Exclude(_)) from the tree in
-                // minus_split_tree_point. So nothing else needs to happen.
+        let p = match ts[1].take() {
+            // A Point(Exclude(_)) already separates two intervals at the
+            // point, so it is not in the tree and nothing needs to happen.
+            Some(pt) if pt.is_point_exclude() => {
+                self.0.insert(pt);
                 return;
-            }
-        } else {
-            p
+            },
+            Some(pt) => match pt.minus(&p) {
+                Some(merged) => merged,
+                // If the point annhilates, then we've already joined two
+                // intervals by removing the Point(Exclude(_)) from the tree
+                // in minus_split_tree_point. So nothing else needs to happen.
+                None => return,
+            },
+            None => p,
         };
         
         // We need to detect whether the point is inside or outside an interval.
@@ -646,7 +768,8 @@ impl<T> TineTree<T> where T: Ord + Clone {
         // Insert tine into the tree, ignoring it if it is wrapped by a
         // surrounding interval.
         // NOTE: We cannot have a Point(Exclude) here, because those will never
-        // result from an interval-tine conversion.
+        // result from an interval-tine conversion, and any already in the
+        // tree are returned above.
         match (open_before, closed_after) {
             (true,  true)  => {
                 // (   )
@@ -701,7 +824,6 @@ impl<T> TineTree<T> where T: Ord + Clone {
             .map(Tine::is_upper_bound)
             .unwrap_or(false);
         
-        println!("Minus {} {} {} {}", open_before, merged_l.is_some(), merged_u.is_some(), closed_after);
         // Insert tines into the tree, ignoring them if the are not wrapped by a
         // surounding interval.
         use Bound::*;
@@ -860,14 +982,11 @@ impl<T> TineTree<T> where T: Ord + Clone {
         let mut res = [None, None, None];
 
         // Get pt if it is in the tree.
-        res[1] = self.0.take(&tine);
-
-        // Get before and after points.
-        let mut right_side = self.0.split_off(&tine);
-        res[0] = self.0.iter().next_back().cloned();
-        res[2] = right_side.iter().next().cloned();
+        res[1] = self.0.take(tine);
 
-        self.0.append(&mut right_side);
+        // Get before and after points. Nothing else in the tree is touched.
+        res[0] = self.0.range(..tine).next_back().cloned();
+        res[2] = self.0.range(tine..).next().cloned();
         res
     }
 
@@ -897,6 +1016,19 @@ impl<T> TineTree<T> where T: Ord + Clone {
         res[1] = self.0.take(lower);
         res[2] = self.0.take(upper);
 
+        // A point, or the interval a point denormalizes to, holds at most
+        // one tine, which can be dropped without splitting the tree.
+        let (first, more) = {
+            let mut inner = self.0.range(lower..upper);
+            (inner.next().cloned(), inner.next().is_some())
+        };
+        if !more {
+            if let Some(first) = first { self.0.take(&first); }
+            res[0] = self.0.range(..lower).next_back().cloned();
+            res[3] = self.0.range(upper..).next().cloned();
+            return res;
+        }
+
         // Get before and after points and drop anything in the center.
         let mut center = self.0.split_off(&lower);
         {
@@ -920,12 +1052,72 @@ impl<T> TineTree<T> where T: Ord + Clone {
 
     /// Returns an iterator over each of the `RawInterval`s in the tree.
     pub(in crate) fn interval_iter(&self) -> Iter<'_, T> {
-        Iter {
-            tine_iter: self.0.iter(),
+        Iter(self.interval_ref_iter())
+    }
+
+    /// Returns an iterator over the `Tine`s in the tree.
+    pub(in crate) fn tine_iter(&self) -> impl Iterator<Item=&Tine<T>> + '_ {
+        self.0.iter()
+    }
+
+    /// Returns an iterator over borrowed views of each of the `RawInterval`s
+    /// in the tree.
+    pub(in crate) fn interval_ref_iter(&self) -> RefIter<'_, T> {
+        RefIter {
+            tine_iter: self.0.range(..),
             saved_lower: None,
             saved_upper: None,
+            remaining: Some(self.interval_count()),
         }
     }
+
+    /// Returns an iterator over the `RawInterval`s in the tree, starting from
+    /// the first one whose upper bound is not below the given point.
+    pub(in crate) fn interval_iter_from(&self, point: &T) -> Iter<'_, T> {
+        let probe = Tine::Point(Bound::Include(point.clone()));
+        let tine_iter = match self.seek(&probe) {
+            Some(start) => self.0.range(start..),
+            None        => self.0.range(&probe..),
+        };
+        Iter(RefIter {
+            tine_iter,
+            saved_lower: None,
+            saved_upper: None,
+            remaining: None,
+        })
+    }
+
+    /// Returns an iterator over the `RawInterval`s in the tree which precede
+    /// those returned by `interval_iter_from` for the given point.
+    pub(in crate) fn interval_iter_before(&self, point: &T) -> Iter<'_, T> {
+        let probe = Tine::Point(Bound::Include(point.clone()));
+        let (tine_iter, saved_upper) = match self.seek(&probe) {
+            // A point exclude tine is also the upper bound of the interval
+            // before it.
+            Some(end) if end.is_point_exclude() => {
+                (self.0.range(..end), Some(end))
+            },
+            Some(end) => (self.0.range(..end), None),
+            None      => (self.0.range(..&probe), None),
+        };
+        Iter(RefIter {
+            tine_iter,
+            saved_lower: None,
+            saved_upper,
+            remaining: None,
+        })
+    }
+
+    /// Returns the tine which starts the first interval whose upper bound is
+    /// not below the given point tine, or `None` if that interval starts after
+    /// it.
+    fn seek(&self, probe: &Tine<T>) -> Option<&Tine<T>> {
+        // If the preceding tine is a lower bound, the interval it starts may
+        // extend to the point.
+        self.0.range(..probe)
+            .next_back()
+            .filter(|prev| prev.is_lower_bound())
+    }
 }
 
 impl<T> Default for TineTree<T> where T: Ord + Clone {
@@ -949,11 +1141,7 @@ impl<T, I> From<I> for TineTree<T>
         I: Iterator<Item=RawInterval<T>>
 {
     fn from(iter: I) -> Self {
-        let mut tine_tree = TineTree::new();
-        for interval in iter {
-            tine_tree.union_in_place(&interval);
-        }
-        tine_tree
+        TineTree::union_all(iter)
     }
 }
 
@@ -963,11 +1151,7 @@ impl<T> FromIterator<RawInterval<T>> for TineTree<T>
     fn from_iter<I>(iter: I) -> Self
         where I: IntoIterator<Item=RawInterval<T>>
     {
-        let mut tine_tree = TineTree::new();
-        for interval in iter.into_iter() {
-            tine_tree.union_in_place(&interval);
-        }
-        tine_tree
+        TineTree::union_all(iter)
     }
 }
 
@@ -978,10 +1162,12 @@ impl<T> IntoIterator for TineTree<T>
     type IntoIter = IntoIter<T>;
 
     fn into_iter(self) -> Self::IntoIter {
+        let remaining = self.interval_count();
         IntoIter {
             inner: self.0.into_iter(),
             saved_lower: None,
             saved_upper: None,
+            remaining,
         }
     }
 }
@@ -992,10 +1178,12 @@ impl<T> IntoIterator for TineTree<T>
 ////////////////////////////////////////////////////////////////////////////////
 /// An owning `Iterator` over the `TineTree`s `RawInterval`s.
 #[derive(Debug)]
-pub(in crate) struct IntoIter<T> {
-    inner: btree_set::IntoIter<Tine<T>>,
+pub struct IntoIter<T> {
+    inner: TineSetIntoIter<T>,
     saved_lower: Option<Tine<T>>,
     saved_upper: Option<Tine<T>>,
+    /// The number of intervals not yet returned.
+    remaining: usize,
 }
 
 impl<T> Iterator for IntoIter<T> where T: Ord + Clone {
@@ -1004,7 +1192,7 @@ impl<T> Iterator for IntoIter<T> where T: Ord + Clone {
     fn next(&mut self) -> Option<Self::Item> {
         use Bound::*;
         use Tine::*;
-        self.saved_lower
+        let next = self.saved_lower
             .take()
             .or_else(|| self.inner.next())
             .map(|lower| {
@@ -1015,13 +1203,19 @@ impl<T> Iterator for IntoIter<T> where T: Ord + Clone {
                     // Next tine must be a lower bound of an interval.
                     debug_assert!(lower.is_lower_bound());
 
-                    let upper = self.inner.next().clone()
-                        .or_else(|| self.saved_upper.take())
-                        .expect("interval is not partial");
-
-                    if upper.is_point_exclude() {
-                        self.saved_lower = Some(upper.clone());
-                    }
+                    // A point exclude tine is shared with the adjacent
+                    // interval, unless it was saved from the other end.
+                    let upper = match self.inner.next() {
+                        Some(upper) => {
+                            if upper.is_point_exclude() {
+                                self.saved_lower = Some(upper.clone());
+                            }
+                            upper
+                        },
+                        None => self.saved_upper
+                            .take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be an upper bound.
                     debug_assert!(upper.is_upper_bound());
@@ -1030,7 +1224,13 @@ impl<T> Iterator for IntoIter<T> where T: Ord + Clone {
                     let upper = upper.into_inner();
                     RawInterval::new(lower, upper)
                 }
-            })
+            });
+        if next.is_some() { self.remaining -= 1; }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
     }
 }
 
@@ -1040,7 +1240,7 @@ impl<T> DoubleEndedIterator for IntoIter<T>
     fn next_back(&mut self) -> Option<Self::Item> {
         use Bound::*;
         use Tine::*;
-        self.saved_upper
+        let next = self.saved_upper
             .take()
             .or_else(|| self.inner.next_back())
             .map(|upper| {
@@ -1051,13 +1251,19 @@ impl<T> DoubleEndedIterator for IntoIter<T>
                     // Next tine must be an upper bound of an interval.
                     debug_assert!(upper.is_upper_bound());
 
-                    let lower = self.inner.next_back().clone()
-                        .or_else(|| self.saved_lower.take())
-                        .expect("interval is not partial");
-
-                    if lower.is_point_exclude() {
-                        self.saved_lower = Some(lower.clone());
-                    }
+                    // A point exclude tine is shared with the adjacent
+                    // interval, unless it was saved from the other end.
+                    let lower = match self.inner.next_back() {
+                        Some(lower) => {
+                            if lower.is_point_exclude() {
+                                self.saved_upper = Some(lower.clone());
+                            }
+                            lower
+                        },
+                        None => self.saved_lower
+                            .take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be a lower bound.
                     debug_assert!(lower.is_lower_bound());
@@ -1066,20 +1272,22 @@ impl<T> DoubleEndedIterator for IntoIter<T>
                     let lower = lower.into_inner();
                     RawInterval::new(lower, upper)
                 }
-            })
+            });
+        if next.is_some() { self.remaining -= 1; }
+        next
     }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> where T: Ord + Clone {}
+
+impl<T> FusedIterator for IntoIter<T> where T: Ord + Clone {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Iter
 ////////////////////////////////////////////////////////////////////////////////
 /// An `Iterator` that constructs `RawInterval`s from a sequence of `Tine`s.
 #[derive(Debug)]
-pub(in crate) struct Iter<'t, T> {
-    tine_iter: collections::btree_set::Iter<'t, Tine<T>>,
-    saved_lower: Option<Tine<T>>,
-    saved_upper: Option<Tine<T>>,
-}
+pub(in crate) struct Iter<'t, T>(RefIter<'t, T>);
 
 impl<'t, T> Iterator for Iter<'t, T>
     where T: Ord + Clone
@@ -1087,75 +1295,455 @@ impl<'t, T> Iterator for Iter<'t, T>
     type Item = RawInterval<T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        use Bound::*;
-        use Tine::*;
-        self.saved_lower
+        self.0.next().map(|interval| interval.to_raw_interval())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<'t, T> DoubleEndedIterator for Iter<'t, T>
+    where T: Ord + Clone 
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back().map(|interval| interval.to_raw_interval())
+    }
+}
+
+impl<'t, T> FusedIterator for Iter<'t, T> where T: Ord + Clone {}
+
+////////////////////////////////////////////////////////////////////////////////
+// RefIter
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` that constructs `RawIntervalRef`s from a sequence of `Tine`s.
+#[derive(Debug)]
+pub(in crate) struct RefIter<'t, T> {
+    tine_iter: TineSetRange<'t, T>,
+    saved_lower: Option<&'t Tine<T>>,
+    saved_upper: Option<&'t Tine<T>>,
+    /// The number of intervals not yet returned, if the iterator covers the
+    /// whole tree.
+    remaining: Option<usize>,
+}
+
+impl<'t, T> Iterator for RefIter<'t, T>
+    where T: Ord + Clone
+{
+    type Item = RawIntervalRef<'t, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.saved_lower
             .take()
-            .or_else(|| self.tine_iter.next().cloned())
+            .or_else(|| self.tine_iter.next())
             .map(|lower| {
-                if let Point(Include(p)) = lower {
+                if lower.is_point_include() {
                     // Next tine is a single point.
-                    RawInterval::Point(p)
+                    RawIntervalRef::new(lower.bound(), lower.bound())
                 } else {
                     // Next tine must be a lower bound of an interval.
                     debug_assert!(lower.is_lower_bound());
 
-                    let upper = self.tine_iter.next().cloned()
-                        .or_else(|| self.saved_upper.take())
-                        .expect("interval is not partial");
-
-                    if upper.is_point_exclude() {
-                        self.saved_lower = Some(upper.clone());
-                    }
+                    // A point exclude tine is shared with the adjacent
+                    // interval, unless it was saved from the other end.
+                    let upper = match self.tine_iter.next() {
+                        Some(upper) => {
+                            if upper.is_point_exclude() {
+                                self.saved_lower = Some(upper);
+                            }
+                            upper
+                        },
+                        None => self.saved_upper
+                            .take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be an upper bound.
                     debug_assert!(upper.is_upper_bound());
 
-                    let lower = lower.into_inner();
-                    let upper = upper.into_inner();
-                    RawInterval::new(lower, upper)
+                    RawIntervalRef::new(lower.bound(), upper.bound())
                 }
-            })
+            });
+        if next.is_some() {
+            if let Some(n) = self.remaining.as_mut() { *n -= 1; }
+        }
+        next
+    }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if let Some(n) = self.remaining { return (n, Some(n)); }
+
+        // Each interval takes one or two of the remaining tines, so the bounds
+        // follow from the tine count.
+        let saved = self.saved_lower.iter().count()
+            + self.saved_upper.iter().count();
+        let (lower, upper) = self.tine_iter.size_hint();
+        ((lower + saved).div_ceil(2), upper.map(|upper| upper + saved))
     }
 }
 
-impl<'t, T> DoubleEndedIterator for Iter<'t, T>
+impl<'t, T> DoubleEndedIterator for RefIter<'t, T>
     where T: Ord + Clone 
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        use Bound::*;
-        use Tine::*;
-        self.saved_upper
+        let next = self.saved_upper
             .take()
-            .or_else(|| self.tine_iter.next_back().cloned())
+            .or_else(|| self.tine_iter.next_back())
             .map(|upper| {
-                if let Point(Include(p)) = upper {
+                if upper.is_point_include() {
                     // Next tine is a single point.
-                    RawInterval::Point(p)
+                    RawIntervalRef::new(upper.bound(), upper.bound())
                 } else {
                     // Next tine must be an upper bound of an interval.
                     debug_assert!(upper.is_upper_bound());
 
-                    let lower = self.tine_iter.next_back().cloned()
-                        .or_else(|| self.saved_lower.take())
-                        .expect("interval is not partial");
-
-                    if lower.is_point_exclude() {
-                        self.saved_lower = Some(lower.clone());
-                    }
+                    // A point exclude tine is shared with the adjacent
+                    // interval, unless it was saved from the other end.
+                    let lower = match self.tine_iter.next_back() {
+                        Some(lower) => {
+                            if lower.is_point_exclude() {
+                                self.saved_upper = Some(lower);
+                            }
+                            lower
+                        },
+                        None => self.saved_lower
+                            .take()
+                            .expect("interval is not partial"),
+                    };
 
                     // ... and the next tine after must be a lower bound.
                     debug_assert!(lower.is_lower_bound());
 
-                    let upper = upper.into_inner();
-                    let lower = lower.into_inner();
-                    RawInterval::new(lower, upper)
+                    RawIntervalRef::new(lower.bound(), upper.bound())
                 }
-            })
+            });
+        if next.is_some() {
+            if let Some(n) = self.remaining.as_mut() { *n -= 1; }
+        }
+        next
     }
 }
 
+impl<'t, T> FusedIterator for RefIter<'t, T> where T: Ord + Clone {}
+
+////////////////////////////////////////////////////////////////////////////////
+// TineSet
+////////////////////////////////////////////////////////////////////////////////
+/// The largest number of `Tine`s stored in a sorted `Vec` before a `TineSet`
+/// switches to a `BTreeSet`.
+const SMALL_TINES: usize = 16;
+
+/// The number of `Tine`s held by each node of a `BTreeSet`, used to estimate
+/// its memory use.
+const BTREE_NODE_TINES: usize = 11;
+
+/// An ordered set of `Tine`s which tracks how many of them are point tines.
+#[derive(Debug, Clone)]
+struct TineSet<T> {
+    tines: Tines<T>,
+    points: usize,
+}
+
+/// The storage of a `TineSet`. Most selections have only a few intervals, for
+/// which a sorted `Vec` is much cheaper than a `BTreeSet`.
+#[derive(Debug, Clone)]
+enum Tines<T> {
+    Small(Vec<Tine<T>>),
+    Large(BTreeSet<Tine<T>>),
+}
+
+impl<T> TineSet<T> where T: Ord + Clone {
+    /// Constructs an empty `TineSet`.
+    const fn new() -> Self {
+        TineSet { tines: Tines::Small(Vec::new()), points: 0 }
+    }
+
+    /// Returns the number of `Tine`s in the set.
+    fn len(&self) -> usize {
+        match self.tines {
+            Tines::Small(ref tines) => tines.len(),
+            Tines::Large(ref tines) => tines.len(),
+        }
+    }
+
+    /// Returns `true` if the set contains no `Tine`s.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an estimate of the number of bytes of heap memory used by the
+    /// set.
+    fn heap_size_estimate(&self) -> usize {
+        let tine_size = size_of::<Tine<T>>();
+        match self.tines {
+            Tines::Small(ref tines) => tines.capacity() * tine_size,
+            Tines::Large(ref tines) => {
+                // Assume the B-tree's nodes are typically two thirds full,
+                // with one internal node for every few leaves.
+                let node_size = BTREE_NODE_TINES * tine_size
+                    + 2 * size_of::<usize>();
+                let edges_size = (BTREE_NODE_TINES + 1)
+                    * size_of::<usize>();
+                let leaves = (tines.len() * 3).div_ceil(2 * BTREE_NODE_TINES);
+                let internal = leaves / (2 * BTREE_NODE_TINES / 3);
+                leaves * node_size + internal * (node_size + edges_size)
+            },
+        }
+    }
+
+    /// Returns an iterator over the `Tine`s in the set.
+    fn iter(&self) -> TineSetRange<'_, T> {
+        self.range(..)
+    }
+
+    /// Returns an iterator over the `Tine`s in the given range.
+    fn range<R>(&self, range: R) -> TineSetRange<'_, T>
+        where R: RangeBounds<Tine<T>>
+    {
+        use std::ops::Bound::*;
+        match self.tines {
+            Tines::Small(ref tines) => {
+                let start = match range.start_bound() {
+                    Included(t) => tines.partition_point(|x| x < t),
+                    Excluded(t) => tines.partition_point(|x| x <= t),
+                    Unbounded   => 0,
+                };
+                let end = match range.end_bound() {
+                    Included(t) => tines.partition_point(|x| x <= t),
+                    Excluded(t) => tines.partition_point(|x| x < t),
+                    Unbounded   => tines.len(),
+                };
+                TineSetRange::Small(tines[start.min(end)..end].iter())
+            },
+            Tines::Large(ref tines) => TineSetRange::Large(tines.range(range)),
+        }
+    }
+
+    /// Adds a `Tine` to the set, returning `false` if a `Tine` at the same
+    /// point is already present.
+    fn insert(&mut self, tine: Tine<T>) -> bool {
+        let is_point = tine.is_point();
+        let inserted = match self.tines {
+            Tines::Small(ref mut tines) => match tines.binary_search(&tine) {
+                Ok(_)      => false,
+                Err(index) => { tines.insert(index, tine); true },
+            },
+            Tines::Large(ref mut tines) => tines.insert(tine),
+        };
+        if inserted && is_point { self.points += 1; }
+        self.rebalance();
+        inserted
+    }
+
+    /// Removes and returns the `Tine` at the same point as the given `Tine`.
+    fn take(&mut self, tine: &Tine<T>) -> Option<Tine<T>> {
+        let taken = match self.tines {
+            Tines::Small(ref mut tines) => tines
+                .binary_search(tine)
+                .ok()
+                .map(|index| tines.remove(index)),
+            Tines::Large(ref mut tines) => tines.take(tine),
+        };
+        if taken.as_ref().is_some_and(Tine::is_point) { self.points -= 1; }
+        self.rebalance();
+        taken
+    }
+
+    /// Splits the set at the given `Tine`, returning everything at or after
+    /// it.
+    fn split_off(&mut self, tine: &Tine<T>) -> Self {
+        let tines = match self.tines {
+            Tines::Small(ref mut tines) => {
+                let index = tines.partition_point(|x| x < tine);
+                Tines::Small(tines.split_off(index))
+            },
+            Tines::Large(ref mut tines) => Tines::Large(tines.split_off(tine)),
+        };
+        let mut split = TineSet { tines, points: 0 };
+        split.points = split.iter().filter(|t| t.is_point()).count();
+        self.points -= split.points;
+        self.rebalance();
+        split.rebalance();
+        split
+    }
+
+    /// Moves all `Tine`s from the given set into this one.
+    fn append(&mut self, other: &mut Self) {
+        let others = std::mem::replace(
+            &mut other.tines,
+            Tines::Small(Vec::new()));
+        let points = std::mem::replace(&mut other.points, 0);
+        match (&mut self.tines, others) {
+            // Sets split from one another are appended in order.
+            (&mut Tines::Small(ref mut tines), Tines::Small(mut others))
+                if tines.last().is_none_or(|last|
+                    others.first().is_none_or(|first| last < first)) =>
+            {
+                tines.append(&mut others);
+                self.points += points;
+            },
+            (&mut Tines::Large(ref mut tines), Tines::Large(mut others)) => {
+                tines.append(&mut others);
+                self.points += points;
+            },
+            (_, Tines::Small(others)) => {
+                for tine in others { self.insert(tine); }
+            },
+            (_, Tines::Large(others)) => {
+                for tine in others { self.insert(tine); }
+            },
+        }
+        self.rebalance();
+    }
+
+    /// Switches the storage of the set according to its size. The sizes at
+    /// which it switches differ so that a set near the limit doesn't switch
+    /// back and forth.
+    fn rebalance(&mut self) {
+        let tines = match self.tines {
+            Tines::Small(ref mut tines) if tines.len() > SMALL_TINES => {
+                Tines::Large(tines.drain(..).collect())
+            },
+            Tines::Large(ref mut tines) if tines.len() <= SMALL_TINES / 2 => {
+                Tines::Small(std::mem::take(tines).into_iter().collect())
+            },
+            _ => return,
+        };
+        self.tines = tines;
+    }
+}
+
+// The point count is derived from the tines, and the storage depends on the
+// history of the set, so both are ignored for equality and hashing.
+impl<T> PartialEq for TineSet<T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        use Tines::*;
+        match (&self.tines, &other.tines) {
+            (Small(a), Small(b)) => a == b,
+            (Large(a), Large(b)) => a == b,
+            (Small(a), Large(b)) |
+            (Large(b), Small(a)) => a.len() == b.len() && a.iter().eq(b),
+        }
+    }
+}
+
+impl<T> Eq for TineSet<T> where T: Eq {}
+
+impl<T> Hash for TineSet<T> where T: Hash {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self.tines {
+            Tines::Small(ref tines) => {
+                state.write_usize(tines.len());
+                for tine in tines { tine.hash(state); }
+            },
+            Tines::Large(ref tines) => {
+                state.write_usize(tines.len());
+                for tine in tines { tine.hash(state); }
+            },
+        }
+    }
+}
+
+impl<T> FromIterator<Tine<T>> for TineSet<T> where T: Ord + Clone {
+    fn from_iter<I>(iter: I) -> Self where I: IntoIterator<Item=Tine<T>> {
+        let mut set = TineSet::new();
+        for tine in iter {
+            set.insert(tine);
+        }
+        set
+    }
+}
+
+impl<T> IntoIterator for TineSet<T> {
+    type Item = Tine<T>;
+    type IntoIter = TineSetIntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match self.tines {
+            Tines::Small(tines) => TineSetIntoIter::Small(tines.into_iter()),
+            Tines::Large(tines) => TineSetIntoIter::Large(tines.into_iter()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// TineSetRange
+////////////////////////////////////////////////////////////////////////////////
+/// An `Iterator` over a range of the `Tine`s in a `TineSet`.
+#[derive(Debug, Clone)]
+enum TineSetRange<'t, T> {
+    Small(std::slice::Iter<'t, Tine<T>>),
+    Large(btree_set::Range<'t, Tine<T>>),
+}
+
+impl<'t, T> Iterator for TineSetRange<'t, T> {
+    type Item = &'t Tine<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            TineSetRange::Small(iter) => iter.next(),
+            TineSetRange::Large(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            TineSetRange::Small(iter) => iter.size_hint(),
+            TineSetRange::Large(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'t, T> DoubleEndedIterator for TineSetRange<'t, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            TineSetRange::Small(iter) => iter.next_back(),
+            TineSetRange::Large(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<'t, T> FusedIterator for TineSetRange<'t, T> {}
+
+////////////////////////////////////////////////////////////////////////////////
+// TineSetIntoIter
+////////////////////////////////////////////////////////////////////////////////
+/// An owning `Iterator` over the `Tine`s in a `TineSet`.
+#[derive(Debug)]
+enum TineSetIntoIter<T> {
+    Small(std::vec::IntoIter<Tine<T>>),
+    Large(btree_set::IntoIter<Tine<T>>),
+}
+
+impl<T> Iterator for TineSetIntoIter<T> {
+    type Item = Tine<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            TineSetIntoIter::Small(iter) => iter.next(),
+            TineSetIntoIter::Large(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            TineSetIntoIter::Small(iter) => iter.size_hint(),
+            TineSetIntoIter::Large(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for TineSetIntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            TineSetIntoIter::Small(iter) => iter.next_back(),
+            TineSetIntoIter::Large(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T> FusedIterator for TineSetIntoIter<T> {}
+
 ////////////////////////////////////////////////////////////////////////////////
 // TreeSplit
 ////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,333 @@
+// Copyright 2018 Skylor R. Schermer.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+////////////////////////////////////////////////////////////////////////////////
+//!
+//! Provides predefined `Selection<char>`s for Unicode character properties.
+//!
+//! The selections are built from tables generated from the Unicode Character
+//! Database by `scripts/unicode_tables.py`. The version of the database used
+//! is given by [`UNICODE_VERSION`].
+//!
+//! [`UNICODE_VERSION`]: constant.UNICODE_VERSION.html
+//!
+////////////////////////////////////////////////////////////////////////////////
+
+// Local imports.
+use crate::interval::Interval;
+use crate::selection::Selection;
+use crate::unicode_tables::ALPHABETIC;
+use crate::unicode_tables::GENERAL_CATEGORY;
+use crate::unicode_tables::GENERAL_CATEGORY_NAMES;
+use crate::unicode_tables::SCRIPT;
+use crate::unicode_tables::SCRIPT_NAMES;
+use crate::unicode_tables::WHITE_SPACE;
+
+// Standard library imports.
+use std::str::FromStr;
+
+// Exports.
+pub use crate::unicode_tables::GeneralCategory;
+pub use crate::unicode_tables::Script;
+pub use crate::unicode_tables::UNICODE_VERSION;
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Selection constructors
+////////////////////////////////////////////////////////////////////////////////
+impl Selection<char> {
+    /// Returns the `Selection` of `char`s in the given general category.
+    ///
+    /// Since surrogate code points are not `char`s, the selection for
+    /// [`GeneralCategory::Surrogate`] is empty.
+    ///
+    /// [`GeneralCategory::Surrogate`]: enum.GeneralCategory.html
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::unicode::GeneralCategory;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let upper = Selection::general_category(
+    ///     GeneralCategory::UppercaseLetter);
+    ///
+    /// assert!(upper.contains(&'A'));
+    /// assert!(upper.contains(&'Ω'));
+    /// assert!(!upper.contains(&'a'));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn general_category(category: GeneralCategory) -> Self {
+        Selection::from_sorted_disjoint(GENERAL_CATEGORY
+            .iter()
+            .filter(|&&(_, _, c)| c == category)
+            .map(|&(lo, hi, _)| Interval::closed(lo, hi)))
+    }
+
+    /// Returns the `Selection` of `char`s in the given script.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # use normalize_interval::unicode::Script;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let greek = Selection::script(Script::Greek);
+    ///
+    /// assert!(greek.contains(&'λ'));
+    /// assert!(!greek.contains(&'l'));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn script(script: Script) -> Self {
+        Selection::from_sorted_disjoint(SCRIPT
+            .iter()
+            .filter(|&&(_, _, s)| s == script)
+            .map(|&(lo, hi, _)| Interval::closed(lo, hi)))
+    }
+
+    /// Returns the `Selection` of `char`s with the `White_Space` property.
+    ///
+    /// These are the `char`s for which [`char::is_whitespace`] returns `true`
+    /// under the same version of Unicode.
+    ///
+    /// [`char::is_whitespace`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_whitespace
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let ws = Selection::whitespace();
+    ///
+    /// assert!(ws.contains(&' '));
+    /// assert!(ws.contains(&'\u{3000}'));
+    /// assert!(!ws.contains(&'_'));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn whitespace() -> Self {
+        from_ranges(WHITE_SPACE)
+    }
+
+    /// Returns the `Selection` of `char`s with the `Alphabetic` property.
+    ///
+    /// These are the `char`s for which [`char::is_alphabetic`] returns `true`
+    /// under the same version of Unicode.
+    ///
+    /// [`char::is_alphabetic`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_alphabetic
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let alpha = Selection::alphabetic();
+    ///
+    /// assert!(alpha.contains(&'q'));
+    /// assert!(alpha.contains(&'京'));
+    /// assert!(!alpha.contains(&'7'));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn alphabetic() -> Self {
+        from_ranges(ALPHABETIC)
+    }
+
+    /// Returns the `Selection` of `char`s in the `Nd`, `Nl`, or `No` general
+    /// categories.
+    ///
+    /// These are the `char`s for which [`char::is_numeric`] returns `true`
+    /// under the same version of Unicode.
+    ///
+    /// [`char::is_numeric`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_numeric
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let numeric = Selection::numeric();
+    ///
+    /// assert!(numeric.contains(&'7'));
+    /// assert!(numeric.contains(&'Ⅻ'));
+    /// assert!(!numeric.contains(&'x'));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn numeric() -> Self {
+        use GeneralCategory::*;
+        Selection::from_sorted_disjoint(GENERAL_CATEGORY
+            .iter()
+            .filter(|&&(_, _, c)| matches!(c,
+                DecimalNumber | LetterNumber | OtherNumber))
+            .map(|&(lo, hi, _)| Interval::closed(lo, hi)))
+    }
+
+    /// Returns the `Selection` of `char`s which are alphabetic or numeric.
+    ///
+    /// These are the `char`s for which [`char::is_alphanumeric`] returns
+    /// `true` under the same version of Unicode.
+    ///
+    /// [`char::is_alphanumeric`]: https://doc.rust-lang.org/std/primitive.char.html#method.is_alphanumeric
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::Selection;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// let alnum = Selection::alphanumeric();
+    ///
+    /// assert!(alnum.contains(&'q'));
+    /// assert!(alnum.contains(&'7'));
+    /// assert!(!alnum.contains(&'-'));
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn alphanumeric() -> Self {
+        Selection::alphabetic().union(&Selection::numeric())
+    }
+}
+
+// Builds a `Selection` from a sorted table of disjoint ranges.
+fn from_ranges(ranges: &[(char, char)]) -> Selection<char> {
+    Selection::from_sorted_disjoint(ranges
+        .iter()
+        .map(|&(lo, hi)| Interval::closed(lo, hi)))
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Property lookup
+////////////////////////////////////////////////////////////////////////////////
+impl GeneralCategory {
+    /// Returns the general category of the given `char`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::unicode::GeneralCategory;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(GeneralCategory::of('a'), GeneralCategory::LowercaseLetter);
+    /// assert_eq!(GeneralCategory::of('€'), GeneralCategory::CurrencySymbol);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn of(c: char) -> Self {
+        lookup(GENERAL_CATEGORY, c).unwrap_or(GeneralCategory::Unassigned)
+    }
+}
+
+impl Script {
+    /// Returns the script of the given `char`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use std::error::Error;
+    /// # use normalize_interval::unicode::Script;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # //-------------------------------------------------------------------
+    /// assert_eq!(Script::of('ж'), Script::Cyrillic);
+    /// assert_eq!(Script::of('!'), Script::Common);
+    /// # //-------------------------------------------------------------------
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn of(c: char) -> Self {
+        lookup(SCRIPT, c).unwrap_or(Script::Unknown)
+    }
+}
+
+// Returns the value of the range containing the given `char` in a sorted
+// table of disjoint ranges.
+fn lookup<V: Copy>(table: &[(char, char, V)], c: char) -> Option<V> {
+    let idx = match table.binary_search_by(|&(lo, _, _)| lo.cmp(&c)) {
+        Ok(idx)  => idx,
+        Err(0)   => return None,
+        Err(idx) => idx - 1,
+    };
+    let (_, hi, value) = table[idx];
+    if c <= hi { Some(value) } else { None }
+}
+
+
+////////////////////////////////////////////////////////////////////////////////
+// Parsing
+////////////////////////////////////////////////////////////////////////////////
+/// An error which can be returned when parsing a [`GeneralCategory`] or
+/// [`Script`] from an unknown name.
+///
+/// [`GeneralCategory`]: enum.GeneralCategory.html
+/// [`Script`]: enum.Script.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParsePropertyValueError;
+
+impl std::fmt::Display for ParsePropertyValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown Unicode property value name")
+    }
+}
+
+impl std::error::Error for ParsePropertyValueError {}
+
+// Parses a general category from its abbreviation or long name, matched
+// loosely as described in UAX #44.
+impl FromStr for GeneralCategory {
+    type Err = ParsePropertyValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_name(GENERAL_CATEGORY_NAMES, s)
+    }
+}
+
+// Parses a script from its abbreviation or long name, matched loosely as
+// described in UAX #44.
+impl FromStr for Script {
+    type Err = ParsePropertyValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_name(SCRIPT_NAMES, s)
+    }
+}
+
+// Looks up a property value in a sorted table of loosely matched names.
+fn parse_name<V: Copy>(table: &[(&str, V)], s: &str)
+    -> Result<V, ParsePropertyValueError>
+{
+    let key: String = s.chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect();
+    let key = key.strip_prefix("is").unwrap_or(&key);
+    table
+        .binary_search_by(|&(name, _)| name.cmp(key))
+        .map(|idx| table[idx].1)
+        .map_err(|_| ParsePropertyValueError)
+}